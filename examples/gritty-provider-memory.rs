@@ -0,0 +1,295 @@
+//! A reference provider plugin, keeping all repositories in memory.
+//!
+//! It implements the plugin protocol described in [gritty::remote::external], and is used by the
+//! test suite to check the round trip between gritty and a plugin. To try it out, build it with
+//! `cargo build --example gritty-provider-memory` and add a remote using it:
+//! ```toml
+//! [remotes.memory]
+//! provider = { External = { command = "target/debug/examples/gritty-provider-memory" } }
+//! url = "https://memory.invalid"
+//! username = "alice"
+//! clone_protocol = "https"
+//! ```
+
+use std::{
+    collections::BTreeMap,
+    io::{self, BufRead, Write},
+};
+
+use gritty::{
+    error::Error,
+    remote::{
+        external::{METHOD_NOT_FOUND, PROTOCOL_VERSION},
+        ListReposInfo, RemoteConfig, RepoCreateInfo, RepoForkOption, RepoRef, RepoUpdateInfo,
+        Repository, Visibility,
+    },
+};
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+#[derive(Deserialize)]
+struct Request {
+    id: Value,
+    method: String,
+    #[serde(default)]
+    params: Value,
+}
+
+#[derive(Deserialize)]
+struct InitializeParams {
+    protocol_version: u32,
+    config: RemoteConfig,
+}
+
+#[derive(Deserialize)]
+struct RepoParams {
+    owner: String,
+    name: String,
+}
+
+#[derive(Deserialize)]
+struct UpdateParams {
+    owner: String,
+    name: String,
+    update: RepoUpdateInfo,
+}
+
+#[derive(Deserialize)]
+struct TransferParams {
+    owner: String,
+    name: String,
+    new_owner: String,
+}
+
+#[derive(Deserialize)]
+struct RenameParams {
+    owner: String,
+    name: String,
+    new_name: String,
+}
+
+#[derive(Default)]
+struct Plugin {
+    config: Option<RemoteConfig>,
+    repos: BTreeMap<(String, String), Repository>,
+}
+
+/// The result of a request, or the JSON-RPC error code and the error to send.
+type Response = Result<Value, (i64, Error)>;
+
+fn params<T: for<'de> Deserialize<'de>>(params: Value) -> Result<T, (i64, Error)> {
+    serde_json::from_value(params).map_err(|err| (-32602, err.into()))
+}
+
+fn failure(err: Error) -> (i64, Error) {
+    (1, err)
+}
+
+impl Plugin {
+    fn username(&self) -> String {
+        self.config
+            .as_ref()
+            .map(|x| x.username.clone())
+            .unwrap_or_default()
+    }
+
+    fn repo(&self, owner: String, name: String, fork: bool) -> Repository {
+        let url = self.config.as_ref().map(|x| x.url.as_str()).unwrap_or("");
+        let host = url.trim_start_matches("https://");
+        Repository {
+            ssh_url: format!("git@{host}:{owner}/{name}.git"),
+            clone_url: format!("{url}/{owner}/{name}.git"),
+            full_name: format!("{owner}/{name}"),
+            name,
+            owner,
+            fork,
+            default_branch: Some("main".to_string()),
+            ..Default::default()
+        }
+    }
+
+    fn insert(&mut self, repo: Repository) -> Response {
+        let key = (repo.owner.clone(), repo.name.clone());
+        if self.repos.contains_key(&key) {
+            let message = format!("Repository {}/{} already exists", key.0, key.1);
+            return Err(failure(Error {
+                status: Some(409),
+                ..Error::other(message)
+            }));
+        }
+        self.repos.insert(key, repo.clone());
+        Ok(json!(repo))
+    }
+
+    fn get(&self, owner: &str, name: &str) -> Result<&Repository, (i64, Error)> {
+        self.repos
+            .get(&(owner.to_string(), name.to_string()))
+            .ok_or_else(|| {
+                let message = format!("Repository {owner}/{name} not found");
+                failure(Error {
+                    status: Some(404),
+                    ..Error::not_found(message)
+                })
+            })
+    }
+
+    /// Move a repository to another owner or name, keeping everything else.
+    fn move_repo(
+        &mut self,
+        owner: String,
+        name: String,
+        new_owner: String,
+        new_name: String,
+    ) -> Response {
+        let old = self.get(&owner, &name)?.clone();
+        let moved = self.repo(new_owner, new_name, old.fork);
+        let repo = self.insert(Repository {
+            name: moved.name,
+            owner: moved.owner,
+            full_name: moved.full_name,
+            ssh_url: moved.ssh_url,
+            clone_url: moved.clone_url,
+            ..old
+        })?;
+        self.repos.remove(&(owner, name));
+        Ok(repo)
+    }
+
+    fn handle(&mut self, method: &str, params_value: Value) -> Response {
+        match method {
+            "initialize" => {
+                let init: InitializeParams = params(params_value)?;
+                if init.protocol_version != PROTOCOL_VERSION {
+                    eprintln!("gritty speaks protocol version {}", init.protocol_version);
+                }
+                self.config = Some(init.config);
+                Ok(json!({ "protocol_version": PROTOCOL_VERSION }))
+            }
+            "check_auth" => Ok(json!(true)),
+            "create_repo" => {
+                let info: RepoCreateInfo = params(params_value)?;
+                let owner = info.owner.clone().unwrap_or_else(|| self.username());
+                let mut repo = self.repo(owner, info.name, false);
+                repo.description = info.description;
+                repo.private = info.private;
+                if info.private {
+                    repo.visibility = Visibility::Private;
+                }
+                self.insert(repo)
+            }
+            "create_fork" => {
+                let options: RepoForkOption = params(params_value)?;
+                let parent = self.get(&options.owner, &options.repo)?.clone();
+                let owner = options.organization.unwrap_or_else(|| self.username());
+                let name = options.name.unwrap_or(options.repo);
+                let mut repo = self.repo(owner, name, true);
+                repo.description = parent.description;
+                repo.private = parent.private;
+                repo.visibility = parent.visibility;
+                repo.parent = Some(RepoRef {
+                    owner: parent.owner,
+                    name: parent.name,
+                });
+                self.insert(repo)
+            }
+            "list_repos" => {
+                let info: ListReposInfo = params(params_value)?;
+                let owner = info.owner.clone().unwrap_or_else(|| self.username());
+                let repos = self
+                    .repos
+                    .values()
+                    .filter(|x| x.owner == owner)
+                    .filter(|x| info.includes(x.private, x.fork, x.archived))
+                    .collect();
+                Ok(json!(info.paginate(repos)))
+            }
+            "get_repo_info" => {
+                let repo: RepoParams = params(params_value)?;
+                Ok(json!(self.get(&repo.owner, &repo.name)?))
+            }
+            "update_repo" => {
+                let UpdateParams {
+                    owner,
+                    name,
+                    update,
+                } = params(params_value)?;
+                self.get(&owner, &name)?;
+                let repo = self.repos.get_mut(&(owner, name)).expect("checked above");
+                if let Some(description) = update.description {
+                    repo.description = Some(description);
+                }
+                if let Some(visibility) = update.visibility {
+                    repo.visibility = visibility;
+                    repo.private = visibility != Visibility::Public;
+                }
+                if let Some(branch) = update.default_branch {
+                    repo.default_branch = Some(branch);
+                }
+                Ok(json!(repo))
+            }
+            "archive_repo" | "unarchive_repo" => {
+                let repo: RepoParams = params(params_value)?;
+                self.get(&repo.owner, &repo.name)?;
+                let repo = self
+                    .repos
+                    .get_mut(&(repo.owner, repo.name))
+                    .expect("checked above");
+                repo.archived = method == "archive_repo";
+                Ok(json!(repo))
+            }
+            "transfer_repo" => {
+                let TransferParams {
+                    owner,
+                    name,
+                    new_owner,
+                } = params(params_value)?;
+                self.move_repo(owner, name.clone(), new_owner, name)
+            }
+            "rename_repo" => {
+                let RenameParams {
+                    owner,
+                    name,
+                    new_name,
+                } = params(params_value)?;
+                self.move_repo(owner.clone(), name, owner, new_name)
+            }
+            "delete_repo" => {
+                let repo: RepoParams = params(params_value)?;
+                self.get(&repo.owner, &repo.name)?;
+                self.repos.remove(&(repo.owner, repo.name));
+                Ok(Value::Null)
+            }
+            _ => Err((
+                METHOD_NOT_FOUND,
+                Error::other(format!("Unknown method {method}")),
+            )),
+        }
+    }
+}
+
+fn main() -> io::Result<()> {
+    let mut plugin = Plugin::default();
+    let mut stdout = io::stdout().lock();
+    // Every line is a request, and we're done once gritty closes stdin.
+    for line in io::stdin().lock().lines() {
+        let line = line?;
+        let response = match serde_json::from_str::<Request>(&line) {
+            Ok(request) => match plugin.handle(&request.method, request.params) {
+                Ok(result) => json!({ "jsonrpc": "2.0", "id": request.id, "result": result }),
+                Err((code, err)) => json!({
+                    "jsonrpc": "2.0",
+                    "id": request.id,
+                    "error": { "code": code, "message": err.message, "data": err },
+                }),
+            },
+            Err(err) => json!({
+                "jsonrpc": "2.0",
+                "id": null,
+                "error": { "code": -32700, "message": err.to_string() },
+            }),
+        };
+        writeln!(stdout, "{response}")?;
+        stdout.flush()?;
+    }
+    Ok(())
+}
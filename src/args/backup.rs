@@ -0,0 +1,37 @@
+use std::path::PathBuf;
+
+use clap::Parser;
+
+#[derive(Debug, Clone, Parser)]
+/// Back up every repository of a remote as mirror clones
+///
+/// Repositories are mirrored to `<dir>/<remote>/<owner>/<repo>.git`. Running the backup again
+/// fetches the changes into the existing mirrors, pruning deleted branches and tags.
+/// `<dir>/<remote>/manifest.json` lists the repositories with their details and the outcome of
+/// the backup.
+pub struct Backup {
+    #[arg()]
+    /// Name of the remote as defined in the config (ex: 'github').
+    pub remote: String,
+
+    #[arg()]
+    /// Directory to keep the backups in.
+    pub dir: PathBuf,
+
+    #[arg(short, long)]
+    /// Only back up the repositories of this organization or group.
+    ///
+    /// If not provided, the repositories the authenticated user owns are backed up.
+    pub org: Option<String>,
+
+    #[arg(long, conflicts_with = "org")]
+    /// Also back up the repositories the authenticated user can access as a member of an
+    /// organization or group, or as a collaborator.
+    pub all_affiliations: bool,
+
+    #[arg(short, long)]
+    /// Back up this many repositories at the same time.
+    ///
+    /// Defaults to the 'concurrency' setting of the remote.
+    pub jobs: Option<usize>,
+}
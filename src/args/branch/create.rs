@@ -0,0 +1,34 @@
+use clap::Parser;
+
+use crate::args::{repo::RepoName, OutputFormat};
+
+#[derive(Debug, Clone, Parser)]
+/// Create a branch without a local clone
+pub struct Create {
+    #[arg()]
+    /// Name of the repository.
+    ///
+    /// Use `owner/repo` for a repository owned by an organization or group.
+    /// If no owner is given, the repository must be owned by the authenticated user.
+    pub name: RepoName,
+
+    #[arg()]
+    /// Name of the remote as defined in the config (ex: 'github').
+    pub remote: String,
+
+    #[arg()]
+    /// Name of the new branch.
+    pub branch: String,
+
+    #[arg(long)]
+    /// Branch, tag or commit to create the branch from.
+    ///
+    /// Defaults to the default branch of the repository.
+    pub from: Option<String>,
+
+    #[arg(long)]
+    /// Change the output format to the specified value.
+    ///
+    /// When using the 'json' format, gritty will output the new branch in JSON.
+    pub format: Option<OutputFormat>,
+}
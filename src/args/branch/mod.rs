@@ -0,0 +1,27 @@
+use clap::{Parser, Subcommand};
+
+pub mod create;
+pub mod delete;
+pub mod list;
+pub mod protect;
+
+pub use create::Create;
+pub use delete::Delete;
+pub use list::List;
+pub use protect::Protect;
+
+#[derive(Debug, Clone, Parser)]
+#[command()]
+/// Manage the branches of a repository.
+pub struct Branch {
+    #[command(subcommand)]
+    pub subcommand: BranchCommands,
+}
+
+#[derive(Debug, Clone, Subcommand)]
+pub enum BranchCommands {
+    List(List),
+    Create(Create),
+    Delete(Delete),
+    Protect(Protect),
+}
@@ -0,0 +1,42 @@
+use clap::Parser;
+
+use crate::args::repo::RepoName;
+
+#[derive(Debug, Clone, Parser)]
+/// Replace the protection rules of a branch.
+///
+/// Settings a provider can't represent are reported and left out.
+pub struct Protect {
+    #[arg()]
+    /// Name of the repository.
+    ///
+    /// Use `owner/repo` for a repository owned by an organization or group.
+    /// If no owner is given, the repository must be owned by the authenticated user.
+    pub name: RepoName,
+
+    #[arg()]
+    /// Name of the remote as defined in the config (ex: 'github').
+    pub remote: String,
+
+    #[arg()]
+    /// Name of the branch.
+    pub branch: String,
+
+    #[arg(long, default_value_t = 0)]
+    /// Number of approving reviews a pull request needs before it can be merged.
+    pub required_reviews: u32,
+
+    #[arg(long = "required-check", value_name = "CHECK")]
+    /// Status check that must pass before a pull request can be merged. Can be repeated.
+    pub required_checks: Vec<String>,
+
+    #[arg(long)]
+    /// Reject force-pushes to the branch.
+    pub no_force_push: bool,
+
+    #[arg(long = "allow-push", value_name = "USER")]
+    /// Only allow these users to push to the branch. Can be repeated.
+    ///
+    /// By default, anyone with write access to the repository can push.
+    pub allow_push: Vec<String>,
+}
@@ -0,0 +1,34 @@
+use std::path::PathBuf;
+
+use clap::Parser;
+
+use crate::args::repo::RepoName;
+
+#[derive(Debug, Clone, Parser)]
+/// Add a deploy key to a repository. Deploy keys are read-only unless --read-write is given
+pub struct Add {
+    #[arg()]
+    /// Name of the repository.
+    ///
+    /// Use `owner/repo` for a repository owned by an organization or group.
+    /// If no owner is given, the repository must be owned by the authenticated user.
+    pub name: RepoName,
+
+    #[arg()]
+    /// Name of the remote as defined in the config (ex: 'github').
+    pub remote: String,
+
+    #[arg()]
+    /// Path to the public key (ex: '~/.ssh/deploy.pub').
+    pub key: PathBuf,
+
+    #[arg(short, long)]
+    /// Title of the key on the remote.
+    ///
+    /// Defaults to the comment of the key, or the file name if it has none.
+    pub title: Option<String>,
+
+    #[arg(long)]
+    /// Allow the key to push to the repository.
+    pub read_write: bool,
+}
@@ -0,0 +1,24 @@
+use clap::{Parser, Subcommand};
+
+pub mod add;
+pub mod list;
+pub mod remove;
+
+pub use add::Add;
+pub use list::List;
+pub use remove::Remove;
+
+#[derive(Debug, Clone, Parser)]
+#[command()]
+/// Manage the deploy keys of a repository.
+pub struct DeployKey {
+    #[command(subcommand)]
+    pub subcommand: DeployKeyCommands,
+}
+
+#[derive(Debug, Clone, Subcommand)]
+pub enum DeployKeyCommands {
+    List(List),
+    Add(Add),
+    Remove(Remove),
+}
@@ -0,0 +1,27 @@
+use clap::Parser;
+
+use crate::args::repo::RepoName;
+
+#[derive(Debug, Clone, Parser)]
+#[command(aliases = &["rm"])]
+/// Remove a deploy key from a repository
+pub struct Remove {
+    #[arg()]
+    /// Name of the repository.
+    ///
+    /// Use `owner/repo` for a repository owned by an organization or group.
+    /// If no owner is given, the repository must be owned by the authenticated user.
+    pub name: RepoName,
+
+    #[arg()]
+    /// Name of the remote as defined in the config (ex: 'github').
+    pub remote: String,
+
+    #[arg()]
+    /// ID of the key, as shown by `gritty deploy-key list`.
+    pub id: u64,
+
+    #[arg(short, long)]
+    /// Force removal without confirmation.
+    pub force: bool,
+}
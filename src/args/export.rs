@@ -0,0 +1,58 @@
+use std::path::PathBuf;
+
+use clap::Parser;
+
+use super::repo::RepoName;
+
+#[derive(Debug, Clone, Parser)]
+/// Export the issues, pull requests, releases and wiki of a repository
+///
+/// Labels, milestones, issues and pull requests with their comments, releases and wiki pages are
+/// written as JSON, in the same format for every remote. The export can be imported into a
+/// repository on any remote with `gritty import`. The git data itself is not exported, use
+/// `gritty backup` or `gritty repo migrate` for that.
+pub struct Export {
+    #[arg()]
+    /// Name of the repository to export.
+    ///
+    /// Use `owner/repo` to export a repository owned by an organization or group.
+    /// If no owner is given, the repository must be owned by the authenticated user.
+    pub name: RepoName,
+
+    #[arg()]
+    /// Name of the remote as defined in the config (ex: 'github').
+    pub remote: String,
+
+    #[arg(short, long)]
+    /// Where to write the export.
+    ///
+    /// Paths ending in `.tar.zst` are written as a compressed archive, anything else as a
+    /// directory of JSON files. Defaults to `<repo>.tar.zst`.
+    pub output: Option<PathBuf>,
+}
+
+#[derive(Debug, Clone, Parser)]
+/// Recreate an export in a repository
+///
+/// The repository is created if it doesn't exist. Labels, milestones, releases, wiki pages and
+/// issues that already exist, matched by name or title, are kept, so an import can be run again.
+/// Issues and pull requests are recreated as issues in the order they were opened, so their
+/// numbers carry over to a repository without any. Pull requests can't be
+/// recreated without their branches, so they become issues describing them. Issues, pull requests
+/// and comments are created by the authenticated user, and mention their original author.
+pub struct Import {
+    #[arg()]
+    /// Name of the repository to import into.
+    ///
+    /// Use `owner/repo` to import into a repository owned by an organization or group.
+    /// If no owner is given, the repository is owned by the authenticated user.
+    pub name: RepoName,
+
+    #[arg()]
+    /// Name of the remote as defined in the config (ex: 'github').
+    pub remote: String,
+
+    #[arg(short, long)]
+    /// The export to import, as written by `gritty export`: a `.tar.zst` archive or a directory.
+    pub input: PathBuf,
+}
@@ -0,0 +1,47 @@
+use clap::Parser;
+
+use crate::args::{repo::RepoName, OutputFormat};
+use crate::remote::{WebhookContentType, WebhookEvent};
+
+#[derive(Debug, Clone, Parser)]
+/// Add a webhook to a repository
+pub struct Create {
+    #[arg()]
+    /// Name of the repository.
+    ///
+    /// Use `owner/repo` for a repository owned by an organization or group.
+    /// If no owner is given, the repository must be owned by the authenticated user.
+    pub name: RepoName,
+
+    #[arg()]
+    /// Name of the remote as defined in the config (ex: 'github').
+    pub remote: String,
+
+    #[arg(long)]
+    /// URL to send deliveries to.
+    pub url: String,
+
+    #[arg(long)]
+    /// Secret used to sign deliveries.
+    pub secret: Option<String>,
+
+    #[arg(long, default_value = "json")]
+    /// Encoding of the delivered payload. Gitlab only supports 'json'.
+    pub content_type: WebhookContentType,
+
+    #[arg(short, long = "event", default_value = "push")]
+    /// Event to deliver. Can be given multiple times.
+    ///
+    /// The events are mapped to the closest events of the provider.
+    pub events: Vec<WebhookEvent>,
+
+    #[arg(long)]
+    /// Create the webhook without sending deliveries. Not supported by Gitlab.
+    pub inactive: bool,
+
+    #[arg(long)]
+    /// Change the output format to the specified value.
+    ///
+    /// When using the 'json' format, gritty will output the new webhook in JSON.
+    pub format: Option<OutputFormat>,
+}
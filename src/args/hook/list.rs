@@ -0,0 +1,24 @@
+use clap::Parser;
+
+use crate::args::{repo::RepoName, OutputFormat};
+
+#[derive(Debug, Clone, Parser)]
+#[command(about = "List the webhooks of a repository", aliases = &["ls"])]
+pub struct List {
+    #[arg()]
+    /// Name of the repository.
+    ///
+    /// Use `owner/repo` for a repository owned by an organization or group.
+    /// If no owner is given, the repository must be owned by the authenticated user.
+    pub name: RepoName,
+
+    #[arg()]
+    /// Name of the remote as defined in the config (ex: 'github').
+    pub remote: String,
+
+    #[arg(long)]
+    /// Change the output format to the specified value.
+    ///
+    /// When using the 'json' format, gritty will output the webhooks in JSON.
+    pub format: Option<OutputFormat>,
+}
@@ -0,0 +1,30 @@
+use clap::{Parser, Subcommand};
+
+pub mod create;
+pub mod delete;
+pub mod list;
+pub mod test;
+pub mod update;
+
+pub use create::Create;
+pub use delete::Delete;
+pub use list::List;
+pub use test::Test;
+pub use update::Update;
+
+#[derive(Debug, Clone, Parser)]
+#[command()]
+/// Manage the webhooks of a repository.
+pub struct Hook {
+    #[command(subcommand)]
+    pub subcommand: HookCommands,
+}
+
+#[derive(Debug, Clone, Subcommand)]
+pub enum HookCommands {
+    List(List),
+    Create(Create),
+    Update(Update),
+    Delete(Delete),
+    Test(Test),
+}
@@ -0,0 +1,22 @@
+use clap::Parser;
+
+use crate::args::repo::RepoName;
+
+#[derive(Debug, Clone, Parser)]
+/// Send a test delivery to a webhook. GitHub sends a ping event, Gitlab and Gitea a push event
+pub struct Test {
+    #[arg()]
+    /// Name of the repository.
+    ///
+    /// Use `owner/repo` for a repository owned by an organization or group.
+    /// If no owner is given, the repository must be owned by the authenticated user.
+    pub name: RepoName,
+
+    #[arg()]
+    /// Name of the remote as defined in the config (ex: 'github').
+    pub remote: String,
+
+    #[arg()]
+    /// ID of the webhook, as shown by `gritty hook list`.
+    pub id: u64,
+}
@@ -0,0 +1,49 @@
+use clap::Parser;
+
+use crate::args::{repo::RepoName, OutputFormat};
+use crate::remote::{WebhookContentType, WebhookEvent};
+
+#[derive(Debug, Clone, Parser)]
+/// Change the settings of a webhook. Settings that are not given are left as they are
+pub struct Update {
+    #[arg()]
+    /// Name of the repository.
+    ///
+    /// Use `owner/repo` for a repository owned by an organization or group.
+    /// If no owner is given, the repository must be owned by the authenticated user.
+    pub name: RepoName,
+
+    #[arg()]
+    /// Name of the remote as defined in the config (ex: 'github').
+    pub remote: String,
+
+    #[arg()]
+    /// ID of the webhook, as shown by `gritty hook list`.
+    pub id: u64,
+
+    #[arg(long)]
+    /// URL to send deliveries to.
+    pub url: Option<String>,
+
+    #[arg(long)]
+    /// Secret used to sign deliveries.
+    pub secret: Option<String>,
+
+    #[arg(long)]
+    /// Encoding of the delivered payload.
+    pub content_type: Option<WebhookContentType>,
+
+    #[arg(short, long = "event")]
+    /// Event to deliver. Can be given multiple times, and replaces all events of the webhook.
+    pub events: Vec<WebhookEvent>,
+
+    #[arg(long)]
+    /// Whether deliveries are sent.
+    pub active: Option<bool>,
+
+    #[arg(long)]
+    /// Change the output format to the specified value.
+    ///
+    /// When using the 'json' format, gritty will output the updated webhook in JSON.
+    pub format: Option<OutputFormat>,
+}
@@ -0,0 +1,43 @@
+use std::path::PathBuf;
+
+use clap::Parser;
+
+use crate::args::{repo::RepoName, OutputFormat};
+
+#[derive(Debug, Clone, Parser)]
+/// Comment on an issue
+pub struct Comment {
+    #[arg()]
+    /// Name of the repository.
+    ///
+    /// Use `owner/repo` for a repository owned by an organization or group.
+    /// If no owner is given, the repository must be owned by the authenticated user.
+    pub name: RepoName,
+
+    #[arg()]
+    /// Name of the remote as defined in the config (ex: 'github').
+    pub remote: String,
+
+    #[arg()]
+    /// Number of the issue.
+    pub number: u64,
+
+    #[arg(
+        short,
+        long,
+        required_unless_present = "body_file",
+        conflicts_with = "body_file"
+    )]
+    /// Text of the comment.
+    pub body: Option<String>,
+
+    #[arg(short = 'F', long)]
+    /// Read the text of the comment from a file, or from stdin if the path is '-'.
+    pub body_file: Option<PathBuf>,
+
+    #[arg(long)]
+    /// Change the output format to the specified value.
+    ///
+    /// When using the 'json' format, gritty will output the new comment in JSON.
+    pub format: Option<OutputFormat>,
+}
@@ -0,0 +1,50 @@
+use std::path::PathBuf;
+
+use clap::Parser;
+
+use crate::args::{repo::RepoName, OutputFormat};
+
+#[derive(Debug, Clone, Parser)]
+/// Open an issue
+pub struct Create {
+    #[arg()]
+    /// Name of the repository.
+    ///
+    /// Use `owner/repo` for a repository owned by an organization or group.
+    /// If no owner is given, the repository must be owned by the authenticated user.
+    pub name: RepoName,
+
+    #[arg()]
+    /// Name of the remote as defined in the config (ex: 'github').
+    pub remote: String,
+
+    #[arg(short, long)]
+    /// Title of the issue.
+    pub title: String,
+
+    #[arg(short, long, conflicts_with = "body_file")]
+    /// Description of the issue.
+    pub body: Option<String>,
+
+    #[arg(short = 'F', long)]
+    /// Read the description of the issue from a file, or from stdin if the path is '-'.
+    pub body_file: Option<PathBuf>,
+
+    #[arg(short, long = "label")]
+    /// Put a label on the issue. Can be given multiple times. The labels must already exist.
+    pub labels: Vec<String>,
+
+    #[arg(short, long = "assignee")]
+    /// Assign a user to the issue. Can be given multiple times.
+    pub assignees: Vec<String>,
+
+    #[arg(short, long)]
+    /// Title of the milestone to add the issue to.
+    pub milestone: Option<String>,
+
+    #[arg(long)]
+    /// Change the output format to the specified value.
+    ///
+    /// When using the 'json' format, gritty will output the new issue in JSON.
+    pub format: Option<OutputFormat>,
+}
@@ -0,0 +1,53 @@
+use clap::{Parser, ValueEnum};
+
+use crate::args::{repo::RepoName, OutputFormat};
+
+/// Which issues to list, by state.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+#[value(rename_all = "lowercase")]
+pub enum IssueStateFilter {
+    #[default]
+    /// Only list open issues.
+    Open,
+    /// Only list closed issues.
+    Closed,
+    /// List issues in any state.
+    All,
+}
+
+#[derive(Debug, Clone, Parser)]
+#[command(about = "List the issues of a repository", aliases = &["ls"])]
+pub struct List {
+    #[arg()]
+    /// Name of the repository.
+    ///
+    /// Use `owner/repo` for a repository owned by an organization or group.
+    /// If no owner is given, the repository must be owned by the authenticated user.
+    pub name: RepoName,
+
+    #[arg()]
+    /// Name of the remote as defined in the config (ex: 'github').
+    pub remote: String,
+
+    #[arg(short, long, default_value = "open")]
+    /// Only list issues in this state.
+    pub state: IssueStateFilter,
+
+    #[arg(short, long = "label")]
+    /// Only list issues with this label. Can be given multiple times to require several labels.
+    pub labels: Vec<String>,
+
+    #[arg(short, long)]
+    /// Only list issues assigned to this user.
+    pub assignee: Option<String>,
+
+    #[arg(short = 'A', long)]
+    /// Only list issues opened by this user.
+    pub author: Option<String>,
+
+    #[arg(long)]
+    /// Change the output format to the specified value.
+    ///
+    /// When using the 'json' format, gritty will output the issues in JSON.
+    pub format: Option<OutputFormat>,
+}
@@ -0,0 +1,31 @@
+use clap::{Parser, Subcommand};
+
+pub mod comment;
+pub mod create;
+pub mod list;
+pub mod state;
+pub mod view;
+
+pub use comment::Comment;
+pub use create::Create;
+pub use list::{IssueStateFilter, List};
+pub use state::{Close, Reopen};
+pub use view::View;
+
+#[derive(Debug, Clone, Parser)]
+#[command()]
+/// Manage the issues of a repository.
+pub struct Issue {
+    #[command(subcommand)]
+    pub subcommand: IssueCommands,
+}
+
+#[derive(Debug, Clone, Subcommand)]
+pub enum IssueCommands {
+    List(List),
+    View(View),
+    Create(Create),
+    Comment(Comment),
+    Close(Close),
+    Reopen(Reopen),
+}
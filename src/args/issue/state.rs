@@ -0,0 +1,61 @@
+use clap::Parser;
+
+use crate::args::{repo::RepoName, OutputFormat};
+
+#[derive(Debug, Clone, Parser)]
+/// Close an issue
+pub struct Close {
+    #[arg()]
+    /// Name of the repository.
+    ///
+    /// Use `owner/repo` for a repository owned by an organization or group.
+    /// If no owner is given, the repository must be owned by the authenticated user.
+    pub name: RepoName,
+
+    #[arg()]
+    /// Name of the remote as defined in the config (ex: 'github').
+    pub remote: String,
+
+    #[arg()]
+    /// Number of the issue to close.
+    pub number: u64,
+
+    #[arg(short, long)]
+    /// Comment on the issue before closing it, e.g. to explain why.
+    pub comment: Option<String>,
+
+    #[arg(long)]
+    /// Change the output format to the specified value.
+    ///
+    /// When using the 'json' format, gritty will output the closed issue in JSON.
+    pub format: Option<OutputFormat>,
+}
+
+#[derive(Debug, Clone, Parser)]
+/// Reopen a closed issue
+pub struct Reopen {
+    #[arg()]
+    /// Name of the repository.
+    ///
+    /// Use `owner/repo` for a repository owned by an organization or group.
+    /// If no owner is given, the repository must be owned by the authenticated user.
+    pub name: RepoName,
+
+    #[arg()]
+    /// Name of the remote as defined in the config (ex: 'github').
+    pub remote: String,
+
+    #[arg()]
+    /// Number of the issue to reopen.
+    pub number: u64,
+
+    #[arg(short, long)]
+    /// Comment on the issue before reopening it.
+    pub comment: Option<String>,
+
+    #[arg(long)]
+    /// Change the output format to the specified value.
+    ///
+    /// When using the 'json' format, gritty will output the reopened issue in JSON.
+    pub format: Option<OutputFormat>,
+}
@@ -0,0 +1,31 @@
+use std::path::PathBuf;
+
+use clap::Parser;
+
+#[derive(Debug, Clone, Parser)]
+#[command(allow_missing_positional = true)]
+/// Add an SSH key to the authenticated user
+pub struct Add {
+    #[arg(
+        required_unless_present = "all_remotes",
+        conflicts_with = "all_remotes"
+    )]
+    /// Name of the remote as defined in the config (ex: 'github').
+    pub remote: Option<String>,
+
+    #[arg()]
+    /// Path to the public key (ex: '~/.ssh/id_ed25519.pub').
+    pub key: PathBuf,
+
+    #[arg(short, long)]
+    /// Title of the key on the remote.
+    ///
+    /// Defaults to the comment of the key, or the file name if it has none.
+    pub title: Option<String>,
+
+    #[arg(long)]
+    /// Add the key to every remote in the config, e.g. to register a new machine.
+    ///
+    /// Remotes that don't support SSH keys are skipped.
+    pub all_remotes: bool,
+}
@@ -0,0 +1,17 @@
+use clap::Parser;
+
+use crate::args::OutputFormat;
+
+#[derive(Debug, Clone, Parser)]
+#[command(about = "List the SSH keys of the authenticated user", aliases = &["ls"])]
+pub struct List {
+    #[arg()]
+    /// Name of the remote as defined in the config (ex: 'github').
+    pub remote: String,
+
+    #[arg(long)]
+    /// Change the output format to the specified value.
+    ///
+    /// When using the 'json' format, gritty will output the keys in JSON.
+    pub format: Option<OutputFormat>,
+}
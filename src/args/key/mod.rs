@@ -0,0 +1,24 @@
+use clap::{Parser, Subcommand};
+
+pub mod add;
+pub mod list;
+pub mod remove;
+
+pub use add::Add;
+pub use list::List;
+pub use remove::Remove;
+
+#[derive(Debug, Clone, Parser)]
+#[command()]
+/// Manage the SSH keys of the authenticated user.
+pub struct Key {
+    #[command(subcommand)]
+    pub subcommand: KeyCommands,
+}
+
+#[derive(Debug, Clone, Subcommand)]
+pub enum KeyCommands {
+    List(List),
+    Add(Add),
+    Remove(Remove),
+}
@@ -0,0 +1,18 @@
+use clap::Parser;
+
+#[derive(Debug, Clone, Parser)]
+#[command(aliases = &["rm"])]
+/// Remove an SSH key from the authenticated user
+pub struct Remove {
+    #[arg()]
+    /// Name of the remote as defined in the config (ex: 'github').
+    pub remote: String,
+
+    #[arg()]
+    /// ID of the key, as shown by `gritty key list`.
+    pub id: u64,
+
+    #[arg(short, long)]
+    /// Force removal without confirmation.
+    pub force: bool,
+}
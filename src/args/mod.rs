@@ -1,14 +1,34 @@
 use std::env;
 
 pub mod auth;
+pub mod backup;
+pub mod branch;
 pub mod completions;
+pub mod deploy_key;
+pub mod export;
+pub mod hook;
+pub mod issue;
+pub mod key;
+pub mod pr;
+pub mod release;
 pub mod remote;
 pub mod repo;
+pub mod tag;
 
 use auth::Auth;
+use backup::Backup;
+use branch::Branch;
 use completions::Completions;
+use deploy_key::DeployKey;
+use export::{Export, Import};
+use hook::Hook;
+use issue::Issue;
+use key::Key;
+use pr::Pr;
+use release::Release;
 use remote::Remote;
 use repo::Repo;
+use tag::Tag;
 
 use clap::{
     builder::styling::{AnsiColor, Effects, Styles},
@@ -100,7 +120,18 @@ pub enum OutputFormat {
 pub enum Commands {
     Auth(Auth),
     Repo(Repo),
+    Issue(Issue),
+    Pr(Pr),
+    Release(Release),
+    Branch(Branch),
+    Tag(Tag),
+    Hook(Hook),
+    Key(Key),
+    DeployKey(DeployKey),
     Remote(Remote),
+    Backup(Backup),
+    Export(Export),
+    Import(Import),
 
     Completions(Completions),
 
@@ -0,0 +1,28 @@
+use clap::Parser;
+
+use crate::args::repo::RepoName;
+
+#[derive(Debug, Clone, Parser)]
+/// Check out the changes of a pull request in a new local branch
+///
+/// Must be run inside a clone of the repository. Pull requests from forks can be checked out too.
+pub struct Checkout {
+    #[arg()]
+    /// Name of the repository.
+    ///
+    /// Use `owner/repo` for a repository owned by an organization or group.
+    /// If no owner is given, the repository must be owned by the authenticated user.
+    pub name: RepoName,
+
+    #[arg()]
+    /// Name of the remote as defined in the config (ex: 'github').
+    pub remote: String,
+
+    #[arg()]
+    /// Number of the pull request.
+    pub number: u64,
+
+    #[arg(short, long)]
+    /// Name of the local branch to create. Defaults to the head branch of the pull request.
+    pub branch: Option<String>,
+}
@@ -0,0 +1,59 @@
+use std::path::PathBuf;
+
+use clap::Parser;
+
+use crate::args::{repo::RepoName, OutputFormat};
+
+#[derive(Debug, Clone, Parser)]
+/// Open a pull request
+///
+/// Run inside a clone of the repository, the current branch is pushed to the repository if needed
+/// and used as the head of the pull request.
+pub struct Create {
+    #[arg()]
+    /// Name of the repository.
+    ///
+    /// Use `owner/repo` for a repository owned by an organization or group.
+    /// If no owner is given, the repository must be owned by the authenticated user.
+    pub name: RepoName,
+
+    #[arg()]
+    /// Name of the remote as defined in the config (ex: 'github').
+    pub remote: String,
+
+    #[arg(short, long)]
+    /// Title of the pull request.
+    pub title: String,
+
+    #[arg(short, long, conflicts_with = "body_file")]
+    /// Description of the pull request.
+    pub body: Option<String>,
+
+    #[arg(short = 'F', long)]
+    /// Read the description of the pull request from a file, or from stdin if the path is '-'.
+    pub body_file: Option<PathBuf>,
+
+    #[arg(short = 'H', long)]
+    /// The branch with the changes. It must already be pushed to the repository.
+    ///
+    /// Defaults to the current branch, which is pushed first unless --no-push is given.
+    pub head: Option<String>,
+
+    #[arg(short = 'B', long)]
+    /// The branch to merge the changes into. Defaults to the default branch of the repository.
+    pub base: Option<String>,
+
+    #[arg(short, long)]
+    /// Open the pull request as a draft.
+    pub draft: bool,
+
+    #[arg(long, conflicts_with = "head")]
+    /// Don't push the current branch before opening the pull request.
+    pub no_push: bool,
+
+    #[arg(long)]
+    /// Change the output format to the specified value.
+    ///
+    /// When using the 'json' format, gritty will output the new pull request in JSON.
+    pub format: Option<OutputFormat>,
+}
@@ -0,0 +1,43 @@
+use clap::{Parser, ValueEnum};
+
+use crate::args::{repo::RepoName, OutputFormat};
+
+/// Which pull requests to list, by state.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+#[value(rename_all = "lowercase")]
+pub enum PullRequestStateFilter {
+    #[default]
+    /// Only list open pull requests.
+    Open,
+    /// Only list pull requests closed without merging them.
+    Closed,
+    /// Only list merged pull requests.
+    Merged,
+    /// List pull requests in any state.
+    All,
+}
+
+#[derive(Debug, Clone, Parser)]
+#[command(about = "List the pull requests of a repository", aliases = &["ls"])]
+pub struct List {
+    #[arg()]
+    /// Name of the repository.
+    ///
+    /// Use `owner/repo` for a repository owned by an organization or group.
+    /// If no owner is given, the repository must be owned by the authenticated user.
+    pub name: RepoName,
+
+    #[arg()]
+    /// Name of the remote as defined in the config (ex: 'github').
+    pub remote: String,
+
+    #[arg(short, long, default_value = "open")]
+    /// Only list pull requests in this state.
+    pub state: PullRequestStateFilter,
+
+    #[arg(long)]
+    /// Change the output format to the specified value.
+    ///
+    /// When using the 'json' format, gritty will output the pull requests in JSON.
+    pub format: Option<OutputFormat>,
+}
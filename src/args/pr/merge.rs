@@ -0,0 +1,40 @@
+use clap::Parser;
+
+use crate::args::{repo::RepoName, OutputFormat};
+use crate::remote::MergeMethod;
+
+#[derive(Debug, Clone, Parser)]
+/// Merge a pull request
+pub struct Merge {
+    #[arg()]
+    /// Name of the repository.
+    ///
+    /// Use `owner/repo` for a repository owned by an organization or group.
+    /// If no owner is given, the repository must be owned by the authenticated user.
+    pub name: RepoName,
+
+    #[arg()]
+    /// Name of the remote as defined in the config (ex: 'github').
+    pub remote: String,
+
+    #[arg()]
+    /// Number of the pull request.
+    pub number: u64,
+
+    #[arg(short, long, default_value = "merge")]
+    /// How to merge the pull request.
+    ///
+    /// GitLab uses the merge method configured for the project, and only supports 'merge' and
+    /// 'squash'.
+    pub method: MergeMethod,
+
+    #[arg(short, long)]
+    /// Delete the head branch once the pull request is merged.
+    pub delete_branch: bool,
+
+    #[arg(long)]
+    /// Change the output format to the specified value.
+    ///
+    /// When using the 'json' format, gritty will output the merged pull request in JSON.
+    pub format: Option<OutputFormat>,
+}
@@ -0,0 +1,36 @@
+use clap::{Parser, Subcommand};
+
+pub mod approve;
+pub mod checkout;
+pub mod comment;
+pub mod create;
+pub mod list;
+pub mod merge;
+pub mod view;
+
+pub use approve::Approve;
+pub use checkout::Checkout;
+pub use comment::Comment;
+pub use create::Create;
+pub use list::{List, PullRequestStateFilter};
+pub use merge::Merge;
+pub use view::View;
+
+#[derive(Debug, Clone, Parser)]
+#[command()]
+/// Manage the pull requests (merge requests on GitLab) of a repository.
+pub struct Pr {
+    #[command(subcommand)]
+    pub subcommand: PrCommands,
+}
+
+#[derive(Debug, Clone, Subcommand)]
+pub enum PrCommands {
+    List(List),
+    View(View),
+    Create(Create),
+    Checkout(Checkout),
+    Comment(Comment),
+    Approve(Approve),
+    Merge(Merge),
+}
@@ -0,0 +1,68 @@
+use std::path::PathBuf;
+
+use clap::Parser;
+
+use crate::args::{repo::RepoName, OutputFormat};
+
+#[derive(Debug, Clone, Parser)]
+/// Publish a release, optionally with files attached
+pub struct Create {
+    #[arg()]
+    /// Name of the repository.
+    ///
+    /// Use `owner/repo` for a repository owned by an organization or group.
+    /// If no owner is given, the repository must be owned by the authenticated user.
+    pub name: RepoName,
+
+    #[arg()]
+    /// Name of the remote as defined in the config (ex: 'github').
+    pub remote: String,
+
+    #[arg(short, long)]
+    /// Tag to create the release for (ex: 'v1.2.3').
+    pub tag: String,
+
+    #[arg(long)]
+    /// Branch or commit to create the tag from, if it doesn't exist yet.
+    ///
+    /// Defaults to the default branch of the repository.
+    pub target: Option<String>,
+
+    #[arg(long)]
+    /// Title of the release. Defaults to the tag.
+    pub title: Option<String>,
+
+    #[arg(short, long, conflicts_with = "notes_file")]
+    /// Release notes.
+    pub notes: Option<String>,
+
+    #[arg(short = 'F', long)]
+    /// Read the release notes from a file, or from stdin if the path is '-'.
+    pub notes_file: Option<PathBuf>,
+
+    #[arg(short, long)]
+    /// Save the release as a draft instead of publishing it.
+    ///
+    /// GitLab has no drafts, the release is published there.
+    pub draft: bool,
+
+    #[arg(short, long)]
+    /// Mark the release as a prerelease.
+    ///
+    /// GitLab has no prereleases, the release is published as a regular release there.
+    pub prerelease: bool,
+
+    #[arg(short, long = "asset", num_args = 1..)]
+    /// Attach a file to the release. Can be given multiple times, or with multiple files.
+    ///
+    /// Wildcards ('*' and '?') in the file name are expanded, so patterns like
+    /// 'dist/*.tar.gz' work even when the shell doesn't expand them.
+    /// On GitLab, files are uploaded to the generic package registry of the project.
+    pub assets: Vec<PathBuf>,
+
+    #[arg(long)]
+    /// Change the output format to the specified value.
+    ///
+    /// When using the 'json' format, gritty will output the new release in JSON.
+    pub format: Option<OutputFormat>,
+}
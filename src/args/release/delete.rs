@@ -0,0 +1,27 @@
+use clap::Parser;
+
+use crate::args::repo::RepoName;
+
+#[derive(Debug, Clone, Parser)]
+#[command(aliases = &["rm"])]
+/// Delete a release. The tag is kept
+pub struct Delete {
+    #[arg()]
+    /// Name of the repository.
+    ///
+    /// Use `owner/repo` for a repository owned by an organization or group.
+    /// If no owner is given, the repository must be owned by the authenticated user.
+    pub name: RepoName,
+
+    #[arg()]
+    /// Name of the remote as defined in the config (ex: 'github').
+    pub remote: String,
+
+    #[arg()]
+    /// Tag of the release.
+    pub tag: String,
+
+    #[arg(short, long)]
+    /// Force deletion without confirmation.
+    pub force: bool,
+}
@@ -0,0 +1,38 @@
+use std::path::PathBuf;
+
+use clap::Parser;
+
+use crate::args::repo::RepoName;
+
+#[derive(Debug, Clone, Parser)]
+/// Download the files attached to a release
+pub struct Download {
+    #[arg()]
+    /// Name of the repository.
+    ///
+    /// Use `owner/repo` for a repository owned by an organization or group.
+    /// If no owner is given, the repository must be owned by the authenticated user.
+    pub name: RepoName,
+
+    #[arg()]
+    /// Name of the remote as defined in the config (ex: 'github').
+    pub remote: String,
+
+    #[arg()]
+    /// Tag of the release.
+    pub tag: String,
+
+    #[arg(short, long = "asset")]
+    /// Name of an asset to download. Can be given multiple times.
+    ///
+    /// All assets are downloaded by default.
+    pub assets: Vec<String>,
+
+    #[arg(short = 'o', long, default_value = ".")]
+    /// Directory to save the assets in. It is created if it doesn't exist.
+    pub dir: PathBuf,
+
+    #[arg(short, long)]
+    /// Overwrite files that already exist.
+    pub force: bool,
+}
@@ -0,0 +1,30 @@
+use clap::{Parser, Subcommand};
+
+pub mod create;
+pub mod delete;
+pub mod download;
+pub mod list;
+pub mod view;
+
+pub use create::Create;
+pub use delete::Delete;
+pub use download::Download;
+pub use list::List;
+pub use view::View;
+
+#[derive(Debug, Clone, Parser)]
+#[command()]
+/// Manage the releases of a repository.
+pub struct Release {
+    #[command(subcommand)]
+    pub subcommand: ReleaseCommands,
+}
+
+#[derive(Debug, Clone, Subcommand)]
+pub enum ReleaseCommands {
+    List(List),
+    View(View),
+    Create(Create),
+    Delete(Delete),
+    Download(Download),
+}
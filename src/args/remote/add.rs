@@ -15,6 +15,13 @@ pub struct Add {
     #[arg(short, long)]
     pub provider: Option<Provider>,
 
+    /// Use a provider plugin instead of a built-in provider.
+    ///
+    /// Gritty will run `gritty-provider-<PLUGIN>` from your PATH to talk to the remote.
+    /// If the plugin contains a path separator, it is used as the path to the plugin instead.
+    #[arg(long, conflicts_with = "provider")]
+    pub plugin: Option<String>,
+
     /// The username to use when interacting with the remote.
     #[arg(short, long)]
     pub username: Option<String>,
@@ -0,0 +1,53 @@
+use clap::Parser;
+
+use crate::args::OutputFormat;
+
+use super::RepoName;
+
+#[derive(Debug, Clone, Parser)]
+/// Archive a repository on a remote, making it read-only
+pub struct Archive {
+    #[arg()]
+    /// Name of the repository to archive.
+    ///
+    /// Use `owner/repo` to archive a repository owned by an organization or group.
+    /// If no owner is given, the repository must be owned by the authenticated user.
+    pub name: RepoName,
+
+    #[arg()]
+    /// Name of the remote as defined in the config (ex: 'github').
+    ///
+    /// The remote must be defined in the config file. There are no restrictions on the name,
+    /// it does not have to correspond to the remote type (GitHub, GitLab, Gitea)
+    pub remote: String,
+
+    #[arg(long)]
+    /// Change the output format to the specified value.
+    ///
+    /// When using the 'json' format, gritty will output information about the archived repository in JSON.
+    pub format: Option<OutputFormat>,
+}
+
+#[derive(Debug, Clone, Parser)]
+/// Unarchive a repository on a remote, making it writable again
+pub struct Unarchive {
+    #[arg()]
+    /// Name of the repository to unarchive.
+    ///
+    /// Use `owner/repo` to unarchive a repository owned by an organization or group.
+    /// If no owner is given, the repository must be owned by the authenticated user.
+    pub name: RepoName,
+
+    #[arg()]
+    /// Name of the remote as defined in the config (ex: 'github').
+    ///
+    /// The remote must be defined in the config file. There are no restrictions on the name,
+    /// it does not have to correspond to the remote type (GitHub, GitLab, Gitea)
+    pub remote: String,
+
+    #[arg(long)]
+    /// Change the output format to the specified value.
+    ///
+    /// When using the 'json' format, gritty will output information about the unarchived repository in JSON.
+    pub format: Option<OutputFormat>,
+}
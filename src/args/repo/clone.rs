@@ -2,6 +2,8 @@ use std::path::PathBuf;
 
 use clap::Parser;
 
+use super::RepoName;
+
 #[derive(Debug, Clone, Parser)]
 #[command()]
 /// Clone a repository from a remote
@@ -9,8 +11,9 @@ pub struct Clone {
     #[arg()]
     /// Name of the repository to clone.
     ///
-    /// The repository must be owned by the authenticated user."
-    pub name: String,
+    /// Use `owner/repo` to clone a repository owned by another user, organization or group.
+    /// If no owner is given, the repository must be owned by the authenticated user.
+    pub name: RepoName,
 
     #[arg()]
     /// Name of the remote as defined in the config (ex: 'github').
@@ -26,7 +29,6 @@ pub struct Clone {
     /// The path must be a valid directory.
     pub path: Option<PathBuf>,
 
-
     #[arg(short, long)]
     /// Initialize and clone all submodules.
     /// This is equivalent to running `git clone --recurse-submodules` (or `git clone --recursive`)."
@@ -2,6 +2,8 @@ use clap::Parser;
 
 use crate::args::OutputFormat;
 
+use super::RepoName;
+
 #[derive(Debug, Clone, Parser)]
 #[command(aliases = &["new"])]
 /// Create a repository on a remote
@@ -39,11 +41,18 @@ pub struct Create {
     /// If not provided, or --init is not specified, no license will be addeed.
     pub license: Option<String>,
 
+    #[arg(short, long)]
+    /// Organization or group to create the repository in.
+    ///
+    /// If not provided, the repository will be created in the authenticated user's account.
+    pub org: Option<String>,
+
     #[arg()]
     /// Name of the repository
     ///
-    /// The repository must be owned by the authenticated user.
-    pub name: String,
+    /// Use `owner/repo` to create the repository in an organization or group, or use --org.
+    /// If no owner is given, the repository will be owned by the authenticated user.
+    pub name: RepoName,
 
     #[arg()]
     /// Name of the remote as defined in the config (ex: 'github').
@@ -53,6 +62,11 @@ pub struct Create {
     /// it does not have to correspond to the remote type (GitHub, GitLab, Gitea)
     pub remote: String,
 
+    #[arg(long = "hook", value_name = "NAME")]
+    /// Attach the webhooks of the `[[hooks.NAME]]` section of the config to the new repository.
+    /// Can be given multiple times.
+    pub hooks: Vec<String>,
+
     #[arg(long)]
     /// Change the output format to the specified value.
     ///
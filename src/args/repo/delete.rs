@@ -1,5 +1,7 @@
 use clap::Parser;
 
+use super::RepoName;
+
 #[derive(Debug, Clone, Parser)]
 #[command(aliases = &["rm"])]
 /// Delete a repository on a remote
@@ -7,8 +9,9 @@ pub struct Delete {
     #[arg()]
     /// Name of the repository to delete.
     ///
-    /// The repository must be owned by the authenticated user.
-    pub name: String,
+    /// Use `owner/repo` to delete a repository owned by an organization or group.
+    /// If no owner is given, the repository must be owned by the authenticated user.
+    pub name: RepoName,
 
     #[arg()]
     /// Name of the remote as defined in the config (ex: 'github').
@@ -0,0 +1,91 @@
+use clap::Parser;
+
+use crate::args::OutputFormat;
+use crate::remote::{RepoUpdateInfo, Visibility};
+
+use super::{toggle, RepoName};
+
+#[derive(Debug, Clone, Parser)]
+#[command(aliases = &["update"])]
+/// Change the settings of a repository on a remote
+///
+/// Only the given settings are changed, everything else is left as is.
+pub struct Edit {
+    #[arg()]
+    /// Name of the repository to edit.
+    ///
+    /// Use `owner/repo` to edit a repository owned by an organization or group.
+    /// If no owner is given, the repository must be owned by the authenticated user.
+    pub name: RepoName,
+
+    #[arg()]
+    /// Name of the remote as defined in the config (ex: 'github').
+    ///
+    /// The remote must be defined in the config file. There are no restrictions on the name,
+    /// it does not have to correspond to the remote type (GitHub, GitLab, Gitea)
+    pub remote: String,
+
+    #[arg(short, long)]
+    /// New description of the repository
+    pub description: Option<String>,
+
+    #[arg(long)]
+    /// URL of the project's website. Not supported by Gitlab.
+    pub homepage: Option<String>,
+
+    #[arg(short, long)]
+    /// Who can see the repository.
+    ///
+    /// 'internal' makes the repository visible to all members of the instance. It is only
+    /// available on GitLab and GitHub Enterprise.
+    pub visibility: Option<Visibility>,
+
+    #[arg(short = 'b', long)]
+    /// Branch to use as the default branch. The branch must already exist.
+    pub default_branch: Option<String>,
+
+    #[arg(long, overrides_with = "no_issues")]
+    /// Enable the issue tracker
+    pub issues: bool,
+    #[arg(long, overrides_with = "issues")]
+    /// Disable the issue tracker
+    pub no_issues: bool,
+
+    #[arg(long, overrides_with = "no_wiki")]
+    /// Enable the wiki
+    pub wiki: bool,
+    #[arg(long, overrides_with = "wiki")]
+    /// Disable the wiki
+    pub no_wiki: bool,
+
+    #[arg(long, overrides_with = "no_projects")]
+    /// Enable projects. Not supported by Gitlab.
+    pub projects: bool,
+    #[arg(long, overrides_with = "projects")]
+    /// Disable projects. Not supported by Gitlab.
+    pub no_projects: bool,
+
+    #[arg(long)]
+    /// Change the output format to the specified value.
+    ///
+    /// This option is useful for parsing the output of gritty, such as in a script or another
+    /// tool integrating with gritty.
+    ///
+    /// When using the 'json' format, gritty will output information about the updated repository in JSON.
+    pub format: Option<OutputFormat>,
+}
+
+impl Edit {
+    /// The settings to change on the repository.
+    pub fn update_info(&self) -> RepoUpdateInfo {
+        RepoUpdateInfo {
+            description: self.description.clone(),
+            homepage: self.homepage.clone(),
+            visibility: self.visibility,
+            default_branch: self.default_branch.clone(),
+            has_issues: toggle(self.issues, self.no_issues),
+            has_wiki: toggle(self.wiki, self.no_wiki),
+            has_projects: toggle(self.projects, self.no_projects),
+        }
+    }
+}
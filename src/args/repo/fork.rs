@@ -2,6 +2,8 @@ use clap::Parser;
 
 use crate::args::OutputFormat;
 
+use super::RepoName;
+
 #[derive(Debug, Clone, Parser)]
 #[command()]
 /// Fork a repository on a remote
@@ -44,12 +46,8 @@ pub struct Fork {
     pub organization: Option<String>,
 
     #[arg()]
-    /// The owner of the repository to fork
-    pub owner: String,
-
-    #[arg()]
-    /// Name of the repository to fork
-    pub repository: String,
+    /// Name of the repository to fork, as `owner/repo`
+    pub repository: RepoName,
 
     #[arg()]
     /// Name of the remote as defined in the config (ex: 'github').
@@ -12,6 +12,10 @@ pub struct List {
     /// it does not have to correspond to the remote type (GitHub, GitLab, Gitea)"
     pub remote: String,
 
+    #[arg(short, long)]
+    /// List the repositories of an organization or group instead of the authenticated user.
+    pub org: Option<String>,
+
     #[arg(short, long)]
     /// Show private repositories
     pub private: bool,
@@ -20,6 +24,35 @@ pub struct List {
     /// Show forked repositories
     pub forks: bool,
 
+    #[arg(long, overrides_with = "no_archived")]
+    /// Only show archived repositories
+    pub archived: bool,
+
+    #[arg(long, overrides_with = "archived")]
+    /// Hide archived repositories
+    pub no_archived: bool,
+
+    #[arg(short, long)]
+    /// Only show this many repositories. By default, all repositories are listed.
+    pub limit: Option<u32>,
+
+    #[arg(long, requires = "limit")]
+    /// Show the given page of repositories, starting at 1. Requires --limit.
+    pub page: Option<u32>,
+
+    #[arg(short, long)]
+    /// Fetch the details of this many repositories at the same time.
+    ///
+    /// Overrides the 'concurrency' setting of the remote. Lower it if you run into rate limits.
+    pub jobs: Option<usize>,
+
+    #[arg(short, long, num_args = 0..=1, require_equals = true, default_missing_value = "1")]
+    /// Fetch the latest commit of every repository, or the latest N with --commits=N.
+    ///
+    /// By default, only the time of the last update is shown, which doesn't need an extra
+    /// request per repository.
+    pub commits: Option<u8>,
+
     #[arg(long)]
     ///  Change the output format to the specified value.
     ///
@@ -0,0 +1,40 @@
+use clap::Parser;
+
+use super::RepoName;
+
+#[derive(Debug, Clone, Parser)]
+/// Copy repositories from one remote to another
+///
+/// If the target remote has a server-side importer (Gitea, GitLab), it fetches the repository
+/// itself, using the credentials of the source remote. Otherwise, the repository is copied with
+/// `git clone --mirror` and `git push --mirror`. The description, visibility and default branch
+/// are carried over.
+pub struct Migrate {
+    #[arg(required_unless_present = "all", conflicts_with = "all")]
+    /// Name of the repository to migrate.
+    ///
+    /// Use `owner/repo` to migrate a repository owned by an organization or group.
+    /// If no owner is given, the repository must be owned by the authenticated user.
+    pub name: Option<RepoName>,
+
+    #[arg(long)]
+    /// Name of the remote to migrate from, as defined in the config (ex: 'github').
+    pub from: String,
+
+    #[arg(long)]
+    /// Name of the remote to migrate to, as defined in the config (ex: 'gitea').
+    pub to: String,
+
+    #[arg(short, long)]
+    /// Migrate every repository the authenticated user owns on the source remote, including
+    /// private repositories and forks.
+    ///
+    /// Repositories of organizations or groups are not included, migrate them by name.
+    pub all: bool,
+
+    #[arg(short, long)]
+    /// Organization or group to create the repositories in on the target remote.
+    ///
+    /// If not provided, the repositories will be created in the authenticated user's account.
+    pub org: Option<String>,
+}
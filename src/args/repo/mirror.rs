@@ -0,0 +1,99 @@
+use clap::{Parser, Subcommand};
+
+use crate::args::OutputFormat;
+
+use super::RepoName;
+
+#[derive(Debug, Clone, Parser)]
+/// Keep copies of repositories on other remotes up to date
+pub struct Mirror {
+    #[command(subcommand)]
+    pub subcommand: MirrorCommands,
+}
+
+#[derive(Debug, Clone, Subcommand)]
+pub enum MirrorCommands {
+    Push(MirrorPush),
+    Pull(MirrorPull),
+    List(MirrorList),
+}
+
+#[derive(Debug, Clone, Parser)]
+/// Push a repository to another remote whenever it changes (Gitea, GitLab)
+///
+/// The repository on the other remote is created if it doesn't exist yet. The remote pushing the
+/// repository is given the credentials of the other remote.
+pub struct MirrorPush {
+    #[arg()]
+    /// Name of the repository to mirror.
+    ///
+    /// Use `owner/repo` to mirror a repository owned by an organization or group.
+    /// If no owner is given, the repository must be owned by the authenticated user.
+    pub name: RepoName,
+
+    #[arg()]
+    /// Name of the remote with the repository, as defined in the config (ex: 'gitea').
+    pub remote: String,
+
+    #[arg(long)]
+    /// Name of the remote to push the repository to, as defined in the config (ex: 'github').
+    pub to: String,
+
+    #[arg(short, long)]
+    /// Organization or group to keep the mirror in on the other remote.
+    ///
+    /// If not provided, the mirror will be in the authenticated user's account.
+    pub org: Option<String>,
+
+    #[arg(short, long)]
+    /// How often to push the repository, in the remote's format (ex: '8h0m0s' for Gitea).
+    ///
+    /// Gitea also pushes after every commit. Not supported by Gitlab.
+    pub interval: Option<String>,
+}
+
+#[derive(Debug, Clone, Parser)]
+/// Create a repository that pulls from a repository on another remote (Gitea, GitLab Premium)
+pub struct MirrorPull {
+    #[arg()]
+    /// Name of the repository to mirror, on the remote given by --from.
+    ///
+    /// Use `owner/repo` to mirror a repository owned by an organization or group.
+    /// If no owner is given, the repository must be owned by the authenticated user.
+    pub name: RepoName,
+
+    #[arg()]
+    /// Name of the remote to create the mirror on, as defined in the config (ex: 'gitea').
+    pub remote: String,
+
+    #[arg(long)]
+    /// Name of the remote to pull the repository from, as defined in the config (ex: 'github').
+    pub from: String,
+
+    #[arg(short, long)]
+    /// Organization or group to create the mirror in.
+    ///
+    /// If not provided, the mirror will be created in the authenticated user's account.
+    pub org: Option<String>,
+}
+
+#[derive(Debug, Clone, Parser)]
+/// List the mirrors of a repository
+pub struct MirrorList {
+    #[arg()]
+    /// Name of the repository.
+    ///
+    /// Use `owner/repo` for a repository owned by an organization or group.
+    /// If no owner is given, the repository must be owned by the authenticated user.
+    pub name: RepoName,
+
+    #[arg()]
+    /// Name of the remote as defined in the config (ex: 'gitea').
+    pub remote: String,
+
+    #[arg(long)]
+    /// Change the output format to the specified value.
+    ///
+    /// When using the 'json' format, gritty will output the list of mirrors in JSON.
+    pub format: Option<OutputFormat>,
+}
@@ -1,16 +1,32 @@
+use std::{fmt, str::FromStr};
+
 use clap::{Parser, Subcommand};
 
+pub mod archive;
 pub mod clone;
 pub mod create;
 pub mod delete;
+pub mod edit;
 pub mod fork;
 pub mod list;
+pub mod migrate;
+pub mod mirror;
+pub mod rename;
+pub mod sync;
+pub mod transfer;
 
+pub use archive::{Archive, Unarchive};
 pub use clone::Clone;
 pub use create::Create;
 pub use delete::Delete;
+pub use edit::Edit;
 pub use fork::Fork;
 pub use list::List;
+pub use migrate::Migrate;
+pub use mirror::Mirror;
+pub use rename::Rename;
+pub use sync::Sync;
+pub use transfer::Transfer;
 
 #[derive(Debug, Clone, Parser)]
 #[command()]
@@ -26,5 +42,113 @@ pub enum RepoCommands {
     List(List),
     Create(Create),
     Fork(Fork),
+    Sync(Sync),
+    Edit(Edit),
+    Rename(Rename),
+    Transfer(Transfer),
+    Migrate(Migrate),
+    Mirror(Mirror),
+    Archive(Archive),
+    Unarchive(Unarchive),
     Delete(Delete),
 }
+
+/// Turn a pair of `--flag` and `--no-flag` arguments into the chosen value, if any.
+pub(crate) fn toggle(enable: bool, disable: bool) -> Option<bool> {
+    match (enable, disable) {
+        (true, _) => Some(true),
+        (_, true) => Some(false),
+        _ => None,
+    }
+}
+
+/// A repository name, optionally prefixed with its owner (`owner/repo`).
+///
+/// The owner may contain slashes itself to refer to repositories in nested GitLab groups
+/// (`group/subgroup/repo`). If no owner is given, the authenticated user is assumed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RepoName {
+    pub owner: Option<String>,
+    pub name: String,
+}
+
+impl RepoName {
+    /// Get the owner of the repository, or `default` if none was given.
+    pub fn owner_or<'a>(&'a self, default: &'a str) -> &'a str {
+        self.owner.as_deref().unwrap_or(default)
+    }
+}
+
+impl FromStr for RepoName {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (owner, name) = match s.rsplit_once('/') {
+            Some((owner, name)) => (Some(owner.to_string()), name),
+            None => (None, s),
+        };
+        if name.is_empty() || owner.as_ref().is_some_and(|x| x.is_empty()) {
+            return Err("repository name and owner cannot be empty".to_string());
+        }
+        Ok(Self {
+            owner,
+            name: name.to_string(),
+        })
+    }
+}
+
+impl fmt::Display for RepoName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.owner {
+            Some(owner) => write!(f, "{}/{}", owner, self.name),
+            None => write!(f, "{}", self.name),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_repo_name() {
+        let name: RepoName = "gritty".parse().unwrap();
+        assert_eq!(name.owner, None);
+        assert_eq!(name.name, "gritty");
+
+        let name: RepoName = "benpueschel/gritty".parse().unwrap();
+        assert_eq!(name.owner.as_deref(), Some("benpueschel"));
+        assert_eq!(name.name, "gritty");
+        assert_eq!(name.to_string(), "benpueschel/gritty");
+    }
+
+    #[test]
+    fn test_parse_nested_repo_name() {
+        let name: RepoName = "group/subgroup/gritty".parse().unwrap();
+        assert_eq!(name.owner.as_deref(), Some("group/subgroup"));
+        assert_eq!(name.name, "gritty");
+        assert_eq!(name.owner_or("user"), "group/subgroup");
+    }
+
+    #[test]
+    fn test_owner_or_default() {
+        let name: RepoName = "gritty".parse().unwrap();
+        assert_eq!(name.owner_or("user"), "user");
+        let name: RepoName = "team/gritty".parse().unwrap();
+        assert_eq!(name.owner_or("user"), "team");
+    }
+
+    #[test]
+    fn test_archived_last_flag_wins() {
+        let args = List::parse_from(["list", "mock", "--archived", "--no-archived"]);
+        assert_eq!(toggle(args.archived, args.no_archived), Some(false));
+        let args = List::parse_from(["list", "mock", "--no-archived", "--archived"]);
+        assert_eq!(toggle(args.archived, args.no_archived), Some(true));
+    }
+
+    #[test]
+    fn test_parse_empty_repo_name() {
+        assert!("".parse::<RepoName>().is_err());
+        assert!("owner/".parse::<RepoName>().is_err());
+    }
+}
@@ -0,0 +1,33 @@
+use clap::Parser;
+
+use super::RepoName;
+
+#[derive(Debug, Clone, Parser)]
+#[command(aliases = &["mv"])]
+/// Rename a repository on a remote
+///
+/// If the current directory is a clone of the repository, its 'origin' remote is updated to
+/// point to the new name.
+pub struct Rename {
+    #[arg()]
+    /// Name of the repository to rename.
+    ///
+    /// Use `owner/repo` to rename a repository owned by an organization or group.
+    /// If no owner is given, the repository must be owned by the authenticated user.
+    pub name: RepoName,
+
+    #[arg()]
+    /// New name of the repository. The owner stays the same.
+    pub new_name: String,
+
+    #[arg()]
+    /// Name of the remote as defined in the config (ex: 'github').
+    ///
+    /// The remote must be defined in the config file. There are no restrictions on the name,
+    /// it does not have to correspond to the remote type (GitHub, GitLab, Gitea)
+    pub remote: String,
+
+    #[arg(short = 'n', long)]
+    /// Only show what would change, without renaming anything.
+    pub dry_run: bool,
+}
@@ -0,0 +1,33 @@
+use clap::Parser;
+
+use super::RepoName;
+
+#[derive(Debug, Clone, Parser)]
+#[command(allow_missing_positional = true)]
+/// Bring forks up to date with the repositories they were forked from
+///
+/// GitHub and Gitea sync forks themselves. On other remotes, the fork is fetched along with its
+/// parent and pushed if it can be fast-forwarded.
+pub struct Sync {
+    #[arg(required_unless_present = "all_forks", conflicts_with = "all_forks")]
+    /// Name of the fork to sync.
+    ///
+    /// Use `owner/repo` to sync a fork owned by an organization or group.
+    /// If no owner is given, the fork must be owned by the authenticated user.
+    pub name: Option<RepoName>,
+
+    #[arg()]
+    /// Name of the remote as defined in the config (ex: 'github').
+    ///
+    /// The remote must be defined in the config file. There are no restrictions on the name,
+    /// it does not have to correspond to the remote type (GitHub, GitLab, Gitea)
+    pub remote: String,
+
+    #[arg(long)]
+    /// Sync every fork of the authenticated user on the remote.
+    pub all_forks: bool,
+
+    #[arg(short, long)]
+    /// Branch to sync. Defaults to the default branch of every fork.
+    pub branch: Option<String>,
+}
@@ -0,0 +1,29 @@
+use clap::Parser;
+
+use super::RepoName;
+
+#[derive(Debug, Clone, Parser)]
+/// Transfer a repository to another user, organization or group
+pub struct Transfer {
+    #[arg()]
+    /// Name of the repository to transfer.
+    ///
+    /// Use `owner/repo` to transfer a repository owned by an organization or group.
+    /// If no owner is given, the repository must be owned by the authenticated user.
+    pub name: RepoName,
+
+    #[arg()]
+    /// The user, organization or group to transfer the repository to.
+    pub new_owner: String,
+
+    #[arg()]
+    /// Name of the remote as defined in the config (ex: 'github').
+    ///
+    /// The remote must be defined in the config file. There are no restrictions on the name,
+    /// it does not have to correspond to the remote type (GitHub, GitLab, Gitea)
+    pub remote: String,
+
+    #[arg(short, long)]
+    /// Transfer without confirmation.
+    pub force: bool,
+}
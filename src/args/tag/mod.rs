@@ -0,0 +1,24 @@
+use clap::{Parser, Subcommand};
+
+pub mod create;
+pub mod delete;
+pub mod list;
+
+pub use create::Create;
+pub use delete::Delete;
+pub use list::List;
+
+#[derive(Debug, Clone, Parser)]
+#[command()]
+/// Manage the tags of a repository.
+pub struct Tag {
+    #[command(subcommand)]
+    pub subcommand: TagCommands,
+}
+
+#[derive(Debug, Clone, Subcommand)]
+pub enum TagCommands {
+    List(List),
+    Create(Create),
+    Delete(Delete),
+}
@@ -12,7 +12,7 @@ pub async fn status(config: &Config) -> Result<()> {
     }
     for name in remotes {
         print!("{:remote_len$}", format!("{}: ", name));
-        if config.get_auth(&config.secrets, name).is_err() {
+        if config.get_remote_config(name).is_err() {
             println!("{}", "not configured".paint(Highlight::Warning));
             continue;
         }
@@ -0,0 +1,257 @@
+use std::path::Path;
+
+use chrono::{DateTime, Utc};
+use futures::{stream, StreamExt};
+use serde::Serialize;
+
+use crate::args::backup::Backup;
+use crate::config::Config;
+use crate::error::{Error, Result};
+use crate::log::{Highlight, Paint};
+use crate::remote::{ListReposInfo, Remote, Repository};
+
+use super::git::{git, git_with_credentials};
+use super::load_remote;
+
+/// Outcome of backing up a single repository.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+enum BackupStatus {
+    Cloned,
+    Updated,
+    Failed,
+}
+
+#[derive(Debug, Serialize)]
+struct ManifestEntry {
+    /// Path of the mirror, relative to the manifest.
+    path: String,
+    status: BackupStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+    repository: Repository,
+}
+
+/// Written to `<dir>/<remote>/manifest.json` after every backup.
+#[derive(Debug, Serialize)]
+struct Manifest<'a> {
+    remote: &'a str,
+    date: DateTime<Utc>,
+    repositories: Vec<ManifestEntry>,
+}
+
+pub async fn backup(args: Backup, config: &Config) -> Result<()> {
+    let remote = load_remote(&args.remote, config).await?;
+    backup_repositories(args, remote.as_ref()).await
+}
+
+async fn backup_repositories(args: Backup, remote: &dyn Remote) -> Result<()> {
+    let list_info = ListReposInfo {
+        owner: args.org.clone(),
+        private: true,
+        forks: true,
+        affiliated: args.all_affiliations,
+        ..Default::default()
+    };
+    let repos = remote.list_repos(list_info).await?;
+    let root = args.dir.join(&args.remote);
+    std::fs::create_dir_all(&root)?;
+
+    let jobs = args
+        .jobs
+        .unwrap_or_else(|| remote.get_config().concurrency_limit());
+    let credentials = remote.get_config().https_credentials();
+    let total = repos.len();
+    // git runs in blocking tasks, so a slow clone doesn't hold up the others.
+    let entries: Vec<ManifestEntry> = stream::iter(repos)
+        .map(|repo| {
+            let url = remote.clone_url(&repo.owner, &repo.name);
+            let path = format!("{}/{}.git", repo.owner, repo.name);
+            let dir = root.join(&path);
+            let credentials = credentials.clone();
+            let task = check_name(&repo).map(|()| {
+                tokio::task::spawn_blocking(move || {
+                    let credentials = credentials.as_ref().map(|(u, p)| (u.as_str(), p.as_str()));
+                    backup_repo(&url, &dir, credentials)
+                })
+            });
+            async move {
+                let full_name = format!("{}/{}", repo.owner, repo.name);
+                let result = match task {
+                    Ok(task) => match task.await {
+                        Ok(result) => result,
+                        Err(err) => Err(Error::other(format!("Backup task failed: {err}"))),
+                    },
+                    Err(err) => Err(err),
+                };
+                let (status, error) = match result {
+                    Ok(status) => {
+                        let action = match status {
+                            BackupStatus::Cloned => "Cloned",
+                            _ => "Updated",
+                        };
+                        println!("{} {}", action, full_name.paint(Highlight::Repo));
+                        (status, None)
+                    }
+                    Err(err) => {
+                        eprintln!(
+                            "{}: Failed to back up {}: {}",
+                            "Error".paint(Highlight::Important),
+                            full_name.paint(Highlight::Repo),
+                            err
+                        );
+                        (BackupStatus::Failed, Some(err.to_string()))
+                    }
+                };
+                ManifestEntry {
+                    path,
+                    status,
+                    error,
+                    repository: repo,
+                }
+            }
+        })
+        .buffered(jobs.max(1))
+        .collect()
+        .await;
+
+    let failed = entries
+        .iter()
+        .filter(|x| x.status == BackupStatus::Failed)
+        .count();
+    let manifest = Manifest {
+        remote: &args.remote,
+        date: Utc::now(),
+        repositories: entries,
+    };
+    let manifest_path = root.join("manifest.json");
+    std::fs::write(&manifest_path, serde_json::to_string_pretty(&manifest)?)?;
+    if failed > 0 {
+        return Err(Error::other(format!(
+            "Failed to back up {failed} of {total} repositories"
+        )));
+    }
+    println!(
+        "Backed up {} repositories to {}.",
+        total,
+        root.to_string_lossy().to_string().paint(Highlight::Path)
+    );
+    Ok(())
+}
+
+/// Make sure the owner and name of the repository can't escape the backup directory or be
+/// mistaken for git options. Owners may contain slashes for nested groups.
+fn check_name(repo: &Repository) -> Result<()> {
+    let unsafe_part = |x: &str| x.is_empty() || x.contains("..") || x.starts_with('-');
+    if repo.owner.split('/').any(unsafe_part) || unsafe_part(&repo.name) || repo.name.contains('/')
+    {
+        return Err(Error::other(format!(
+            "Refusing to back up {}/{}, its name is not a safe path",
+            repo.owner, repo.name
+        )));
+    }
+    Ok(())
+}
+
+/// Mirror the repository at `url` to `dir`, or update the mirror if there already is one.
+fn backup_repo(url: &str, dir: &Path, credentials: Option<(&str, &str)>) -> Result<BackupStatus> {
+    let dir_str = dir.to_string_lossy();
+    if dir.exists() {
+        // Don't update whatever repository the directory happens to be in.
+        if git(&["-C", &dir_str, "rev-parse", "--git-dir"])
+            .ok()
+            .as_deref()
+            != Some(".")
+        {
+            return Err(Error::other(format!(
+                "{dir_str} exists, but is not a mirror clone"
+            )));
+        }
+        let args = ["-C", &dir_str, "remote", "update", "--prune"];
+        git_with_credentials(&args, credentials)?;
+        return Ok(BackupStatus::Updated);
+    }
+    if let Some(parent) = dir.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let args = ["clone", "--quiet", "--mirror", url, &dir_str];
+    git_with_credentials(&args, credentials)?;
+    Ok(BackupStatus::Cloned)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::commands::mock_remote;
+    use crate::remote::{mock::MockRemote, RemoteConfig};
+    use clap::Parser;
+
+    /// A remote serving empty repositories from `<root>/source`.
+    fn remote(root: &Path, repos: &[(&str, &str)]) -> MockRemote {
+        if root.exists() {
+            std::fs::remove_dir_all(root).unwrap();
+        }
+        let source = root.join("source");
+        let remote = MockRemote::with_config(RemoteConfig {
+            url: source.to_string_lossy().to_string(),
+            ..mock_remote().get_config().clone()
+        });
+        for (owner, name) in repos {
+            let path = source.join(format!("{owner}/{name}.git"));
+            git(&["init", "--quiet", "--bare", &path.to_string_lossy()]).unwrap();
+            remote.insert(Repository {
+                name: name.to_string(),
+                owner: owner.to_string(),
+                ..Default::default()
+            });
+        }
+        remote
+    }
+
+    #[tokio::test]
+    async fn test_backup_all_affiliations() {
+        let root = std::env::temp_dir().join("gritty-backup-test-affiliations");
+        let remote = remote(&root, &[("mock", "tool"), ("team", "shared")]);
+        let dir = root.join("backup").to_string_lossy().to_string();
+
+        let args = Backup::parse_from(["backup", "mock", &dir]);
+        backup_repositories(args, &remote).await.unwrap();
+        assert!(root.join("backup/mock/mock/tool.git").exists());
+        assert!(!root.join("backup/mock/team/shared.git").exists());
+
+        let args = Backup::parse_from(["backup", "mock", &dir, "--all-affiliations"]);
+        backup_repositories(args, &remote).await.unwrap();
+        assert!(root.join("backup/mock/team/shared.git").exists());
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_backup_rejects_unsafe_names() {
+        let root = std::env::temp_dir().join("gritty-backup-test-unsafe");
+        let remote = remote(&root, &[("mock", "tool"), ("mock", "-tool")]);
+        let dir = root.join("backup").to_string_lossy().to_string();
+
+        let args = Backup::parse_from(["backup", "mock", &dir]);
+        let err = backup_repositories(args, &remote).await.unwrap_err();
+        assert_eq!(err.message, "Failed to back up 1 of 2 repositories");
+        assert!(root.join("backup/mock/mock/tool.git").exists());
+        assert!(!root.join("backup/mock/mock/-tool.git").exists());
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_check_name() {
+        let repo = |owner: &str, name: &str| Repository {
+            owner: owner.to_string(),
+            name: name.to_string(),
+            ..Default::default()
+        };
+        assert!(check_name(&repo("group/subgroup", "tool")).is_ok());
+        assert!(check_name(&repo("mock", "..")).is_err());
+        assert!(check_name(&repo("..", "tool")).is_err());
+        assert!(check_name(&repo("mock", "a/b")).is_err());
+        assert!(check_name(&repo("mock", "-tool")).is_err());
+        assert!(check_name(&repo("-mock", "tool")).is_err());
+        assert!(check_name(&repo("group//sub", "tool")).is_err());
+    }
+}
@@ -0,0 +1,60 @@
+use crate::args::branch::Create;
+use crate::args::OutputFormat;
+use crate::config::Config;
+use crate::error::Result;
+use crate::log::{Highlight, Paint};
+use crate::remote::Remote;
+
+use crate::commands::{load_remote, short_sha};
+
+pub async fn create_branch(args: Create, config: &Config) -> Result<()> {
+    let remote = load_remote(&args.remote, config).await?;
+    create(args, remote.as_ref()).await
+}
+
+async fn create(args: Create, remote: &dyn Remote) -> Result<()> {
+    let owner = args.name.owner_or(&remote.get_config().username);
+    let name = &args.name.name;
+    let branch = remote
+        .create_branch(owner, name, &args.branch, args.from.as_deref())
+        .await?;
+    match args.format.unwrap_or_default() {
+        OutputFormat::Human => println!(
+            "Branch {} created in {} at {}.",
+            branch.name.paint(Highlight::Special),
+            format!("{owner}/{name}").paint(Highlight::Repo),
+            short_sha(&branch.commit.sha).paint(Highlight::Commit)
+        ),
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&branch)?),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::commands::mock_remote;
+    use crate::remote::RepoCreateInfo;
+    use clap::Parser;
+
+    #[tokio::test]
+    async fn test_create_from_branch() {
+        let remote = mock_remote();
+        let create_info = RepoCreateInfo {
+            name: "tool".to_string(),
+            init: true,
+            ..Default::default()
+        };
+        remote.create_repo(create_info).await.unwrap();
+        let args = Create::parse_from(["create", "tool", "mock", "release"]);
+        create(args, &remote).await.unwrap();
+        let head = remote.commit("mock", "tool", "Fix").unwrap();
+
+        let args = Create::parse_from(["create", "tool", "mock", "hotfix", "--from", "release"]);
+        create(args, &remote).await.unwrap();
+        let branches = remote.list_branches("mock", "tool").await.unwrap();
+        let hotfix = branches.iter().find(|x| x.name == "hotfix").unwrap();
+        assert_ne!(hotfix.commit, head);
+        assert_eq!(hotfix.commit, branches[1].commit);
+    }
+}
@@ -0,0 +1,87 @@
+use crate::args::branch::Delete;
+use crate::config::Config;
+use crate::error::{Error, Result};
+use crate::log::{Highlight, Paint};
+use crate::remote::Remote;
+
+use crate::commands::{confirm, load_remote};
+
+pub async fn delete_branch(args: Delete, config: &Config) -> Result<()> {
+    let remote = load_remote(&args.remote, config).await?;
+    delete(args, remote.as_ref()).await
+}
+
+async fn delete(args: Delete, remote: &dyn Remote) -> Result<()> {
+    let owner = args.name.owner_or(&remote.get_config().username);
+    let name = &args.name.name;
+    let full_name = format!("{owner}/{name}");
+    let branches = remote.list_branches(owner, name).await?;
+    let Some(branch) = branches.into_iter().find(|x| x.name == args.branch) else {
+        return Err(Error::not_found(format!(
+            "Branch {} not found in {full_name}",
+            args.branch
+        )));
+    };
+    let warning = format!(
+        "You are about to delete branch {} of {}.",
+        branch.name.paint(Highlight::Special),
+        full_name.paint(Highlight::Repo),
+    );
+    if !args.force && !confirm(&warning, Some(&branch.commit))? {
+        println!("{}", "Operation cancelled.".paint(Highlight::Special));
+        return Ok(());
+    }
+    remote.delete_branch(owner, name, &branch.name).await?;
+    println!(
+        "Branch {} of {} deleted.",
+        branch.name.paint(Highlight::Special),
+        full_name.paint(Highlight::Repo)
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::commands::mock_remote;
+    use crate::error::ErrorKind;
+    use crate::remote::{mock::Call, RepoCreateInfo};
+    use clap::Parser;
+
+    async fn remote() -> crate::remote::mock::MockRemote {
+        let remote = mock_remote();
+        let create_info = RepoCreateInfo {
+            name: "tool".to_string(),
+            init: true,
+            ..Default::default()
+        };
+        remote.create_repo(create_info).await.unwrap();
+        remote
+            .create_branch("mock", "tool", "feature", None)
+            .await
+            .unwrap();
+        remote
+    }
+
+    #[tokio::test]
+    async fn test_force_delete() {
+        let remote = remote().await;
+        let args = Delete::parse_from(["delete", "tool", "mock", "feature", "--force"]);
+        delete(args, &remote).await.unwrap();
+        let branches = remote.list_branches("mock", "tool").await.unwrap();
+        assert_eq!(branches.len(), 1);
+        assert_eq!(branches[0].name, "main");
+    }
+
+    #[tokio::test]
+    async fn test_delete_missing_branch() {
+        let remote = remote().await;
+        let args = Delete::parse_from(["delete", "tool", "mock", "nope", "--force"]);
+        let err = delete(args, &remote).await.unwrap_err();
+        assert_eq!(err.kind, ErrorKind::NotFound);
+        assert!(!remote
+            .calls()
+            .iter()
+            .any(|x| matches!(x, Call::DeleteBranch { .. })));
+    }
+}
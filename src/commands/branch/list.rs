@@ -0,0 +1,103 @@
+use crate::args::branch::List;
+use crate::args::OutputFormat;
+use crate::config::Config;
+use crate::error::Result;
+use crate::log::{Highlight, Paint};
+use crate::remote::{Branch, Remote};
+
+use crate::commands::{load_remote, short_sha};
+
+pub async fn list_branches(args: List, config: &Config) -> Result<()> {
+    let remote = load_remote(&args.remote, config).await?;
+    println!("{}", list(args, remote.as_ref()).await?);
+    Ok(())
+}
+
+async fn list(args: List, remote: &dyn Remote) -> Result<String> {
+    let owner = args.name.owner_or(&remote.get_config().username);
+    let branches = remote.list_branches(owner, &args.name.name).await?;
+    Ok(match args.format.unwrap_or_default() {
+        OutputFormat::Human => format_human(&branches),
+        OutputFormat::Json => serde_json::to_string_pretty(&branches)?,
+    })
+}
+
+fn format_human(branches: &[Branch]) -> String {
+    if branches.is_empty() {
+        return "No branches found.".to_string();
+    }
+    let width = branches
+        .iter()
+        .map(|x| x.name.len())
+        .max()
+        .unwrap_or_default();
+    let lines: Vec<_> = branches
+        .iter()
+        .map(|branch| {
+            let commit = &branch.commit;
+            // Only show the first line of the commit message
+            let message = commit.message.lines().next().unwrap_or_default();
+            let mut line = format!(
+                "{} {} - {} by {} on {}",
+                format!("{:<width$}", branch.name).paint(Highlight::Special),
+                short_sha(&commit.sha).paint(Highlight::Commit),
+                message.paint(Highlight::CommitMsg),
+                commit.author.paint(Highlight::Author),
+                commit.date.to_string().paint(Highlight::Date),
+            );
+            if branch.protected {
+                line.push_str(&format!(" {}", "(protected)".paint(Highlight::Warning)));
+            }
+            line
+        })
+        .collect();
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::commands::mock_remote;
+    use crate::remote::{BranchProtection, RepoCreateInfo};
+    use clap::Parser;
+
+    #[tokio::test]
+    async fn test_list_branches() {
+        let remote = mock_remote();
+        let create_info = RepoCreateInfo {
+            name: "tool".to_string(),
+            ..Default::default()
+        };
+        remote.create_repo(create_info).await.unwrap();
+        let args = List::parse_from(["list", "tool", "mock"]);
+        assert_eq!(list(args, &remote).await.unwrap(), "No branches found.");
+
+        let commit = remote.commit("mock", "tool", "Initial commit").unwrap();
+        remote
+            .create_branch("mock", "tool", "feature", None)
+            .await
+            .unwrap();
+        let protection = BranchProtection {
+            required_reviews: 1,
+            ..Default::default()
+        };
+        remote
+            .protect_branch("mock", "tool", "main", protection)
+            .await
+            .unwrap();
+
+        let args = List::parse_from(["list", "tool", "mock"]);
+        let expected = format!(
+            "main    00000000 - Initial commit by mock on {0} (protected)\n\
+             feature 00000000 - Initial commit by mock on {0}",
+            commit.date
+        );
+        assert_eq!(list(args, &remote).await.unwrap(), expected);
+
+        let args = List::parse_from(["list", "tool", "mock", "--format", "json"]);
+        let json: serde_json::Value =
+            serde_json::from_str(&list(args, &remote).await.unwrap()).unwrap();
+        assert_eq!(json[1]["name"], "feature");
+        assert_eq!(json[1]["commit"]["sha"], commit.sha);
+    }
+}
@@ -0,0 +1,17 @@
+use crate::args::branch::{Branch, BranchCommands};
+use crate::config::Config;
+use crate::error::Result;
+
+mod create;
+mod delete;
+mod list;
+mod protect;
+
+pub async fn branch(branch: Branch, config: &Config) -> Result<()> {
+    match branch.subcommand {
+        BranchCommands::List(args) => list::list_branches(args, config).await,
+        BranchCommands::Create(args) => create::create_branch(args, config).await,
+        BranchCommands::Delete(args) => delete::delete_branch(args, config).await,
+        BranchCommands::Protect(args) => protect::protect_branch(args, config).await,
+    }
+}
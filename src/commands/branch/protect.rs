@@ -0,0 +1,90 @@
+use crate::args::branch::Protect;
+use crate::config::Config;
+use crate::error::Result;
+use crate::log::{Highlight, Paint};
+use crate::remote::{BranchProtection, Remote};
+
+use crate::commands::load_remote;
+
+pub async fn protect_branch(args: Protect, config: &Config) -> Result<()> {
+    let remote = load_remote(&args.remote, config).await?;
+    protect(args, remote.as_ref()).await
+}
+
+async fn protect(args: Protect, remote: &dyn Remote) -> Result<()> {
+    let owner = args.name.owner_or(&remote.get_config().username);
+    let name = &args.name.name;
+    let protection = BranchProtection {
+        required_reviews: args.required_reviews,
+        required_status_checks: args.required_checks,
+        no_force_push: args.no_force_push,
+        push_allowlist: args.allow_push,
+    };
+    let unsupported = remote
+        .protect_branch(owner, name, &args.branch, protection)
+        .await?;
+    println!(
+        "Branch {} of {} protected.",
+        args.branch.paint(Highlight::Special),
+        format!("{owner}/{name}").paint(Highlight::Repo)
+    );
+    for setting in unsupported {
+        eprintln!(
+            "{}: {} can't be set on remote {}, and was left out.",
+            "Warning".paint(Highlight::Warning),
+            setting,
+            args.remote.paint(Highlight::Remote)
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::commands::mock_remote;
+    use crate::remote::{mock::Call, RepoCreateInfo};
+    use clap::Parser;
+
+    #[tokio::test]
+    async fn test_protect_branch() {
+        let remote = mock_remote();
+        let create_info = RepoCreateInfo {
+            name: "tool".to_string(),
+            init: true,
+            ..Default::default()
+        };
+        remote.create_repo(create_info).await.unwrap();
+        let args = Protect::parse_from([
+            "protect",
+            "tool",
+            "mock",
+            "main",
+            "--required-reviews",
+            "2",
+            "--required-check",
+            "ci/build",
+            "--required-check",
+            "ci/test",
+            "--no-force-push",
+            "--allow-push",
+            "alice",
+        ]);
+        protect(args, &remote).await.unwrap();
+
+        let Some(Call::ProtectBranch { protection, .. }) = remote.calls().pop() else {
+            panic!("expected the branch to be protected");
+        };
+        assert_eq!(
+            protection,
+            BranchProtection {
+                required_reviews: 2,
+                required_status_checks: vec!["ci/build".to_string(), "ci/test".to_string()],
+                no_force_push: true,
+                push_allowlist: vec!["alice".to_string()],
+            }
+        );
+        let branches = remote.list_branches("mock", "tool").await.unwrap();
+        assert!(branches[0].protected);
+    }
+}
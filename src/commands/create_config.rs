@@ -115,7 +115,7 @@ fn ask_for_remote() -> Result<(String, GitRemoteConfig, Option<AuthConfig>)> {
     }
     print!(
         "Enter the name of the remote ({}): ",
-        "github/gitea/gitlab".paint(Highlight::Remote)
+        "github/gitea/gitlab/bitbucket/local".paint(Highlight::Remote)
     );
     // we need to flush stdout, this is the cleanest way to do it
     log::print("");
@@ -127,9 +127,11 @@ fn ask_for_remote() -> Result<(String, GitRemoteConfig, Option<AuthConfig>)> {
         "github" => remote::Provider::GitHub,
         "gitea" => remote::Provider::Gitea,
         "gitlab" => remote::Provider::GitLab,
+        "bitbucket" => remote::Provider::Bitbucket,
+        "local" => remote::Provider::Local,
         _ => {
             return Err(Error::other(
-                "Remote provider must be either 'github', 'gitea' or 'gitlab'.",
+                "Remote provider must be either 'github', 'gitea', 'gitlab', 'bitbucket' or 'local'.",
             ));
         }
     };
@@ -188,6 +190,8 @@ fn ask_for_remote() -> Result<(String, GitRemoteConfig, Option<AuthConfig>)> {
             provider,
             username,
             url,
+            concurrency: None,
+            commit_count: None,
         },
         auth,
     ))
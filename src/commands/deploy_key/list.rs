@@ -0,0 +1,92 @@
+use crate::args::deploy_key::List;
+use crate::args::OutputFormat;
+use crate::config::Config;
+use crate::error::Result;
+use crate::log::{Highlight, Paint};
+use crate::remote::{DeployKey, Remote};
+
+use crate::commands::load_remote;
+
+pub async fn list_deploy_keys(args: List, config: &Config) -> Result<()> {
+    let remote = load_remote(&args.remote, config).await?;
+    println!("{}", list(args, remote.as_ref()).await?);
+    Ok(())
+}
+
+async fn list(args: List, remote: &dyn Remote) -> Result<String> {
+    let owner = args.name.owner_or(&remote.get_config().username);
+    let keys = remote.list_deploy_keys(owner, &args.name.name).await?;
+    Ok(match args.format.unwrap_or_default() {
+        OutputFormat::Human => format_human(&keys),
+        OutputFormat::Json => serde_json::to_string_pretty(&keys)?,
+    })
+}
+
+fn format_human(keys: &[DeployKey]) -> String {
+    if keys.is_empty() {
+        return "No deploy keys found.".to_string();
+    }
+    let width = keys
+        .iter()
+        .map(|x| x.id.to_string().len())
+        .max()
+        .unwrap_or_default();
+    let lines: Vec<_> = keys
+        .iter()
+        .map(|key| {
+            let mut line = format!(
+                "{} {}",
+                format!("{:<width$}", key.id).paint(Highlight::Special),
+                key.title
+            );
+            if !key.read_only {
+                line.push_str(&format!(" {}", "(read-write)".paint(Highlight::Warning)));
+            }
+            line
+        })
+        .collect();
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::commands::mock_remote;
+    use crate::remote::{RepoCreateInfo, SshKeyCreateInfo};
+    use clap::Parser;
+
+    #[tokio::test]
+    async fn test_list_deploy_keys() {
+        let remote = mock_remote();
+        let create_info = RepoCreateInfo {
+            name: "tool".to_string(),
+            ..Default::default()
+        };
+        remote.create_repo(create_info).await.unwrap();
+        let args = List::parse_from(["list", "tool", "mock"]);
+        assert_eq!(list(args, &remote).await.unwrap(), "No deploy keys found.");
+
+        for (title, read_only) in [("ci", true), ("deploy", false)] {
+            let key = SshKeyCreateInfo {
+                title: title.to_string(),
+                key: format!("ssh-ed25519 AAAAC3NzaC1lZDI1NTE5{title}"),
+            };
+            remote
+                .add_deploy_key("mock", "tool", key, read_only)
+                .await
+                .unwrap();
+        }
+
+        let args = List::parse_from(["list", "tool", "mock"]);
+        assert_eq!(
+            list(args, &remote).await.unwrap(),
+            "1 ci\n2 deploy (read-write)"
+        );
+
+        let args = List::parse_from(["list", "tool", "mock", "--format", "json"]);
+        let json: serde_json::Value =
+            serde_json::from_str(&list(args, &remote).await.unwrap()).unwrap();
+        assert_eq!(json[0]["read_only"], true);
+        assert_eq!(json[1]["title"], "deploy");
+    }
+}
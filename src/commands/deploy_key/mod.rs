@@ -0,0 +1,15 @@
+use crate::args::deploy_key::{DeployKey, DeployKeyCommands};
+use crate::config::Config;
+use crate::error::Result;
+
+mod add;
+mod list;
+mod remove;
+
+pub async fn deploy_key(deploy_key: DeployKey, config: &Config) -> Result<()> {
+    match deploy_key.subcommand {
+        DeployKeyCommands::List(args) => list::list_deploy_keys(args, config).await,
+        DeployKeyCommands::Add(args) => add::add_deploy_key(args, config).await,
+        DeployKeyCommands::Remove(args) => remove::remove_deploy_key(args, config).await,
+    }
+}
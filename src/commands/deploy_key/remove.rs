@@ -0,0 +1,87 @@
+use crate::args::deploy_key::Remove;
+use crate::config::Config;
+use crate::error::{Error, Result};
+use crate::log::{Highlight, Paint};
+use crate::remote::Remote;
+
+use crate::commands::{confirm, load_remote};
+
+pub async fn remove_deploy_key(args: Remove, config: &Config) -> Result<()> {
+    let remote = load_remote(&args.remote, config).await?;
+    remove(args, remote.as_ref()).await
+}
+
+async fn remove(args: Remove, remote: &dyn Remote) -> Result<()> {
+    let owner = args.name.owner_or(&remote.get_config().username);
+    let name = &args.name.name;
+    let full_name = format!("{owner}/{name}");
+    let keys = remote.list_deploy_keys(owner, name).await?;
+    let Some(key) = keys.iter().find(|x| x.id == args.id) else {
+        return Err(Error::not_found(format!(
+            "Deploy key {} not found in {full_name}",
+            args.id
+        )));
+    };
+    let warning = format!(
+        "You are about to remove deploy key {} from {}.",
+        key.title.paint(Highlight::Special),
+        full_name.paint(Highlight::Repo),
+    );
+    if !args.force && !confirm(&warning, None)? {
+        println!("{}", "Operation cancelled.".paint(Highlight::Special));
+        return Ok(());
+    }
+    remote.delete_deploy_key(owner, name, args.id).await?;
+    println!(
+        "Deploy key {} removed from {}.",
+        key.title.paint(Highlight::Special),
+        full_name.paint(Highlight::Repo)
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::commands::mock_remote;
+    use crate::error::ErrorKind;
+    use crate::remote::{mock::Call, RepoCreateInfo, SshKeyCreateInfo};
+    use clap::Parser;
+
+    #[tokio::test]
+    async fn test_force_remove() {
+        let remote = mock_remote();
+        let create_info = RepoCreateInfo {
+            name: "tool".to_string(),
+            ..Default::default()
+        };
+        remote.create_repo(create_info).await.unwrap();
+        let key = SshKeyCreateInfo {
+            title: "ci".to_string(),
+            key: "ssh-ed25519 AAAAC3NzaC1lZDI1NTE5AAAAI".to_string(),
+        };
+        let key = remote
+            .add_deploy_key("mock", "tool", key, true)
+            .await
+            .unwrap();
+
+        let id = key.id.to_string();
+        let args = Remove::parse_from(["remove", "tool", "mock", &id, "--force"]);
+        remove(args, &remote).await.unwrap();
+        assert!(remote
+            .list_deploy_keys("mock", "tool")
+            .await
+            .unwrap()
+            .is_empty());
+
+        let args = Remove::parse_from(["remove", "tool", "mock", &id, "--force"]);
+        let err = remove(args, &remote).await.unwrap_err();
+        assert_eq!(err.kind, ErrorKind::NotFound);
+        let deletes = remote
+            .calls()
+            .into_iter()
+            .filter(|x| matches!(x, Call::DeleteDeployKey { .. }))
+            .count();
+        assert_eq!(deletes, 1);
+    }
+}
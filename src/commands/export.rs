@@ -0,0 +1,462 @@
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Utc};
+use futures::{stream, StreamExt, TryStreamExt};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::args::export::Export;
+use crate::config::Config;
+use crate::error::{Error, ErrorKind, Result};
+use crate::log::{Highlight, Paint};
+use crate::remote::{
+    Comment, Issue, Label, ListIssuesInfo, ListPullRequestsInfo, Milestone, PullRequest, Release,
+    Remote, Repository, WikiPage,
+};
+
+use super::git::git_with_credentials;
+use super::load_remote;
+
+/// The version of the export format. It is bumped on every incompatible change.
+const FORMAT_VERSION: u32 = 1;
+
+/// Describes the export, written to `export.json`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(super) struct Metadata {
+    pub version: u32,
+    /// The name of the remote the repository was exported from.
+    pub remote: String,
+    pub exported_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(super) struct IssueExport {
+    pub issue: Issue,
+    pub comments: Vec<Comment>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(super) struct PullRequestExport {
+    pub pull_request: PullRequest,
+    pub comments: Vec<Comment>,
+}
+
+/// Everything exported from a repository. Every field is stored in its own JSON file, named after
+/// the field.
+#[derive(Debug, Clone)]
+pub(super) struct RepoExport {
+    pub metadata: Metadata,
+    pub repository: Repository,
+    pub labels: Vec<Label>,
+    pub milestones: Vec<Milestone>,
+    pub issues: Vec<IssueExport>,
+    pub pull_requests: Vec<PullRequestExport>,
+    pub releases: Vec<Release>,
+    pub wiki: Vec<WikiPage>,
+}
+
+impl RepoExport {
+    /// Write the export to `path`, as an archive if it ends in `.tar.zst` and as a directory
+    /// otherwise.
+    pub fn write(&self, path: &Path) -> Result<()> {
+        let files = [
+            ("export.json", serde_json::to_vec_pretty(&self.metadata)?),
+            (
+                "repository.json",
+                serde_json::to_vec_pretty(&self.repository)?,
+            ),
+            ("labels.json", serde_json::to_vec_pretty(&self.labels)?),
+            (
+                "milestones.json",
+                serde_json::to_vec_pretty(&self.milestones)?,
+            ),
+            ("issues.json", serde_json::to_vec_pretty(&self.issues)?),
+            (
+                "pull_requests.json",
+                serde_json::to_vec_pretty(&self.pull_requests)?,
+            ),
+            ("releases.json", serde_json::to_vec_pretty(&self.releases)?),
+            ("wiki.json", serde_json::to_vec_pretty(&self.wiki)?),
+        ];
+        if !is_archive(path) {
+            std::fs::create_dir_all(path)?;
+            for (name, data) in files {
+                std::fs::write(path.join(name), data)?;
+            }
+            return Ok(());
+        }
+
+        if let Some(parent) = path.parent().filter(|x| !x.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent)?;
+        }
+        let encoder = zstd::Encoder::new(File::create(path)?, 0)?;
+        let mut archive = tar::Builder::new(encoder);
+        for (name, data) in files {
+            let mut header = tar::Header::new_gnu();
+            header.set_size(data.len() as u64);
+            header.set_mode(0o644);
+            header.set_mtime(self.metadata.exported_at.timestamp().max(0) as u64);
+            header.set_cksum();
+            archive.append_data(&mut header, name, data.as_slice())?;
+        }
+        archive.into_inner()?.finish()?;
+        Ok(())
+    }
+
+    /// Read an export written by [RepoExport::write].
+    pub fn read(path: &Path) -> Result<Self> {
+        let mut files = HashMap::new();
+        if is_archive(path) {
+            let decoder = zstd::Decoder::new(File::open(path)?)?;
+            let mut archive = tar::Archive::new(decoder);
+            for entry in archive.entries()? {
+                let mut entry = entry?;
+                let name = entry.path()?.to_string_lossy().to_string();
+                let mut data = Vec::new();
+                entry.read_to_end(&mut data)?;
+                files.insert(name, data);
+            }
+        } else {
+            for entry in std::fs::read_dir(path)? {
+                let entry = entry?;
+                if !entry.file_type()?.is_file() {
+                    continue;
+                }
+                let name = entry.file_name().to_string_lossy().to_string();
+                files.insert(name, std::fs::read(entry.path())?);
+            }
+        }
+
+        let file = |name: &str| -> Result<&[u8]> {
+            files.get(name).map(Vec::as_slice).ok_or_else(|| {
+                Error::deserialization(format!(
+                    "{} is not a gritty export: {name} is missing",
+                    path.to_string_lossy()
+                ))
+            })
+        };
+        let metadata: Metadata = parse(file("export.json")?, "export.json")?;
+        if metadata.version > FORMAT_VERSION {
+            return Err(Error::deserialization(format!(
+                "{} was exported with a newer version of gritty (format version {}), \
+                 this version only supports format version {FORMAT_VERSION}",
+                path.to_string_lossy(),
+                metadata.version
+            )));
+        }
+        Ok(Self {
+            metadata,
+            repository: parse(file("repository.json")?, "repository.json")?,
+            labels: parse(file("labels.json")?, "labels.json")?,
+            milestones: parse(file("milestones.json")?, "milestones.json")?,
+            issues: parse(file("issues.json")?, "issues.json")?,
+            pull_requests: parse(file("pull_requests.json")?, "pull_requests.json")?,
+            releases: parse(file("releases.json")?, "releases.json")?,
+            wiki: parse(file("wiki.json")?, "wiki.json")?,
+        })
+    }
+}
+
+fn parse<T: DeserializeOwned>(data: &[u8], name: &str) -> Result<T> {
+    serde_json::from_slice(data)
+        .map_err(|err| Error::deserialization(format!("Could not read {name}: {err}")))
+}
+
+fn is_archive(path: &Path) -> bool {
+    path.to_string_lossy().ends_with(".tar.zst")
+}
+
+pub async fn export(args: Export, config: &Config) -> Result<()> {
+    let remote = load_remote(&args.remote, config).await?;
+    export_repository(args, remote.as_ref()).await
+}
+
+async fn export_repository(args: Export, remote: &dyn Remote) -> Result<()> {
+    let owner = args.name.owner_or(&remote.get_config().username);
+    let name = args.name.name.as_str();
+    let repository = remote.get_repo_info(owner, name).await?;
+    let concurrency = remote.get_config().concurrency_limit();
+
+    let labels = section("labels", remote.list_labels(owner, name).await)?;
+    let milestones = section("milestones", remote.list_milestones(owner, name).await)?;
+    let issues = remote.list_issues(owner, name, ListIssuesInfo::default());
+    let issues = stream::iter(section("issues", issues.await)?)
+        .map(|issue| async move {
+            let comments = remote.list_issue_comments(owner, name, issue.number);
+            let comments = comments
+                .await
+                .map_err(|err| context(err, &format!("comments of issue #{}", issue.number)))?;
+            Ok::<_, Error>(IssueExport { issue, comments })
+        })
+        .buffered(concurrency)
+        .try_collect()
+        .await?;
+    let pull_requests = remote.list_pull_requests(owner, name, ListPullRequestsInfo::default());
+    let pull_requests = stream::iter(section("pull requests", pull_requests.await)?)
+        .map(|pull_request| async move {
+            let comments = remote.list_pull_request_comments(owner, name, pull_request.number);
+            let comments = comments.await.map_err(|err| {
+                context(
+                    err,
+                    &format!("comments of pull request #{}", pull_request.number),
+                )
+            })?;
+            Ok::<_, Error>(PullRequestExport {
+                pull_request,
+                comments,
+            })
+        })
+        .buffered(concurrency)
+        .try_collect()
+        .await?;
+    let releases = section("releases", remote.list_releases(owner, name).await)?;
+    let wiki = match remote.list_wiki_pages(owner, name).await {
+        Err(err) if err.kind == ErrorKind::Unsupported => clone_wiki(remote, owner, name),
+        result => result,
+    };
+    let wiki = section("wiki pages", wiki)?;
+
+    let export = RepoExport {
+        metadata: Metadata {
+            version: FORMAT_VERSION,
+            remote: args.remote.clone(),
+            exported_at: Utc::now(),
+        },
+        repository,
+        labels,
+        milestones,
+        issues,
+        pull_requests,
+        releases,
+        wiki,
+    };
+    let output = args
+        .output
+        .unwrap_or_else(|| PathBuf::from(format!("{name}.tar.zst")));
+    export.write(&output)?;
+    println!(
+        "Exported {} issues, {} pull requests, {} releases and {} wiki pages of {} to {}.",
+        export.issues.len(),
+        export.pull_requests.len(),
+        export.releases.len(),
+        export.wiki.len(),
+        export.repository.full_name.paint(Highlight::Repo),
+        output.to_string_lossy().to_string().paint(Highlight::Path)
+    );
+    Ok(())
+}
+
+/// Read the wiki from its git repository, for remotes without a wiki API. Every Markdown file
+/// is a page, named after the file like GitHub does.
+fn clone_wiki(remote: &dyn Remote, owner: &str, name: &str) -> Result<Vec<WikiPage>> {
+    let dir = std::env::temp_dir().join(format!(
+        "gritty-export-{}-{name}.wiki",
+        owner.replace('/', "-")
+    ));
+    if dir.exists() {
+        std::fs::remove_dir_all(&dir)?;
+    }
+    let url = remote.clone_url(owner, name);
+    let url = format!("{}.wiki.git", url.trim_end_matches(".git"));
+    let credentials = remote.get_config().https_credentials();
+    let credentials = credentials.as_ref().map(|(u, p)| (u.as_str(), p.as_str()));
+    let pages = read_wiki(&url, &dir, credentials);
+    if dir.exists() {
+        std::fs::remove_dir_all(&dir)?;
+    }
+    pages
+}
+
+fn read_wiki(url: &str, dir: &Path, credentials: Option<(&str, &str)>) -> Result<Vec<WikiPage>> {
+    let dir_str = dir.to_string_lossy();
+    let args = ["clone", "--quiet", "--depth", "1", url, &dir_str];
+    // The repository only exists once the wiki has a page, so there may be nothing to clone.
+    git_with_credentials(&args, credentials)
+        .map_err(|err| Error::unsupported(format!("Could not clone the wiki: {}", err.message)))?;
+    let mut pages = Vec::new();
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.extension().is_some_and(|x| x == "md") && path.is_file() {
+            let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+            pages.push(WikiPage {
+                title: stem.replace('-', " "),
+                content: std::fs::read_to_string(&path)?,
+            });
+        }
+    }
+    pages.sort_by(|a, b| a.title.cmp(&b.title));
+    Ok(pages)
+}
+
+/// Skip the parts of the repository the remote doesn't support, instead of failing the export.
+fn section<T>(what: &str, result: Result<Vec<T>>) -> Result<Vec<T>> {
+    match result {
+        Err(err) if err.kind == ErrorKind::Unsupported => {
+            eprintln!(
+                "{}: Not exporting {what}: {err}",
+                "Warning".paint(Highlight::Warning)
+            );
+            Ok(Vec::new())
+        }
+        Err(err) => Err(context(err, what)),
+        Ok(items) => Ok(items),
+    }
+}
+
+fn context(err: Error, what: &str) -> Error {
+    Error {
+        message: format!("Failed to export {what}: {}", err.message),
+        ..err
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::commands::git::git;
+    use crate::commands::mock_remote;
+    use crate::remote::mock::{Failure, Method, MockRemote};
+    use crate::remote::{
+        IssueCreateInfo, IssueState, PullRequestState, ReleaseCreateInfo, RemoteConfig,
+    };
+    use clap::Parser;
+
+    async fn repository() -> MockRemote {
+        let remote = mock_remote();
+        remote.insert(Repository {
+            name: "tool".to_string(),
+            owner: "mock".to_string(),
+            full_name: "mock/tool".to_string(),
+            description: Some("A tool".to_string()),
+            ..Default::default()
+        });
+        let label = Label {
+            name: "bug".to_string(),
+            color: "d73a4a".to_string(),
+            description: None,
+        };
+        remote.create_label("mock", "tool", label).await.unwrap();
+        let issue = IssueCreateInfo {
+            title: "Crash".to_string(),
+            labels: vec!["bug".to_string()],
+            ..Default::default()
+        };
+        remote.create_issue("mock", "tool", issue).await.unwrap();
+        remote
+            .create_issue_comment("mock", "tool", 1, "Same here")
+            .await
+            .unwrap();
+        let pull_request = PullRequest {
+            title: "Fix crash".to_string(),
+            state: PullRequestState::Merged,
+            author: "bob".to_string(),
+            head: "fix".to_string(),
+            base: "main".to_string(),
+            ..Default::default()
+        };
+        remote
+            .insert_pull_request("mock", "tool", pull_request)
+            .unwrap();
+        remote
+            .set_issue_state("mock", "tool", 1, IssueState::Closed)
+            .await
+            .unwrap();
+        let release = ReleaseCreateInfo {
+            tag: "v1.0.0".to_string(),
+            ..Default::default()
+        };
+        remote
+            .create_release("mock", "tool", release)
+            .await
+            .unwrap();
+        remote
+    }
+
+    #[tokio::test]
+    async fn test_export_round_trip() {
+        let remote = repository().await;
+        let dir = std::env::temp_dir().join("gritty-test-export");
+        let _ = std::fs::remove_dir_all(&dir);
+        for output in [dir.join("tool"), dir.join("tool.tar.zst")] {
+            let path = output.to_string_lossy().to_string();
+            let args = Export::parse_from(["export", "tool", "mock", "-o", &path]);
+            export_repository(args, &remote).await.unwrap();
+
+            let export = RepoExport::read(&output).unwrap();
+            assert_eq!(export.metadata.version, FORMAT_VERSION);
+            assert_eq!(export.repository.full_name, "mock/tool");
+            assert_eq!(export.labels[0].name, "bug");
+            assert_eq!(export.issues.len(), 1);
+            assert_eq!(export.issues[0].issue.state, IssueState::Closed);
+            assert_eq!(export.issues[0].comments[0].body, "Same here");
+            assert_eq!(export.pull_requests[0].pull_request.number, 2);
+            assert_eq!(export.releases[0].tag, "v1.0.0");
+            assert!(export.wiki.is_empty());
+        }
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_export_skips_unsupported() {
+        let remote = repository().await;
+        remote.fail(
+            Method::ListWikiPages,
+            Failure::Error(Error::unsupported("Wikis are not supported")),
+        );
+        remote.fail(Method::ListReleases, Failure::NotFound);
+        let dir = std::env::temp_dir().join("gritty-test-export-unsupported");
+        let path = dir.to_string_lossy().to_string();
+
+        let args = Export::parse_from(["export", "tool", "mock", "-o", &path]);
+        let err = export_repository(args, &remote).await.unwrap_err();
+        assert_eq!(err.message, "Failed to export releases: Not Found");
+
+        remote.succeed(Method::ListReleases);
+        let args = Export::parse_from(["export", "tool", "mock", "-o", &path]);
+        export_repository(args, &remote).await.unwrap();
+        assert!(RepoExport::read(&dir).unwrap().wiki.is_empty());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_clone_wiki() {
+        let root = std::env::temp_dir().join("gritty-test-export-wiki");
+        let _ = std::fs::remove_dir_all(&root);
+        let wiki = root.join("mock/tool.wiki.git");
+        let wiki_str = wiki.to_string_lossy().to_string();
+        git(&["init", "--quiet", &wiki_str]).unwrap();
+        std::fs::write(wiki.join("Home.md"), "Welcome").unwrap();
+        std::fs::write(wiki.join("Getting-Started.md"), "Run it").unwrap();
+        std::fs::write(wiki.join("logo.png"), "").unwrap();
+        git(&["-C", &wiki_str, "add", "."]).unwrap();
+        let identity = ["-c", "user.name=Mock", "-c", "user.email=mock@mock.invalid"];
+        let mut args = vec!["-C", &wiki_str];
+        args.extend(identity);
+        args.extend(["commit", "--quiet", "-m", "Add pages"]);
+        git(&args).unwrap();
+
+        let remote = MockRemote::with_config(RemoteConfig {
+            url: root.to_string_lossy().to_string(),
+            ..mock_remote().get_config().clone()
+        });
+        let pages = clone_wiki(&remote, "mock", "tool").unwrap();
+        std::fs::remove_dir_all(&root).unwrap();
+        let titles: Vec<_> = pages.iter().map(|x| x.title.as_str()).collect();
+        assert_eq!(titles, ["Getting Started", "Home"]);
+        assert_eq!(pages[1].content, "Welcome");
+
+        let err = clone_wiki(&remote, "mock", "tool").unwrap_err();
+        assert_eq!(err.kind, ErrorKind::Unsupported);
+    }
+
+    #[test]
+    fn test_read_rejects_other_directories() {
+        let err = RepoExport::read(Path::new("src")).unwrap_err();
+        assert_eq!(
+            err.message,
+            "src is not a gritty export: export.json is missing"
+        );
+    }
+}
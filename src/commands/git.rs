@@ -0,0 +1,130 @@
+use std::process::Command;
+
+use crate::error::{Error, Result};
+
+/// Run git with the given arguments, returning its output.
+pub(super) fn git(args: &[&str]) -> Result<String> {
+    let output = Command::new("git").args(args).output()?;
+    check_output(args, output)
+}
+
+/// Git config answering credential requests from the environment, so secrets don't end up in the
+/// arguments or in the config of the repository. The empty helper first disables any configured
+/// helpers, which could otherwise store the credentials.
+const CREDENTIAL_HELPER: [&str; 4] = [
+    "-c",
+    "credential.helper=",
+    "-c",
+    "credential.helper=!f() { test \"$1\" = get && echo \"username=$GRITTY_GIT_USERNAME\" && echo \"password=$GRITTY_GIT_PASSWORD\"; }; f",
+];
+
+/// Run git with the given arguments, answering its HTTP(S) credential requests with
+/// `credentials`, returning its output.
+pub(super) fn git_with_credentials(
+    args: &[&str],
+    credentials: Option<(&str, &str)>,
+) -> Result<String> {
+    let mut command = Command::new("git");
+    if let Some((username, password)) = credentials {
+        command
+            .args(CREDENTIAL_HELPER)
+            .env("GRITTY_GIT_USERNAME", username)
+            .env("GRITTY_GIT_PASSWORD", password);
+    }
+    let output = command.args(args).output()?;
+    check_output(args, output)
+}
+
+/// Run git with the given arguments, returning whether it succeeded. For commands like
+/// `merge-base --is-ancestor` that answer with their exit code.
+pub(super) fn git_succeeds(args: &[&str]) -> Result<bool> {
+    let output = Command::new("git").args(args).output()?;
+    Ok(output.status.success())
+}
+
+/// Run git with the given arguments and input, returning its output.
+pub(super) fn git_with_input(args: &[&str], input: &str) -> Result<String> {
+    use std::io::Write;
+
+    let mut child = Command::new("git")
+        .args(args)
+        .stdin(std::process::Stdio::piped())
+        .stdout(std::process::Stdio::piped())
+        .stderr(std::process::Stdio::piped())
+        .spawn()?;
+    child
+        .stdin
+        .take()
+        .expect("stdin must be piped")
+        .write_all(input.as_bytes())?;
+    check_output(args, child.wait_with_output()?)
+}
+
+fn check_output(args: &[&str], output: std::process::Output) -> Result<String> {
+    if !output.status.success() {
+        return Err(Error::other(format!(
+            "Failed to run git {}: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .trim_end()
+        .to_string())
+}
+
+/// Start a git HTTP server that asks for credentials and rejects the request once it gets them.
+/// Returns the URL of the server and a handle to the `username:password` git answered with.
+#[cfg(test)]
+pub(super) fn credentials_server() -> (String, std::thread::JoinHandle<Option<String>>) {
+    use base64::{prelude::BASE64_STANDARD, Engine};
+    use std::io::{BufRead, BufReader, ErrorKind, Write};
+    use std::net::TcpListener;
+    use std::time::{Duration, Instant};
+
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    listener.set_nonblocking(true).unwrap();
+    let handle = std::thread::spawn(move || {
+        let deadline = Instant::now() + Duration::from_secs(30);
+        while Instant::now() < deadline {
+            let mut stream = match listener.accept() {
+                Ok((stream, _)) => stream,
+                Err(err) if err.kind() == ErrorKind::WouldBlock => {
+                    std::thread::sleep(Duration::from_millis(10));
+                    continue;
+                }
+                Err(_) => return None,
+            };
+            stream.set_nonblocking(false).ok()?;
+            let authorization = BufReader::new(&stream)
+                .lines()
+                .map_while(|line| line.ok())
+                .take_while(|line| !line.is_empty())
+                .find_map(|line| {
+                    let (name, value) = line.split_once(':')?;
+                    name.eq_ignore_ascii_case("authorization")
+                        .then(|| value.trim().to_string())
+                });
+            let Some(authorization) = authorization else {
+                stream
+                    .write_all(
+                        b"HTTP/1.1 401 Unauthorized\r\nWWW-Authenticate: Basic realm=\"git\"\r\n\
+                          Content-Length: 0\r\nConnection: close\r\n\r\n",
+                    )
+                    .ok()?;
+                continue;
+            };
+            stream
+                .write_all(
+                    b"HTTP/1.1 403 Forbidden\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+                )
+                .ok()?;
+            let encoded = authorization.strip_prefix("Basic ")?;
+            let decoded = BASE64_STANDARD.decode(encoded).ok()?;
+            return String::from_utf8(decoded).ok();
+        }
+        None
+    });
+    (url, handle)
+}
@@ -0,0 +1,83 @@
+use crate::args::hook::Create;
+use crate::args::OutputFormat;
+use crate::config::Config;
+use crate::error::Result;
+use crate::log::{Highlight, Paint};
+use crate::remote::{Remote, WebhookCreateInfo};
+
+use crate::commands::load_remote;
+
+pub async fn create_hook(args: Create, config: &Config) -> Result<()> {
+    let remote = load_remote(&args.remote, config).await?;
+    create(args, remote.as_ref()).await
+}
+
+async fn create(args: Create, remote: &dyn Remote) -> Result<()> {
+    let owner = args.name.owner_or(&remote.get_config().username);
+    let name = &args.name.name;
+    let hook = WebhookCreateInfo {
+        url: args.url,
+        secret: args.secret,
+        content_type: args.content_type,
+        events: args.events,
+        active: !args.inactive,
+    };
+    let hook = remote.create_webhook(owner, name, hook).await?;
+    match args.format.unwrap_or_default() {
+        OutputFormat::Human => println!(
+            "Webhook {} created in {}.",
+            hook.id.to_string().paint(Highlight::Special),
+            format!("{owner}/{name}").paint(Highlight::Repo)
+        ),
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&hook)?),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::commands::mock_remote;
+    use crate::remote::{RepoCreateInfo, WebhookContentType, WebhookEvent};
+    use clap::Parser;
+
+    #[tokio::test]
+    async fn test_create_hook() {
+        let remote = mock_remote();
+        let create_info = RepoCreateInfo {
+            name: "tool".to_string(),
+            ..Default::default()
+        };
+        remote.create_repo(create_info).await.unwrap();
+
+        let args = Create::parse_from([
+            "create",
+            "tool",
+            "mock",
+            "--url",
+            "https://ci.example.com/hook",
+            "-e",
+            "push",
+            "-e",
+            "pull_request",
+            "--content-type",
+            "form",
+            "--inactive",
+        ]);
+        create(args, &remote).await.unwrap();
+        let hooks = remote.list_webhooks("mock", "tool").await.unwrap();
+        assert_eq!(
+            hooks[0].events,
+            vec![WebhookEvent::Push, WebhookEvent::PullRequest]
+        );
+        assert_eq!(hooks[0].content_type, WebhookContentType::Form);
+        assert!(!hooks[0].active);
+
+        // Only push events by default.
+        let args = Create::parse_from(["create", "tool", "mock", "--url", "https://x"]);
+        create(args, &remote).await.unwrap();
+        let hooks = remote.list_webhooks("mock", "tool").await.unwrap();
+        assert_eq!(hooks[1].events, vec![WebhookEvent::Push]);
+        assert!(hooks[1].active);
+    }
+}
@@ -0,0 +1,84 @@
+use crate::args::hook::Delete;
+use crate::config::Config;
+use crate::error::{Error, Result};
+use crate::log::{Highlight, Paint};
+use crate::remote::Remote;
+
+use crate::commands::{confirm, load_remote};
+
+pub async fn delete_hook(args: Delete, config: &Config) -> Result<()> {
+    let remote = load_remote(&args.remote, config).await?;
+    delete(args, remote.as_ref()).await
+}
+
+async fn delete(args: Delete, remote: &dyn Remote) -> Result<()> {
+    let owner = args.name.owner_or(&remote.get_config().username);
+    let name = &args.name.name;
+    let full_name = format!("{owner}/{name}");
+    let hooks = remote.list_webhooks(owner, name).await?;
+    let Some(hook) = hooks.iter().find(|x| x.id == args.id) else {
+        return Err(Error::not_found(format!(
+            "Webhook {} not found in {full_name}",
+            args.id
+        )));
+    };
+    let warning = format!(
+        "You are about to delete the webhook of {} that delivers to {}.",
+        full_name.paint(Highlight::Repo),
+        hook.url.paint(Highlight::Url),
+    );
+    if !args.force && !confirm(&warning, None)? {
+        println!("{}", "Operation cancelled.".paint(Highlight::Special));
+        return Ok(());
+    }
+    remote.delete_webhook(owner, name, args.id).await?;
+    println!(
+        "Webhook {} of {} deleted.",
+        args.id.to_string().paint(Highlight::Special),
+        full_name.paint(Highlight::Repo)
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::commands::mock_remote;
+    use crate::error::ErrorKind;
+    use crate::remote::{mock::Call, RepoCreateInfo, WebhookCreateInfo};
+    use clap::Parser;
+
+    #[tokio::test]
+    async fn test_force_delete() {
+        let remote = mock_remote();
+        let create_info = RepoCreateInfo {
+            name: "tool".to_string(),
+            ..Default::default()
+        };
+        remote.create_repo(create_info).await.unwrap();
+        let hook = WebhookCreateInfo {
+            url: "https://ci.example.com/hook".to_string(),
+            ..Default::default()
+        };
+        let hook = remote.create_webhook("mock", "tool", hook).await.unwrap();
+
+        let id = hook.id.to_string();
+        let args = Delete::parse_from(["delete", "tool", "mock", &id, "--force"]);
+        delete(args, &remote).await.unwrap();
+        assert!(remote
+            .list_webhooks("mock", "tool")
+            .await
+            .unwrap()
+            .is_empty());
+
+        let args = Delete::parse_from(["delete", "tool", "mock", &id, "--force"]);
+        let err = delete(args, &remote).await.unwrap_err();
+        assert_eq!(err.kind, ErrorKind::NotFound);
+        let deletes = remote
+            .calls()
+            .into_iter()
+            .filter(|x| matches!(x, Call::DeleteWebhook { .. }))
+            .count();
+        assert_eq!(deletes, 1);
+    }
+}
@@ -0,0 +1,88 @@
+use crate::args::hook::List;
+use crate::args::OutputFormat;
+use crate::config::Config;
+use crate::error::Result;
+use crate::remote::{Remote, Webhook};
+
+use super::format_hook;
+use crate::commands::load_remote;
+
+pub async fn list_hooks(args: List, config: &Config) -> Result<()> {
+    let remote = load_remote(&args.remote, config).await?;
+    println!("{}", list(args, remote.as_ref()).await?);
+    Ok(())
+}
+
+async fn list(args: List, remote: &dyn Remote) -> Result<String> {
+    let owner = args.name.owner_or(&remote.get_config().username);
+    let hooks = remote.list_webhooks(owner, &args.name.name).await?;
+    Ok(match args.format.unwrap_or_default() {
+        OutputFormat::Human => format_human(&hooks),
+        OutputFormat::Json => serde_json::to_string_pretty(&hooks)?,
+    })
+}
+
+fn format_human(hooks: &[Webhook]) -> String {
+    if hooks.is_empty() {
+        return "No webhooks found.".to_string();
+    }
+    let width = hooks
+        .iter()
+        .map(|x| x.id.to_string().len())
+        .max()
+        .unwrap_or_default();
+    let lines: Vec<_> = hooks.iter().map(|x| format_hook(x, width)).collect();
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::commands::mock_remote;
+    use crate::remote::{RepoCreateInfo, WebhookContentType, WebhookCreateInfo, WebhookEvent};
+    use clap::Parser;
+
+    #[tokio::test]
+    async fn test_list_hooks() {
+        let remote = mock_remote();
+        let create_info = RepoCreateInfo {
+            name: "tool".to_string(),
+            ..Default::default()
+        };
+        remote.create_repo(create_info).await.unwrap();
+        let args = List::parse_from(["list", "tool", "mock"]);
+        assert_eq!(list(args, &remote).await.unwrap(), "No webhooks found.");
+
+        let hooks = [
+            WebhookCreateInfo {
+                url: "https://ci.example.com/hook".to_string(),
+                events: vec![WebhookEvent::Push, WebhookEvent::PullRequest],
+                active: true,
+                ..Default::default()
+            },
+            WebhookCreateInfo {
+                url: "https://chat.example.com/hook".to_string(),
+                content_type: WebhookContentType::Form,
+                events: vec![WebhookEvent::Release],
+                active: false,
+                ..Default::default()
+            },
+        ];
+        for hook in hooks {
+            remote.create_webhook("mock", "tool", hook).await.unwrap();
+        }
+
+        let args = List::parse_from(["list", "tool", "mock"]);
+        assert_eq!(
+            list(args, &remote).await.unwrap(),
+            "1 https://ci.example.com/hook [push, pull_request]\n\
+             2 https://chat.example.com/hook [release] (form) (inactive)"
+        );
+
+        let args = List::parse_from(["list", "tool", "mock", "--format", "json"]);
+        let json: serde_json::Value =
+            serde_json::from_str(&list(args, &remote).await.unwrap()).unwrap();
+        assert_eq!(json[0]["id"], 1);
+        assert_eq!(json[1]["active"], false);
+    }
+}
@@ -0,0 +1,46 @@
+use crate::args::hook::{Hook, HookCommands};
+use crate::config::Config;
+use crate::error::Result;
+use crate::log::{Highlight, Paint};
+use crate::remote::{Webhook, WebhookContentType};
+use clap::ValueEnum;
+
+mod create;
+mod delete;
+mod list;
+mod test;
+mod update;
+
+pub async fn hook(hook: Hook, config: &Config) -> Result<()> {
+    match hook.subcommand {
+        HookCommands::List(args) => list::list_hooks(args, config).await,
+        HookCommands::Create(args) => create::create_hook(args, config).await,
+        HookCommands::Update(args) => update::update_hook(args, config).await,
+        HookCommands::Delete(args) => delete::delete_hook(args, config).await,
+        HookCommands::Test(args) => test::test_hook(args, config).await,
+    }
+}
+
+/// Format a webhook as a single line, in the format used by `hook list`.
+fn format_hook(hook: &Webhook, width: usize) -> String {
+    let events = hook
+        .events
+        .iter()
+        .filter_map(|x| x.to_possible_value())
+        .map(|x| x.get_name().to_string())
+        .collect::<Vec<_>>()
+        .join(", ");
+    let mut line = format!(
+        "{} {} [{}]",
+        format!("{:<width$}", hook.id).paint(Highlight::Special),
+        hook.url.paint(Highlight::Url),
+        events
+    );
+    if hook.content_type == WebhookContentType::Form {
+        line.push_str(&format!(" {}", "(form)".paint(Highlight::Protocol)));
+    }
+    if !hook.active {
+        line.push_str(&format!(" {}", "(inactive)".paint(Highlight::Warning)));
+    }
+    line
+}
@@ -0,0 +1,50 @@
+use crate::args::hook::Test;
+use crate::config::Config;
+use crate::error::Result;
+use crate::log::{Highlight, Paint};
+use crate::remote::Remote;
+
+use crate::commands::load_remote;
+
+pub async fn test_hook(args: Test, config: &Config) -> Result<()> {
+    let remote = load_remote(&args.remote, config).await?;
+    test(args, remote.as_ref()).await
+}
+
+async fn test(args: Test, remote: &dyn Remote) -> Result<()> {
+    let owner = args.name.owner_or(&remote.get_config().username);
+    let name = &args.name.name;
+    remote.test_webhook(owner, name, args.id).await?;
+    println!(
+        "Test delivery for webhook {} of {} requested.",
+        args.id.to_string().paint(Highlight::Special),
+        format!("{owner}/{name}").paint(Highlight::Repo)
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::mock_remote;
+    use crate::remote::{mock::Call, RepoCreateInfo};
+    use clap::Parser;
+
+    #[tokio::test]
+    async fn test_missing_hook() {
+        let remote = mock_remote();
+        let create_info = RepoCreateInfo {
+            name: "tool".to_string(),
+            ..Default::default()
+        };
+        remote.create_repo(create_info).await.unwrap();
+
+        let args = Test::parse_from(["test", "tool", "mock", "7"]);
+        let err = test(args, &remote).await.unwrap_err();
+        assert_eq!(err.status, Some(404));
+        assert!(matches!(
+            remote.calls().pop(),
+            Some(Call::TestWebhook { id: 7, .. })
+        ));
+    }
+}
@@ -0,0 +1,77 @@
+use crate::args::hook::Update;
+use crate::args::OutputFormat;
+use crate::config::Config;
+use crate::error::Result;
+use crate::log::{Highlight, Paint};
+use crate::remote::{Remote, WebhookUpdateInfo};
+
+use crate::commands::load_remote;
+
+pub async fn update_hook(args: Update, config: &Config) -> Result<()> {
+    let remote = load_remote(&args.remote, config).await?;
+    update(args, remote.as_ref()).await
+}
+
+async fn update(args: Update, remote: &dyn Remote) -> Result<()> {
+    let owner = args.name.owner_or(&remote.get_config().username);
+    let name = &args.name.name;
+    let update = WebhookUpdateInfo {
+        url: args.url,
+        secret: args.secret,
+        content_type: args.content_type,
+        events: (!args.events.is_empty()).then_some(args.events),
+        active: args.active,
+    };
+    let hook = remote.update_webhook(owner, name, args.id, update).await?;
+    match args.format.unwrap_or_default() {
+        OutputFormat::Human => println!(
+            "Webhook {} of {} updated.",
+            hook.id.to_string().paint(Highlight::Special),
+            format!("{owner}/{name}").paint(Highlight::Repo)
+        ),
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&hook)?),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::commands::mock_remote;
+    use crate::remote::{
+        mock::Call, RepoCreateInfo, WebhookContentType, WebhookCreateInfo, WebhookEvent,
+    };
+    use clap::Parser;
+
+    #[tokio::test]
+    async fn test_update_hook() {
+        let remote = mock_remote();
+        let create_info = RepoCreateInfo {
+            name: "tool".to_string(),
+            ..Default::default()
+        };
+        remote.create_repo(create_info).await.unwrap();
+        let hook = WebhookCreateInfo {
+            url: "https://ci.example.com/hook".to_string(),
+            ..Default::default()
+        };
+        let hook = remote.create_webhook("mock", "tool", hook).await.unwrap();
+
+        let id = hook.id.to_string();
+        let args = Update::parse_from([
+            "update", "tool", "mock", &id, "-e", "release", "--active", "false",
+        ]);
+        update(args, &remote).await.unwrap();
+        // Settings that weren't given are left out of the update.
+        assert!(matches!(
+            remote.calls().pop(),
+            Some(Call::UpdateWebhook { update, .. })
+                if update.url.is_none() && update.content_type.is_none()
+        ));
+        let hooks = remote.list_webhooks("mock", "tool").await.unwrap();
+        assert_eq!(hooks[0].url, "https://ci.example.com/hook");
+        assert_eq!(hooks[0].content_type, WebhookContentType::Json);
+        assert_eq!(hooks[0].events, vec![WebhookEvent::Release]);
+        assert!(!hooks[0].active);
+    }
+}
@@ -0,0 +1,582 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
+
+use chrono::{DateTime, Utc};
+
+use crate::args::export::Import;
+use crate::config::Config;
+use crate::error::{Error, ErrorKind, Result};
+use crate::log::{Highlight, Paint};
+use crate::remote::{
+    Comment, IssueCreateInfo, IssueState, ListIssuesInfo, PullRequestState, ReleaseCreateInfo,
+    Remote, RepoCreateInfo, Repository,
+};
+
+use super::export::{IssueExport, PullRequestExport, RepoExport};
+use super::load_remote;
+
+/// Counts the imported items, reporting the ones that could not be imported.
+#[derive(Debug, Default)]
+struct Progress {
+    total: usize,
+    failed: usize,
+}
+
+impl Progress {
+    /// Record the outcome of importing an item. Returns `None` if it failed.
+    ///
+    /// Unsupported operations are returned as errors instead, since the remaining items of the
+    /// same kind would fail the same way.
+    fn record<T>(&mut self, what: impl Display, result: Result<T>) -> Result<Option<T>> {
+        match result {
+            Ok(item) => {
+                self.total += 1;
+                Ok(Some(item))
+            }
+            Err(err) if err.kind == ErrorKind::Unsupported => Err(err),
+            Err(err) => {
+                self.total += 1;
+                self.failed += 1;
+                eprintln!(
+                    "{}: Failed to import {what}: {err}",
+                    "Error".paint(Highlight::Important)
+                );
+                Ok(None)
+            }
+        }
+    }
+}
+
+/// An issue or pull request, which are imported together in the order they were opened.
+enum Item<'a> {
+    Issue(&'a IssueExport),
+    PullRequest(&'a PullRequestExport),
+}
+
+impl Item<'_> {
+    fn number(&self) -> u64 {
+        match self {
+            Item::Issue(x) => x.issue.number,
+            Item::PullRequest(x) => x.pull_request.number,
+        }
+    }
+
+    fn title(&self) -> &str {
+        match self {
+            Item::Issue(x) => &x.issue.title,
+            Item::PullRequest(x) => &x.pull_request.title,
+        }
+    }
+
+    fn comments(&self) -> &[Comment] {
+        match self {
+            Item::Issue(x) => &x.comments,
+            Item::PullRequest(x) => &x.comments,
+        }
+    }
+
+    /// Whether the item has to be closed once it is created.
+    fn closed(&self) -> bool {
+        match self {
+            Item::Issue(x) => x.issue.state == IssueState::Closed,
+            Item::PullRequest(x) => x.pull_request.state != PullRequestState::Open,
+        }
+    }
+
+    /// The issue to create for the item, with the labels and milestone the repository has.
+    fn create_info(
+        &self,
+        labels: &HashSet<String>,
+        milestones: &HashMap<String, u64>,
+    ) -> IssueCreateInfo {
+        let (title, body, item_labels, milestone, header) = match self {
+            Item::Issue(IssueExport { issue, .. }) => {
+                let origin = match &issue.html_url {
+                    Some(url) => format!(" as {url}"),
+                    None => String::new(),
+                };
+                let header = attribution("Opened", &issue.author, issue.created_at, &origin);
+                (
+                    &issue.title,
+                    &issue.body,
+                    &issue.labels,
+                    &issue.milestone,
+                    header,
+                )
+            }
+            Item::PullRequest(PullRequestExport { pull_request, .. }) => {
+                let state = match pull_request.state {
+                    PullRequestState::Open => "open",
+                    PullRequestState::Closed => "closed",
+                    PullRequestState::Merged => "merged",
+                };
+                let url = match &pull_request.html_url {
+                    Some(url) => format!("{url} "),
+                    None => String::new(),
+                };
+                let origin = format!(
+                    " as pull request {url}from `{}` into `{}` ({state})",
+                    pull_request.head, pull_request.base
+                );
+                let header = attribution(
+                    "Opened",
+                    &pull_request.author,
+                    pull_request.created_at,
+                    &origin,
+                );
+                (
+                    &pull_request.title,
+                    &pull_request.body,
+                    &pull_request.labels,
+                    &pull_request.milestone,
+                    header,
+                )
+            }
+        };
+        IssueCreateInfo {
+            title: title.clone(),
+            body: Some(with_header(&header, body.as_deref())),
+            // A label that doesn't exist would fail the whole issue.
+            labels: item_labels
+                .iter()
+                .filter(|x| labels.contains(*x))
+                .cloned()
+                .collect(),
+            milestone: milestone.as_ref().and_then(|x| milestones.get(x)).copied(),
+            // The users may not exist on the target, the header credits the author instead.
+            assignees: Vec::new(),
+        }
+    }
+}
+
+impl Display for Item<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Item::Issue(x) => write!(f, "issue #{}", x.issue.number),
+            Item::PullRequest(x) => write!(f, "pull request #{}", x.pull_request.number),
+        }
+    }
+}
+
+pub async fn import(args: Import, config: &Config) -> Result<()> {
+    let remote = load_remote(&args.remote, config).await?;
+    import_repository(args, remote.as_ref()).await
+}
+
+async fn import_repository(args: Import, remote: &dyn Remote) -> Result<()> {
+    let export = RepoExport::read(&args.input)?;
+    let owner = args.name.owner_or(&remote.get_config().username);
+    let repo = match remote.get_repo_info(owner, &args.name.name).await {
+        Ok(repo) => repo,
+        Err(err) if err.kind == ErrorKind::NotFound => {
+            let create_info = RepoCreateInfo {
+                name: args.name.name.clone(),
+                owner: args.name.owner.clone(),
+                description: export.repository.description.clone(),
+                private: export.repository.private,
+                ..Default::default()
+            };
+            let repo = remote.create_repo(create_info).await?;
+            println!(
+                "Created repository {}.",
+                repo.full_name.paint(Highlight::Repo)
+            );
+            repo
+        }
+        Err(err) => return Err(err),
+    };
+
+    let mut progress = Progress::default();
+    let labels = import_labels(&export, &repo, remote, &mut progress);
+    let labels = skip_unsupported("labels", labels)
+        .await?
+        .unwrap_or_default();
+    let milestones = import_milestones(&export, &repo, remote, &mut progress);
+    let milestones = skip_unsupported("milestones", milestones)
+        .await?
+        .unwrap_or_default();
+    let issues = import_issues(&export, &repo, remote, &labels, &milestones, &mut progress);
+    skip_unsupported("issues", issues).await?;
+    let releases = import_releases(&export, &repo, remote, &mut progress);
+    skip_unsupported("releases", releases).await?;
+    let wiki = import_wiki(&export, &repo, remote, &mut progress);
+    skip_unsupported("wiki pages", wiki).await?;
+
+    if progress.failed > 0 {
+        return Err(Error::other(format!(
+            "Failed to import {} of {} items",
+            progress.failed, progress.total
+        )));
+    }
+    println!(
+        "Imported {} items into {}.",
+        progress.total,
+        repo.full_name.paint(Highlight::Repo)
+    );
+    Ok(())
+}
+
+/// Skip the parts of the export the remote doesn't support, instead of failing the import.
+async fn skip_unsupported<T>(
+    what: &str,
+    section: impl std::future::Future<Output = Result<T>>,
+) -> Result<Option<T>> {
+    match section.await {
+        Ok(result) => Ok(Some(result)),
+        Err(err) if err.kind == ErrorKind::Unsupported => {
+            eprintln!(
+                "{}: Not importing {what}: {err}",
+                "Warning".paint(Highlight::Warning)
+            );
+            Ok(None)
+        }
+        Err(err) => Err(err),
+    }
+}
+
+/// Create the labels the repository doesn't have yet.
+/// Returns the names of the labels the repository has afterwards.
+async fn import_labels(
+    export: &RepoExport,
+    repo: &Repository,
+    remote: &dyn Remote,
+    progress: &mut Progress,
+) -> Result<HashSet<String>> {
+    let existing = remote.list_labels(&repo.owner, &repo.name).await?;
+    let mut names: HashSet<_> = existing.into_iter().map(|x| x.name).collect();
+    for label in &export.labels {
+        if names.contains(&label.name) {
+            continue;
+        }
+        let created = remote
+            .create_label(&repo.owner, &repo.name, label.clone())
+            .await;
+        let what = format!("label {}", label.name);
+        if progress.record(what, created)?.is_some() {
+            names.insert(label.name.clone());
+        }
+    }
+    Ok(names)
+}
+
+/// Create the milestones the repository doesn't have yet.
+/// Returns the IDs of the milestones the repository has afterwards, by title.
+async fn import_milestones(
+    export: &RepoExport,
+    repo: &Repository,
+    remote: &dyn Remote,
+    progress: &mut Progress,
+) -> Result<HashMap<String, u64>> {
+    let existing = remote.list_milestones(&repo.owner, &repo.name).await?;
+    let mut ids: HashMap<_, _> = existing.into_iter().map(|x| (x.title, x.id)).collect();
+    for milestone in &export.milestones {
+        if ids.contains_key(&milestone.title) {
+            continue;
+        }
+        let created = remote
+            .create_milestone(&repo.owner, &repo.name, milestone.clone())
+            .await;
+        let what = format!("milestone {}", milestone.title);
+        if let Some(created) = progress.record(what, created)? {
+            ids.insert(created.title, created.id);
+        }
+    }
+    Ok(ids)
+}
+
+/// Recreate the issues and pull requests the repository doesn't have yet as issues, with their
+/// comments. Existing issues are matched by title.
+async fn import_issues(
+    export: &RepoExport,
+    repo: &Repository,
+    remote: &dyn Remote,
+    labels: &HashSet<String>,
+    milestones: &HashMap<String, u64>,
+    progress: &mut Progress,
+) -> Result<()> {
+    let issues = export.issues.iter().map(Item::Issue);
+    let pull_requests = export.pull_requests.iter().map(Item::PullRequest);
+    let existing = remote
+        .list_issues(&repo.owner, &repo.name, ListIssuesInfo::default())
+        .await?;
+    let titles: HashSet<_> = existing.into_iter().map(|x| x.title).collect();
+    let mut items: Vec<_> = issues
+        .chain(pull_requests)
+        .filter(|x| !titles.contains(x.title()))
+        .collect();
+    items.sort_by_key(Item::number);
+
+    for item in items {
+        let create_info = item.create_info(labels, milestones);
+        let created = remote
+            .create_issue(&repo.owner, &repo.name, create_info)
+            .await;
+        let Some(created) = progress.record(&item, created)? else {
+            continue;
+        };
+        for comment in item.comments() {
+            let body = comment_body(comment);
+            let result = remote
+                .create_issue_comment(&repo.owner, &repo.name, created.number, &body)
+                .await;
+            progress.record(format!("comment {} on {item}", comment.id), result)?;
+        }
+        if item.closed() {
+            let result = remote
+                .set_issue_state(&repo.owner, &repo.name, created.number, IssueState::Closed)
+                .await;
+            progress.record(format!("state of {item}"), result)?;
+        }
+    }
+    Ok(())
+}
+
+/// Create the releases for the tags that don't have one yet. Assets are not imported.
+async fn import_releases(
+    export: &RepoExport,
+    repo: &Repository,
+    remote: &dyn Remote,
+    progress: &mut Progress,
+) -> Result<()> {
+    let existing = remote.list_releases(&repo.owner, &repo.name).await?;
+    let tags: HashSet<_> = existing.into_iter().map(|x| x.tag).collect();
+    // Releases are listed newest first, create the oldest first.
+    for release in export.releases.iter().rev() {
+        if tags.contains(&release.tag) {
+            continue;
+        }
+        let create_info = ReleaseCreateInfo {
+            tag: release.tag.clone(),
+            target: None,
+            name: release.name.clone(),
+            body: release.body.clone(),
+            draft: release.draft,
+            prerelease: release.prerelease,
+        };
+        let created = remote
+            .create_release(&repo.owner, &repo.name, create_info)
+            .await;
+        progress.record(format!("release {}", release.tag), created)?;
+    }
+    Ok(())
+}
+
+/// Create the wiki pages the repository doesn't have yet.
+async fn import_wiki(
+    export: &RepoExport,
+    repo: &Repository,
+    remote: &dyn Remote,
+    progress: &mut Progress,
+) -> Result<()> {
+    let existing = remote.list_wiki_pages(&repo.owner, &repo.name).await?;
+    let titles: HashSet<_> = existing.into_iter().map(|x| x.title).collect();
+    for page in &export.wiki {
+        if titles.contains(&page.title) {
+            continue;
+        }
+        let created = remote
+            .create_wiki_page(&repo.owner, &repo.name, page.clone())
+            .await;
+        progress.record(format!("wiki page {}", page.title), created)?;
+    }
+    Ok(())
+}
+
+/// Everything is created by the authenticated user, so credit the original author.
+fn attribution(action: &str, author: &str, date: DateTime<Utc>, origin: &str) -> String {
+    format!(
+        "_{action} by {author} on {}{origin}_",
+        date.format("%Y-%m-%d")
+    )
+}
+
+fn with_header(header: &str, body: Option<&str>) -> String {
+    match body.filter(|x| !x.is_empty()) {
+        Some(body) => format!("{header}\n\n{body}"),
+        None => header.to_string(),
+    }
+}
+
+fn comment_body(comment: &Comment) -> String {
+    let header = attribution("Posted", &comment.author, comment.created_at, "");
+    with_header(&header, Some(&comment.body))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::commands::export::Metadata;
+    use crate::commands::mock_remote;
+    use crate::remote::mock::{Call, Failure, Method};
+    use crate::remote::{Issue, Label, Milestone, PullRequest, Release, WikiPage};
+    use clap::Parser;
+
+    fn export() -> RepoExport {
+        let comment = Comment {
+            id: 7,
+            author: "carol".to_string(),
+            body: "Same here".to_string(),
+            ..Default::default()
+        };
+        RepoExport {
+            metadata: Metadata {
+                version: 1,
+                remote: "github".to_string(),
+                exported_at: Utc::now(),
+            },
+            repository: Repository {
+                name: "tool".to_string(),
+                owner: "alice".to_string(),
+                description: Some("A tool".to_string()),
+                private: true,
+                ..Default::default()
+            },
+            labels: vec![Label {
+                name: "bug".to_string(),
+                color: "d73a4a".to_string(),
+                description: None,
+            }],
+            milestones: vec![Milestone {
+                id: 3,
+                title: "1.0".to_string(),
+                ..Default::default()
+            }],
+            issues: vec![IssueExport {
+                issue: Issue {
+                    number: 1,
+                    title: "Crash".to_string(),
+                    body: Some("It crashes".to_string()),
+                    state: IssueState::Closed,
+                    author: "bob".to_string(),
+                    labels: vec!["bug".to_string(), "gone".to_string()],
+                    milestone: Some("1.0".to_string()),
+                    ..Default::default()
+                },
+                comments: vec![comment],
+            }],
+            pull_requests: vec![PullRequestExport {
+                pull_request: PullRequest {
+                    number: 2,
+                    title: "Fix crash".to_string(),
+                    state: PullRequestState::Merged,
+                    author: "bob".to_string(),
+                    head: "fix".to_string(),
+                    base: "main".to_string(),
+                    ..Default::default()
+                },
+                comments: Vec::new(),
+            }],
+            releases: vec![Release {
+                tag: "v1.0.0".to_string(),
+                ..Default::default()
+            }],
+            wiki: vec![WikiPage {
+                title: "Home".to_string(),
+                content: "Welcome".to_string(),
+            }],
+        }
+    }
+
+    fn write(name: &str) -> String {
+        let path = std::env::temp_dir().join(format!("gritty-test-{name}.tar.zst"));
+        export().write(&path).unwrap();
+        path.to_string_lossy().to_string()
+    }
+
+    #[tokio::test]
+    async fn test_import_creates_repository() {
+        let remote = mock_remote();
+        let path = write("import");
+        let args = Import::parse_from(["import", "tool", "mock", "-i", &path]);
+        import_repository(args, &remote).await.unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let repo = remote.repo("mock", "tool").unwrap();
+        assert!(repo.private);
+        assert_eq!(repo.description.as_deref(), Some("A tool"));
+        let issues = remote
+            .list_issues("mock", "tool", ListIssuesInfo::default())
+            .await
+            .unwrap();
+        assert_eq!(issues.len(), 2);
+        // The label that isn't in the export is dropped, the milestone gets its new ID.
+        assert_eq!(issues[0].labels, ["bug"]);
+        assert_eq!(issues[0].milestone.as_deref(), Some("1.0"));
+        assert!(issues[0]
+            .body
+            .as_ref()
+            .unwrap()
+            .starts_with("_Opened by bob on "));
+        assert!(issues[0].body.as_ref().unwrap().ends_with("\n\nIt crashes"));
+        assert!(issues.iter().all(|x| x.state == IssueState::Closed));
+        assert_eq!(issues[1].number, 2);
+        assert!(issues[1]
+            .body
+            .as_ref()
+            .unwrap()
+            .contains("as pull request from `fix` into `main` (merged)"));
+        let comments = remote.list_issue_comments("mock", "tool", 1).await.unwrap();
+        assert!(comments[0].body.starts_with("_Posted by carol on "));
+        assert_eq!(remote.list_releases("mock", "tool").await.unwrap().len(), 1);
+        assert_eq!(
+            remote.list_wiki_pages("mock", "tool").await.unwrap()[0].title,
+            "Home"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_import_keeps_existing_and_skips_unsupported() {
+        let remote = mock_remote();
+        remote.insert(Repository {
+            name: "tool".to_string(),
+            owner: "mock".to_string(),
+            ..Default::default()
+        });
+        remote
+            .create_label("mock", "tool", export().labels[0].clone())
+            .await
+            .unwrap();
+        remote.fail(
+            Method::ListWikiPages,
+            Failure::Error(Error::unsupported("Wikis are not supported")),
+        );
+        remote.fail(Method::CreateRelease, Failure::NotFound);
+
+        let path = write("import-existing");
+        let args = Import::parse_from(["import", "tool", "mock", "-i", &path]);
+        let err = import_repository(args, &remote).await.unwrap_err();
+        std::fs::remove_file(&path).unwrap();
+
+        // 1 milestone, 2 issues, 1 comment, 2 closes and the release.
+        assert_eq!(err.message, "Failed to import 1 of 7 items");
+        let created_labels = remote
+            .calls()
+            .iter()
+            .filter(|x| matches!(x, Call::CreateLabel { .. }))
+            .count();
+        assert_eq!(created_labels, 1);
+        assert!(!remote
+            .calls()
+            .iter()
+            .any(|x| matches!(x, Call::CreateWikiPage { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_import_twice() {
+        let remote = mock_remote();
+        let path = write("import-twice");
+        for _ in 0..2 {
+            let args = Import::parse_from(["import", "tool", "mock", "-i", &path]);
+            import_repository(args, &remote).await.unwrap();
+        }
+        std::fs::remove_file(&path).unwrap();
+
+        let issues = remote
+            .list_issues("mock", "tool", ListIssuesInfo::default())
+            .await
+            .unwrap();
+        assert_eq!(issues.len(), 2);
+        let comments = remote.list_issue_comments("mock", "tool", 1).await.unwrap();
+        assert_eq!(comments.len(), 1);
+    }
+}
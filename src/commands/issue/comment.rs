@@ -0,0 +1,62 @@
+use crate::args::issue::Comment;
+use crate::args::OutputFormat;
+use crate::config::Config;
+use crate::error::Result;
+use crate::log::{Highlight, Paint};
+use crate::remote::Remote;
+
+use crate::commands::{load_remote, read_body};
+
+pub async fn comment_on_issue(args: Comment, config: &Config) -> Result<()> {
+    let remote = load_remote(&args.remote, config).await?;
+    comment(args, remote.as_ref()).await
+}
+
+async fn comment(args: Comment, remote: &dyn Remote) -> Result<()> {
+    let owner = args.name.owner_or(&remote.get_config().username);
+    let name = &args.name.name;
+    // Clap requires one of them.
+    let body = read_body(args.body.clone(), args.body_file.as_deref())?.unwrap_or_default();
+    let comment = remote
+        .create_issue_comment(owner, name, args.number, &body)
+        .await?;
+    match args.format.unwrap_or_default() {
+        OutputFormat::Human => println!(
+            "Commented on issue {} of {}.",
+            format!("#{}", args.number).paint(Highlight::Special),
+            format!("{owner}/{name}").paint(Highlight::Repo)
+        ),
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&comment)?),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::commands::mock_remote;
+    use crate::remote::{IssueCreateInfo, Repository};
+    use clap::Parser;
+
+    #[tokio::test]
+    async fn test_comment() {
+        let remote = mock_remote();
+        remote.insert(Repository {
+            name: "tool".to_string(),
+            owner: "mock".to_string(),
+            ..Default::default()
+        });
+        let issue = IssueCreateInfo {
+            title: "Crash".to_string(),
+            ..Default::default()
+        };
+        remote.create_issue("mock", "tool", issue).await.unwrap();
+
+        let args = Comment::parse_from(["comment", "tool", "mock", "1", "-b", "Same here"]);
+        comment(args, &remote).await.unwrap();
+        let comments = remote.list_issue_comments("mock", "tool", 1).await.unwrap();
+        assert_eq!(comments[0].body, "Same here");
+
+        assert!(Comment::try_parse_from(["comment", "tool", "mock", "1"]).is_err());
+    }
+}
@@ -0,0 +1,111 @@
+use crate::args::issue::Create;
+use crate::args::OutputFormat;
+use crate::config::Config;
+use crate::error::{Error, Result};
+use crate::log::{Highlight, Paint};
+use crate::remote::{IssueCreateInfo, Remote};
+
+use crate::commands::{load_remote, read_body};
+
+pub async fn create_issue(args: Create, config: &Config) -> Result<()> {
+    let remote = load_remote(&args.remote, config).await?;
+    create(args, remote.as_ref()).await
+}
+
+async fn create(args: Create, remote: &dyn Remote) -> Result<()> {
+    let owner = args.name.owner_or(&remote.get_config().username);
+    let name = &args.name.name;
+    let full_name = format!("{owner}/{name}");
+    // Milestones are referred to by their ID, which nobody knows offhand.
+    let milestone = match &args.milestone {
+        Some(title) => {
+            let milestones = remote.list_milestones(owner, name).await?;
+            match milestones.into_iter().find(|x| &x.title == title) {
+                Some(milestone) => Some(milestone.id),
+                None => {
+                    return Err(Error::not_found(format!(
+                        "Milestone {title} does not exist in {full_name}"
+                    )))
+                }
+            }
+        }
+        None => None,
+    };
+    let create_info = IssueCreateInfo {
+        title: args.title.clone(),
+        body: read_body(args.body.clone(), args.body_file.as_deref())?,
+        labels: args.labels.clone(),
+        milestone,
+        assignees: args.assignees.clone(),
+    };
+    let issue = remote.create_issue(owner, name, create_info).await?;
+    match args.format.unwrap_or_default() {
+        OutputFormat::Human => {
+            println!(
+                "Issue {} opened in {}.",
+                format!("#{}", issue.number).paint(Highlight::Special),
+                full_name.paint(Highlight::Repo)
+            );
+            if let Some(url) = &issue.html_url {
+                println!("{}", url.paint(Highlight::Url));
+            }
+        }
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&issue)?),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::commands::mock_remote;
+    use crate::remote::{Milestone, Repository};
+    use clap::Parser;
+
+    #[tokio::test]
+    async fn test_create_resolves_milestone() {
+        let remote = mock_remote();
+        remote.insert(Repository {
+            name: "tool".to_string(),
+            owner: "mock".to_string(),
+            ..Default::default()
+        });
+        let milestone = Milestone {
+            title: "v1.0".to_string(),
+            ..Default::default()
+        };
+        remote
+            .create_milestone("mock", "tool", milestone)
+            .await
+            .unwrap();
+
+        let args = Create::parse_from([
+            "create",
+            "tool",
+            "mock",
+            "-t",
+            "Crash",
+            "-b",
+            "It crashes",
+            "-m",
+            "v1.0",
+            "-a",
+            "alice",
+        ]);
+        create(args, &remote).await.unwrap();
+        let issue = remote.get_issue("mock", "tool", 1).await.unwrap();
+        assert_eq!(issue.body.as_deref(), Some("It crashes"));
+        assert_eq!(issue.milestone.as_deref(), Some("v1.0"));
+        assert_eq!(issue.assignees, ["alice"]);
+
+        let args = Create::parse_from(["create", "tool", "mock", "-t", "Crash", "-m", "v2.0"]);
+        let err = create(args, &remote).await.unwrap_err();
+        assert_eq!(err.message, "Milestone v2.0 does not exist in mock/tool");
+    }
+
+    #[test]
+    fn test_body_conflicts_with_body_file() {
+        let args = ["create", "tool", "mock", "-t", "x", "-b", "y", "-F", "z"];
+        assert!(Create::try_parse_from(args).is_err());
+    }
+}
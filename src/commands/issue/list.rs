@@ -0,0 +1,145 @@
+use crate::args::issue::{IssueStateFilter, List};
+use crate::args::OutputFormat;
+use crate::config::Config;
+use crate::error::Result;
+use crate::log::{self, Highlight, Paint};
+use crate::remote::{Issue, IssueState, ListIssuesInfo, Remote};
+
+use crate::commands::load_remote;
+
+pub async fn list_issues(args: List, config: &Config) -> Result<()> {
+    let remote = load_remote(&args.remote, config).await?;
+    println!("{}", list(args, remote.as_ref()).await?);
+    Ok(())
+}
+
+async fn list(args: List, remote: &dyn Remote) -> Result<String> {
+    let owner = args.name.owner_or(&remote.get_config().username);
+    let list_info = ListIssuesInfo {
+        state: match args.state {
+            IssueStateFilter::Open => Some(IssueState::Open),
+            IssueStateFilter::Closed => Some(IssueState::Closed),
+            IssueStateFilter::All => None,
+        },
+        labels: args.labels.clone(),
+        assignee: args.assignee.clone(),
+        author: args.author.clone(),
+    };
+    let issues = remote
+        .list_issues(owner, &args.name.name, list_info)
+        .await?;
+    Ok(match args.format.unwrap_or_default() {
+        OutputFormat::Human => format_human(&issues),
+        OutputFormat::Json => serde_json::to_string_pretty(&issues)?,
+    })
+}
+
+fn format_human(issues: &[Issue]) -> String {
+    if issues.is_empty() {
+        return "No issues found.".to_string();
+    }
+    let width = issues
+        .iter()
+        .map(|x| x.number.to_string().len() + 1)
+        .max()
+        .unwrap_or_default();
+    let lines: Vec<_> = issues
+        .iter()
+        .map(|issue| {
+            let number = format!("#{}", issue.number);
+            let mut line = format!(
+                "{} {}",
+                log::leftpad(&number, width).paint(Highlight::Special),
+                issue.title
+            );
+            if issue.state == IssueState::Closed {
+                line.push_str(&format!(" {}", "(closed)".paint(Highlight::Important)));
+            }
+            if !issue.labels.is_empty() {
+                line.push_str(&format!(" [{}]", issue.labels.join(", ")));
+            }
+            line.push_str(&format!(
+                " by {} on {}",
+                issue.author.paint(Highlight::Author),
+                issue
+                    .created_at
+                    .date_naive()
+                    .to_string()
+                    .paint(Highlight::Date)
+            ));
+            line
+        })
+        .collect();
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::commands::mock_remote;
+    use crate::remote::{mock::Call, IssueCreateInfo, Label, Repository};
+    use clap::Parser;
+
+    #[tokio::test]
+    async fn test_list_filters() {
+        let remote = mock_remote();
+        remote.insert(Repository {
+            name: "tool".to_string(),
+            owner: "mock".to_string(),
+            ..Default::default()
+        });
+        let label = Label {
+            name: "bug".to_string(),
+            color: "d73a4a".to_string(),
+            description: None,
+        };
+        remote.create_label("mock", "tool", label).await.unwrap();
+        for (title, labels) in [("Crash", vec!["bug".to_string()]), ("Idea", vec![])] {
+            let issue = IssueCreateInfo {
+                title: title.to_string(),
+                labels,
+                assignees: vec!["alice".to_string()],
+                ..Default::default()
+            };
+            remote.create_issue("mock", "tool", issue).await.unwrap();
+        }
+        remote
+            .set_issue_state("mock", "tool", 2, IssueState::Closed)
+            .await
+            .unwrap();
+
+        let args = List::parse_from(["list", "tool", "mock", "-l", "bug", "-a", "alice"]);
+        let output = list(args, &remote).await.unwrap();
+        let Some(Call::ListIssues { list_info, .. }) = remote.calls().pop() else {
+            panic!("expected issues to be listed, got {:?}", remote.calls());
+        };
+        assert_eq!(list_info.state, Some(IssueState::Open));
+        assert_eq!(list_info.labels, ["bug"]);
+        assert_eq!(list_info.assignee.as_deref(), Some("alice"));
+
+        let list_info = ListIssuesInfo {
+            author: Some("mock".to_string()),
+            ..list_info
+        };
+        let issues = remote.list_issues("mock", "tool", list_info).await.unwrap();
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].title, "Crash");
+        let date = issues[0].created_at.date_naive();
+        assert_eq!(output, format!("#1 Crash [bug] by mock on {date}"));
+
+        let args = List::parse_from(["list", "tool", "mock", "--state", "all"]);
+        let output = list(args, &remote).await.unwrap();
+        let Some(Call::ListIssues { list_info, .. }) = remote.calls().pop() else {
+            panic!("expected issues to be listed, got {:?}", remote.calls());
+        };
+        assert_eq!(list_info.state, None);
+        let lines: Vec<_> = output.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[1].starts_with("#2 Idea (closed) by mock on "));
+
+        let args = List::parse_from(["list", "tool", "mock", "-l", "wontfix", "--format", "json"]);
+        assert_eq!(list(args, &remote).await.unwrap(), "[]");
+        let args = List::parse_from(["list", "tool", "mock", "-l", "wontfix"]);
+        assert_eq!(list(args, &remote).await.unwrap(), "No issues found.");
+    }
+}
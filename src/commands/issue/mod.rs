@@ -0,0 +1,20 @@
+use crate::args::issue::{Issue, IssueCommands};
+use crate::config::Config;
+use crate::error::Result;
+
+mod comment;
+mod create;
+mod list;
+mod state;
+mod view;
+
+pub async fn issue(issue: Issue, config: &Config) -> Result<()> {
+    match issue.subcommand {
+        IssueCommands::List(args) => list::list_issues(args, config).await,
+        IssueCommands::View(args) => view::view_issue(args, config).await,
+        IssueCommands::Create(args) => create::create_issue(args, config).await,
+        IssueCommands::Comment(args) => comment::comment_on_issue(args, config).await,
+        IssueCommands::Close(args) => state::close_issue(args, config).await,
+        IssueCommands::Reopen(args) => state::reopen_issue(args, config).await,
+    }
+}
@@ -0,0 +1,129 @@
+use crate::args::issue::{Close, Reopen};
+use crate::args::{repo::RepoName, OutputFormat};
+use crate::config::Config;
+use crate::error::Result;
+use crate::log::{Highlight, Paint};
+use crate::remote::{IssueState, Remote};
+
+use crate::commands::load_remote;
+
+pub async fn close_issue(args: Close, config: &Config) -> Result<()> {
+    let remote = load_remote(&args.remote, config).await?;
+    let Close {
+        name,
+        number,
+        comment,
+        format,
+        ..
+    } = args;
+    let change = StateChange {
+        name: &name,
+        number,
+        comment: comment.as_deref(),
+        state: IssueState::Closed,
+    };
+    set_state(change, format, remote.as_ref()).await
+}
+
+pub async fn reopen_issue(args: Reopen, config: &Config) -> Result<()> {
+    let remote = load_remote(&args.remote, config).await?;
+    let Reopen {
+        name,
+        number,
+        comment,
+        format,
+        ..
+    } = args;
+    let change = StateChange {
+        name: &name,
+        number,
+        comment: comment.as_deref(),
+        state: IssueState::Open,
+    };
+    set_state(change, format, remote.as_ref()).await
+}
+
+struct StateChange<'a> {
+    name: &'a RepoName,
+    number: u64,
+    /// Posted before the state changes, so it shows up above the event.
+    comment: Option<&'a str>,
+    state: IssueState,
+}
+
+async fn set_state(
+    change: StateChange<'_>,
+    format: Option<OutputFormat>,
+    remote: &dyn Remote,
+) -> Result<()> {
+    let owner = change.name.owner_or(&remote.get_config().username);
+    let name = &change.name.name;
+    if let Some(comment) = change.comment {
+        remote
+            .create_issue_comment(owner, name, change.number, comment)
+            .await?;
+    }
+    let issue = remote
+        .set_issue_state(owner, name, change.number, change.state)
+        .await?;
+    match format.unwrap_or_default() {
+        OutputFormat::Human => println!(
+            "Issue {} of {} {}.",
+            format!("#{}", issue.number).paint(Highlight::Special),
+            format!("{owner}/{name}").paint(Highlight::Repo),
+            match change.state {
+                IssueState::Open => "reopened",
+                IssueState::Closed => "closed",
+            }
+        ),
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&issue)?),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::commands::mock_remote;
+    use crate::remote::{mock::Call, IssueCreateInfo, Repository};
+
+    #[tokio::test]
+    async fn test_close_with_comment_and_reopen() {
+        let remote = mock_remote();
+        remote.insert(Repository {
+            name: "tool".to_string(),
+            owner: "mock".to_string(),
+            ..Default::default()
+        });
+        let issue = IssueCreateInfo {
+            title: "Crash".to_string(),
+            ..Default::default()
+        };
+        remote.create_issue("mock", "tool", issue).await.unwrap();
+
+        let name: RepoName = "tool".parse().unwrap();
+        let change = StateChange {
+            name: &name,
+            number: 1,
+            comment: Some("Fixed in v1.1"),
+            state: IssueState::Closed,
+        };
+        set_state(change, None, &remote).await.unwrap();
+        let calls = remote.calls();
+        assert!(matches!(&calls[1], Call::CreateIssueComment { .. }));
+        assert!(matches!(&calls[2], Call::SetIssueState { .. }));
+        let issue = remote.get_issue("mock", "tool", 1).await.unwrap();
+        assert_eq!(issue.state, IssueState::Closed);
+
+        let change = StateChange {
+            name: &name,
+            number: 1,
+            comment: None,
+            state: IssueState::Open,
+        };
+        set_state(change, None, &remote).await.unwrap();
+        let issue = remote.get_issue("mock", "tool", 1).await.unwrap();
+        assert_eq!(issue.state, IssueState::Open);
+        assert!(issue.closed_at.is_none());
+    }
+}
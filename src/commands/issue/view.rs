@@ -0,0 +1,137 @@
+use serde::Serialize;
+
+use crate::args::issue::View;
+use crate::args::OutputFormat;
+use crate::config::Config;
+use crate::error::Result;
+use crate::log::{Highlight, Paint};
+use crate::remote::{Comment, Issue, IssueState, Remote};
+
+use crate::commands::load_remote;
+
+/// An issue with its comments, as printed with `--format json`.
+#[derive(Debug, Serialize)]
+struct IssueView {
+    issue: Issue,
+    comments: Vec<Comment>,
+}
+
+pub async fn view_issue(args: View, config: &Config) -> Result<()> {
+    let remote = load_remote(&args.remote, config).await?;
+    println!("{}", view(args, remote.as_ref()).await?);
+    Ok(())
+}
+
+async fn view(args: View, remote: &dyn Remote) -> Result<String> {
+    let owner = args.name.owner_or(&remote.get_config().username);
+    let name = &args.name.name;
+    let issue = remote.get_issue(owner, name, args.number).await?;
+    let comments = remote.list_issue_comments(owner, name, args.number).await?;
+    Ok(match args.format.unwrap_or_default() {
+        OutputFormat::Human => format_human(&issue, &comments),
+        OutputFormat::Json => {
+            let view = IssueView { issue, comments };
+            serde_json::to_string_pretty(&view)?
+        }
+    })
+}
+
+fn format_human(issue: &Issue, comments: &[Comment]) -> String {
+    let state = match issue.state {
+        IssueState::Open => "open".paint(Highlight::Special),
+        IssueState::Closed => "closed".paint(Highlight::Important),
+    };
+    let mut lines = vec![
+        format!(
+            "{} {} ({})",
+            format!("#{}", issue.number).paint(Highlight::Special),
+            issue.title,
+            state
+        ),
+        format!(
+            "Opened by {} on {}",
+            issue.author.paint(Highlight::Author),
+            issue.created_at.to_string().paint(Highlight::Date)
+        ),
+    ];
+    if !issue.labels.is_empty() {
+        lines.push(format!("Labels: {}", issue.labels.join(", ")));
+    }
+    if !issue.assignees.is_empty() {
+        let assignees: Vec<_> = issue
+            .assignees
+            .iter()
+            .map(|x| x.paint(Highlight::Username).to_string())
+            .collect();
+        lines.push(format!("Assignees: {}", assignees.join(", ")));
+    }
+    if let Some(milestone) = &issue.milestone {
+        lines.push(format!("Milestone: {milestone}"));
+    }
+    if let Some(url) = &issue.html_url {
+        lines.push(url.paint(Highlight::Url).to_string());
+    }
+    if let Some(body) = issue.body.as_deref().filter(|x| !x.trim().is_empty()) {
+        lines.push(format!("\n{}", body.trim_end()));
+    }
+    for comment in comments {
+        lines.push(format!(
+            "\n{} commented on {}:",
+            comment.author.paint(Highlight::Author),
+            comment.created_at.to_string().paint(Highlight::Date)
+        ));
+        lines.push(comment.body.trim_end().to_string());
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::commands::mock_remote;
+    use crate::remote::{IssueCreateInfo, Repository};
+    use clap::Parser;
+
+    #[tokio::test]
+    async fn test_view_issue() {
+        let remote = mock_remote();
+        remote.insert(Repository {
+            name: "tool".to_string(),
+            owner: "mock".to_string(),
+            ..Default::default()
+        });
+        let issue = IssueCreateInfo {
+            title: "Crash".to_string(),
+            body: Some("It crashes.\n".to_string()),
+            assignees: vec!["alice".to_string()],
+            ..Default::default()
+        };
+        let issue = remote.create_issue("mock", "tool", issue).await.unwrap();
+        let comment = remote
+            .create_issue_comment("mock", "tool", 1, "Same here.")
+            .await
+            .unwrap();
+
+        let args = View::parse_from(["view", "tool", "mock", "1"]);
+        let expected = [
+            "#1 Crash (open)".to_string(),
+            format!("Opened by mock on {}", issue.created_at),
+            "Assignees: alice".to_string(),
+            "https://mock.invalid/mock/tool/issues/1".to_string(),
+            "\nIt crashes.".to_string(),
+            format!("\nmock commented on {}:", comment.created_at),
+            "Same here.".to_string(),
+        ]
+        .join("\n");
+        assert_eq!(view(args, &remote).await.unwrap(), expected);
+
+        let args = View::parse_from(["view", "tool", "mock", "1", "--format", "json"]);
+        let json: serde_json::Value =
+            serde_json::from_str(&view(args, &remote).await.unwrap()).unwrap();
+        assert_eq!(json["issue"]["title"], "Crash");
+        assert_eq!(json["comments"][0]["body"], "Same here.");
+
+        let args = View::parse_from(["view", "tool", "mock", "2"]);
+        assert_eq!(view(args, &remote).await.unwrap_err().status, Some(404));
+    }
+}
@@ -0,0 +1,129 @@
+use crate::args::key::Add;
+use crate::config::Config;
+use crate::error::{Error, ErrorKind, Result};
+use crate::log::{Highlight, Paint};
+use crate::remote::{Remote, SshKeyCreateInfo};
+
+use crate::commands::{load_remote, read_public_key};
+
+pub async fn add_key(args: Add, config: &Config) -> Result<()> {
+    let key = read_public_key(&args.key, args.title)?;
+    let names: Vec<String> = match args.remote {
+        Some(remote) => vec![remote],
+        None => config.remotes.keys().cloned().collect(),
+    };
+    let mut loaded = vec![];
+    for name in names {
+        let remote = load_remote(&name, config).await;
+        loaded.push((name, remote));
+    }
+    let remotes = loaded
+        .iter()
+        .map(|(name, remote)| (name.as_str(), remote.as_deref().map_err(Clone::clone)))
+        .collect();
+    add(key, remotes).await
+}
+
+/// Add the key to every remote. Remotes that fail don't stop the others.
+async fn add(key: SshKeyCreateInfo, remotes: Vec<(&str, Result<&dyn Remote>)>) -> Result<()> {
+    let total = remotes.len();
+    let mut failed = 0;
+    for (name, remote) in remotes {
+        let result = match remote {
+            Ok(remote) => remote.add_ssh_key(key.clone()).await,
+            Err(err) => Err(err),
+        };
+        match result {
+            Ok(key) => println!(
+                "Key {} added to {} as {}.",
+                key.title.paint(Highlight::Special),
+                name.paint(Highlight::Remote),
+                key.id.to_string().paint(Highlight::Special)
+            ),
+            // Only worth failing for when it's the only remote.
+            Err(err) if err.kind == ErrorKind::Unsupported && total > 1 => eprintln!(
+                "{}: Skipping {}: {}",
+                "Warning".paint(Highlight::Warning),
+                name.paint(Highlight::Remote),
+                err
+            ),
+            Err(err) if total == 1 => return Err(err),
+            Err(err) => {
+                failed += 1;
+                eprintln!(
+                    "{}: Failed to add the key to {}: {}",
+                    "Error".paint(Highlight::Important),
+                    name.paint(Highlight::Remote),
+                    err
+                );
+            }
+        }
+    }
+    if failed > 0 {
+        return Err(Error::other(format!(
+            "Failed to add the key to {failed} of {total} remotes"
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::commands::mock_remote;
+    use crate::remote::mock::{Failure, Method};
+    use clap::Parser;
+
+    fn key() -> SshKeyCreateInfo {
+        SshKeyCreateInfo {
+            title: "laptop".to_string(),
+            key: "ssh-ed25519 AAAAC3NzaC1lZDI1NTE5AAAAI".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_add_to_all_remotes() {
+        let github = mock_remote();
+        let server = mock_remote();
+        server.fail(
+            Method::AddSshKey,
+            Failure::Error(Error::unsupported("SSH keys are not supported")),
+        );
+        let gitea = mock_remote();
+        gitea.add_ssh_key(key()).await.unwrap();
+
+        let remotes: Vec<(&str, Result<&dyn Remote>)> = vec![
+            ("github", Ok(&github)),
+            ("server", Ok(&server)),
+            ("gitea", Ok(&gitea)),
+            ("gone", Err(Error::not_found("Could not find remote gone"))),
+        ];
+        let err = add(key(), remotes).await.unwrap_err();
+        // The unsupported remote is skipped, the duplicate key and missing remote fail.
+        assert_eq!(err.message, "Failed to add the key to 2 of 4 remotes");
+        assert_eq!(github.list_ssh_keys().await.unwrap()[0].title, "laptop");
+    }
+
+    #[tokio::test]
+    async fn test_add_to_one_remote() {
+        let remote = mock_remote();
+        remote.fail(
+            Method::AddSshKey,
+            Failure::Error(Error::unsupported("SSH keys are not supported")),
+        );
+        let remotes: Vec<(&str, Result<&dyn Remote>)> = vec![("server", Ok(&remote))];
+        let err = add(key(), remotes).await.unwrap_err();
+        assert_eq!(err.kind, ErrorKind::Unsupported);
+    }
+
+    #[test]
+    fn test_parse_all_remotes() {
+        let args = Add::parse_from(["add", "--all-remotes", "id_ed25519.pub"]);
+        assert_eq!(args.remote, None);
+        assert_eq!(args.key, std::path::Path::new("id_ed25519.pub"));
+        let args = Add::parse_from(["add", "github", "id_ed25519.pub"]);
+        assert_eq!(args.remote.as_deref(), Some("github"));
+        assert!(Add::try_parse_from(["add", "id_ed25519.pub"]).is_err());
+        assert!(Add::try_parse_from(["add", "github", "k.pub", "--all-remotes"]).is_err());
+    }
+}
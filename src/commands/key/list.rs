@@ -0,0 +1,76 @@
+use crate::args::key::List;
+use crate::args::OutputFormat;
+use crate::config::Config;
+use crate::error::Result;
+use crate::log::{Highlight, Paint};
+use crate::remote::{Remote, SshKey};
+
+use crate::commands::load_remote;
+
+pub async fn list_keys(args: List, config: &Config) -> Result<()> {
+    let remote = load_remote(&args.remote, config).await?;
+    println!("{}", list(args, remote.as_ref()).await?);
+    Ok(())
+}
+
+async fn list(args: List, remote: &dyn Remote) -> Result<String> {
+    let keys = remote.list_ssh_keys().await?;
+    Ok(match args.format.unwrap_or_default() {
+        OutputFormat::Human => format_human(&keys),
+        OutputFormat::Json => serde_json::to_string_pretty(&keys)?,
+    })
+}
+
+fn format_human(keys: &[SshKey]) -> String {
+    if keys.is_empty() {
+        return "No SSH keys found.".to_string();
+    }
+    let width = keys
+        .iter()
+        .map(|x| x.id.to_string().len())
+        .max()
+        .unwrap_or_default();
+    let lines: Vec<_> = keys
+        .iter()
+        .map(|key| {
+            // The type of the key, the key itself is too long to be useful here.
+            let kind = key.key.split_whitespace().next().unwrap_or_default();
+            format!(
+                "{} {} {}",
+                format!("{:<width$}", key.id).paint(Highlight::Special),
+                key.title,
+                format!("({kind})").paint(Highlight::Protocol)
+            )
+        })
+        .collect();
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::commands::mock_remote;
+    use crate::remote::SshKeyCreateInfo;
+    use clap::Parser;
+
+    #[tokio::test]
+    async fn test_list_keys() {
+        let remote = mock_remote();
+        let args = List::parse_from(["list", "mock"]);
+        assert_eq!(list(args, &remote).await.unwrap(), "No SSH keys found.");
+
+        let key = SshKeyCreateInfo {
+            title: "laptop".to_string(),
+            key: "ssh-ed25519 AAAAC3NzaC1lZDI1NTE5AAAAI".to_string(),
+        };
+        remote.add_ssh_key(key).await.unwrap();
+
+        let args = List::parse_from(["list", "mock"]);
+        assert_eq!(list(args, &remote).await.unwrap(), "1 laptop (ssh-ed25519)");
+
+        let args = List::parse_from(["list", "mock", "--format", "json"]);
+        let json: serde_json::Value =
+            serde_json::from_str(&list(args, &remote).await.unwrap()).unwrap();
+        assert_eq!(json[0]["key"], "ssh-ed25519 AAAAC3NzaC1lZDI1NTE5AAAAI");
+    }
+}
@@ -0,0 +1,15 @@
+use crate::args::key::{Key, KeyCommands};
+use crate::config::Config;
+use crate::error::Result;
+
+mod add;
+mod list;
+mod remove;
+
+pub async fn key(key: Key, config: &Config) -> Result<()> {
+    match key.subcommand {
+        KeyCommands::List(args) => list::list_keys(args, config).await,
+        KeyCommands::Add(args) => add::add_key(args, config).await,
+        KeyCommands::Remove(args) => remove::remove_key(args, config).await,
+    }
+}
@@ -0,0 +1,72 @@
+use crate::args::key::Remove;
+use crate::config::Config;
+use crate::error::{Error, Result};
+use crate::log::{Highlight, Paint};
+use crate::remote::Remote;
+
+use crate::commands::{confirm, load_remote};
+
+pub async fn remove_key(args: Remove, config: &Config) -> Result<()> {
+    let remote = load_remote(&args.remote, config).await?;
+    remove(args, remote.as_ref()).await
+}
+
+async fn remove(args: Remove, remote: &dyn Remote) -> Result<()> {
+    let keys = remote.list_ssh_keys().await?;
+    let Some(key) = keys.iter().find(|x| x.id == args.id) else {
+        return Err(Error::not_found(format!(
+            "SSH key {} not found on {}",
+            args.id, args.remote
+        )));
+    };
+    let warning = format!(
+        "You are about to remove SSH key {} from {}.",
+        key.title.paint(Highlight::Special),
+        args.remote.paint(Highlight::Remote),
+    );
+    if !args.force && !confirm(&warning, None)? {
+        println!("{}", "Operation cancelled.".paint(Highlight::Special));
+        return Ok(());
+    }
+    remote.delete_ssh_key(args.id).await?;
+    println!(
+        "Key {} removed from {}.",
+        key.title.paint(Highlight::Special),
+        args.remote.paint(Highlight::Remote)
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::commands::mock_remote;
+    use crate::error::ErrorKind;
+    use crate::remote::{mock::Call, SshKeyCreateInfo};
+    use clap::Parser;
+
+    #[tokio::test]
+    async fn test_force_remove() {
+        let remote = mock_remote();
+        let key = SshKeyCreateInfo {
+            title: "laptop".to_string(),
+            key: "ssh-ed25519 AAAAC3NzaC1lZDI1NTE5AAAAI".to_string(),
+        };
+        let key = remote.add_ssh_key(key).await.unwrap();
+
+        let id = key.id.to_string();
+        let args = Remove::parse_from(["remove", "mock", &id, "--force"]);
+        remove(args, &remote).await.unwrap();
+        assert!(remote.list_ssh_keys().await.unwrap().is_empty());
+
+        let args = Remove::parse_from(["remove", "mock", &id, "--force"]);
+        let err = remove(args, &remote).await.unwrap_err();
+        assert_eq!(err.kind, ErrorKind::NotFound);
+        let deletes = remote
+            .calls()
+            .into_iter()
+            .filter(|x| matches!(x, Call::DeleteSshKey { .. }))
+            .count();
+        assert_eq!(deletes, 1);
+    }
+}
@@ -1,12 +1,38 @@
-use std::io::{stdin, stdout, Write};
+use std::io::{stdin, stdout, Read, Write};
+use std::path::Path;
 
 use crate::config::Config;
-use crate::error::Result;
-use crate::remote::{create_remote, Remote};
+use crate::error::{Error, Result};
+use crate::log::{self, Highlight, Paint};
+use crate::remote::{create_remote, Commit, Remote, SshKeyCreateInfo};
 
 mod repo;
 pub use repo::repo;
 
+mod issue;
+pub use issue::issue;
+
+mod pr;
+pub use pr::pr;
+
+mod release;
+pub use release::release;
+
+mod branch;
+pub use branch::branch;
+
+mod tag;
+pub use tag::tag;
+
+mod hook;
+pub use hook::hook;
+
+mod key;
+pub use key::key;
+
+mod deploy_key;
+pub use deploy_key::deploy_key;
+
 mod remote;
 pub use remote::remote;
 
@@ -19,6 +45,17 @@ pub use create_config::create_config;
 mod completions;
 pub use completions::completions;
 
+mod backup;
+pub use backup::backup;
+
+mod export;
+pub use export::export;
+
+mod import;
+pub use import::import;
+
+mod git;
+
 async fn load_remote(remote_name: &str, config: &Config) -> Result<Box<dyn Remote>> {
     let provider = config.get_remote_provider(remote_name)?;
     let remote_config = config.get_remote_config(remote_name)?;
@@ -31,3 +68,91 @@ fn get_input() -> Result<String> {
     stdin().read_line(&mut input)?;
     Ok(input.trim().to_string())
 }
+
+/// Get the text given with `--body`, or read it from the `--body-file`. A file named `-` is read
+/// from stdin.
+fn read_body(body: Option<String>, body_file: Option<&Path>) -> Result<Option<String>> {
+    let Some(path) = body_file else {
+        return Ok(body);
+    };
+    let mut text = String::new();
+    let read = match path == Path::new("-") {
+        true => stdin().read_to_string(&mut text),
+        false => std::fs::File::open(path).and_then(|mut x| x.read_to_string(&mut text)),
+    };
+    match read {
+        Ok(_) => Ok(Some(text)),
+        Err(err) => Err(Error::other(format!(
+            "Could not read {}: {err}",
+            path.display()
+        ))),
+    }
+}
+
+/// Read a public SSH key from a file, such as `~/.ssh/id_ed25519.pub`. Without a title, the key is
+/// titled after its comment, or the file name if it has none.
+fn read_public_key(path: &Path, title: Option<String>) -> Result<SshKeyCreateInfo> {
+    let path = match path.strip_prefix("~") {
+        Ok(rest) => Path::new(&std::env::var("HOME").unwrap_or_default()).join(rest),
+        Err(_) => path.to_path_buf(),
+    };
+    let key = std::fs::read_to_string(&path)
+        .map_err(|err| Error::other(format!("Could not read {}: {err}", path.display())))?;
+    let key = key.trim();
+    if key.starts_with("-----BEGIN") {
+        return Err(Error::other(format!(
+            "{} is a private key, use the public key instead (usually ending in .pub)",
+            path.display()
+        )));
+    }
+    let fields: Vec<&str> = key.split_whitespace().collect();
+    if fields.len() < 2 || key.lines().count() > 1 {
+        return Err(Error::other(format!(
+            "{} is not a public SSH key",
+            path.display()
+        )));
+    }
+    let title = title.unwrap_or_else(|| match fields.get(2..) {
+        Some(comment) if !comment.is_empty() => comment.join(" "),
+        _ => path
+            .file_name()
+            .map(|x| x.to_string_lossy().to_string())
+            .unwrap_or_default(),
+    });
+    Ok(SshKeyCreateInfo {
+        title,
+        key: key.to_string(),
+    })
+}
+
+/// Warn the user about an operation and ask them to confirm it, showing the last commit it
+/// affects if there is one. Only "y" or "Y" count as confirmation.
+fn confirm(warning: &str, commit: Option<&Commit>) -> Result<bool> {
+    println!("{}: {warning}", "WARNING".paint(Highlight::Important));
+    if let Some(commit) = commit {
+        // Only show the first line of the commit message
+        let message = commit.message.lines().next().unwrap_or_default();
+        println!(
+            "Last commit: {} - {} by {} on {}",
+            short_sha(&commit.sha).paint(Highlight::Commit),
+            message.paint(Highlight::CommitMsg),
+            commit.author.paint(Highlight::Author),
+            commit.date.to_string().paint(Highlight::Date),
+        );
+    }
+    log::print("Are you sure you want to continue? (y/N): ".paint(Highlight::Important));
+    let input = get_input()?;
+    Ok(input.eq_ignore_ascii_case("y"))
+}
+
+/// The first 8 characters of a commit SHA.
+fn short_sha(sha: &str) -> &str {
+    sha.get(..8).unwrap_or(sha)
+}
+
+/// A mock remote for testing the commands, with color output disabled.
+#[cfg(test)]
+fn mock_remote() -> crate::remote::mock::MockRemote {
+    let _ = crate::log::COLOR_MODE.set(crate::args::Color::Never);
+    crate::remote::mock::MockRemote::default()
+}
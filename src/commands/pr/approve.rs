@@ -0,0 +1,59 @@
+use crate::args::pr::Approve;
+use crate::config::Config;
+use crate::error::Result;
+use crate::log::{Highlight, Paint};
+use crate::remote::Remote;
+
+use crate::commands::load_remote;
+
+pub async fn approve_pull_request(args: Approve, config: &Config) -> Result<()> {
+    let remote = load_remote(&args.remote, config).await?;
+    approve(args, remote.as_ref()).await
+}
+
+async fn approve(args: Approve, remote: &dyn Remote) -> Result<()> {
+    let owner = args.name.owner_or(&remote.get_config().username);
+    let name = &args.name.name;
+    remote
+        .approve_pull_request(owner, name, args.number)
+        .await?;
+    println!(
+        "Approved pull request {} of {}.",
+        format!("#{}", args.number).paint(Highlight::Special),
+        format!("{owner}/{name}").paint(Highlight::Repo)
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::commands::mock_remote;
+    use crate::remote::{mock::Call, PullRequest, Repository};
+    use clap::Parser;
+
+    #[tokio::test]
+    async fn test_approve() {
+        let remote = mock_remote();
+        remote.insert(Repository {
+            name: "tool".to_string(),
+            owner: "mock".to_string(),
+            ..Default::default()
+        });
+        let pull = PullRequest {
+            title: "Fix".to_string(),
+            ..Default::default()
+        };
+        remote.insert_pull_request("mock", "tool", pull).unwrap();
+
+        let args = Approve::parse_from(["approve", "tool", "mock", "1"]);
+        approve(args, &remote).await.unwrap();
+        assert!(matches!(
+            &remote.calls()[0],
+            Call::ApprovePullRequest { number: 1, .. }
+        ));
+
+        let args = Approve::parse_from(["approve", "tool", "mock", "2"]);
+        assert_eq!(approve(args, &remote).await.unwrap_err().status, Some(404));
+    }
+}
@@ -0,0 +1,119 @@
+use std::path::Path;
+
+use crate::args::pr::Checkout;
+use crate::config::Config;
+use crate::error::{Error, Result};
+use crate::log::{Highlight, Paint};
+use crate::remote::Remote;
+
+use crate::commands::git::{git, git_succeeds, git_with_credentials};
+use crate::commands::load_remote;
+
+pub async fn checkout_pull_request(args: Checkout, config: &Config) -> Result<()> {
+    let remote = load_remote(&args.remote, config).await?;
+    checkout(args, remote.as_ref(), Path::new(".")).await
+}
+
+/// Fetch the head of the pull request into a new branch of the clone in `dir` and check it out.
+async fn checkout(args: Checkout, remote: &dyn Remote, dir: &Path) -> Result<()> {
+    let owner = args.name.owner_or(&remote.get_config().username);
+    let name = &args.name.name;
+    let pull = remote.get_pull_request(owner, name, args.number).await?;
+    let branch = args.branch.unwrap_or(pull.head);
+
+    let dir = dir.to_string_lossy();
+    let local = format!("refs/heads/{branch}");
+    if git_succeeds(&["-C", &dir, "show-ref", "--verify", "--quiet", &local])? {
+        return Err(Error::other(format!(
+            "Branch {branch} already exists, use --branch to choose another name"
+        )));
+    }
+    // The pull request ref also exists for pull requests from forks.
+    let url = remote.clone_url(owner, name);
+    let refspec = format!("{}:{local}", remote.pull_request_ref(args.number));
+    let credentials = remote.get_config().https_credentials();
+    let credentials = credentials.as_ref().map(|(u, p)| (u.as_str(), p.as_str()));
+    let fetch = ["-C", &dir, "fetch", "--quiet", &url, &refspec];
+    git_with_credentials(&fetch, credentials)?;
+    git(&["-C", &dir, "checkout", "--quiet", &branch])?;
+    println!(
+        "Checked out pull request {} in branch {}.",
+        format!("#{}", pull.number).paint(Highlight::Special),
+        branch.paint(Highlight::Origin)
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::commands::mock_remote;
+    use crate::remote::{mock::MockRemote, PullRequest, RemoteConfig, Repository};
+    use clap::Parser;
+
+    #[tokio::test]
+    async fn test_checkout_fetches_pull_request_ref() {
+        let root = std::env::temp_dir().join("gritty-pr-checkout");
+        if root.exists() {
+            std::fs::remove_dir_all(&root).unwrap();
+        }
+        let remote = MockRemote::with_config(RemoteConfig {
+            url: root.to_string_lossy().to_string(),
+            ..mock_remote().get_config().clone()
+        });
+        remote.insert(Repository {
+            name: "tool".to_string(),
+            owner: "mock".to_string(),
+            ..Default::default()
+        });
+        let pull = PullRequest {
+            title: "Fix".to_string(),
+            head: "fix".to_string(),
+            ..Default::default()
+        };
+        remote.insert_pull_request("mock", "tool", pull).unwrap();
+
+        // The forge keeps the head of the pull request under its pull request ref.
+        let url = remote.clone_url("mock", "tool");
+        let clone = root.join("clone").to_string_lossy().to_string();
+        git(&["init", "--quiet", "-b", "main", &clone]).unwrap();
+        let commit = [
+            "-C",
+            &clone,
+            "-c",
+            "user.name=Mock",
+            "-c",
+            "user.email=mock@mock.invalid",
+            "commit",
+            "--quiet",
+            "--allow-empty",
+            "-m",
+            "Fix",
+        ];
+        git(&commit).unwrap();
+        let head = git(&["-C", &clone, "rev-parse", "HEAD"]).unwrap();
+        git(&["init", "--quiet", "--bare", &url]).unwrap();
+        git(&[
+            "-C",
+            &clone,
+            "push",
+            "--quiet",
+            &url,
+            "HEAD:refs/pull/1/head",
+        ])
+        .unwrap();
+
+        let args = Checkout::parse_from(["checkout", "tool", "mock", "1"]);
+        checkout(args, &remote, Path::new(&clone)).await.unwrap();
+        let branch = git(&["-C", &clone, "symbolic-ref", "--short", "HEAD"]).unwrap();
+        assert_eq!(branch, "fix");
+        assert_eq!(git(&["-C", &clone, "rev-parse", "HEAD"]).unwrap(), head);
+
+        let args = Checkout::parse_from(["checkout", "tool", "mock", "1"]);
+        let err = checkout(args, &remote, Path::new(&clone))
+            .await
+            .unwrap_err();
+        assert!(err.message.contains("already exists"));
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,66 @@
+use crate::args::pr::Comment;
+use crate::args::OutputFormat;
+use crate::config::Config;
+use crate::error::Result;
+use crate::log::{Highlight, Paint};
+use crate::remote::Remote;
+
+use crate::commands::{load_remote, read_body};
+
+pub async fn comment_on_pull_request(args: Comment, config: &Config) -> Result<()> {
+    let remote = load_remote(&args.remote, config).await?;
+    comment(args, remote.as_ref()).await
+}
+
+async fn comment(args: Comment, remote: &dyn Remote) -> Result<()> {
+    let owner = args.name.owner_or(&remote.get_config().username);
+    let name = &args.name.name;
+    // Clap requires one of them.
+    let body = read_body(args.body.clone(), args.body_file.as_deref())?.unwrap_or_default();
+    let comment = remote
+        .create_pull_request_comment(owner, name, args.number, &body)
+        .await?;
+    match args.format.unwrap_or_default() {
+        OutputFormat::Human => println!(
+            "Commented on pull request {} of {}.",
+            format!("#{}", args.number).paint(Highlight::Special),
+            format!("{owner}/{name}").paint(Highlight::Repo)
+        ),
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&comment)?),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::commands::mock_remote;
+    use crate::remote::{PullRequest, Repository};
+    use clap::Parser;
+
+    #[tokio::test]
+    async fn test_comment() {
+        let remote = mock_remote();
+        remote.insert(Repository {
+            name: "tool".to_string(),
+            owner: "mock".to_string(),
+            ..Default::default()
+        });
+        let pull = PullRequest {
+            title: "Fix".to_string(),
+            ..Default::default()
+        };
+        remote.insert_pull_request("mock", "tool", pull).unwrap();
+
+        let args = Comment::parse_from(["comment", "tool", "mock", "1", "-b", "Looks good"]);
+        comment(args, &remote).await.unwrap();
+        let comments = remote
+            .list_pull_request_comments("mock", "tool", 1)
+            .await
+            .unwrap();
+        assert_eq!(comments[0].body, "Looks good");
+
+        let args = Comment::parse_from(["comment", "tool", "mock", "2", "-b", "Looks good"]);
+        assert_eq!(comment(args, &remote).await.unwrap_err().status, Some(404));
+    }
+}
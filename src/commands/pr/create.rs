@@ -0,0 +1,190 @@
+use std::path::Path;
+
+use crate::args::pr::Create;
+use crate::args::OutputFormat;
+use crate::config::Config;
+use crate::error::{Error, Result};
+use crate::log::{Highlight, Paint};
+use crate::remote::{PullRequestCreateInfo, Remote};
+
+use crate::commands::git::{git, git_with_credentials};
+use crate::commands::{load_remote, read_body};
+
+pub async fn create_pull_request(args: Create, config: &Config) -> Result<()> {
+    let remote = load_remote(&args.remote, config).await?;
+    create(args, remote.as_ref(), Path::new(".")).await
+}
+
+/// Open the pull request, taking the head branch from the clone in `dir` if none is given.
+async fn create(args: Create, remote: &dyn Remote, dir: &Path) -> Result<()> {
+    let owner = args.name.owner_or(&remote.get_config().username);
+    let name = &args.name.name;
+    let full_name = format!("{owner}/{name}");
+    let format = args.format.unwrap_or_default();
+
+    let head = match &args.head {
+        Some(head) => head.clone(),
+        None => current_branch(dir)?,
+    };
+    let base = match &args.base {
+        Some(base) => base.clone(),
+        None => {
+            let repo = remote.get_repo_info(owner, name).await?;
+            repo.default_branch.ok_or_else(|| {
+                Error::other(format!(
+                    "Repository {full_name} has no default branch, use --base to choose one"
+                ))
+            })?
+        }
+    };
+    if args.head.is_none() && !args.no_push {
+        let url = remote.clone_url(owner, name);
+        let credentials = remote.get_config().https_credentials();
+        let credentials = credentials.as_ref().map(|(u, p)| (u.as_str(), p.as_str()));
+        if push_branch(&url, &head, dir, credentials)? && matches!(format, OutputFormat::Human) {
+            println!(
+                "Pushed branch {} to {}.",
+                head.paint(Highlight::Origin),
+                full_name.paint(Highlight::Repo)
+            );
+        }
+    }
+
+    let create_info = PullRequestCreateInfo {
+        title: args.title.clone(),
+        body: read_body(args.body.clone(), args.body_file.as_deref())?,
+        head,
+        base,
+        draft: args.draft,
+    };
+    let pull = remote.create_pull_request(owner, name, create_info).await?;
+    match format {
+        OutputFormat::Human => {
+            println!(
+                "Pull request {} opened in {}.",
+                format!("#{}", pull.number).paint(Highlight::Special),
+                full_name.paint(Highlight::Repo)
+            );
+            if let Some(url) = &pull.html_url {
+                println!("{}", url.paint(Highlight::Url));
+            }
+        }
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&pull)?),
+    }
+    Ok(())
+}
+
+/// The branch checked out in the clone in `dir`.
+fn current_branch(dir: &Path) -> Result<String> {
+    let dir = dir.to_string_lossy();
+    git(&["-C", &dir, "symbolic-ref", "--quiet", "--short", "HEAD"]).map_err(|_| {
+        Error::other("Not on a branch of a clone, use --head to choose the branch with the changes")
+    })
+}
+
+/// Push the branch from the clone in `dir` to `url`, unless it's already up to date there.
+/// Returns whether the branch was pushed.
+fn push_branch(
+    url: &str,
+    branch: &str,
+    dir: &Path,
+    credentials: Option<(&str, &str)>,
+) -> Result<bool> {
+    let dir = dir.to_string_lossy();
+    let refname = format!("refs/heads/{branch}");
+    let local = git(&["-C", &dir, "rev-parse", &refname])?;
+    let args = ["-C", &dir, "ls-remote", url, &refname];
+    let pushed = git_with_credentials(&args, credentials)?;
+    if pushed.split_whitespace().next() == Some(local.as_str()) {
+        return Ok(false);
+    }
+    let refspec = format!("{refname}:{refname}");
+    let args = ["-C", &dir, "push", "--quiet", url, &refspec];
+    git_with_credentials(&args, credentials)?;
+    Ok(true)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::commands::mock_remote;
+    use crate::remote::{mock::MockRemote, RemoteConfig, Repository};
+    use clap::Parser;
+
+    /// A mock remote serving its repositories from bare repositories in `root`, with a clone of
+    /// `mock/tool` in `root/clone` on the branch `fix`.
+    fn remote_with_clone(root: &Path) -> MockRemote {
+        let remote = MockRemote::with_config(RemoteConfig {
+            url: root.to_string_lossy().to_string(),
+            ..mock_remote().get_config().clone()
+        });
+        remote.insert(Repository {
+            name: "tool".to_string(),
+            owner: "mock".to_string(),
+            default_branch: Some("trunk".to_string()),
+            ..Default::default()
+        });
+        let url = remote.clone_url("mock", "tool");
+        let clone = root.join("clone").to_string_lossy().to_string();
+        git(&["init", "--quiet", "--bare", &url]).unwrap();
+        git(&["init", "--quiet", "-b", "fix", &clone]).unwrap();
+        let commit = [
+            "-C",
+            &clone,
+            "-c",
+            "user.name=Mock",
+            "-c",
+            "user.email=mock@mock.invalid",
+            "commit",
+            "--quiet",
+            "--allow-empty",
+            "-m",
+            "Fix",
+        ];
+        git(&commit).unwrap();
+        remote
+    }
+
+    #[tokio::test]
+    async fn test_create_pushes_current_branch() {
+        let root = std::env::temp_dir().join("gritty-pr-create-push");
+        if root.exists() {
+            std::fs::remove_dir_all(&root).unwrap();
+        }
+        let remote = remote_with_clone(&root);
+        let clone = root.join("clone");
+
+        let args = Create::parse_from(["create", "tool", "mock", "-t", "Fix", "--draft"]);
+        create(args, &remote, &clone).await.unwrap();
+        let pull = remote.get_pull_request("mock", "tool", 1).await.unwrap();
+        assert_eq!(pull.head, "fix");
+        assert_eq!(pull.base, "trunk");
+        assert!(pull.draft);
+        let url = remote.clone_url("mock", "tool");
+        let pushed = git(&["-C", &url, "rev-parse", "refs/heads/fix"]).unwrap();
+        let local = git(&["-C", &clone.to_string_lossy(), "rev-parse", "HEAD"]).unwrap();
+        assert_eq!(pushed, local);
+
+        // Already up to date, so there's nothing to push.
+        assert!(!push_branch(&url, "fix", &clone, None).unwrap());
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_create_outside_clone_needs_head() {
+        let remote = mock_remote();
+        let args = Create::parse_from(["create", "tool", "mock", "-t", "Fix"]);
+        let dir = Path::new("/");
+        let err = create(args, &remote, dir).await.unwrap_err();
+        assert!(err.message.contains("--head"));
+
+        remote.insert(Repository {
+            name: "tool".to_string(),
+            owner: "mock".to_string(),
+            ..Default::default()
+        });
+        let args = Create::parse_from(["create", "tool", "mock", "-t", "Fix", "-H", "fix"]);
+        let err = create(args, &remote, dir).await.unwrap_err();
+        assert!(err.message.contains("--base"));
+    }
+}
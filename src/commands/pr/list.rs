@@ -0,0 +1,130 @@
+use crate::args::pr::{List, PullRequestStateFilter};
+use crate::args::OutputFormat;
+use crate::config::Config;
+use crate::error::Result;
+use crate::log::{self, Highlight, Paint};
+use crate::remote::{ListPullRequestsInfo, PullRequest, PullRequestState, Remote};
+
+use crate::commands::load_remote;
+
+pub async fn list_pull_requests(args: List, config: &Config) -> Result<()> {
+    let remote = load_remote(&args.remote, config).await?;
+    println!("{}", list(args, remote.as_ref()).await?);
+    Ok(())
+}
+
+async fn list(args: List, remote: &dyn Remote) -> Result<String> {
+    let owner = args.name.owner_or(&remote.get_config().username);
+    let list_info = ListPullRequestsInfo {
+        state: match args.state {
+            PullRequestStateFilter::Open => Some(PullRequestState::Open),
+            PullRequestStateFilter::Closed => Some(PullRequestState::Closed),
+            PullRequestStateFilter::Merged => Some(PullRequestState::Merged),
+            PullRequestStateFilter::All => None,
+        },
+    };
+    let pulls = remote
+        .list_pull_requests(owner, &args.name.name, list_info)
+        .await?;
+    Ok(match args.format.unwrap_or_default() {
+        OutputFormat::Human => format_human(&pulls),
+        OutputFormat::Json => serde_json::to_string_pretty(&pulls)?,
+    })
+}
+
+fn format_human(pulls: &[PullRequest]) -> String {
+    if pulls.is_empty() {
+        return "No pull requests found.".to_string();
+    }
+    let width = pulls
+        .iter()
+        .map(|x| x.number.to_string().len() + 1)
+        .max()
+        .unwrap_or_default();
+    let lines: Vec<_> = pulls
+        .iter()
+        .map(|pull| {
+            let number = format!("#{}", pull.number);
+            let mut line = format!(
+                "{} {}",
+                log::leftpad(&number, width).paint(Highlight::Special),
+                pull.title
+            );
+            let state = match pull.state {
+                PullRequestState::Open if pull.draft => Some("(draft)".paint(Highlight::Warning)),
+                PullRequestState::Open => None,
+                PullRequestState::Closed => Some("(closed)".paint(Highlight::Important)),
+                PullRequestState::Merged => Some("(merged)".paint(Highlight::Special)),
+            };
+            if let Some(state) = state {
+                line.push_str(&format!(" {state}"));
+            }
+            line.push_str(&format!(
+                " {} into {} by {}",
+                pull.head.paint(Highlight::Origin),
+                pull.base.paint(Highlight::Origin),
+                pull.author.paint(Highlight::Author),
+            ));
+            line
+        })
+        .collect();
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::commands::mock_remote;
+    use crate::remote::{mock::Call, Repository};
+    use clap::Parser;
+
+    #[tokio::test]
+    async fn test_list_state_filter() {
+        let remote = mock_remote();
+        remote.insert(Repository {
+            name: "tool".to_string(),
+            owner: "mock".to_string(),
+            ..Default::default()
+        });
+        for state in [PullRequestState::Open, PullRequestState::Merged] {
+            let pull = PullRequest {
+                title: "Fix".to_string(),
+                state,
+                author: "alice".to_string(),
+                head: "fix".to_string(),
+                base: "main".to_string(),
+                ..Default::default()
+            };
+            remote.insert_pull_request("mock", "tool", pull).unwrap();
+        }
+
+        let args = List::parse_from(["list", "tool", "mock", "-s", "merged"]);
+        let output = list(args, &remote).await.unwrap();
+        let Some(Call::ListPullRequests { list_info, .. }) = remote.calls().pop() else {
+            panic!(
+                "expected pull requests to be listed, got {:?}",
+                remote.calls()
+            );
+        };
+        assert_eq!(list_info.state, Some(PullRequestState::Merged));
+        assert_eq!(output, "#2 Fix (merged) fix into main by alice");
+
+        let args = List::parse_from(["list", "tool", "mock"]);
+        assert_eq!(args.state, PullRequestStateFilter::Open);
+        assert_eq!(
+            list(args, &remote).await.unwrap(),
+            "#1 Fix fix into main by alice"
+        );
+
+        let args = List::parse_from(["list", "tool", "mock", "-s", "closed"]);
+        assert_eq!(
+            list(args, &remote).await.unwrap(),
+            "No pull requests found."
+        );
+        let args = List::parse_from(["list", "tool", "mock", "-s", "all", "--format", "json"]);
+        let json: serde_json::Value =
+            serde_json::from_str(&list(args, &remote).await.unwrap()).unwrap();
+        assert_eq!(json[0]["number"], 1);
+        assert_eq!(json[1]["state"], "merged");
+    }
+}
@@ -0,0 +1,72 @@
+use crate::args::pr::Merge;
+use crate::args::OutputFormat;
+use crate::config::Config;
+use crate::error::Result;
+use crate::log::{Highlight, Paint};
+use crate::remote::{PullRequestMergeInfo, Remote};
+
+use crate::commands::load_remote;
+
+pub async fn merge_pull_request(args: Merge, config: &Config) -> Result<()> {
+    let remote = load_remote(&args.remote, config).await?;
+    merge(args, remote.as_ref()).await
+}
+
+async fn merge(args: Merge, remote: &dyn Remote) -> Result<()> {
+    let owner = args.name.owner_or(&remote.get_config().username);
+    let name = &args.name.name;
+    let merge_info = PullRequestMergeInfo {
+        method: args.method,
+        delete_branch: args.delete_branch,
+    };
+    let pull = remote
+        .merge_pull_request(owner, name, args.number, merge_info)
+        .await?;
+    match args.format.unwrap_or_default() {
+        OutputFormat::Human => println!(
+            "Merged pull request {} of {} into {}.",
+            format!("#{}", pull.number).paint(Highlight::Special),
+            format!("{owner}/{name}").paint(Highlight::Repo),
+            pull.base.paint(Highlight::Origin)
+        ),
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&pull)?),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::commands::mock_remote;
+    use crate::remote::{mock::Call, MergeMethod, PullRequest, PullRequestState, Repository};
+    use clap::Parser;
+
+    #[tokio::test]
+    async fn test_merge() {
+        let remote = mock_remote();
+        remote.insert(Repository {
+            name: "tool".to_string(),
+            owner: "mock".to_string(),
+            ..Default::default()
+        });
+        let pull = PullRequest {
+            title: "Fix".to_string(),
+            ..Default::default()
+        };
+        remote.insert_pull_request("mock", "tool", pull).unwrap();
+
+        let args = Merge::parse_from(["merge", "tool", "mock", "1", "-m", "squash", "-d"]);
+        merge(args, &remote).await.unwrap();
+        let Call::MergePullRequest { merge_info, .. } = &remote.calls()[0] else {
+            panic!("expected a merge, got {:?}", remote.calls());
+        };
+        assert_eq!(merge_info.method, MergeMethod::Squash);
+        assert!(merge_info.delete_branch);
+        let pull = remote.get_pull_request("mock", "tool", 1).await.unwrap();
+        assert_eq!(pull.state, PullRequestState::Merged);
+
+        // Merging twice fails.
+        let args = Merge::parse_from(["merge", "tool", "mock", "1"]);
+        assert_eq!(merge(args, &remote).await.unwrap_err().status, Some(405));
+    }
+}
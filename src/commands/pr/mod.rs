@@ -0,0 +1,23 @@
+use crate::args::pr::{Pr, PrCommands};
+use crate::config::Config;
+use crate::error::Result;
+
+mod approve;
+mod checkout;
+mod comment;
+mod create;
+mod list;
+mod merge;
+mod view;
+
+pub async fn pr(pr: Pr, config: &Config) -> Result<()> {
+    match pr.subcommand {
+        PrCommands::List(args) => list::list_pull_requests(args, config).await,
+        PrCommands::View(args) => view::view_pull_request(args, config).await,
+        PrCommands::Create(args) => create::create_pull_request(args, config).await,
+        PrCommands::Checkout(args) => checkout::checkout_pull_request(args, config).await,
+        PrCommands::Comment(args) => comment::comment_on_pull_request(args, config).await,
+        PrCommands::Approve(args) => approve::approve_pull_request(args, config).await,
+        PrCommands::Merge(args) => merge::merge_pull_request(args, config).await,
+    }
+}
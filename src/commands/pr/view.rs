@@ -0,0 +1,148 @@
+use serde::Serialize;
+
+use crate::args::pr::View;
+use crate::args::OutputFormat;
+use crate::config::Config;
+use crate::error::Result;
+use crate::log::{Highlight, Paint};
+use crate::remote::{Comment, PullRequest, PullRequestState, Remote};
+
+use crate::commands::load_remote;
+
+/// A pull request with its comments, as printed with `--format json`.
+#[derive(Debug, Serialize)]
+struct PullRequestView {
+    pull_request: PullRequest,
+    comments: Vec<Comment>,
+}
+
+pub async fn view_pull_request(args: View, config: &Config) -> Result<()> {
+    let remote = load_remote(&args.remote, config).await?;
+    println!("{}", view(args, remote.as_ref()).await?);
+    Ok(())
+}
+
+async fn view(args: View, remote: &dyn Remote) -> Result<String> {
+    let owner = args.name.owner_or(&remote.get_config().username);
+    let name = &args.name.name;
+    let pull_request = remote.get_pull_request(owner, name, args.number).await?;
+    let comments = remote
+        .list_pull_request_comments(owner, name, args.number)
+        .await?;
+    Ok(match args.format.unwrap_or_default() {
+        OutputFormat::Human => format_human(&pull_request, &comments),
+        OutputFormat::Json => {
+            let view = PullRequestView {
+                pull_request,
+                comments,
+            };
+            serde_json::to_string_pretty(&view)?
+        }
+    })
+}
+
+fn format_human(pull: &PullRequest, comments: &[Comment]) -> String {
+    let state = match pull.state {
+        PullRequestState::Open if pull.draft => "draft".paint(Highlight::Warning),
+        PullRequestState::Open => "open".paint(Highlight::Special),
+        PullRequestState::Closed => "closed".paint(Highlight::Important),
+        PullRequestState::Merged => "merged".paint(Highlight::Special),
+    };
+    let mut lines = vec![
+        format!(
+            "{} {} ({})",
+            format!("#{}", pull.number).paint(Highlight::Special),
+            pull.title,
+            state
+        ),
+        format!(
+            "Opened by {} on {}, wants to merge {} into {}",
+            pull.author.paint(Highlight::Author),
+            pull.created_at.to_string().paint(Highlight::Date),
+            pull.head.paint(Highlight::Origin),
+            pull.base.paint(Highlight::Origin)
+        ),
+    ];
+    if let Some(merged_at) = pull.merged_at {
+        lines.push(format!(
+            "Merged on {}",
+            merged_at.to_string().paint(Highlight::Date)
+        ));
+    }
+    if !pull.labels.is_empty() {
+        lines.push(format!("Labels: {}", pull.labels.join(", ")));
+    }
+    if let Some(milestone) = &pull.milestone {
+        lines.push(format!("Milestone: {milestone}"));
+    }
+    if let Some(url) = &pull.html_url {
+        lines.push(url.paint(Highlight::Url).to_string());
+    }
+    if let Some(body) = pull.body.as_deref().filter(|x| !x.trim().is_empty()) {
+        lines.push(format!("\n{}", body.trim_end()));
+    }
+    for comment in comments {
+        lines.push(format!(
+            "\n{} commented on {}:",
+            comment.author.paint(Highlight::Author),
+            comment.created_at.to_string().paint(Highlight::Date)
+        ));
+        lines.push(comment.body.trim_end().to_string());
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::commands::mock_remote;
+    use crate::remote::Repository;
+    use clap::Parser;
+
+    #[tokio::test]
+    async fn test_view_pull_request() {
+        let remote = mock_remote();
+        remote.insert(Repository {
+            name: "tool".to_string(),
+            owner: "mock".to_string(),
+            ..Default::default()
+        });
+        let pull = PullRequest {
+            title: "Fix".to_string(),
+            author: "alice".to_string(),
+            head: "fix".to_string(),
+            base: "main".to_string(),
+            draft: true,
+            labels: vec!["bug".to_string()],
+            ..Default::default()
+        };
+        let pull = remote.insert_pull_request("mock", "tool", pull).unwrap();
+        let comment = remote
+            .create_pull_request_comment("mock", "tool", 1, "Looks good.")
+            .await
+            .unwrap();
+
+        let args = View::parse_from(["view", "tool", "mock", "1"]);
+        let expected = [
+            "#1 Fix (draft)".to_string(),
+            format!(
+                "Opened by alice on {}, wants to merge fix into main",
+                pull.created_at
+            ),
+            "Labels: bug".to_string(),
+            format!("\nmock commented on {}:", comment.created_at),
+            "Looks good.".to_string(),
+        ]
+        .join("\n");
+        assert_eq!(view(args, &remote).await.unwrap(), expected);
+
+        let args = View::parse_from(["view", "tool", "mock", "1", "--format", "json"]);
+        let json: serde_json::Value =
+            serde_json::from_str(&view(args, &remote).await.unwrap()).unwrap();
+        assert_eq!(json["pull_request"]["draft"], true);
+        assert_eq!(json["comments"][0]["body"], "Looks good.");
+
+        let args = View::parse_from(["view", "tool", "mock", "2"]);
+        assert_eq!(view(args, &remote).await.unwrap_err().status, Some(404));
+    }
+}
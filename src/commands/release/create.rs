@@ -0,0 +1,243 @@
+use std::path::{Path, PathBuf};
+
+use crate::args::release::Create;
+use crate::args::OutputFormat;
+use crate::config::Config;
+use crate::error::{Error, Result};
+use crate::log::{Highlight, Paint};
+use crate::remote::{ReleaseCreateInfo, Remote};
+
+use crate::commands::{load_remote, read_body};
+
+pub async fn create_release(args: Create, config: &Config) -> Result<()> {
+    let remote = load_remote(&args.remote, config).await?;
+    create(args, remote.as_ref()).await
+}
+
+async fn create(args: Create, remote: &dyn Remote) -> Result<()> {
+    let owner = args.name.owner_or(&remote.get_config().username);
+    let name = &args.name.name;
+    let full_name = format!("{owner}/{name}");
+    // Check the assets first, so nothing is published if one is missing.
+    let assets = expand_assets(&args.assets)?;
+    let create_info = ReleaseCreateInfo {
+        tag: args.tag.clone(),
+        target: args.target.clone(),
+        name: args.title.clone(),
+        body: read_body(args.notes.clone(), args.notes_file.as_deref())?,
+        draft: args.draft,
+        prerelease: args.prerelease,
+    };
+    let mut release = remote.create_release(owner, name, create_info).await?;
+    let human = matches!(args.format.unwrap_or_default(), OutputFormat::Human);
+    if human {
+        println!(
+            "Release {} created in {}.",
+            release.tag.paint(Highlight::Special),
+            full_name.paint(Highlight::Repo)
+        );
+    }
+
+    let mut failed = 0;
+    for path in &assets {
+        let file_name = path
+            .file_name()
+            .unwrap_or_default()
+            .to_string_lossy()
+            .to_string();
+        let result = match std::fs::read(path) {
+            Ok(data) => {
+                remote
+                    .upload_release_asset(owner, name, &release.tag, &file_name, data)
+                    .await
+            }
+            Err(err) => Err(err.into()),
+        };
+        match result {
+            Ok(asset) => {
+                if human {
+                    println!("Uploaded {}", asset.name.paint(Highlight::Path));
+                }
+                release.assets.push(asset);
+            }
+            // Keep going with the other assets, the release exists either way.
+            Err(err) => {
+                failed += 1;
+                eprintln!(
+                    "{}: Failed to upload {}: {}",
+                    "Error".paint(Highlight::Important),
+                    path.display().to_string().paint(Highlight::Path),
+                    err
+                );
+            }
+        }
+    }
+
+    match human {
+        true => {
+            if let Some(url) = &release.html_url {
+                println!("{}", url.paint(Highlight::Url));
+            }
+        }
+        false => println!("{}", serde_json::to_string_pretty(&release)?),
+    }
+    if failed > 0 {
+        return Err(Error::other(format!(
+            "Failed to upload {failed} of {} assets",
+            assets.len()
+        )));
+    }
+    Ok(())
+}
+
+/// Get the files to upload, expanding wildcards in the file names of the given paths. Shells
+/// usually do this already, but not when the pattern is quoted or on Windows.
+fn expand_assets(assets: &[PathBuf]) -> Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    for asset in assets {
+        let pattern = asset.file_name().unwrap_or_default().to_string_lossy();
+        if !pattern.contains(['*', '?']) {
+            if !asset.is_file() {
+                return Err(Error::not_found(format!(
+                    "Asset {} is not a file",
+                    asset.display()
+                )));
+            }
+            files.push(asset.clone());
+            continue;
+        }
+        let dir = match asset.parent() {
+            Some(dir) if !dir.as_os_str().is_empty() => dir,
+            _ => Path::new("."),
+        };
+        let entries = std::fs::read_dir(dir)
+            .map_err(|err| Error::other(format!("Could not read {}: {err}", dir.display())))?;
+        let mut matches: Vec<PathBuf> = entries
+            .filter_map(|x| x.ok())
+            .filter(|x| wildcard_match(&pattern, &x.file_name().to_string_lossy()))
+            .map(|x| x.path())
+            .filter(|x| x.is_file())
+            .collect();
+        if matches.is_empty() {
+            return Err(Error::not_found(format!(
+                "No files match {}",
+                asset.display()
+            )));
+        }
+        matches.sort();
+        files.extend(matches);
+    }
+    Ok(files)
+}
+
+/// Match a file name against a pattern where `*` matches any number of characters and `?` a
+/// single one. Like in shells, wildcards don't match a leading dot.
+fn wildcard_match(pattern: &str, name: &str) -> bool {
+    if name.starts_with('.') && !pattern.starts_with('.') {
+        return false;
+    }
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+    let (mut p, mut n) = (0, 0);
+    // The position after the last `*`, and the part of the name it matches up to.
+    let mut star = None;
+    while n < name.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                star = Some((p + 1, n));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == name[n] => {
+                p += 1;
+                n += 1;
+            }
+            // Let the last `*` match one more character and try again.
+            _ => match star {
+                Some((after, matched)) => {
+                    star = Some((after, matched + 1));
+                    p = after;
+                    n = matched + 1;
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|x| *x == '*')
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::commands::mock_remote;
+    use crate::remote::{mock::Call, Repository};
+    use clap::Parser;
+
+    fn asset_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(name);
+        if dir.exists() {
+            std::fs::remove_dir_all(&dir).unwrap();
+        }
+        std::fs::create_dir_all(&dir).unwrap();
+        for file in ["tool-linux.tar.gz", "tool-macos.tar.gz", "CHANGELOG.md"] {
+            std::fs::write(dir.join(file), file).unwrap();
+        }
+        dir
+    }
+
+    #[test]
+    fn test_wildcard_match() {
+        assert!(wildcard_match("*.tar.gz", "tool-linux.tar.gz"));
+        assert!(wildcard_match("tool-?????.tar.gz", "tool-macos.tar.gz"));
+        assert!(wildcard_match("*-*.tar.gz", "a-b-c.tar.gz"));
+        assert!(!wildcard_match("*.tar.gz", "tool.tar.gz.sig"));
+        assert!(!wildcard_match("*", ".hidden"));
+        assert!(wildcard_match(".*", ".hidden"));
+    }
+
+    #[tokio::test]
+    async fn test_create_uploads_assets() {
+        let dir = asset_dir("gritty-release-create");
+        let remote = mock_remote();
+        remote.insert(Repository {
+            name: "tool".to_string(),
+            owner: "mock".to_string(),
+            ..Default::default()
+        });
+        let pattern = dir.join("*.tar.gz").to_string_lossy().to_string();
+        let notes = dir.join("CHANGELOG.md").to_string_lossy().to_string();
+        let args = Create::parse_from([
+            "create", "tool", "mock", "--tag", "v1.2.3", "-F", &notes, "--asset", &pattern,
+        ]);
+        create(args, &remote).await.unwrap();
+
+        let release = remote.get_release("mock", "tool", "v1.2.3").await.unwrap();
+        assert_eq!(release.body.as_deref(), Some("CHANGELOG.md"));
+        let assets: Vec<_> = release.assets.iter().map(|x| x.name.as_str()).collect();
+        assert_eq!(assets, ["tool-linux.tar.gz", "tool-macos.tar.gz"]);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_create_checks_assets_first() {
+        let remote = mock_remote();
+        remote.insert(Repository {
+            name: "tool".to_string(),
+            owner: "mock".to_string(),
+            ..Default::default()
+        });
+        let missing = std::env::temp_dir().join("gritty-release-missing.tar.gz");
+        let missing = missing.to_string_lossy().to_string();
+        let args = Create::parse_from(["create", "tool", "mock", "-t", "v1", "-a", &missing]);
+        assert!(create(args, &remote).await.is_err());
+        assert!(!remote
+            .calls()
+            .iter()
+            .any(|x| matches!(x, Call::CreateRelease { .. })));
+    }
+
+    #[test]
+    fn test_asset_takes_multiple_files() {
+        let args = Create::parse_from(["create", "tool", "mock", "-t", "v1", "-a", "a", "b"]);
+        assert_eq!(args.assets, [PathBuf::from("a"), PathBuf::from("b")]);
+    }
+}
@@ -0,0 +1,87 @@
+use crate::args::release::Delete;
+use crate::config::Config;
+use crate::error::Result;
+use crate::log::{Highlight, Paint};
+use crate::remote::Remote;
+
+use crate::commands::{confirm, load_remote};
+
+pub async fn delete_release(args: Delete, config: &Config) -> Result<()> {
+    let remote = load_remote(&args.remote, config).await?;
+    delete(args, remote.as_ref()).await
+}
+
+async fn delete(args: Delete, remote: &dyn Remote) -> Result<()> {
+    let owner = args.name.owner_or(&remote.get_config().username);
+    let name = &args.name.name;
+    let full_name = format!("{owner}/{name}");
+    let release = remote.get_release(owner, name, &args.tag).await?;
+    let warning = format!(
+        "You are about to delete release {} of {}.",
+        release.tag.paint(Highlight::Special),
+        full_name.paint(Highlight::Repo),
+    );
+    if !args.force && !confirm(&warning, None)? {
+        println!("{}", "Operation cancelled.".paint(Highlight::Special));
+        return Ok(());
+    }
+    remote.delete_release(owner, name, &release.tag).await?;
+    println!(
+        "Release {} of {} deleted.",
+        release.tag.paint(Highlight::Special),
+        full_name.paint(Highlight::Repo)
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::commands::mock_remote;
+    use crate::error::ErrorKind;
+    use crate::remote::{mock::Call, ReleaseCreateInfo, Repository};
+    use clap::Parser;
+
+    #[tokio::test]
+    async fn test_force_delete() {
+        let remote = mock_remote();
+        remote.insert(Repository {
+            name: "tool".to_string(),
+            owner: "mock".to_string(),
+            ..Default::default()
+        });
+        let release = ReleaseCreateInfo {
+            tag: "v1.0.0".to_string(),
+            ..Default::default()
+        };
+        remote
+            .create_release("mock", "tool", release)
+            .await
+            .unwrap();
+
+        let args = Delete::parse_from(["delete", "tool", "mock", "v1.0.0", "--force"]);
+        delete(args, &remote).await.unwrap();
+        assert!(remote
+            .list_releases("mock", "tool")
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_delete_missing_release() {
+        let remote = mock_remote();
+        remote.insert(Repository {
+            name: "tool".to_string(),
+            owner: "mock".to_string(),
+            ..Default::default()
+        });
+        let args = Delete::parse_from(["delete", "tool", "mock", "v1.0.0", "--force"]);
+        let err = delete(args, &remote).await.unwrap_err();
+        assert_eq!(err.kind, ErrorKind::NotFound);
+        assert!(!remote
+            .calls()
+            .iter()
+            .any(|x| matches!(x, Call::DeleteRelease { .. })));
+    }
+}
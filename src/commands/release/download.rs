@@ -0,0 +1,158 @@
+use std::path::Path;
+
+use crate::args::release::Download;
+use crate::config::Config;
+use crate::error::{Error, Result};
+use crate::log::{Highlight, Paint};
+use crate::remote::Remote;
+
+use crate::commands::load_remote;
+
+pub async fn download_release(args: Download, config: &Config) -> Result<()> {
+    let remote = load_remote(&args.remote, config).await?;
+    download(args, remote.as_ref()).await
+}
+
+async fn download(args: Download, remote: &dyn Remote) -> Result<()> {
+    let owner = args.name.owner_or(&remote.get_config().username);
+    let name = &args.name.name;
+    let full_name = format!("{owner}/{name}");
+    let release = remote.get_release(owner, name, &args.tag).await?;
+    if let Some(missing) = args
+        .assets
+        .iter()
+        .find(|x| !release.assets.iter().any(|asset| &asset.name == *x))
+    {
+        return Err(Error::not_found(format!(
+            "Release {} of {full_name} has no asset named {missing}",
+            release.tag
+        )));
+    }
+    let assets: Vec<_> = release
+        .assets
+        .iter()
+        .filter(|x| args.assets.is_empty() || args.assets.contains(&x.name))
+        .collect();
+    if assets.is_empty() {
+        println!(
+            "Release {} has no assets.",
+            release.tag.paint(Highlight::Special)
+        );
+        return Ok(());
+    }
+
+    std::fs::create_dir_all(&args.dir)?;
+    let mut failed = 0;
+    for asset in &assets {
+        // The names come from the remote, don't let them point outside of the directory.
+        let file_name = Path::new(&asset.name).file_name().unwrap_or_default();
+        let path = args.dir.join(file_name);
+        let result = match path.exists() && !args.force {
+            true => Err(Error::other(format!(
+                "{} already exists, use --force to overwrite it",
+                path.display()
+            ))),
+            false => remote
+                .download_release_asset(owner, name, &release.tag, &asset.name)
+                .await
+                .and_then(|data| Ok(std::fs::write(&path, data)?)),
+        };
+        match result {
+            Ok(()) => println!(
+                "Downloaded {}",
+                path.display().to_string().paint(Highlight::Path)
+            ),
+            Err(err) => {
+                failed += 1;
+                eprintln!(
+                    "{}: Failed to download {}: {}",
+                    "Error".paint(Highlight::Important),
+                    asset.name.paint(Highlight::Path),
+                    err
+                );
+            }
+        }
+    }
+    if failed > 0 {
+        return Err(Error::other(format!(
+            "Failed to download {failed} of {} assets",
+            assets.len()
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::commands::mock_remote;
+    use crate::remote::mock::MockRemote;
+    use crate::remote::{ReleaseCreateInfo, Repository};
+    use clap::Parser;
+
+    async fn release_with_assets() -> MockRemote {
+        let remote = mock_remote();
+        remote.insert(Repository {
+            name: "tool".to_string(),
+            owner: "mock".to_string(),
+            ..Default::default()
+        });
+        let release = ReleaseCreateInfo {
+            tag: "v1.0.0".to_string(),
+            ..Default::default()
+        };
+        remote
+            .create_release("mock", "tool", release)
+            .await
+            .unwrap();
+        for file in ["tool.tar.gz", "tool.zip"] {
+            let data = file.as_bytes().to_vec();
+            remote
+                .upload_release_asset("mock", "tool", "v1.0.0", file, data)
+                .await
+                .unwrap();
+        }
+        remote
+    }
+
+    #[tokio::test]
+    async fn test_download_assets() {
+        let remote = release_with_assets().await;
+        let dir = std::env::temp_dir().join("gritty-release-download");
+        if dir.exists() {
+            std::fs::remove_dir_all(&dir).unwrap();
+        }
+        let dir_arg = dir.to_string_lossy().to_string();
+
+        let args = Download::parse_from(["download", "tool", "mock", "v1.0.0", "-o", &dir_arg]);
+        download(args, &remote).await.unwrap();
+        assert_eq!(std::fs::read(dir.join("tool.zip")).unwrap(), b"tool.zip");
+        assert_eq!(
+            std::fs::read(dir.join("tool.tar.gz")).unwrap(),
+            b"tool.tar.gz"
+        );
+
+        // Existing files are only overwritten with --force.
+        let args = [
+            "download", "tool", "mock", "v1.0.0", "-a", "tool.zip", "-o", &dir_arg,
+        ];
+        let err = download(Download::parse_from(args), &remote)
+            .await
+            .unwrap_err();
+        assert_eq!(err.message, "Failed to download 1 of 1 assets");
+        let args = Download::parse_from(args.into_iter().chain(["--force"]));
+        download(args, &remote).await.unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_download_missing_asset() {
+        let remote = release_with_assets().await;
+        let args = Download::parse_from(["download", "tool", "mock", "v1.0.0", "-a", "tool.deb"]);
+        let err = download(args, &remote).await.unwrap_err();
+        assert_eq!(
+            err.message,
+            "Release v1.0.0 of mock/tool has no asset named tool.deb"
+        );
+    }
+}
@@ -0,0 +1,106 @@
+use crate::args::release::List;
+use crate::args::OutputFormat;
+use crate::config::Config;
+use crate::error::Result;
+use crate::log::{Highlight, Paint};
+use crate::remote::{Release, Remote};
+
+use crate::commands::load_remote;
+
+pub async fn list_releases(args: List, config: &Config) -> Result<()> {
+    let remote = load_remote(&args.remote, config).await?;
+    println!("{}", list(args, remote.as_ref()).await?);
+    Ok(())
+}
+
+async fn list(args: List, remote: &dyn Remote) -> Result<String> {
+    let owner = args.name.owner_or(&remote.get_config().username);
+    let releases = remote.list_releases(owner, &args.name.name).await?;
+    Ok(match args.format.unwrap_or_default() {
+        OutputFormat::Human => format_human(&releases),
+        OutputFormat::Json => serde_json::to_string_pretty(&releases)?,
+    })
+}
+
+fn format_human(releases: &[Release]) -> String {
+    if releases.is_empty() {
+        return "No releases found.".to_string();
+    }
+    let width = releases
+        .iter()
+        .map(|x| x.tag.len())
+        .max()
+        .unwrap_or_default();
+    let lines: Vec<_> = releases
+        .iter()
+        .map(|release| {
+            let mut line = format!("{:<width$}", release.tag)
+                .paint(Highlight::Special)
+                .to_string();
+            if let Some(name) = &release.name {
+                line.push_str(&format!(" {name}"));
+            }
+            if release.draft {
+                line.push_str(&format!(" {}", "(draft)".paint(Highlight::Warning)));
+            } else if release.prerelease {
+                line.push_str(&format!(" {}", "(prerelease)".paint(Highlight::Warning)));
+            }
+            if let Some(date) = release.published_at.or(release.created_at) {
+                line.push_str(&format!(" on {}", date.to_string().paint(Highlight::Date)));
+            }
+            if let Some(author) = &release.author {
+                line.push_str(&format!(" by {}", author.paint(Highlight::Author)));
+            }
+            line
+        })
+        .collect();
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::commands::mock_remote;
+    use crate::remote::{ReleaseCreateInfo, Repository};
+    use clap::Parser;
+
+    #[tokio::test]
+    async fn test_list_releases() {
+        let remote = mock_remote();
+        remote.insert(Repository {
+            name: "tool".to_string(),
+            owner: "mock".to_string(),
+            ..Default::default()
+        });
+        let args = List::parse_from(["list", "tool", "mock"]);
+        assert_eq!(list(args, &remote).await.unwrap(), "No releases found.");
+
+        let mut dates = Vec::new();
+        for (tag, name, draft) in [("v1.0.0", Some("First"), false), ("v1.1.0", None, true)] {
+            let release = ReleaseCreateInfo {
+                tag: tag.to_string(),
+                name: name.map(str::to_string),
+                draft,
+                ..Default::default()
+            };
+            let release = remote
+                .create_release("mock", "tool", release)
+                .await
+                .unwrap();
+            dates.push(release.published_at.or(release.created_at).unwrap());
+        }
+
+        let args = List::parse_from(["list", "tool", "mock"]);
+        let expected = format!(
+            "v1.1.0 (draft) on {} by mock\nv1.0.0 First on {} by mock",
+            dates[1], dates[0]
+        );
+        assert_eq!(list(args, &remote).await.unwrap(), expected);
+
+        let args = List::parse_from(["list", "tool", "mock", "--format", "json"]);
+        let json: serde_json::Value =
+            serde_json::from_str(&list(args, &remote).await.unwrap()).unwrap();
+        assert_eq!(json[0]["draft"], true);
+        assert_eq!(json[1]["tag"], "v1.0.0");
+    }
+}
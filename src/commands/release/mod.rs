@@ -0,0 +1,19 @@
+use crate::args::release::{Release, ReleaseCommands};
+use crate::config::Config;
+use crate::error::Result;
+
+mod create;
+mod delete;
+mod download;
+mod list;
+mod view;
+
+pub async fn release(release: Release, config: &Config) -> Result<()> {
+    match release.subcommand {
+        ReleaseCommands::List(args) => list::list_releases(args, config).await,
+        ReleaseCommands::View(args) => view::view_release(args, config).await,
+        ReleaseCommands::Create(args) => create::create_release(args, config).await,
+        ReleaseCommands::Delete(args) => delete::delete_release(args, config).await,
+        ReleaseCommands::Download(args) => download::download_release(args, config).await,
+    }
+}
@@ -0,0 +1,124 @@
+use crate::args::release::View;
+use crate::args::OutputFormat;
+use crate::config::Config;
+use crate::error::Result;
+use crate::log::{Highlight, Paint};
+use crate::remote::{Release, Remote};
+
+use crate::commands::load_remote;
+
+pub async fn view_release(args: View, config: &Config) -> Result<()> {
+    let remote = load_remote(&args.remote, config).await?;
+    println!("{}", view(args, remote.as_ref()).await?);
+    Ok(())
+}
+
+async fn view(args: View, remote: &dyn Remote) -> Result<String> {
+    let owner = args.name.owner_or(&remote.get_config().username);
+    let release = remote
+        .get_release(owner, &args.name.name, &args.tag)
+        .await?;
+    Ok(match args.format.unwrap_or_default() {
+        OutputFormat::Human => format_human(&release),
+        OutputFormat::Json => serde_json::to_string_pretty(&release)?,
+    })
+}
+
+fn format_human(release: &Release) -> String {
+    let mut title = release.tag.paint(Highlight::Special).to_string();
+    if let Some(name) = &release.name {
+        title.push_str(&format!(" {name}"));
+    }
+    if release.draft {
+        title.push_str(&format!(" ({})", "draft".paint(Highlight::Warning)));
+    } else if release.prerelease {
+        title.push_str(&format!(" ({})", "prerelease".paint(Highlight::Warning)));
+    }
+    let mut lines = vec![title];
+    match (&release.author, release.published_at.or(release.created_at)) {
+        (Some(author), Some(date)) => lines.push(format!(
+            "Published by {} on {}",
+            author.paint(Highlight::Author),
+            date.to_string().paint(Highlight::Date)
+        )),
+        (Some(author), None) => {
+            lines.push(format!("Published by {}", author.paint(Highlight::Author)))
+        }
+        (None, Some(date)) => lines.push(format!(
+            "Published on {}",
+            date.to_string().paint(Highlight::Date)
+        )),
+        (None, None) => {}
+    }
+    if let Some(url) = &release.html_url {
+        lines.push(url.paint(Highlight::Url).to_string());
+    }
+    if let Some(body) = release.body.as_deref().filter(|x| !x.trim().is_empty()) {
+        lines.push(format!("\n{}", body.trim_end()));
+    }
+    if !release.assets.is_empty() {
+        lines.push("\nAssets:".to_string());
+    }
+    for asset in &release.assets {
+        let mut line = format!("  {}", asset.name.paint(Highlight::Path));
+        if let Some(size) = asset.size {
+            line.push_str(&format!(" ({size} bytes)"));
+        }
+        lines.push(line);
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::commands::mock_remote;
+    use crate::error::ErrorKind;
+    use crate::remote::{ReleaseCreateInfo, Repository};
+    use clap::Parser;
+
+    #[tokio::test]
+    async fn test_view_release() {
+        let remote = mock_remote();
+        remote.insert(Repository {
+            name: "tool".to_string(),
+            owner: "mock".to_string(),
+            ..Default::default()
+        });
+        let release = ReleaseCreateInfo {
+            tag: "v1.0.0".to_string(),
+            name: Some("First".to_string()),
+            body: Some("Changes".to_string()),
+            prerelease: true,
+            ..Default::default()
+        };
+        let release = remote
+            .create_release("mock", "tool", release)
+            .await
+            .unwrap();
+        remote
+            .upload_release_asset("mock", "tool", "v1.0.0", "tool.tar.gz", vec![0; 42])
+            .await
+            .unwrap();
+
+        let args = View::parse_from(["view", "tool", "mock", "v1.0.0"]);
+        let expected = [
+            "v1.0.0 First (prerelease)".to_string(),
+            format!("Published by mock on {}", release.published_at.unwrap()),
+            "\nChanges".to_string(),
+            "\nAssets:".to_string(),
+            "  tool.tar.gz (42 bytes)".to_string(),
+        ]
+        .join("\n");
+        assert_eq!(view(args, &remote).await.unwrap(), expected);
+
+        let args = View::parse_from(["view", "tool", "mock", "v1.0.0", "--format", "json"]);
+        let json: serde_json::Value =
+            serde_json::from_str(&view(args, &remote).await.unwrap()).unwrap();
+        assert_eq!(json["assets"][0]["name"], "tool.tar.gz");
+
+        let args = View::parse_from(["view", "tool", "mock", "v2.0.0"]);
+        let err = view(args, &remote).await.unwrap_err();
+        assert_eq!(err.kind, ErrorKind::NotFound);
+    }
+}
@@ -16,22 +16,34 @@ pub async fn add_remote(args: Add, config: &mut Config) -> Result<()> {
         )));
     }
 
-    let provider = args.provider.map(Result::Ok).unwrap_or_else(|| {
-        print!(
-            "Enter the {} for the remote ({}): ",
-            "provider".paint(Highlight::Special),
-            "github/gitea/gitlab".paint(Highlight::Special)
-        );
-        match get_input()?.as_str() {
-            "github" => Ok(Provider::GitHub),
-            "gitea" => Ok(Provider::Gitea),
-            "gitlab" => Ok(Provider::GitLab),
-            other => Err(Error::other(format!(
-                "Unknown provider: {}",
-                other.paint(Highlight::Special)
-            ))),
-        }
-    })?;
+    let plugin = args.plugin.map(|command| Provider::External { command });
+    let provider = plugin
+        .or(args.provider)
+        .map(Result::Ok)
+        .unwrap_or_else(|| {
+            print!(
+                "Enter the {} for the remote ({}): ",
+                "provider".paint(Highlight::Special),
+                "github/gitea/gitlab/bitbucket/local/plugin".paint(Highlight::Special)
+            );
+            match get_input()?.as_str() {
+                "github" => Ok(Provider::GitHub),
+                "gitea" => Ok(Provider::Gitea),
+                "gitlab" => Ok(Provider::GitLab),
+                "bitbucket" => Ok(Provider::Bitbucket),
+                "local" => Ok(Provider::Local),
+                "plugin" => {
+                    print!("Enter the {}: ", "plugin name".paint(Highlight::Special));
+                    Ok(Provider::External {
+                        command: get_input()?,
+                    })
+                }
+                other => Err(Error::other(format!(
+                    "Unknown provider: {}",
+                    other.paint(Highlight::Special)
+                ))),
+            }
+        })?;
 
     let username = args.username.map(Result::Ok).unwrap_or_else(|| {
         print!(
@@ -67,6 +79,8 @@ pub async fn add_remote(args: Add, config: &mut Config) -> Result<()> {
         clone_protocol,
         url,
         provider,
+        concurrency: None,
+        commit_count: None,
     };
 
     config.remotes.insert(args.name.clone(), remote);
@@ -0,0 +1,86 @@
+use crate::args::repo::{Archive, RepoName, Unarchive};
+use crate::args::OutputFormat;
+use crate::config::Config;
+use crate::error::Result;
+use crate::log::{Highlight, Paint};
+use crate::remote::Remote;
+
+use crate::commands::load_remote;
+
+pub async fn archive_repository(args: Archive, config: &Config) -> Result<()> {
+    let remote = load_remote(&args.remote, config).await?;
+    let Archive {
+        name,
+        remote: remote_name,
+        format,
+    } = args;
+    set_archived(&name, &remote_name, format, remote.as_ref(), true).await
+}
+
+pub async fn unarchive_repository(args: Unarchive, config: &Config) -> Result<()> {
+    let remote = load_remote(&args.remote, config).await?;
+    let Unarchive {
+        name,
+        remote: remote_name,
+        format,
+    } = args;
+    set_archived(&name, &remote_name, format, remote.as_ref(), false).await
+}
+
+async fn set_archived(
+    name: &RepoName,
+    remote_name: &str,
+    format: Option<OutputFormat>,
+    remote: &dyn Remote,
+    archived: bool,
+) -> Result<()> {
+    let owner = name.owner_or(&remote.get_config().username);
+    let full_name = format!("{}/{}", owner, name.name);
+    let repo = match archived {
+        true => remote.archive_repo(owner, &name.name).await?,
+        false => remote.unarchive_repo(owner, &name.name).await?,
+    };
+    match format.unwrap_or_default() {
+        OutputFormat::Human => println!(
+            "Repository {} {} on remote {}.",
+            full_name.paint(Highlight::Repo),
+            if archived { "archived" } else { "unarchived" },
+            remote_name.paint(Highlight::Remote)
+        ),
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&repo)?),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::commands::mock_remote;
+    use crate::remote::{mock::Call, Repository};
+
+    #[tokio::test]
+    async fn test_archive_and_unarchive() {
+        let remote = mock_remote();
+        remote.insert(Repository {
+            name: "old".to_string(),
+            owner: "team".to_string(),
+            ..Default::default()
+        });
+        let name: RepoName = "team/old".parse().unwrap();
+        set_archived(&name, "mock", None, &remote, true)
+            .await
+            .unwrap();
+        assert!(remote.repo("team", "old").unwrap().archived);
+
+        set_archived(&name, "mock", None, &remote, false)
+            .await
+            .unwrap();
+        assert!(!remote.repo("team", "old").unwrap().archived);
+        assert!(matches!(remote.calls()[1], Call::UnarchiveRepo { .. }));
+
+        let name: RepoName = "missing".parse().unwrap();
+        assert!(set_archived(&name, "mock", None, &remote, true)
+            .await
+            .is_err());
+    }
+}
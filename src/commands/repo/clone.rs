@@ -2,12 +2,53 @@ use crate::args::repo::Clone;
 use crate::commands::load_remote;
 use crate::config::Config;
 use crate::error::Result;
+use crate::remote::Remote;
 
 pub async fn clone_repository(args: Clone, config: &Config) -> Result<()> {
     let remote = load_remote(&args.remote, config).await?;
-    let path = args.path.as_ref().map(|x| x.to_str().unwrap()).unwrap_or(&args.name);
-    remote
-        .clone_repo(&args.name, path, args.recursive)
-        .await?;
+    clone(args, remote.as_ref()).await
+}
+
+async fn clone(args: Clone, remote: &dyn Remote) -> Result<()> {
+    let owner = args.name.owner_or(&remote.get_config().username);
+    let name = &args.name.name;
+    let path = args
+        .path
+        .as_ref()
+        .map(|x| x.to_str().unwrap())
+        .unwrap_or(name);
+    remote.clone_repo(owner, name, path, args.recursive).await?;
     Ok(())
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::commands::mock_remote;
+    use crate::remote::{mock::Call, RepoCreateInfo};
+    use clap::Parser;
+
+    #[tokio::test]
+    async fn test_clone_defaults_to_own_repository() {
+        let remote = mock_remote();
+        remote
+            .create_repo(RepoCreateInfo {
+                name: "hello".to_string(),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        let args = Clone::parse_from(["clone", "hello", "mock", "--path", "dir", "-r"]);
+        clone(args, &remote).await.unwrap();
+        assert_eq!(
+            remote.calls().last(),
+            Some(&Call::CloneRepo {
+                owner: "mock".to_string(),
+                name: "hello".to_string(),
+                path: "dir".to_string(),
+                recursive: true,
+            })
+        );
+    }
+}
@@ -1,31 +1,52 @@
 use crate::args::{repo::Create, OutputFormat};
 use crate::config::Config;
-use crate::error::Result;
+use crate::error::{Error, Result};
 use crate::log::{Highlight, Paint};
-use crate::remote::RepoCreateInfo;
+use crate::remote::{Remote, RepoCreateInfo, WebhookCreateInfo};
 
 use crate::commands::load_remote;
 
 pub async fn create_repository(args: Create, config: &Config) -> Result<()> {
+    // Look up the hooks first, so a typo doesn't leave a repository without them.
+    let mut hooks = vec![];
+    for name in &args.hooks {
+        hooks.extend_from_slice(config.get_hooks(name)?);
+    }
+    let remote = load_remote(&args.remote, config).await?;
+    create(args, hooks, remote.as_ref()).await
+}
+
+async fn create(args: Create, hooks: Vec<WebhookCreateInfo>, remote: &dyn Remote) -> Result<()> {
     let Create {
         private,
         clone,
         recursive,
         add_remote,
+        org,
         name,
         description,
         license,
         init,
-        remote,
+        remote: _,
+        hooks: _,
         format,
     } = args;
     let format = format.unwrap_or_default();
-    let remote = load_remote(&remote, config).await?;
+    let owner = org.or(name.owner.clone());
+    let name = name.name;
     if let OutputFormat::Human = format {
-        println!("Creating repository {}...", name.paint(Highlight::Repo));
+        let full_name = match &owner {
+            Some(owner) => format!("{owner}/{name}"),
+            None => name.clone(),
+        };
+        println!(
+            "Creating repository {}...",
+            full_name.paint(Highlight::Repo)
+        );
     }
     let info = RepoCreateInfo {
         name: name.clone(),
+        owner,
         description,
         license,
         init,
@@ -38,15 +59,109 @@ pub async fn create_repository(args: Create, config: &Config) -> Result<()> {
             repo.clone_url.paint(Highlight::Url)
         );
     }
+    let total = hooks.len();
+    let mut failed = 0;
+    for hook in hooks {
+        let url = hook.url.clone();
+        // Keep going with the other hooks, the repository exists either way.
+        match remote.create_webhook(&repo.owner, &repo.name, hook).await {
+            Ok(hook) => {
+                if let OutputFormat::Human = format {
+                    println!(
+                        "Webhook {} attached for {}",
+                        hook.id.to_string().paint(Highlight::Special),
+                        url.paint(Highlight::Url)
+                    );
+                }
+            }
+            Err(err) => {
+                failed += 1;
+                eprintln!(
+                    "{}: Failed to attach webhook for {}: {}",
+                    "Error".paint(Highlight::Important),
+                    url.paint(Highlight::Url),
+                    err
+                );
+            }
+        }
+    }
     if clone {
-        remote.clone_repo(&name, &name, recursive).await?;
+        remote
+            .clone_repo(&repo.owner, &repo.name, &name, recursive)
+            .await?;
     } else if add_remote {
         remote
-            .add_remote(&name, repo.default_branch.clone())
+            .add_remote(&repo.owner, &repo.name, repo.default_branch.clone())
             .await?;
     }
     if let OutputFormat::Json = format {
         println!("{}", serde_json::to_string_pretty(&repo)?);
     }
+    if failed > 0 {
+        return Err(Error::other(format!(
+            "Failed to attach {failed} of {total} webhooks"
+        )));
+    }
     Ok(())
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::commands::mock_remote;
+    use crate::remote::mock::Call;
+    use clap::Parser;
+
+    #[tokio::test]
+    async fn test_create_in_org_and_clone() {
+        let remote = mock_remote();
+        let args = Create::parse_from(["create", "hello", "mock", "--org", "team", "-p", "-c"]);
+        create(args, vec![], &remote).await.unwrap();
+
+        let repo = remote.repo("team", "hello").unwrap();
+        assert!(repo.private);
+        let calls = remote.calls();
+        assert!(
+            matches!(&calls[0], Call::CreateRepo(info) if info.owner.as_deref() == Some("team"))
+        );
+        assert_eq!(
+            calls[1],
+            Call::CloneRepo {
+                owner: "team".to_string(),
+                name: "hello".to_string(),
+                path: "hello".to_string(),
+                recursive: false,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_create_existing_repository() {
+        let remote = mock_remote();
+        let args = Create::parse_from(["create", "hello", "mock"]);
+        create(args.clone(), vec![], &remote).await.unwrap();
+        let err = create(args, vec![], &remote).await.unwrap_err();
+        assert_eq!(err.status, Some(409));
+    }
+
+    #[tokio::test]
+    async fn test_create_with_hooks() {
+        let remote = mock_remote();
+        let hooks = vec![
+            WebhookCreateInfo {
+                url: "https://ci.example.com/hook".to_string(),
+                ..Default::default()
+            },
+            WebhookCreateInfo {
+                url: "https://chat.example.com/hook".to_string(),
+                ..Default::default()
+            },
+        ];
+        let args = Create::parse_from(["create", "hello", "mock", "--hook", "ci"]);
+        create(args, hooks, &remote).await.unwrap();
+
+        let hooks = remote.list_webhooks("mock", "hello").await.unwrap();
+        assert_eq!(hooks.len(), 2);
+        assert_eq!(hooks[1].url, "https://chat.example.com/hook");
+    }
+}
@@ -1,61 +1,88 @@
 use crate::args::repo::Delete;
 use crate::config::Config;
 use crate::error::{Error, Result};
-use crate::log::{self, Highlight, Paint};
-use crate::remote::Repository;
+use crate::log::{Highlight, Paint};
+use crate::remote::Remote;
 
-use crate::commands::{get_input, load_remote};
+use crate::commands::{confirm, load_remote};
 
 pub async fn delete_repository(args: Delete, config: &Config) -> Result<()> {
+    let remote = load_remote(&args.remote, config).await?;
+    delete(args, remote.as_ref()).await
+}
+
+async fn delete(args: Delete, remote: &dyn Remote) -> Result<()> {
     let Delete {
         name,
         force,
         remote: remote_name,
     } = &args;
-    let remote = load_remote(remote_name, config).await?;
-    let repo_info = match remote.get_repo_info(name).await {
+    let owner = name.owner_or(&remote.get_config().username);
+    let full_name = format!("{}/{}", owner, name.name);
+    let repo_info = match remote.get_repo_info(owner, &name.name).await {
         Ok(x) => x,
         Err(_) => {
             // TODO: match the actual error type
             return Err(Error::not_found(format!(
-                "Repository {name} not found on remote {remote_name}."
+                "Repository {full_name} not found on remote {remote_name}."
             )));
         }
     };
-    if !force && !ask_for_confirmation(name, remote_name, &repo_info)? {
+    let warning = format!(
+        "You are about to delete repository {} on remote {}.",
+        full_name.paint(Highlight::Repo),
+        remote_name.paint(Highlight::Remote),
+    );
+    if !force && !confirm(&warning, repo_info.last_commits.first())? {
         println!("{}", "Operation cancelled.".paint(Highlight::Special));
         return Ok(());
     }
-    remote.delete_repo(name).await?;
+    remote.delete_repo(owner, &name.name).await?;
     println!(
         "Repository {} deleted on remote {}.",
-        &name.paint(Highlight::Repo),
+        &full_name.paint(Highlight::Repo),
         &remote_name.paint(Highlight::Remote)
     );
     Ok(())
 }
 
-fn ask_for_confirmation(name: &str, remote_name: &str, repo: &Repository) -> Result<bool> {
-    println!(
-        "{}: You are about to delete repository {} on remote {}.",
-        "WARNING".paint(Highlight::Important),
-        &name.paint(Highlight::Repo),
-        &remote_name.paint(Highlight::Remote),
-    );
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::commands::mock_remote;
+    use crate::error::ErrorKind;
+    use crate::remote::{
+        mock::{Call, Failure, Method},
+        RepoCreateInfo,
+    };
+    use clap::Parser;
+
+    #[tokio::test]
+    async fn test_force_delete() {
+        let remote = mock_remote();
+        remote
+            .create_repo(RepoCreateInfo {
+                name: "hello".to_string(),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        let args = Delete::parse_from(["delete", "hello", "mock", "--force"]);
+        delete(args, &remote).await.unwrap();
+        assert!(remote.repo("mock", "hello").is_none());
+    }
 
-    if let Some(last) = repo.last_commits.first() {
-        // Only show the first line of the commit message
-        let message = last.message.split('\n').next().unwrap_or(&last.message);
-        println!(
-            "Last commit: {} - {} by {} on {}",
-            last.sha.split_at(8).0.paint(Highlight::Commit),
-            message.paint(Highlight::CommitMsg),
-            &last.author.paint(Highlight::Author),
-            &last.date.to_string().paint(Highlight::Date),
-        );
+    #[tokio::test]
+    async fn test_delete_missing_repository() {
+        let remote = mock_remote();
+        remote.fail(Method::GetRepoInfo, Failure::NotFound);
+        let args = Delete::parse_from(["delete", "team/hello", "mock", "--force"]);
+        let err = delete(args, &remote).await.unwrap_err();
+        assert_eq!(err.kind, ErrorKind::NotFound);
+        assert!(!remote
+            .calls()
+            .iter()
+            .any(|x| matches!(x, Call::DeleteRepo { .. })));
     }
-    log::print("Are you sure you want to continue? (y/N): ".paint(Highlight::Important));
-    let input = get_input()?;
-    // Only accept "y" or "Y" as confirmation, return false otherwise
-    Ok(input.eq_ignore_ascii_case("y"))
 }
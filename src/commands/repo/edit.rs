@@ -0,0 +1,102 @@
+use crate::args::{repo::Edit, OutputFormat};
+use crate::config::Config;
+use crate::error::{Error, Result};
+use crate::log::{Highlight, Paint};
+use crate::remote::Remote;
+
+use crate::commands::load_remote;
+
+pub async fn edit_repository(args: Edit, config: &Config) -> Result<()> {
+    let remote = load_remote(&args.remote, config).await?;
+    edit(args, remote.as_ref()).await
+}
+
+async fn edit(args: Edit, remote: &dyn Remote) -> Result<()> {
+    let update = args.update_info();
+    if update.is_empty() {
+        return Err(Error::other(
+            "Nothing to change. Use --help to see the settings you can edit.",
+        ));
+    }
+    let format = args.format.unwrap_or_default();
+    let owner = args.name.owner_or(&remote.get_config().username);
+    let full_name = format!("{}/{}", owner, args.name.name);
+    if let OutputFormat::Human = format {
+        println!(
+            "Updating repository {}...",
+            full_name.paint(Highlight::Repo)
+        );
+    }
+    let repo = remote.update_repo(owner, &args.name.name, update).await?;
+    match format {
+        OutputFormat::Human => println!(
+            "Repository {} updated on remote {}.",
+            full_name.paint(Highlight::Repo),
+            args.remote.paint(Highlight::Remote)
+        ),
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&repo)?),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::commands::mock_remote;
+    use crate::remote::{mock::Call, RepoCreateInfo, RepoUpdateInfo, Visibility};
+    use clap::Parser;
+
+    #[tokio::test]
+    async fn test_edit() {
+        let remote = mock_remote();
+        remote
+            .create_repo(RepoCreateInfo {
+                name: "hello".to_string(),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        let args = Edit::parse_from([
+            "edit",
+            "hello",
+            "mock",
+            "-d",
+            "Hello again",
+            "--visibility",
+            "private",
+            "--no-wiki",
+            "--issues",
+        ]);
+        edit(args, &remote).await.unwrap();
+
+        let repo = remote.repo("mock", "hello").unwrap();
+        assert_eq!(repo.description.as_deref(), Some("Hello again"));
+        assert_eq!(repo.visibility, Visibility::Private);
+        assert!(repo.private);
+        assert_eq!(
+            remote.calls()[1],
+            Call::UpdateRepo {
+                owner: "mock".to_string(),
+                name: "hello".to_string(),
+                update: RepoUpdateInfo {
+                    description: Some("Hello again".to_string()),
+                    visibility: Some(Visibility::Private),
+                    has_issues: Some(true),
+                    has_wiki: Some(false),
+                    ..Default::default()
+                },
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_edit_nothing() {
+        let remote = mock_remote();
+        let args = Edit::parse_from(["edit", "hello", "mock", "--wiki", "--no-wiki"]);
+        assert_eq!(args.update_info().has_wiki, Some(false));
+
+        let args = Edit::parse_from(["edit", "hello", "mock"]);
+        assert!(edit(args, &remote).await.is_err());
+        assert!(remote.calls().is_empty());
+    }
+}
@@ -1,11 +1,16 @@
 use crate::args::{repo::Fork, OutputFormat};
 use crate::commands::load_remote;
 use crate::config::Config;
-use crate::error::Result;
+use crate::error::{Error, Result};
 use crate::log::{Highlight, Paint};
-use crate::remote::RepoForkOption;
+use crate::remote::{Remote, RepoForkOption};
 
 pub async fn fork_repository(args: Fork, config: &Config) -> Result<()> {
+    let remote = load_remote(&args.remote, config).await?;
+    fork(args, remote.as_ref()).await
+}
+
+async fn fork(args: Fork, remote: &dyn Remote) -> Result<()> {
     let Fork {
         clone,
         add_remote,
@@ -13,14 +18,15 @@ pub async fn fork_repository(args: Fork, config: &Config) -> Result<()> {
         target,
         default_branch_only,
         organization,
-        owner,
         repository,
-        remote,
+        remote: _,
     } = args;
 
-    let repo = repository;
+    let owner = repository.owner.ok_or(Error::other(
+        "The repository to fork must be given as owner/repo.",
+    ))?;
+    let repo = repository.name;
     let format = format.unwrap_or_default();
-    let remote = load_remote(&remote, config).await?;
 
     if let OutputFormat::Human = format {
         let org = organization
@@ -51,11 +57,11 @@ pub async fn fork_repository(args: Fork, config: &Config) -> Result<()> {
 
     if clone {
         // TODO: add recursive option
-        // TODO: add organization option - currently only supports the authenticated user
-        remote.clone_repo(&repo.name, &repo.name, false).await?;
+        remote
+            .clone_repo(&repo.owner, &repo.name, &repo.name, false)
+            .await?;
     } else if add_remote {
-        // TODO: add organization option - currently only supports the authenticated user
-        remote.add_remote(&repo.name, None).await?;
+        remote.add_remote(&repo.owner, &repo.name, None).await?;
     }
 
     if let OutputFormat::Json = format {
@@ -63,3 +69,44 @@ pub async fn fork_repository(args: Fork, config: &Config) -> Result<()> {
     }
     Ok(())
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::commands::mock_remote;
+    use crate::remote::RepoCreateInfo;
+    use clap::Parser;
+
+    #[tokio::test]
+    async fn test_fork_into_org() {
+        let remote = mock_remote();
+        remote
+            .create_repo(RepoCreateInfo {
+                name: "tool".to_string(),
+                owner: Some("upstream".to_string()),
+                init: true,
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        let args = Fork::parse_from(["fork", "upstream/tool", "mock", "-o", "team", "-t", "mine"]);
+        fork(args, &remote).await.unwrap();
+
+        let repo = remote.repo("team", "mine").unwrap();
+        assert!(repo.fork);
+        assert_eq!(repo.last_commits.len(), 1);
+        assert_eq!(
+            remote.parent("team", "mine"),
+            Some(("upstream".to_string(), "tool".to_string()))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fork_requires_owner() {
+        let remote = mock_remote();
+        let args = Fork::parse_from(["fork", "tool", "mock"]);
+        assert!(fork(args, &remote).await.is_err());
+        assert!(remote.calls().is_empty());
+    }
+}
@@ -1,11 +1,14 @@
-use crate::args::{repo::List, OutputFormat};
+use crate::args::{
+    repo::{toggle, List},
+    OutputFormat,
+};
 use crate::config::Config;
 use crate::error::Result;
 use crate::log::{self, Highlight, Paint};
-use crate::remote::{ListReposInfo, Repository};
+use crate::remote::{ListReposInfo, Remote, Repository};
 use chrono::{DateTime, Local};
 
-use crate::commands::load_remote;
+use crate::commands::{load_remote, short_sha};
 
 pub async fn list_repositories(args: List, config: &Config) -> Result<()> {
     let remote = &args.remote;
@@ -17,57 +20,284 @@ pub async fn list_repositories(args: List, config: &Config) -> Result<()> {
         );
     }
 
-    let remote = load_remote(remote, config).await?;
+    let mut config = config.clone();
+    if let (Some(jobs), Some(remote)) = (args.jobs, config.remotes.get_mut(remote)) {
+        remote.concurrency = Some(jobs);
+    }
+    let remote = load_remote(&args.remote, &config).await?;
+    println!("{}", list(args, remote.as_ref()).await?);
+    Ok(())
+}
+
+async fn list(args: List, remote: &dyn Remote) -> Result<String> {
     let list_info = ListReposInfo {
+        owner: args.org.clone(),
         private: args.private,
         forks: args.forks,
+        limit: args.limit,
+        page: args.page,
+        commits: args.commits.unwrap_or(0).into(),
+        archived: toggle(args.archived, args.no_archived),
+        affiliated: false,
     };
     let repos = remote.list_repos(list_info).await?;
-    match format {
-        OutputFormat::Human => print_human(args, repos),
-        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&repos)?),
-    }
-    Ok(())
+    Ok(match args.format.unwrap_or_default() {
+        OutputFormat::Human => format_human(&args, &repos),
+        OutputFormat::Json => serde_json::to_string_pretty(&repos)?,
+    })
 }
 
-fn print_human(args: List, repos: Vec<Repository>) {
-    if args.private {
-        println!("* denotes private repositories");
+fn format_human(args: &List, repos: &[Repository]) -> String {
+    if repos.is_empty() {
+        return "No repositories found.".to_string();
     }
-    if args.forks {
-        println!("^ denotes forked repositories");
+    let mut lines = Vec::new();
+    if repos.iter().any(|x| x.private) {
+        lines.push("* denotes private repositories".to_string());
     }
-    let mut longest_name = 0;
-    for repo in &repos {
-        if repo.name.len() > longest_name {
-            longest_name = repo.name.len();
-        }
+    if repos.iter().any(|x| x.fork) {
+        lines.push("^ denotes forked repositories".to_string());
     }
-    for repo in &repos {
-        if repo.private {
-            print!("* ");
-        } else if repo.fork {
-            print!("^ ");
-        } else {
-            print!("  ");
-        }
-
-        log::print(log::leftpad(&repo.name, longest_name).paint(Highlight::Repo));
-
-        if repo.last_commits.is_empty() {
-            print!(" - no commits");
-        } else {
-            let last = &repo.last_commits[0];
+    if repos.iter().any(|x| x.archived) {
+        lines.push("~ denotes archived repositories".to_string());
+    }
+    if repos.iter().any(|x| x.mirror) {
+        lines.push("= denotes mirror repositories".to_string());
+    }
+    let marker_width = repos
+        .iter()
+        .map(|x| marker(x).len())
+        .max()
+        .unwrap_or_default()
+        .max(1);
+    let name_width = repos.iter().map(|x| x.name.len()).max().unwrap_or_default();
+    for repo in repos {
+        let mut line = format!(
+            "{} {}",
+            log::leftpad(&marker(repo), marker_width),
+            log::leftpad(&repo.name, name_width).paint(Highlight::Repo)
+        );
+        if let Some(last) = repo.last_commits.first() {
             let date: DateTime<Local> = last.date.into();
-            let sha = last.sha.split_at(8).0;
             let message = last.message.split('\n').next().unwrap_or(&last.message);
-            print!(
+            line.push_str(&format!(
                 " - {}: {} - {}",
                 date.to_string().paint(Highlight::Date),
-                sha.paint(Highlight::Commit),
+                short_sha(&last.sha).paint(Highlight::Commit),
                 message.paint(Highlight::CommitMsg)
-            );
+            ));
+        } else if args.commits.is_some_and(|x| x > 0) {
+            line.push_str(" - no commits");
+        } else if let Some(updated_at) = repo.last_activity() {
+            let date: DateTime<Local> = updated_at.into();
+            line.push_str(&format!(
+                " - updated {}",
+                date.to_string().paint(Highlight::Date)
+            ));
+        }
+        lines.push(line);
+    }
+    lines.join("\n")
+}
+
+/// The markers in front of a repository, one for each of its notable properties.
+fn marker(repo: &Repository) -> String {
+    [
+        (repo.private, '*'),
+        (repo.fork, '^'),
+        (repo.mirror, '='),
+        (repo.archived, '~'),
+    ]
+    .into_iter()
+    .filter_map(|(set, marker)| set.then_some(marker))
+    .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::commands::mock_remote;
+    use crate::remote::mock::{Call, Failure, Method};
+    use crate::remote::{Commit, CommitHistory};
+    use clap::Parser;
+
+    #[tokio::test]
+    async fn test_list_passes_filters() {
+        let remote = mock_remote();
+        let args = List::parse_from(["list", "mock", "--org", "team", "-p", "--format", "json"]);
+        list(args, &remote).await.unwrap();
+        assert_eq!(
+            remote.calls(),
+            [Call::ListRepos(ListReposInfo {
+                owner: Some("team".to_string()),
+                private: true,
+                forks: false,
+                limit: None,
+                page: None,
+                commits: CommitHistory::None,
+                archived: None,
+                affiliated: false,
+            })]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_list_pages() {
+        let remote = mock_remote();
+        for name in ["a", "b", "c"] {
+            remote.insert(Repository {
+                name: name.to_string(),
+                owner: "mock".to_string(),
+                ..Default::default()
+            });
         }
-        println!();
+        let args = List::parse_from(["list", "mock", "-l", "2", "--page", "2"]);
+        list(args, &remote).await.unwrap();
+        let Call::ListRepos(info) = &remote.calls()[0] else {
+            panic!("expected a list call");
+        };
+        assert_eq!((info.limit, info.page), (Some(2), Some(2)));
+
+        let repos = remote.list_repos(info.clone()).await.unwrap();
+        assert_eq!(repos.len(), 1);
+        assert_eq!(repos[0].name, "c");
+
+        assert!(List::try_parse_from(["list", "mock", "--page", "2"]).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_list_commits() {
+        let remote = mock_remote();
+        remote.insert(Repository {
+            name: "tool".to_string(),
+            owner: "mock".to_string(),
+            ..Default::default()
+        });
+        remote.commit("mock", "tool", "First").unwrap();
+        remote.commit("mock", "tool", "Second").unwrap();
+
+        for (flags, commits) in [
+            (&[][..], CommitHistory::None),
+            (&["--commits"][..], CommitHistory::Latest),
+            (&["--commits=5"][..], CommitHistory::Count(5)),
+        ] {
+            let args = List::parse_from(["list", "mock"].iter().chain(flags));
+            list(args, &remote).await.unwrap();
+            let Some(Call::ListRepos(info)) = remote.calls().pop() else {
+                panic!("expected a list call");
+            };
+            assert_eq!(info.commits, commits);
+        }
+
+        let repos = remote
+            .list_repos(ListReposInfo {
+                commits: CommitHistory::Latest,
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(repos[0].last_commits.len(), 1);
+        assert_eq!(repos[0].last_commits[0].message, "Second");
+    }
+
+    #[tokio::test]
+    async fn test_list_short_sha() {
+        let remote = mock_remote();
+        remote.insert(Repository {
+            name: "tool".to_string(),
+            owner: "mock".to_string(),
+            last_commits: vec![Commit {
+                sha: "abc".to_string(),
+                message: "First".to_string(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        });
+        let args = List::parse_from(["list", "mock", "--commits"]);
+        let output = list(args, &remote).await.unwrap();
+        assert!(output.ends_with(": abc - First"), "{output}");
+    }
+
+    #[tokio::test]
+    async fn test_list_archived() {
+        let remote = mock_remote();
+        for (name, archived) in [("old", true), ("new", false)] {
+            remote.insert(Repository {
+                name: name.to_string(),
+                owner: "mock".to_string(),
+                archived,
+                ..Default::default()
+            });
+        }
+        for (flag, expected) in [("--archived", "old"), ("--no-archived", "new")] {
+            let args = List::parse_from(["list", "mock", flag]);
+            list(args, &remote).await.unwrap();
+            let Some(Call::ListRepos(info)) = remote.calls().last().cloned() else {
+                panic!("expected a list call");
+            };
+            let repos = remote.list_repos(info).await.unwrap();
+            assert_eq!(repos.len(), 1);
+            assert_eq!(repos[0].name, expected);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_list_markers() {
+        let remote = mock_remote();
+        let args = List::parse_from(["list", "mock"]);
+        assert_eq!(list(args, &remote).await.unwrap(), "No repositories found.");
+
+        for (name, private, archived) in [("tool", true, true), ("site", false, false)] {
+            remote.insert(Repository {
+                name: name.to_string(),
+                owner: "mock".to_string(),
+                private,
+                archived,
+                ..Default::default()
+            });
+        }
+        let args = List::parse_from(["list", "mock", "-p"]);
+        let output = list(args, &remote).await.unwrap();
+        let lines: Vec<_> = output.lines().collect();
+        assert_eq!(
+            lines,
+            [
+                "* denotes private repositories",
+                "~ denotes archived repositories",
+                "   site",
+                "*~ tool",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_marker() {
+        let repo = Repository {
+            private: true,
+            mirror: true,
+            ..Default::default()
+        };
+        assert_eq!(marker(&repo), "*=");
+        let archived = Repository {
+            archived: true,
+            mirror: false,
+            ..repo.clone()
+        };
+        assert_eq!(marker(&archived), "*~");
+        let fork = Repository {
+            fork: true,
+            ..Default::default()
+        };
+        assert_eq!(marker(&fork), "^");
+        assert_eq!(marker(&Repository::default()), "");
+    }
+
+    #[tokio::test]
+    async fn test_list_unauthorized() {
+        let remote = mock_remote();
+        remote.fail(Method::ListRepos, Failure::Unauthorized);
+        let args = List::parse_from(["list", "mock"]);
+        let err = list(args, &remote).await.unwrap_err();
+        assert_eq!(err.status, Some(401));
     }
 }
@@ -0,0 +1,304 @@
+use std::path::Path;
+
+use crate::args::repo::Migrate;
+use crate::config::Config;
+use crate::error::{Error, ErrorKind, Result};
+use crate::log::{Highlight, Paint};
+use crate::remote::{
+    ListReposInfo, Remote, RepoCreateInfo, RepoImportInfo, RepoUpdateInfo, Repository, Visibility,
+};
+
+use crate::commands::git::{git, git_with_credentials, git_with_input};
+use crate::commands::load_remote;
+
+use super::is_http;
+
+/// Refs forges create for pull and merge requests. They can't be pushed to another forge.
+const FORGE_REFS: [&str; 2] = ["refs/pull", "refs/merge-requests"];
+
+pub async fn migrate_repositories(args: Migrate, config: &Config) -> Result<()> {
+    let from = load_remote(&args.from, config).await?;
+    let to = load_remote(&args.to, config).await?;
+    migrate(args, from.as_ref(), to.as_ref(), &std::env::temp_dir()).await
+}
+
+/// Migrate the requested repositories, using `work_dir` for mirror clones.
+async fn migrate(args: Migrate, from: &dyn Remote, to: &dyn Remote, work_dir: &Path) -> Result<()> {
+    let repos = match &args.name {
+        Some(name) => {
+            let owner = name.owner_or(&from.get_config().username);
+            vec![from.get_repo_info(owner, &name.name).await?]
+        }
+        None => {
+            let list_info = ListReposInfo {
+                private: true,
+                forks: true,
+                ..Default::default()
+            };
+            from.list_repos(list_info).await?
+        }
+    };
+
+    let mut failed = 0;
+    for repo in &repos {
+        let full_name = format!("{}/{}", repo.owner, repo.name);
+        match migrate_repo(repo, args.org.as_deref(), from, to, work_dir).await {
+            Ok(new) => println!(
+                "Repository {} migrated to {} on remote {}.",
+                full_name.paint(Highlight::Repo),
+                new.full_name.paint(Highlight::Repo),
+                args.to.paint(Highlight::Remote)
+            ),
+            Err(err) if args.all => {
+                failed += 1;
+                eprintln!(
+                    "{}: Failed to migrate {}: {}",
+                    "Error".paint(Highlight::Important),
+                    full_name.paint(Highlight::Repo),
+                    err
+                );
+            }
+            Err(err) => return Err(err),
+        }
+    }
+    if failed > 0 {
+        return Err(Error::other(format!(
+            "Failed to migrate {failed} of {} repositories",
+            repos.len()
+        )));
+    }
+    Ok(())
+}
+
+/// Copy a single repository, preferring the server-side importer of the target.
+async fn migrate_repo(
+    repo: &Repository,
+    org: Option<&str>,
+    from: &dyn Remote,
+    to: &dyn Remote,
+    work_dir: &Path,
+) -> Result<Repository> {
+    // The importer runs on the target's server.
+    if is_http(&repo.clone_url) {
+        let (auth_username, auth_password) = from.get_config().https_credentials().unzip();
+        let import = RepoImportInfo {
+            clone_url: repo.clone_url.clone(),
+            auth_username,
+            auth_password,
+            name: repo.name.clone(),
+            owner: org.map(str::to_string),
+            description: repo.description.clone(),
+            visibility: repo.visibility,
+            mirror: false,
+        };
+        match to.import_repo(import).await {
+            Err(err) if err.kind == ErrorKind::Unsupported => {}
+            result => return result,
+        }
+    }
+    mirror_repo(repo, org, from, to, work_dir).await
+}
+
+/// Copy a repository by pushing a mirror clone of it to a new repository on the target.
+async fn mirror_repo(
+    repo: &Repository,
+    org: Option<&str>,
+    from: &dyn Remote,
+    to: &dyn Remote,
+    work_dir: &Path,
+) -> Result<Repository> {
+    let create_info = RepoCreateInfo {
+        name: repo.name.clone(),
+        owner: org.map(str::to_string),
+        description: repo.description.clone(),
+        private: repo.private,
+        ..Default::default()
+    };
+    let new = to.create_repo(create_info).await?;
+
+    let dir = work_dir.join(format!(
+        "gritty-migrate-{}-{}.git",
+        repo.owner.replace('/', "-"),
+        repo.name
+    ));
+    if dir.exists() {
+        std::fs::remove_dir_all(&dir)?;
+    }
+    let source = from.clone_url(&repo.owner, &repo.name);
+    let target = to.clone_url(&new.owner, &new.name);
+    let source_credentials = from.get_config().https_credentials();
+    let target_credentials = to.get_config().https_credentials();
+    let pushed = push_mirror(
+        &source,
+        source_credentials
+            .as_ref()
+            .map(|(u, p)| (u.as_str(), p.as_str())),
+        &target,
+        target_credentials
+            .as_ref()
+            .map(|(u, p)| (u.as_str(), p.as_str())),
+        &dir,
+    );
+    if dir.exists() {
+        std::fs::remove_dir_all(&dir)?;
+    }
+    pushed?;
+
+    // New repositories start out with the forge's default branch, and only some forges can
+    // create internal repositories directly.
+    let update = RepoUpdateInfo {
+        default_branch: repo
+            .default_branch
+            .clone()
+            .filter(|x| new.default_branch.as_ref() != Some(x)),
+        visibility: (repo.visibility == Visibility::Internal).then_some(Visibility::Internal),
+        ..Default::default()
+    };
+    if update.is_empty() {
+        return Ok(new);
+    }
+    match to.update_repo(&new.owner, &new.name, update).await {
+        Ok(updated) => Ok(updated),
+        Err(err) => {
+            if err.kind != ErrorKind::Unsupported {
+                eprintln!(
+                    "{}: Could not carry over the settings of {}: {}",
+                    "Warning".paint(Highlight::Warning),
+                    new.full_name.paint(Highlight::Repo),
+                    err
+                );
+            }
+            Ok(new)
+        }
+    }
+}
+
+/// Mirror `source` to `target`, using `dir` for the mirror clone. The credentials are used to
+/// access the repositories over HTTPS.
+fn push_mirror(
+    source: &str,
+    source_credentials: Option<(&str, &str)>,
+    target: &str,
+    target_credentials: Option<(&str, &str)>,
+    dir: &Path,
+) -> Result<()> {
+    let dir = dir.to_string_lossy();
+    let args = ["clone", "--quiet", "--mirror", source, &dir];
+    git_with_credentials(&args, source_credentials)?;
+    let mut args = vec!["-C", &dir, "for-each-ref", "--format=delete %(refname)"];
+    args.extend(FORGE_REFS);
+    let forge_refs = git(&args)?;
+    if !forge_refs.is_empty() {
+        git_with_input(&["-C", &dir, "update-ref", "--stdin"], &forge_refs)?;
+    }
+    // Git refuses to push a mirror without any refs, and there's nothing to copy anyway.
+    if git(&["-C", &dir, "for-each-ref", "--count=1"])?.is_empty() {
+        return Ok(());
+    }
+    let args = ["-C", &dir, "push", "--quiet", "--mirror", target];
+    git_with_credentials(&args, target_credentials)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::commands::git::credentials_server;
+    use crate::commands::mock_remote;
+    use crate::remote::mock::{Call, Failure, Method, MockRemote};
+    use crate::remote::{Auth, RemoteConfig};
+    use clap::Parser;
+
+    fn source() -> MockRemote {
+        let remote = MockRemote::with_config(RemoteConfig {
+            auth: Auth::Token {
+                token: "secret".to_string(),
+            },
+            ..mock_remote().get_config().clone()
+        });
+        remote.insert(Repository {
+            name: "tool".to_string(),
+            owner: "mock".to_string(),
+            description: Some("A tool".to_string()),
+            private: true,
+            visibility: Visibility::Private,
+            default_branch: Some("trunk".to_string()),
+            clone_url: "https://mock.invalid/mock/tool.git".to_string(),
+            ..Default::default()
+        });
+        remote
+    }
+
+    #[tokio::test]
+    async fn test_migrate_with_importer() {
+        let from = source();
+        let to = mock_remote();
+        let args =
+            Migrate::parse_from(["migrate", "tool", "--from", "a", "--to", "b", "-o", "team"]);
+        migrate(args, &from, &to, &std::env::temp_dir())
+            .await
+            .unwrap();
+
+        let Call::ImportRepo(import) = &to.calls()[0] else {
+            panic!("expected an import, got {:?}", to.calls());
+        };
+        assert_eq!(import.clone_url, "https://mock.invalid/mock/tool.git");
+        assert_eq!(import.auth_username.as_deref(), Some("mock"));
+        assert_eq!(import.auth_password.as_deref(), Some("secret"));
+        assert_eq!(import.visibility, Visibility::Private);
+        let repo = to.repo("team", "tool").unwrap();
+        assert_eq!(repo.description.as_deref(), Some("A tool"));
+        assert!(repo.private);
+    }
+
+    #[tokio::test]
+    async fn test_migrate_all_keeps_going() {
+        let from = source();
+        from.insert(Repository {
+            name: "other".to_string(),
+            owner: "mock".to_string(),
+            clone_url: "https://mock.invalid/mock/other.git".to_string(),
+            ..Default::default()
+        });
+        let to = mock_remote();
+        to.insert(Repository {
+            name: "other".to_string(),
+            owner: "mock".to_string(),
+            ..Default::default()
+        });
+        let args = Migrate::parse_from(["migrate", "--all", "--from", "a", "--to", "b"]);
+        let err = migrate(args, &from, &to, &std::env::temp_dir())
+            .await
+            .unwrap_err();
+        assert_eq!(err.message, "Failed to migrate 1 of 2 repositories");
+        assert!(to.repo("mock", "tool").is_some());
+    }
+
+    #[tokio::test]
+    async fn test_mirror_uses_source_credentials() {
+        let (url, server) = credentials_server();
+        let from = MockRemote::with_config(RemoteConfig {
+            url,
+            ..source().get_config().clone()
+        });
+        from.insert(source().repo("mock", "tool").unwrap());
+        let to = mock_remote();
+        to.fail(
+            Method::ImportRepo,
+            Failure::Error(Error::unsupported("No importer")),
+        );
+        let args = Migrate::parse_from(["migrate", "tool", "--from", "a", "--to", "b"]);
+        migrate(args, &from, &to, &std::env::temp_dir())
+            .await
+            .unwrap_err();
+        assert_eq!(server.join().unwrap().as_deref(), Some("mock:secret"));
+    }
+
+    #[test]
+    fn test_name_or_all_required() {
+        assert!(Migrate::try_parse_from(["migrate", "--from", "a", "--to", "b"]).is_err());
+        assert!(
+            Migrate::try_parse_from(["migrate", "x", "--all", "--from", "a", "--to", "b"]).is_err()
+        );
+    }
+}
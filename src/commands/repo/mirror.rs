@@ -0,0 +1,275 @@
+use chrono::{DateTime, Local};
+
+use crate::args::repo::mirror::{Mirror, MirrorCommands, MirrorList, MirrorPull, MirrorPush};
+use crate::args::OutputFormat;
+use crate::config::Config;
+use crate::error::{Error, ErrorKind, Result};
+use crate::log::{Highlight, Paint};
+use crate::remote::{MirrorCreateInfo, MirrorDirection, Remote, RepoCreateInfo, RepoImportInfo};
+
+use crate::commands::load_remote;
+
+use super::is_http;
+
+pub async fn mirror(mirror: Mirror, config: &Config) -> Result<()> {
+    match mirror.subcommand {
+        MirrorCommands::Push(args) => {
+            let remote = load_remote(&args.remote, config).await?;
+            let target = load_remote(&args.to, config).await?;
+            push(args, remote.as_ref(), target.as_ref()).await
+        }
+        MirrorCommands::Pull(args) => {
+            let remote = load_remote(&args.remote, config).await?;
+            let source = load_remote(&args.from, config).await?;
+            pull(args, remote.as_ref(), source.as_ref()).await
+        }
+        MirrorCommands::List(args) => {
+            let remote = load_remote(&args.remote, config).await?;
+            list(args, remote.as_ref()).await
+        }
+    }
+}
+
+/// Name the remote in the error if it can't do mirrors at all.
+fn unsupported<'a>(remote_name: &'a str, what: &'a str) -> impl FnOnce(Error) -> Error + 'a {
+    move |err| match err.kind {
+        ErrorKind::Unsupported => {
+            Error::unsupported(format!("Remote {remote_name} does not support {what}"))
+        }
+        _ => err,
+    }
+}
+
+async fn push(args: MirrorPush, remote: &dyn Remote, target: &dyn Remote) -> Result<()> {
+    let owner = args.name.owner_or(&remote.get_config().username);
+    let name = &args.name.name;
+    let full_name = format!("{owner}/{name}");
+    // Check for support first, so nothing is created on the other remote if it's missing.
+    let mirrors = remote
+        .list_mirrors(owner, name)
+        .await
+        .map_err(unsupported(&args.remote, "push mirrors"))?;
+    if let Some(interval) = &args.interval {
+        remote
+            .check_push_mirror_interval(owner, name, interval)
+            .await?;
+    }
+    let repo = remote.get_repo_info(owner, name).await?;
+
+    let target_owner = args.org.as_deref().unwrap_or(&target.get_config().username);
+    let copy = match target.get_repo_info(target_owner, name).await {
+        Ok(copy) => copy,
+        Err(err) if err.kind == ErrorKind::NotFound => {
+            let create_info = RepoCreateInfo {
+                name: name.clone(),
+                owner: args.org.clone(),
+                description: repo.description.clone(),
+                private: repo.private,
+                ..Default::default()
+            };
+            target.create_repo(create_info).await?
+        }
+        Err(err) => return Err(err),
+    };
+    if !is_http(&copy.clone_url) {
+        return Err(Error::other(format!(
+            "Remote {} has no HTTP(S) URL for {}, which push mirrors need",
+            args.to, copy.full_name
+        )));
+    }
+    if mirrors.iter().any(|x| x.url == copy.clone_url) {
+        return Err(Error::other(format!(
+            "Repository {full_name} is already pushed to {}",
+            copy.clone_url
+        )));
+    }
+
+    let (auth_username, auth_password) = target.get_config().https_credentials().unzip();
+    let mirror = MirrorCreateInfo {
+        url: copy.clone_url.clone(),
+        auth_username,
+        auth_password,
+        interval: args.interval,
+    };
+    remote.create_push_mirror(owner, name, mirror).await?;
+    println!(
+        "Repository {} on remote {} is now pushed to {} on remote {}.",
+        full_name.paint(Highlight::Repo),
+        args.remote.paint(Highlight::Remote),
+        copy.full_name.paint(Highlight::Repo),
+        args.to.paint(Highlight::Remote)
+    );
+    Ok(())
+}
+
+async fn pull(args: MirrorPull, remote: &dyn Remote, source: &dyn Remote) -> Result<()> {
+    let owner = args.name.owner_or(&source.get_config().username);
+    let repo = source.get_repo_info(owner, &args.name.name).await?;
+    if !is_http(&repo.clone_url) {
+        return Err(Error::other(format!(
+            "Remote {} has no HTTP(S) URL for {}, which pull mirrors need",
+            args.from, repo.full_name
+        )));
+    }
+    let (auth_username, auth_password) = source.get_config().https_credentials().unzip();
+    let import = RepoImportInfo {
+        clone_url: repo.clone_url.clone(),
+        auth_username,
+        auth_password,
+        name: repo.name.clone(),
+        owner: args.org.clone(),
+        description: repo.description.clone(),
+        visibility: repo.visibility,
+        mirror: true,
+    };
+    let mirror = remote
+        .import_repo(import)
+        .await
+        .map_err(unsupported(&args.remote, "pull mirrors"))?;
+    println!(
+        "Repository {} created on remote {}, pulling from {} on remote {}.",
+        mirror.full_name.paint(Highlight::Repo),
+        args.remote.paint(Highlight::Remote),
+        repo.full_name.paint(Highlight::Repo),
+        args.from.paint(Highlight::Remote)
+    );
+    Ok(())
+}
+
+async fn list(args: MirrorList, remote: &dyn Remote) -> Result<()> {
+    let owner = args.name.owner_or(&remote.get_config().username);
+    let full_name = format!("{}/{}", owner, args.name.name);
+    let mirrors = remote
+        .list_mirrors(owner, &args.name.name)
+        .await
+        .map_err(unsupported(&args.remote, "mirrors"))?;
+    if let Some(OutputFormat::Json) = args.format {
+        println!("{}", serde_json::to_string_pretty(&mirrors)?);
+        return Ok(());
+    }
+
+    if mirrors.is_empty() {
+        println!(
+            "Repository {} has no mirrors.",
+            full_name.paint(Highlight::Repo)
+        );
+        return Ok(());
+    }
+    for mirror in mirrors {
+        let direction = match mirror.direction {
+            MirrorDirection::Push => "push to  ",
+            MirrorDirection::Pull => "pull from",
+        };
+        print!("{} {}", direction, mirror.url.paint(Highlight::Url));
+        if let Some(interval) = mirror.interval.filter(|x| !x.is_empty()) {
+            print!(" every {interval}");
+        }
+        if let Some(date) = mirror.last_update {
+            let date: DateTime<Local> = date.into();
+            print!(
+                ", last updated {}",
+                date.format("%Y-%m-%d %H:%M")
+                    .to_string()
+                    .paint(Highlight::Date)
+            );
+        }
+        println!();
+        if let Some(error) = mirror.last_error {
+            eprintln!("  {}: {}", "Error".paint(Highlight::Important), error);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::commands::mock_remote;
+    use crate::remote::mock::{Call, Failure, Method, MockRemote};
+    use crate::remote::{Auth, RemoteConfig, Repository};
+    use clap::Parser;
+
+    fn with_token(token: &str) -> MockRemote {
+        MockRemote::with_config(RemoteConfig {
+            auth: Auth::Token {
+                token: token.to_string(),
+            },
+            ..mock_remote().get_config().clone()
+        })
+    }
+
+    fn insert(remote: &MockRemote, name: &str) {
+        remote.insert(Repository {
+            name: name.to_string(),
+            owner: "mock".to_string(),
+            private: true,
+            clone_url: format!("https://mock.invalid/mock/{name}.git"),
+            ..Default::default()
+        });
+    }
+
+    #[tokio::test]
+    async fn test_push_creates_the_copy() {
+        let remote = mock_remote();
+        insert(&remote, "tool");
+        let target = with_token("secret");
+        let args = MirrorPush::parse_from(["push", "tool", "gitea", "--to", "github", "-i", "1h"]);
+        push(args, &remote, &target).await.unwrap();
+
+        let copy = target.repo("mock", "tool").unwrap();
+        assert!(copy.private);
+        let Some(Call::CreatePushMirror { mirror, .. }) = remote.calls().pop() else {
+            panic!("expected a push mirror, got {:?}", remote.calls());
+        };
+        assert_eq!(mirror.url, copy.clone_url);
+        assert_eq!(mirror.auth_username.as_deref(), Some("mock"));
+        assert_eq!(mirror.auth_password.as_deref(), Some("secret"));
+        assert_eq!(mirror.interval.as_deref(), Some("1h"));
+
+        let args = MirrorPush::parse_from(["push", "tool", "gitea", "--to", "github"]);
+        let err = push(args, &remote, &target).await.unwrap_err();
+        assert!(err.message.contains("already pushed"));
+    }
+
+    #[tokio::test]
+    async fn test_push_unsupported() {
+        let remote = mock_remote();
+        insert(&remote, "tool");
+        let unsupported = Error::unsupported("Push mirrors are not supported by this remote");
+        remote.fail(Method::ListMirrors, Failure::Error(unsupported));
+        let target = mock_remote();
+        let args = MirrorPush::parse_from(["push", "tool", "github", "--to", "gitea"]);
+        let err = push(args, &remote, &target).await.unwrap_err();
+        assert_eq!(err.kind, ErrorKind::Unsupported);
+        assert_eq!(err.message, "Remote github does not support push mirrors");
+        assert!(target.calls().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_pull() {
+        let remote = mock_remote();
+        let source = with_token("secret");
+        insert(&source, "tool");
+        let args = MirrorPull::parse_from(["pull", "tool", "gitea", "--from", "github"]);
+        pull(args, &remote, &source).await.unwrap();
+
+        assert!(remote.repo("mock", "tool").unwrap().mirror);
+        let mirrors = remote.list_mirrors("mock", "tool").await.unwrap();
+        assert_eq!(mirrors.len(), 1);
+        assert_eq!(mirrors[0].direction, MirrorDirection::Pull);
+        assert_eq!(mirrors[0].url, "https://mock.invalid/mock/tool.git");
+    }
+
+    #[tokio::test]
+    async fn test_push_interval_unsupported() {
+        let remote = mock_remote();
+        insert(&remote, "tool");
+        let unsupported = Error::unsupported("Mirror intervals are not supported by this remote");
+        remote.fail(Method::CheckPushMirrorInterval, Failure::Error(unsupported));
+        let target = mock_remote();
+        let args = MirrorPush::parse_from(["push", "tool", "gitlab", "--to", "x", "-i", "1h"]);
+        let err = push(args, &remote, &target).await.unwrap_err();
+        assert_eq!(err.kind, ErrorKind::Unsupported);
+        assert!(target.calls().is_empty());
+    }
+}
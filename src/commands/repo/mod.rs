@@ -2,11 +2,23 @@ use crate::args::repo::{Repo, RepoCommands};
 
 use crate::{config::Config, error::Result};
 
+mod archive;
 mod clone;
 mod create;
 mod delete;
+mod edit;
 mod fork;
 mod list;
+mod migrate;
+mod mirror;
+mod rename;
+mod sync;
+mod transfer;
+
+/// Whether other forges can fetch from the URL. They can't reach local paths or SSH-only hosts.
+fn is_http(url: &str) -> bool {
+    url.starts_with("http://") || url.starts_with("https://")
+}
 
 pub async fn repo(repo: Repo, config: &Config) -> Result<()> {
     match repo.subcommand {
@@ -14,6 +26,16 @@ pub async fn repo(repo: Repo, config: &Config) -> Result<()> {
         RepoCommands::List(list) => list::list_repositories(list, config).await,
         RepoCommands::Create(create) => create::create_repository(create, config).await,
         RepoCommands::Fork(fork) => fork::fork_repository(fork, config).await,
+        RepoCommands::Sync(sync) => sync::sync_repositories(sync, config).await,
+        RepoCommands::Edit(edit) => edit::edit_repository(edit, config).await,
+        RepoCommands::Rename(rename) => rename::rename_repository(rename, config).await,
+        RepoCommands::Transfer(transfer) => transfer::transfer_repository(transfer, config).await,
+        RepoCommands::Migrate(migrate) => migrate::migrate_repositories(migrate, config).await,
+        RepoCommands::Mirror(mirror) => mirror::mirror(mirror, config).await,
+        RepoCommands::Archive(archive) => archive::archive_repository(archive, config).await,
+        RepoCommands::Unarchive(unarchive) => {
+            archive::unarchive_repository(unarchive, config).await
+        }
         RepoCommands::Delete(delete) => delete::delete_repository(delete, config).await,
     }
 }
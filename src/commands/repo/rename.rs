@@ -0,0 +1,217 @@
+use std::path::Path;
+use std::process::Command;
+
+use crate::args::repo::Rename;
+use crate::config::Config;
+use crate::error::{Error, Result};
+use crate::log::{Highlight, Paint};
+use crate::remote::{Remote, Repository};
+
+use crate::commands::load_remote;
+
+pub async fn rename_repository(args: Rename, config: &Config) -> Result<()> {
+    let remote = load_remote(&args.remote, config).await?;
+    rename(args, remote.as_ref(), Path::new(".")).await
+}
+
+/// Rename the repository and update the origin of the clone in `dir`, if it is one.
+async fn rename(args: Rename, remote: &dyn Remote, dir: &Path) -> Result<()> {
+    let Rename {
+        name,
+        new_name,
+        remote: remote_name,
+        dry_run,
+    } = &args;
+    let owner = name.owner_or(&remote.get_config().username);
+    let full_name = format!("{}/{}", owner, name.name);
+    let new_full_name = format!("{owner}/{new_name}");
+
+    let repo = remote.get_repo_info(owner, &name.name).await?;
+    let origin = origin_url(dir).filter(|url| is_clone_of(url, &repo, remote));
+    let new_url = remote.clone_url(owner, new_name);
+
+    if *dry_run {
+        println!(
+            "Would rename {} to {} on remote {}.",
+            full_name.paint(Highlight::Repo),
+            new_full_name.paint(Highlight::Repo),
+            remote_name.paint(Highlight::Remote)
+        );
+        if let Some(origin) = origin {
+            println!(
+                "Would update 'origin' from {} to {}.",
+                origin.paint(Highlight::Url),
+                new_url.paint(Highlight::Url)
+            );
+        }
+        return Ok(());
+    }
+
+    remote.rename_repo(owner, &name.name, new_name).await?;
+    println!(
+        "Repository {} renamed to {} on remote {}.",
+        full_name.paint(Highlight::Repo),
+        new_full_name.paint(Highlight::Repo),
+        remote_name.paint(Highlight::Remote)
+    );
+    if origin.is_some() {
+        set_origin_url(dir, &new_url)?;
+        println!("Updated 'origin' to {}.", new_url.paint(Highlight::Url));
+    }
+    Ok(())
+}
+
+/// Get the fetch URL of the 'origin' remote of the git repository in `dir`, if there is one.
+fn origin_url(dir: &Path) -> Option<String> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(dir)
+        .args(["remote", "-v"])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    parse_origin(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// Find the fetch URL of 'origin' in the output of `git remote -v`.
+fn parse_origin(remotes: &str) -> Option<String> {
+    remotes.lines().find_map(|line| {
+        let mut parts = line.split_whitespace();
+        match (parts.next(), parts.next(), parts.next()) {
+            (Some("origin"), Some(url), Some("(fetch)")) => Some(url.to_string()),
+            _ => None,
+        }
+    })
+}
+
+/// Check whether `url` points to the given repository, using any of its clone URLs.
+fn is_clone_of(url: &str, repo: &Repository, remote: &dyn Remote) -> bool {
+    let normalize = |url: &str| {
+        url.trim_end_matches('/')
+            .trim_end_matches(".git")
+            .to_lowercase()
+    };
+    let url = normalize(url);
+    [
+        repo.clone_url.as_str(),
+        repo.ssh_url.as_str(),
+        &remote.clone_url(&repo.owner, &repo.name),
+    ]
+    .into_iter()
+    .any(|x| normalize(x) == url)
+}
+
+fn set_origin_url(dir: &Path, url: &str) -> Result<()> {
+    let status = Command::new("git")
+        .arg("-C")
+        .arg(dir)
+        .args(["remote", "set-url", "origin", url])
+        .status()?;
+    if !status.success() {
+        return Err(Error::other("Failed to update the URL of remote 'origin'"));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::commands::mock_remote;
+    use crate::remote::RepoCreateInfo;
+    use clap::Parser;
+
+    #[test]
+    fn test_parse_origin() {
+        let remotes = "fork\tgit@example.com:me/tool.git (fetch)\n\
+            fork\tgit@example.com:me/tool.git (push)\n\
+            origin\thttps://example.com/alice/tool.git (fetch)\n\
+            origin\tgit@example.com:alice/tool.git (push)\n";
+        assert_eq!(
+            parse_origin(remotes).as_deref(),
+            Some("https://example.com/alice/tool.git")
+        );
+        assert_eq!(parse_origin(""), None);
+    }
+
+    /// Create a git repository in a fresh temporary directory with the given origin.
+    fn clone_dir(name: &str, origin: &str) -> std::path::PathBuf {
+        let dir = std::env::temp_dir().join(format!("gritty-rename-{name}-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        let git = |args: &[&str]| {
+            let status = Command::new("git")
+                .arg("-C")
+                .arg(&dir)
+                .args(args)
+                .output()
+                .unwrap()
+                .status;
+            assert!(status.success());
+        };
+        git(&["init", "--quiet"]);
+        git(&["remote", "add", "origin", origin]);
+        dir
+    }
+
+    async fn remote_with_repo() -> crate::remote::mock::MockRemote {
+        let remote = mock_remote();
+        remote
+            .create_repo(RepoCreateInfo {
+                name: "hello".to_string(),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        remote
+    }
+
+    #[tokio::test]
+    async fn test_rename_updates_origin() {
+        let remote = remote_with_repo().await;
+        let dir = clone_dir("origin", "git@mock.invalid:mock/hello.git");
+        let args = Rename::parse_from(["rename", "hello", "world", "mock"]);
+        rename(args, &remote, &dir).await.unwrap();
+
+        assert!(remote.repo("mock", "hello").is_none());
+        assert_eq!(
+            remote.repo("mock", "world").unwrap().full_name,
+            "mock/world"
+        );
+        assert_eq!(
+            origin_url(&dir).as_deref(),
+            Some("https://mock.invalid/mock/world.git")
+        );
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_rename_dry_run() {
+        let remote = remote_with_repo().await;
+        let dir = clone_dir("dry-run", "https://mock.invalid/mock/hello.git");
+        let args = Rename::parse_from(["rename", "hello", "world", "mock", "--dry-run"]);
+        rename(args, &remote, &dir).await.unwrap();
+
+        assert!(remote.repo("mock", "hello").is_some());
+        assert_eq!(
+            origin_url(&dir).as_deref(),
+            Some("https://mock.invalid/mock/hello.git")
+        );
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_rename_keeps_unrelated_origin() {
+        let remote = remote_with_repo().await;
+        let dir = clone_dir("unrelated", "https://example.com/someone/else.git");
+        let args = Rename::parse_from(["rename", "hello", "world", "mock"]);
+        rename(args, &remote, &dir).await.unwrap();
+
+        assert_eq!(
+            origin_url(&dir).as_deref(),
+            Some("https://example.com/someone/else.git")
+        );
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
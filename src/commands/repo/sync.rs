@@ -0,0 +1,285 @@
+use std::path::Path;
+
+use crate::args::repo::Sync;
+use crate::config::Config;
+use crate::error::{Error, ErrorKind, Result};
+use crate::log::{Highlight, Paint};
+use crate::remote::{ListReposInfo, Remote, Repository, SyncStatus};
+
+use crate::commands::git::{git, git_succeeds, git_with_credentials};
+use crate::commands::load_remote;
+
+pub async fn sync_repositories(args: Sync, config: &Config) -> Result<()> {
+    let remote = load_remote(&args.remote, config).await?;
+    sync(args, remote.as_ref(), &std::env::temp_dir()).await
+}
+
+/// Sync the requested forks, using `work_dir` for the clones of the fallback.
+async fn sync(args: Sync, remote: &dyn Remote, work_dir: &Path) -> Result<()> {
+    let forks = match &args.name {
+        Some(name) => {
+            let owner = name.owner_or(&remote.get_config().username);
+            vec![remote.get_repo_info(owner, &name.name).await?]
+        }
+        None => {
+            let list_info = ListReposInfo {
+                private: true,
+                forks: true,
+                ..Default::default()
+            };
+            let repos = remote.list_repos(list_info).await?;
+            repos.into_iter().filter(|x| x.fork).collect()
+        }
+    };
+
+    let mut failed = 0;
+    for fork in &forks {
+        let full_name = format!("{}/{}", fork.owner, fork.name);
+        match sync_fork(fork, args.branch.as_deref(), remote, work_dir).await {
+            Ok(status) => {
+                let status = match status {
+                    SyncStatus::UpToDate => "already up to date".paint(Highlight::Special),
+                    SyncStatus::FastForwarded => "fast-forwarded".paint(Highlight::Special),
+                    SyncStatus::Merged => "merged with upstream".paint(Highlight::Special),
+                    SyncStatus::Diverged => {
+                        "diverged from upstream, not synced".paint(Highlight::Warning)
+                    }
+                };
+                println!("{}: {}", full_name.paint(Highlight::Repo), status);
+            }
+            Err(err) if args.all_forks => {
+                failed += 1;
+                eprintln!(
+                    "{}: Failed to sync {}: {}",
+                    "Error".paint(Highlight::Important),
+                    full_name.paint(Highlight::Repo),
+                    err
+                );
+            }
+            Err(err) => return Err(err),
+        }
+    }
+    if failed > 0 {
+        return Err(Error::other(format!(
+            "Failed to sync {failed} of {} forks",
+            forks.len()
+        )));
+    }
+    Ok(())
+}
+
+/// Sync a single fork, letting the remote do it if it can.
+async fn sync_fork(
+    fork: &Repository,
+    branch: Option<&str>,
+    remote: &dyn Remote,
+    work_dir: &Path,
+) -> Result<SyncStatus> {
+    let Some(branch) = branch.or(fork.default_branch.as_deref()) else {
+        return Err(Error::other(format!(
+            "Repository {} has no default branch, use --branch to choose one",
+            fork.full_name
+        )));
+    };
+    match remote.sync_fork(&fork.owner, &fork.name, branch).await {
+        Err(err) if err.kind == ErrorKind::Unsupported => {}
+        result => return result,
+    }
+
+    let Some(parent) = &fork.parent else {
+        return Err(Error::other(format!(
+            "Repository {} is not a fork",
+            fork.full_name
+        )));
+    };
+    let dir = work_dir.join(format!(
+        "gritty-sync-{}-{}.git",
+        fork.owner.replace('/', "-"),
+        fork.name
+    ));
+    if dir.exists() {
+        std::fs::remove_dir_all(&dir)?;
+    }
+    let fork_url = remote.clone_url(&fork.owner, &fork.name);
+    let parent_url = remote.clone_url(&parent.owner, &parent.name);
+    let credentials = remote.get_config().https_credentials();
+    let credentials = credentials.as_ref().map(|(u, p)| (u.as_str(), p.as_str()));
+    let pattern = format!("refs/heads/{branch}");
+    let args = ["ls-remote", "--heads", &parent_url, &pattern];
+    if git_with_credentials(&args, credentials)?.is_empty() {
+        return Err(Error::not_found(format!(
+            "Repository {}/{} has no branch {branch}",
+            parent.owner, parent.name
+        )));
+    }
+    let status = fast_forward(&fork_url, &parent_url, branch, &dir, credentials);
+    if dir.exists() {
+        std::fs::remove_dir_all(&dir)?;
+    }
+    status
+}
+
+/// Fetch the branch of the fork and its parent into a bare repository at `dir`, and push the
+/// parent's branch to the fork if the fork is behind.
+fn fast_forward(
+    fork_url: &str,
+    parent_url: &str,
+    branch: &str,
+    dir: &Path,
+    credentials: Option<(&str, &str)>,
+) -> Result<SyncStatus> {
+    let dir = dir.to_string_lossy();
+    let fork = format!("refs/remotes/fork/{branch}");
+    let parent = format!("refs/remotes/parent/{branch}");
+    git(&["init", "--quiet", "--bare", &dir])?;
+    for (url, local) in [(fork_url, &fork), (parent_url, &parent)] {
+        let refspec = format!("+refs/heads/{branch}:{local}");
+        let args = ["-C", &dir, "fetch", "--quiet", url, &refspec];
+        git_with_credentials(&args, credentials)?;
+    }
+
+    let is_ancestor =
+        |a: &str, b: &str| git_succeeds(&["-C", &dir, "merge-base", "--is-ancestor", a, b]);
+    if is_ancestor(&parent, &fork)? {
+        return Ok(SyncStatus::UpToDate);
+    }
+    if !is_ancestor(&fork, &parent)? {
+        return Ok(SyncStatus::Diverged);
+    }
+    let refspec = format!("{parent}:refs/heads/{branch}");
+    let args = ["-C", &dir, "push", "--quiet", fork_url, &refspec];
+    git_with_credentials(&args, credentials)?;
+    Ok(SyncStatus::FastForwarded)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::commands::git::credentials_server;
+    use crate::commands::mock_remote;
+    use crate::remote::mock::{Call, Failure, Method, MockRemote};
+    use crate::remote::{Auth, RemoteConfig, RepoCreateInfo, RepoForkOption};
+    use clap::Parser;
+
+    async fn fork(remote: &MockRemote) {
+        remote
+            .create_repo(RepoCreateInfo {
+                name: "tool".to_string(),
+                owner: Some("upstream".to_string()),
+                init: true,
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        let mut fork = remote
+            .create_fork(RepoForkOption {
+                owner: "upstream".to_string(),
+                repo: "tool".to_string(),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        fork.default_branch = Some("main".to_string());
+        remote.insert(fork);
+    }
+
+    #[tokio::test]
+    async fn test_sync_fast_forwards() {
+        let remote = mock_remote();
+        fork(&remote).await;
+        let parent = remote.commit("upstream", "tool", "Fix").unwrap();
+
+        let args = Sync::parse_from(["sync", "tool", "mock"]);
+        sync(args, &remote, &std::env::temp_dir()).await.unwrap();
+        assert_eq!(remote.repo("mock", "tool").unwrap().last_commits[0], parent);
+
+        let fork = remote.repo("mock", "tool").unwrap();
+        let status = sync_fork(&fork, None, &remote, &std::env::temp_dir()).await;
+        assert_eq!(status.unwrap(), SyncStatus::UpToDate);
+    }
+
+    #[tokio::test]
+    async fn test_sync_diverged() {
+        let remote = mock_remote();
+        fork(&remote).await;
+        remote.commit("upstream", "tool", "Upstream").unwrap();
+        remote.commit("mock", "tool", "Fork").unwrap();
+
+        let fork = remote.repo("mock", "tool").unwrap();
+        let status = sync_fork(&fork, Some("main"), &remote, &std::env::temp_dir()).await;
+        assert_eq!(status.unwrap(), SyncStatus::Diverged);
+        assert_eq!(
+            remote.repo("mock", "tool").unwrap().last_commits[0].message,
+            "Fork"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_sync_all_forks() {
+        let remote = mock_remote();
+        fork(&remote).await;
+        remote.insert(Repository {
+            name: "own".to_string(),
+            owner: "mock".to_string(),
+            ..Default::default()
+        });
+        let args = Sync::parse_from(["sync", "--all-forks", "mock"]);
+        assert_eq!(args.remote, "mock");
+        sync(args, &remote, &std::env::temp_dir()).await.unwrap();
+        let synced: Vec<_> = remote
+            .calls()
+            .into_iter()
+            .filter_map(|x| match x {
+                Call::SyncFork { name, .. } => Some(name),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(synced, ["tool"]);
+    }
+
+    /// A remote that can't sync forks itself, with its repositories at `url`.
+    async fn fallback_remote(url: String) -> MockRemote {
+        let remote = MockRemote::with_config(RemoteConfig {
+            url,
+            auth: Auth::Token {
+                token: "secret".to_string(),
+            },
+            ..mock_remote().get_config().clone()
+        });
+        fork(&remote).await;
+        remote.fail(
+            Method::SyncFork,
+            Failure::Error(Error::unsupported("No fork sync")),
+        );
+        remote
+    }
+
+    #[tokio::test]
+    async fn test_fallback_uses_credentials() {
+        let (url, server) = credentials_server();
+        let remote = fallback_remote(url).await;
+        let fork = remote.repo("mock", "tool").unwrap();
+        sync_fork(&fork, Some("main"), &remote, &std::env::temp_dir())
+            .await
+            .unwrap_err();
+        assert_eq!(server.join().unwrap().as_deref(), Some("mock:secret"));
+    }
+
+    #[tokio::test]
+    async fn test_fallback_missing_parent_branch() {
+        let root = std::env::temp_dir().join("gritty-sync-test-missing-branch");
+        if root.exists() {
+            std::fs::remove_dir_all(&root).unwrap();
+        }
+        let parent = root.join("upstream/tool.git");
+        git(&["init", "--quiet", "--bare", &parent.to_string_lossy()]).unwrap();
+        let remote = fallback_remote(root.to_string_lossy().to_string()).await;
+        let fork = remote.repo("mock", "tool").unwrap();
+        let err = sync_fork(&fork, Some("main"), &remote, &std::env::temp_dir())
+            .await
+            .unwrap_err();
+        std::fs::remove_dir_all(&root).unwrap();
+        assert_eq!(err.kind, ErrorKind::NotFound);
+        assert_eq!(err.message, "Repository upstream/tool has no branch main");
+    }
+}
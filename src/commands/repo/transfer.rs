@@ -0,0 +1,85 @@
+use crate::args::repo::Transfer;
+use crate::config::Config;
+use crate::error::Result;
+use crate::log::{Highlight, Paint};
+use crate::remote::Remote;
+
+use crate::commands::{confirm, load_remote};
+
+pub async fn transfer_repository(args: Transfer, config: &Config) -> Result<()> {
+    let remote = load_remote(&args.remote, config).await?;
+    transfer(args, remote.as_ref()).await
+}
+
+async fn transfer(args: Transfer, remote: &dyn Remote) -> Result<()> {
+    let Transfer {
+        name,
+        new_owner,
+        remote: remote_name,
+        force,
+    } = &args;
+    let owner = name.owner_or(&remote.get_config().username);
+    let full_name = format!("{}/{}", owner, name.name);
+    let repo = remote.get_repo_info(owner, &name.name).await?;
+    if !force {
+        let warning = format!(
+            "You are about to transfer repository {} on remote {} to {}.",
+            full_name.paint(Highlight::Repo),
+            remote_name.paint(Highlight::Remote),
+            new_owner.paint(Highlight::Repo),
+        );
+        if !confirm(&warning, repo.last_commits.first())? {
+            println!("{}", "Operation cancelled.".paint(Highlight::Special));
+            return Ok(());
+        }
+    }
+    let repo = remote.transfer_repo(owner, &name.name, new_owner).await?;
+    println!(
+        "Repository {} transferred to {} on remote {}.",
+        full_name.paint(Highlight::Repo),
+        repo.full_name.paint(Highlight::Repo),
+        remote_name.paint(Highlight::Remote)
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::commands::mock_remote;
+    use crate::error::ErrorKind;
+    use crate::remote::{mock::Call, RepoCreateInfo};
+    use clap::Parser;
+
+    #[tokio::test]
+    async fn test_force_transfer() {
+        let remote = mock_remote();
+        remote
+            .create_repo(RepoCreateInfo {
+                name: "hello".to_string(),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        let args = Transfer::parse_from(["transfer", "hello", "team", "mock", "--force"]);
+        transfer(args, &remote).await.unwrap();
+        assert!(remote.repo("mock", "hello").is_none());
+        assert_eq!(
+            remote.repo("team", "hello").unwrap().full_name,
+            "team/hello"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_transfer_missing_repository() {
+        let remote = mock_remote();
+        let args = Transfer::parse_from(["transfer", "hello", "team", "mock", "--force"]);
+        let err = transfer(args, &remote).await.unwrap_err();
+        assert_eq!(err.kind, ErrorKind::NotFound);
+        assert!(!remote
+            .calls()
+            .iter()
+            .any(|x| matches!(x, Call::TransferRepo { .. })));
+    }
+}
@@ -0,0 +1,67 @@
+use crate::args::tag::Create;
+use crate::args::OutputFormat;
+use crate::config::Config;
+use crate::error::Result;
+use crate::log::{Highlight, Paint};
+use crate::remote::{Remote, TagCreateInfo};
+
+use crate::commands::{load_remote, short_sha};
+
+pub async fn create_tag(args: Create, config: &Config) -> Result<()> {
+    let remote = load_remote(&args.remote, config).await?;
+    create(args, remote.as_ref()).await
+}
+
+async fn create(args: Create, remote: &dyn Remote) -> Result<()> {
+    let owner = args.name.owner_or(&remote.get_config().username);
+    let name = &args.name.name;
+    let tag = TagCreateInfo {
+        name: args.tag,
+        target: args.target,
+        message: args.message,
+    };
+    let tag = remote.create_tag(owner, name, tag).await?;
+    match args.format.unwrap_or_default() {
+        OutputFormat::Human => println!(
+            "Tag {} created in {} at {}.",
+            tag.name.paint(Highlight::Special),
+            format!("{owner}/{name}").paint(Highlight::Repo),
+            short_sha(&tag.sha).paint(Highlight::Commit)
+        ),
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&tag)?),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::commands::mock_remote;
+    use crate::remote::RepoCreateInfo;
+    use clap::Parser;
+
+    #[tokio::test]
+    async fn test_create_annotated_tag() {
+        let remote = mock_remote();
+        let create_info = RepoCreateInfo {
+            name: "tool".to_string(),
+            init: true,
+            ..Default::default()
+        };
+        let repo = remote.create_repo(create_info).await.unwrap();
+        remote.commit("mock", "tool", "Fix").unwrap();
+
+        let first = &repo.last_commits[0].sha;
+        let args = Create::parse_from([
+            "create", "tool", "mock", "v1.0.0", "--target", first, "-m", "First",
+        ]);
+        create(args, &remote).await.unwrap();
+        let tags = remote.list_tags("mock", "tool").await.unwrap();
+        assert_eq!(tags[0].sha, *first);
+        assert_eq!(tags[0].message.as_deref(), Some("First"));
+
+        let args = Create::parse_from(["create", "tool", "mock", "v1.0.0"]);
+        let err = create(args, &remote).await.unwrap_err();
+        assert_eq!(err.status, Some(422));
+    }
+}
@@ -0,0 +1,80 @@
+use crate::args::tag::Delete;
+use crate::config::Config;
+use crate::error::{Error, Result};
+use crate::log::{Highlight, Paint};
+use crate::remote::Remote;
+
+use crate::commands::{confirm, load_remote};
+
+pub async fn delete_tag(args: Delete, config: &Config) -> Result<()> {
+    let remote = load_remote(&args.remote, config).await?;
+    delete(args, remote.as_ref()).await
+}
+
+async fn delete(args: Delete, remote: &dyn Remote) -> Result<()> {
+    let owner = args.name.owner_or(&remote.get_config().username);
+    let name = &args.name.name;
+    let full_name = format!("{owner}/{name}");
+    let tags = remote.list_tags(owner, name).await?;
+    if !tags.iter().any(|x| x.name == args.tag) {
+        return Err(Error::not_found(format!(
+            "Tag {} not found in {full_name}",
+            args.tag
+        )));
+    }
+    let warning = format!(
+        "You are about to delete tag {} of {}.",
+        args.tag.paint(Highlight::Special),
+        full_name.paint(Highlight::Repo),
+    );
+    if !args.force && !confirm(&warning, None)? {
+        println!("{}", "Operation cancelled.".paint(Highlight::Special));
+        return Ok(());
+    }
+    remote.delete_tag(owner, name, &args.tag).await?;
+    println!(
+        "Tag {} of {} deleted.",
+        args.tag.paint(Highlight::Special),
+        full_name.paint(Highlight::Repo)
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::commands::mock_remote;
+    use crate::error::ErrorKind;
+    use crate::remote::{mock::Call, RepoCreateInfo, TagCreateInfo};
+    use clap::Parser;
+
+    #[tokio::test]
+    async fn test_force_delete() {
+        let remote = mock_remote();
+        let create_info = RepoCreateInfo {
+            name: "tool".to_string(),
+            init: true,
+            ..Default::default()
+        };
+        remote.create_repo(create_info).await.unwrap();
+        let tag = TagCreateInfo {
+            name: "v1.0.0".to_string(),
+            ..Default::default()
+        };
+        remote.create_tag("mock", "tool", tag).await.unwrap();
+
+        let args = Delete::parse_from(["delete", "tool", "mock", "v1.0.0", "--force"]);
+        delete(args, &remote).await.unwrap();
+        assert!(remote.list_tags("mock", "tool").await.unwrap().is_empty());
+
+        let args = Delete::parse_from(["delete", "tool", "mock", "v1.0.0", "--force"]);
+        let err = delete(args, &remote).await.unwrap_err();
+        assert_eq!(err.kind, ErrorKind::NotFound);
+        let deletes = remote
+            .calls()
+            .into_iter()
+            .filter(|x| matches!(x, Call::DeleteTag { .. }))
+            .count();
+        assert_eq!(deletes, 1);
+    }
+}
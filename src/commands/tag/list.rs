@@ -0,0 +1,92 @@
+use crate::args::tag::List;
+use crate::args::OutputFormat;
+use crate::config::Config;
+use crate::error::Result;
+use crate::log::{Highlight, Paint};
+use crate::remote::{Remote, Tag};
+
+use crate::commands::{load_remote, short_sha};
+
+pub async fn list_tags(args: List, config: &Config) -> Result<()> {
+    let remote = load_remote(&args.remote, config).await?;
+    println!("{}", list(args, remote.as_ref()).await?);
+    Ok(())
+}
+
+async fn list(args: List, remote: &dyn Remote) -> Result<String> {
+    let owner = args.name.owner_or(&remote.get_config().username);
+    let tags = remote.list_tags(owner, &args.name.name).await?;
+    Ok(match args.format.unwrap_or_default() {
+        OutputFormat::Human => format_human(&tags),
+        OutputFormat::Json => serde_json::to_string_pretty(&tags)?,
+    })
+}
+
+fn format_human(tags: &[Tag]) -> String {
+    if tags.is_empty() {
+        return "No tags found.".to_string();
+    }
+    let width = tags.iter().map(|x| x.name.len()).max().unwrap_or_default();
+    let lines: Vec<_> = tags
+        .iter()
+        .map(|tag| {
+            let mut line = format!(
+                "{} {}",
+                format!("{:<width$}", tag.name).paint(Highlight::Special),
+                short_sha(&tag.sha).paint(Highlight::Commit)
+            );
+            if let Some(message) = tag.message.as_ref().and_then(|x| x.lines().next()) {
+                line.push_str(&format!(" - {}", message.paint(Highlight::CommitMsg)));
+            }
+            line
+        })
+        .collect();
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::commands::mock_remote;
+    use crate::remote::{RepoCreateInfo, TagCreateInfo};
+    use clap::Parser;
+
+    #[tokio::test]
+    async fn test_list_tags() {
+        let remote = mock_remote();
+        let create_info = RepoCreateInfo {
+            name: "tool".to_string(),
+            init: true,
+            ..Default::default()
+        };
+        remote.create_repo(create_info).await.unwrap();
+
+        let args = List::parse_from(["list", "tool", "mock"]);
+        assert_eq!(list(args, &remote).await.unwrap(), "No tags found.");
+
+        for (name, message) in [
+            ("v1.0.0", Some("First release\n\nDetails")),
+            ("v1.0.0-rc.1", None),
+        ] {
+            let tag = TagCreateInfo {
+                name: name.to_string(),
+                message: message.map(str::to_string),
+                ..Default::default()
+            };
+            remote.create_tag("mock", "tool", tag).await.unwrap();
+        }
+
+        let args = List::parse_from(["list", "tool", "mock"]);
+        assert_eq!(
+            list(args, &remote).await.unwrap(),
+            "v1.0.0      00000000 - First release\nv1.0.0-rc.1 00000000"
+        );
+
+        let args = List::parse_from(["list", "tool", "mock", "--format", "json"]);
+        let json: serde_json::Value =
+            serde_json::from_str(&list(args, &remote).await.unwrap()).unwrap();
+        assert_eq!(json[0]["name"], "v1.0.0");
+        assert_eq!(json[0]["sha"], format!("{:040x}", 1));
+        assert!(json[1].get("message").is_none());
+    }
+}
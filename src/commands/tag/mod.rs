@@ -0,0 +1,15 @@
+use crate::args::tag::{Tag, TagCommands};
+use crate::config::Config;
+use crate::error::Result;
+
+mod create;
+mod delete;
+mod list;
+
+pub async fn tag(tag: Tag, config: &Config) -> Result<()> {
+    match tag.subcommand {
+        TagCommands::List(args) => list::list_tags(args, config).await,
+        TagCommands::Create(args) => create::create_tag(args, config).await,
+        TagCommands::Delete(args) => delete::delete_tag(args, config).await,
+    }
+}
@@ -12,7 +12,7 @@ use std::{
 use keyring::Entry;
 use serde::{Deserialize, Serialize};
 
-use crate::remote::{Auth, CloneProtocol, Provider, RemoteConfig};
+use crate::remote::{Auth, CloneProtocol, Provider, RemoteConfig, WebhookCreateInfo};
 
 use self::colors::ConfigColorMap;
 
@@ -44,6 +44,10 @@ pub struct Config {
     pub remotes: BTreeMap<String, GitRemoteConfig>,
     pub secrets: Secrets,
     pub colors: Option<ConfigColorMap>,
+    /// Named sets of webhooks that can be attached to new repositories.
+    /// Maps the set name to its hooks, written as `[[hooks.<name>]]` tables.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub hooks: BTreeMap<String, Vec<WebhookCreateInfo>>,
     #[serde(skip)]
     pub path: PathBuf,
 }
@@ -54,6 +58,12 @@ pub struct GitRemoteConfig {
     pub clone_protocol: CloneProtocol,
     pub url: String,
     pub username: String,
+    /// The maximum number of requests to make at the same time, e.g. when listing repositories.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub concurrency: Option<usize>,
+    /// The number of commits to fetch when showing a single repository.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub commit_count: Option<u8>,
 }
 
 pub type InlineSecrets = BTreeMap<String, AuthConfig>;
@@ -125,15 +135,32 @@ impl Config {
     }
     pub fn get_remote_config(&self, name: &str) -> Result<RemoteConfig> {
         if let Some(remote) = self.remotes.get(name) {
+            // Local remotes are plain directories, there's nothing to authenticate with.
+            let auth = match remote.provider {
+                Provider::Local => Auth::None,
+                // Plugins decide for themselves whether they need credentials.
+                Provider::External { .. } => {
+                    self.get_auth(&self.secrets, name).unwrap_or(Auth::None)
+                }
+                _ => self.get_auth(&self.secrets, name)?,
+            };
             return Ok(RemoteConfig {
                 clone_protocol: remote.clone_protocol.clone(),
                 username: remote.username.clone(),
                 url: remote.url.clone(),
-                auth: self.get_auth(&self.secrets, name)?,
+                auth,
+                concurrency: remote.concurrency,
+                commit_count: remote.commit_count,
             });
         }
         Err(Error::not_found(format!("Could not find remote {name}")))
     }
+    pub fn get_hooks(&self, name: &str) -> Result<&[WebhookCreateInfo]> {
+        if let Some(hooks) = self.hooks.get(name) {
+            return Ok(hooks);
+        }
+        Err(Error::not_found(format!("Could not find hooks {name}")))
+    }
     pub fn store_token(&mut self, name: &str, token: &str) -> Result<()> {
         if !self.remotes.contains_key(name) {
             return Err(Error::not_found(format!("Could not find remote {name}")));
@@ -247,6 +274,7 @@ impl Default for Config {
             path: format!("{config_dir}/gritty/config.toml").into(),
             remotes: BTreeMap::new(),
             colors: None,
+            hooks: BTreeMap::new(),
             #[cfg(feature = "keyring")]
             secrets: Secrets::Keyring,
             #[cfg(not(feature = "keyring"))]
@@ -260,6 +288,7 @@ impl Default for Config {
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::remote::{WebhookContentType, WebhookEvent};
 
     #[test]
     fn test_deserialize_secrets_file() {
@@ -341,4 +370,67 @@ token = \"super-secret-token\"
 "
         );
     }
+
+    #[test]
+    fn test_remote_concurrency() {
+        let config: Config = toml::from_str(
+            "\
+[remotes.github]
+provider = \"GitHub\"
+clone_protocol = \"ssh\"
+url = \"https://github.com\"
+username = \"octocat\"
+concurrency = 2
+
+[remotes.gitea]
+provider = \"Gitea\"
+clone_protocol = \"https\"
+url = \"https://gitea.example.com\"
+username = \"octocat\"
+
+[secrets]
+type = \"Plaintext\"
+",
+        )
+        .unwrap();
+        assert_eq!(config.remotes["github"].concurrency, Some(2));
+        assert_eq!(config.remotes["gitea"].concurrency, None);
+        // Unset settings are left out of the config file.
+        let toml = toml::to_string(&config).unwrap();
+        assert_eq!(toml.matches("concurrency").count(), 1);
+    }
+
+    #[test]
+    fn test_hooks() {
+        let config: Config = toml::from_str(
+            "\
+[remotes]
+
+[secrets]
+type = \"Plaintext\"
+
+[[hooks.ci]]
+url = \"https://ci.example.com/hook\"
+secret = \"hunter2\"
+events = [\"push\", \"pull_request\"]
+
+[[hooks.ci]]
+url = \"https://chat.example.com/hook\"
+content_type = \"form\"
+",
+        )
+        .unwrap();
+        let hooks = config.get_hooks("ci").unwrap();
+        assert_eq!(hooks.len(), 2);
+        assert_eq!(hooks[0].secret.as_deref(), Some("hunter2"));
+        assert_eq!(
+            hooks[0].events,
+            vec![WebhookEvent::Push, WebhookEvent::PullRequest]
+        );
+        assert!(hooks[0].active);
+        // Unset settings fall back to the defaults.
+        assert_eq!(hooks[1].content_type, WebhookContentType::Form);
+        assert_eq!(hooks[1].events, vec![WebhookEvent::Push]);
+        assert!(config.get_hooks("chat").is_err());
+    }
 }
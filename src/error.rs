@@ -17,6 +17,8 @@ pub enum ErrorKind {
     Serialization,
     Deserialization,
     Authentication,
+    /// The provider doesn't support the operation.
+    Unsupported,
     Other,
 }
 
@@ -49,6 +51,13 @@ impl Error {
             status: None,
         }
     }
+    pub fn unsupported(message: impl Display) -> Self {
+        Self {
+            message: message.to_string(),
+            kind: ErrorKind::Unsupported,
+            status: None,
+        }
+    }
     pub fn other(message: impl Display) -> Self {
         Self {
             message: message.to_string(),
@@ -6,6 +6,8 @@
 //! - [remote::github::GitHubRemote]
 //! - [remote::gitlab::GitlabRemote]
 //! - [remote::gitea::GiteaRemote]
+//! - [remote::bitbucket::BitbucketRemote]
+//! - [remote::local::LocalRemote]
 //!
 //! The [remote::Remote] trait provides a main method `create_remote` which returns a remote for
 //! the given [remote::Provider] and configuration:
@@ -14,12 +16,12 @@
 //! use gritty::remote::{self, Remote, RemoteConfig, Provider, Auth, CloneProtocol};
 //! use gritty::remote::github::GitHubRemote;
 //!
-//! let config = RemoteConfig {
-//!     username: "octocat".to_string(),
-//!     clone_protocol: CloneProtocol::HTTPS,
-//!     url: "https://github.com".to_string(),
-//!     auth: Auth::Token { token: "your-gh-token".to_string() },
-//! };
+//! let config = RemoteConfig::new(
+//!     "octocat",
+//!     CloneProtocol::HTTPS,
+//!     "https://github.com",
+//!     Auth::Token { token: "your-gh-token".to_string() },
+//! );
 //!
 //! let remote = remote::create_remote(&config, Provider::GitHub).await.unwrap();
 //! # }
@@ -30,15 +32,15 @@
 //! # async fn run() {
 //! # use gritty::remote::{
 //!       self, Remote, RemoteConfig, Provider, Auth, CloneProtocol,
-//!       RepoCreateInfo, ListReposInfo, RepoForkOption,
+//!       RepoCreateInfo, ListReposInfo, RepoForkOption, CommitHistory,
 //!   };
 //! # use gritty::remote::github::GitHubRemote;
-//! # let config = RemoteConfig {
-//! #     username: "octocat".to_string(),
-//! #     clone_protocol: CloneProtocol::HTTPS,
-//! #     url: "https://github.com".to_string(),
-//! #     auth: Auth::Token { token: "your-gh-token".to_string() },
-//! # };
+//! # let config = RemoteConfig::new(
+//! #     "octocat",
+//! #     CloneProtocol::HTTPS,
+//! #     "https://github.com",
+//! #     Auth::Token { token: "your-gh-token".to_string() },
+//! # );
 //! # let remote = remote::create_remote(&config, Provider::GitHub).await.unwrap();
 //!
 //! // Check if we're authenticated
@@ -49,11 +51,12 @@
 //! }
 //!
 //! // Get information about octocat/hello-world
-//! let repo = remote.get_repo_info("hello-world").await.unwrap();
+//! let repo = remote.get_repo_info("octocat", "hello-world").await.unwrap();
 //!
 //! // Create a new repository
 //! let repo_create_info = RepoCreateInfo {
 //!     name: "a-new-repo".to_string(),
+//!     owner: None, // Create the repository in the user's account
 //!     description: Some("A new repository for testing".to_string()),
 //!     license: Some("MIT".to_string()),
 //!     private: false,
@@ -63,8 +66,14 @@
 //!
 //! // List all repositories, including private ones
 //! let list_repos_info = ListReposInfo {
+//!     owner: None, // List the authenticated user's repositories
 //!     private: true, // Include private repositories
 //!     forks: false, // Exclude forked repositories
+//!     limit: None, // List all repositories instead of a single page
+//!     page: None,
+//!     commits: CommitHistory::Latest, // Fetch the latest commit of every repository
+//!     archived: Some(false), // Exclude archived repositories
+//!     affiliated: false, // Only list repositories the user owns
 //! };
 //! let private_repos = remote.list_repos(list_repos_info).await.unwrap();
 //!
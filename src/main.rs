@@ -57,7 +57,18 @@ async fn execute_command(args: Args) -> Result<()> {
     match args.subcommand {
         Commands::Remote(remote) => commands::remote(remote, &mut config).await,
         Commands::Repo(repo) => commands::repo(repo, &config).await,
+        Commands::Issue(issue) => commands::issue(issue, &config).await,
+        Commands::Pr(pr) => commands::pr(pr, &config).await,
+        Commands::Release(release) => commands::release(release, &config).await,
+        Commands::Branch(branch) => commands::branch(branch, &config).await,
+        Commands::Tag(tag) => commands::tag(tag, &config).await,
+        Commands::Hook(hook) => commands::hook(hook, &config).await,
+        Commands::Key(key) => commands::key(key, &config).await,
+        Commands::DeployKey(deploy_key) => commands::deploy_key(deploy_key, &config).await,
         Commands::Auth(args) => commands::auth(args, &mut config).await,
+        Commands::Backup(args) => commands::backup(args, &config).await,
+        Commands::Export(args) => commands::export(args, &config).await,
+        Commands::Import(args) => commands::import(args, &config).await,
         Commands::CreateConfig => unreachable!(),
         Commands::Completions(completions) => commands::completions(completions).await,
     }
@@ -0,0 +1,727 @@
+//! Bitbucket support.
+//!
+//! Two flavours of the Bitbucket REST API are supported:
+//! - Bitbucket Cloud (`bitbucket.org`), using the 2.0 API. Repositories are owned by workspaces.
+//! - Bitbucket Data Center (or Server), using the 1.0 REST API of a self-hosted instance.
+//!   Repositories are owned by projects, the personal project of a user is `~username`.
+//!
+//! The flavour is selected by the remote URL: `https://bitbucket.org` (or a URL pointing directly
+//! at a 2.0 API root, ending in `/2.0`) selects Bitbucket Cloud, anything else is treated as a
+//! Data Center instance.
+
+use crate::error::{Error, ErrorKind, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use reqwest::{RequestBuilder, StatusCode};
+use serde::{de::DeserializeOwned, Deserialize};
+use serde_json::{json, Value};
+
+use super::{
+    Auth, CloneProtocol, Commit, ListReposInfo, Remote, RemoteConfig, RepoCreateInfo,
+    RepoForkOption, RepoRef, Repository, Visibility, PAGE_SIZE,
+};
+
+pub struct BitbucketRemote {
+    config: RemoteConfig,
+    client: reqwest::Client,
+    api: Api,
+}
+
+/// The flavour of the Bitbucket API a remote talks to, along with the API root.
+#[derive(Debug, PartialEq)]
+enum Api {
+    Cloud(String),
+    DataCenter(String),
+}
+
+impl Api {
+    fn from_url(url: &str) -> Self {
+        let url = url.trim_end_matches('/');
+        let host = url
+            .trim_start_matches("https://")
+            .trim_start_matches("http://")
+            .split('/')
+            .next()
+            .unwrap_or_default();
+        match host {
+            "bitbucket.org" | "www.bitbucket.org" | "api.bitbucket.org" => {
+                Api::Cloud("https://api.bitbucket.org/2.0".to_string())
+            }
+            _ if url.ends_with("/2.0") => Api::Cloud(url.to_string()),
+            _ => Api::DataCenter(format!("{url}/rest/api/1.0")),
+        }
+    }
+}
+
+impl From<reqwest::Error> for Error {
+    fn from(value: reqwest::Error) -> Self {
+        let status = value.status().map(|x| x.as_u16());
+        let kind = if value.is_decode() {
+            ErrorKind::Deserialization
+        } else {
+            ErrorKind::Other
+        };
+        Error {
+            message: value.to_string(),
+            kind,
+            status,
+        }
+    }
+}
+
+/// A repository as returned by the Bitbucket Cloud API.
+#[derive(Debug, Deserialize)]
+struct CloudRepository {
+    name: String,
+    full_name: String,
+    description: Option<String>,
+    is_private: bool,
+    parent: Option<CloudParent>,
+    mainbranch: Option<Branch>,
+    links: Links,
+    created_on: Option<DateTime<Utc>>,
+    updated_on: Option<DateTime<Utc>>,
+    /// The size in bytes.
+    size: Option<u64>,
+    language: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CloudParent {
+    full_name: String,
+}
+
+/// A repository as returned by the Bitbucket Data Center API.
+#[derive(Debug, Deserialize)]
+struct DataCenterRepository {
+    slug: String,
+    description: Option<String>,
+    public: bool,
+    origin: Option<DataCenterOrigin>,
+    project: Project,
+    links: Links,
+    #[serde(default)]
+    archived: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct DataCenterOrigin {
+    slug: String,
+    project: Project,
+}
+
+#[derive(Debug, Deserialize)]
+struct Project {
+    key: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct Branch {
+    name: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct Links {
+    #[serde(default)]
+    clone: Vec<Link>,
+    /// The web page of a Cloud repository.
+    html: Option<Href>,
+    /// The web page of a Data Center repository.
+    #[serde(default, rename = "self")]
+    self_links: Vec<Href>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Href {
+    href: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct Link {
+    href: String,
+    name: String,
+}
+
+impl Links {
+    fn clone_urls(&self) -> (Option<String>, Option<String>) {
+        let find = |names: &[&str]| {
+            self.clone
+                .iter()
+                .find(|x| names.contains(&x.name.as_str()))
+                .map(|x| x.href.clone())
+        };
+        (find(&["ssh"]), find(&["https", "http"]))
+    }
+
+    fn html_url(&self) -> Option<String> {
+        let link = self.html.as_ref().or(self.self_links.first());
+        link.map(|x| x.href.clone())
+    }
+}
+
+/// A page of results from the Bitbucket Cloud API.
+#[derive(Debug, Deserialize)]
+struct CloudPage<T> {
+    values: Vec<T>,
+    next: Option<String>,
+}
+
+/// A page of results from the Bitbucket Data Center API.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DataCenterPage<T> {
+    values: Vec<T>,
+    is_last_page: bool,
+    next_page_start: Option<u64>,
+}
+
+/// Bitbucket identifies repositories by their slug, which is derived from the name.
+fn slug(name: &str) -> String {
+    name.replace(' ', "-").to_lowercase()
+}
+
+#[async_trait]
+impl Remote for BitbucketRemote {
+    async fn new(config: &RemoteConfig) -> Result<Self> {
+        let client = reqwest::Client::builder()
+            .user_agent(concat!(
+                env!("CARGO_PKG_NAME"),
+                "/",
+                env!("CARGO_PKG_VERSION")
+            ))
+            .build()?;
+        Ok(Self {
+            api: Api::from_url(&config.url),
+            config: config.clone(),
+            client,
+        })
+    }
+
+    fn get_config(&self) -> &RemoteConfig {
+        &self.config
+    }
+
+    async fn check_auth(&self) -> Result<bool> {
+        let req = match &self.api {
+            Api::Cloud(base) => self.request(reqwest::Method::GET, format!("{base}/user")),
+            Api::DataCenter(base) => self.request(
+                reqwest::Method::GET,
+                format!("{base}/users/{}", self.config.username),
+            ),
+        };
+        match self.send::<Value>(req).await {
+            Ok(_) => Ok(true),
+            Err(err) if err.kind == ErrorKind::Authentication => Ok(false),
+            Err(err) => Err(err),
+        }
+    }
+
+    async fn create_repo(&self, create_info: RepoCreateInfo) -> Result<Repository> {
+        if create_info.license.is_some() || create_info.init {
+            eprintln!(
+                "Warning: Initializing repositories is not supported by Bitbucket. Ignoring."
+            );
+        }
+        let owner = create_info
+            .owner
+            .clone()
+            .unwrap_or(self.config.username.clone());
+        match &self.api {
+            Api::Cloud(base) => {
+                let url = format!("{base}/repositories/{owner}/{}", slug(&create_info.name));
+                let body = json!({
+                    "scm": "git",
+                    "name": create_info.name,
+                    "description": create_info.description.unwrap_or_default(),
+                    "is_private": create_info.private,
+                });
+                let req = self.request(reqwest::Method::POST, url).json(&body);
+                let repo: CloudRepository = self.send(req).await?;
+                self.cloud_repo_info(base, repo, self.config.commit_limit())
+                    .await
+            }
+            Api::DataCenter(base) => {
+                let url = format!("{base}/projects/{}/repos", self.project_key(&owner));
+                let mut body = json!({
+                    "name": create_info.name,
+                    "public": !create_info.private,
+                });
+                if let Some(description) = create_info.description {
+                    body["description"] = json!(description);
+                }
+                let req = self.request(reqwest::Method::POST, url).json(&body);
+                let repo: DataCenterRepository = self.send(req).await?;
+                self.data_center_repo_info(base, repo, self.config.commit_limit())
+                    .await
+            }
+        }
+    }
+
+    async fn create_fork(&self, options: RepoForkOption) -> Result<Repository> {
+        let source = slug(&options.repo);
+        match &self.api {
+            Api::Cloud(base) => {
+                let url = format!("{base}/repositories/{}/{source}/forks", options.owner);
+                let mut body = json!({});
+                if let Some(name) = options.name {
+                    body["name"] = json!(name);
+                }
+                if let Some(workspace) = options.organization {
+                    body["workspace"] = json!({ "slug": workspace });
+                }
+                let req = self.request(reqwest::Method::POST, url).json(&body);
+                let repo: CloudRepository = self.send(req).await?;
+                self.cloud_repo_info(base, repo, self.config.commit_limit())
+                    .await
+            }
+            Api::DataCenter(base) => {
+                let key = self.project_key(&options.owner);
+                let url = format!("{base}/projects/{key}/repos/{source}");
+                let mut body = json!({});
+                if let Some(name) = options.name {
+                    body["name"] = json!(name);
+                }
+                if let Some(project) = options.organization {
+                    body["project"] = json!({ "key": self.project_key(&project) });
+                }
+                let req = self.request(reqwest::Method::POST, url).json(&body);
+                let repo: DataCenterRepository = self.send(req).await?;
+                self.data_center_repo_info(base, repo, self.config.commit_limit())
+                    .await
+            }
+        }
+    }
+
+    async fn list_repos(&self, list_info: ListReposInfo) -> Result<Vec<Repository>> {
+        let owner = list_info
+            .owner
+            .clone()
+            .unwrap_or(self.config.username.clone());
+        // Bitbucket doesn't support filtering by privacy or forks when listing repositories,
+        // so we need to do it here.
+        let commits = list_info.commits.count();
+        let affiliated = list_info.affiliated && list_info.owner.is_none();
+        let mut result = Vec::new();
+        match &self.api {
+            Api::Cloud(base) => {
+                let mut repos = Vec::new();
+                let first = match affiliated {
+                    true => format!("{base}/repositories?role=member&pagelen={PAGE_SIZE}"),
+                    false => format!("{base}/repositories/{owner}?pagelen={PAGE_SIZE}"),
+                };
+                let mut next = Some(first);
+                while let Some(url) = next {
+                    let req = self.request(reqwest::Method::GET, url);
+                    let page: CloudPage<CloudRepository> = self.send(req).await?;
+                    repos.extend(
+                        page.values.into_iter().filter(|x| {
+                            list_info.includes(x.is_private, x.parent.is_some(), false)
+                        }),
+                    );
+                    next = match list_info.is_satisfied(repos.len()) {
+                        true => None,
+                        false => page.next,
+                    };
+                }
+                for repo in list_info.paginate(repos) {
+                    result.push(self.cloud_repo_info(base, repo, commits).await?);
+                }
+            }
+            Api::DataCenter(base) => {
+                let key = self.project_key(&owner);
+                let mut repos = Vec::new();
+                let mut start = Some(0);
+                let route = match affiliated {
+                    true => format!("{base}/repos"),
+                    false => format!("{base}/projects/{key}/repos"),
+                };
+                while let Some(offset) = start {
+                    let url = format!("{route}?limit={PAGE_SIZE}&start={offset}");
+                    let req = self.request(reqwest::Method::GET, url);
+                    let page: DataCenterPage<DataCenterRepository> = self.send(req).await?;
+                    repos.extend(
+                        page.values.into_iter().filter(|x| {
+                            list_info.includes(!x.public, x.origin.is_some(), x.archived)
+                        }),
+                    );
+                    start = match page.is_last_page || list_info.is_satisfied(repos.len()) {
+                        true => None,
+                        false => page.next_page_start,
+                    };
+                }
+                for repo in list_info.paginate(repos) {
+                    result.push(self.data_center_repo_info(base, repo, commits).await?);
+                }
+            }
+        }
+        Ok(result)
+    }
+
+    async fn get_repo_info(&self, owner: &str, name: &str) -> Result<Repository> {
+        let url = self.repo_url(owner, name);
+        let req = self.request(reqwest::Method::GET, url);
+        match &self.api {
+            Api::Cloud(base) => {
+                self.cloud_repo_info(base, self.send(req).await?, self.config.commit_limit())
+                    .await
+            }
+            Api::DataCenter(base) => {
+                self.data_center_repo_info(base, self.send(req).await?, self.config.commit_limit())
+                    .await
+            }
+        }
+    }
+
+    async fn archive_repo(&self, owner: &str, name: &str) -> Result<Repository> {
+        self.set_archived(owner, name, true).await
+    }
+
+    async fn unarchive_repo(&self, owner: &str, name: &str) -> Result<Repository> {
+        self.set_archived(owner, name, false).await
+    }
+
+    async fn transfer_repo(&self, owner: &str, name: &str, new_owner: &str) -> Result<Repository> {
+        // Data Center moves repositories between projects, Bitbucket Cloud can only transfer them
+        // through the web interface.
+        if let Api::Cloud(_) = self.api {
+            return Err(Error::unsupported(
+                "Bitbucket Cloud does not support transferring repositories through the API",
+            ));
+        }
+        let body = json!({ "project": { "key": self.project_key(new_owner) } });
+        self.put_repo(owner, name, body).await
+    }
+
+    async fn rename_repo(&self, owner: &str, name: &str, new_name: &str) -> Result<Repository> {
+        // Both APIs derive the new slug from the name.
+        self.put_repo(owner, name, json!({ "name": new_name }))
+            .await
+    }
+
+    async fn delete_repo(&self, owner: &str, name: &str) -> Result<()> {
+        let url = self.repo_url(owner, name);
+        let req = self.request(reqwest::Method::DELETE, url);
+        self.send_empty(req).await
+    }
+
+    fn clone_url(&self, owner: &str, repo_name: &str) -> String {
+        self.clone_url_for(owner, repo_name, &self.config.clone_protocol)
+    }
+}
+
+impl BitbucketRemote {
+    fn request(&self, method: reqwest::Method, url: String) -> RequestBuilder {
+        let req = self.client.request(method, url);
+        match &self.config.auth {
+            Auth::Token { token } => req.bearer_auth(token),
+            Auth::Basic { username, password } => req.basic_auth(username, Some(password)),
+            Auth::None => req,
+        }
+    }
+
+    async fn send<T: DeserializeOwned>(&self, req: RequestBuilder) -> Result<T> {
+        let res = Self::check_status(req.send().await?).await?;
+        Ok(res.json().await?)
+    }
+
+    async fn send_empty(&self, req: RequestBuilder) -> Result<()> {
+        Self::check_status(req.send().await?).await?;
+        Ok(())
+    }
+
+    async fn check_status(res: reqwest::Response) -> Result<reqwest::Response> {
+        let status = res.status();
+        if status.is_success() {
+            return Ok(res);
+        }
+        let body: Value = res.json().await.unwrap_or_default();
+        // Cloud returns `{"error": {"message": ...}}`, Data Center returns
+        // `{"errors": [{"message": ...}]}`.
+        let message = body["error"]["message"]
+            .as_str()
+            .or(body["errors"][0]["message"].as_str())
+            .unwrap_or(status.canonical_reason().unwrap_or("Unknown error"))
+            .to_string();
+        let kind = match status {
+            StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => ErrorKind::Authentication,
+            StatusCode::NOT_FOUND => ErrorKind::NotFound,
+            _ => ErrorKind::Other,
+        };
+        Err(Error {
+            message,
+            kind,
+            status: Some(status.as_u16()),
+        })
+    }
+
+    /// Change settings of a repository, returning the updated repository.
+    async fn put_repo(&self, owner: &str, name: &str, body: Value) -> Result<Repository> {
+        let url = self.repo_url(owner, name);
+        let req = self.request(reqwest::Method::PUT, url).json(&body);
+        match &self.api {
+            Api::Cloud(base) => {
+                self.cloud_repo_info(base, self.send(req).await?, self.config.commit_limit())
+                    .await
+            }
+            Api::DataCenter(base) => {
+                self.data_center_repo_info(base, self.send(req).await?, self.config.commit_limit())
+                    .await
+            }
+        }
+    }
+
+    /// Archiving is only available on Data Center (since 8.0), Bitbucket Cloud has no such thing.
+    async fn set_archived(&self, owner: &str, name: &str, archived: bool) -> Result<Repository> {
+        if let Api::Cloud(_) = self.api {
+            return Err(Error::unsupported(
+                "Bitbucket Cloud does not support archiving repositories",
+            ));
+        }
+        self.put_repo(owner, name, json!({ "archived": archived }))
+            .await
+    }
+
+    /// Get the Data Center project key for the given owner.
+    /// Repositories of the authenticated user live in their personal project, `~username`.
+    fn project_key(&self, owner: &str) -> String {
+        if owner.eq_ignore_ascii_case(&self.config.username) {
+            format!("~{owner}")
+        } else {
+            owner.to_string()
+        }
+    }
+
+    fn repo_url(&self, owner: &str, name: &str) -> String {
+        match &self.api {
+            Api::Cloud(base) => format!("{base}/repositories/{owner}/{}", slug(name)),
+            Api::DataCenter(base) => format!(
+                "{base}/projects/{}/repos/{}",
+                self.project_key(owner),
+                slug(name)
+            ),
+        }
+    }
+
+    async fn cloud_repo_info(
+        &self,
+        base: &str,
+        repo: CloudRepository,
+        commits: u8,
+    ) -> Result<Repository> {
+        #[derive(Deserialize)]
+        struct CloudCommit {
+            hash: String,
+            message: String,
+            date: DateTime<Utc>,
+            author: CloudAuthor,
+        }
+        #[derive(Deserialize)]
+        struct CloudAuthor {
+            raw: String,
+            user: Option<CloudUser>,
+        }
+        #[derive(Deserialize)]
+        struct CloudUser {
+            display_name: String,
+        }
+
+        let mut last_commits = Vec::new();
+        if commits > 0 {
+            let url = format!(
+                "{base}/repositories/{}/commits?pagelen={commits}",
+                repo.full_name
+            );
+            let req = self.request(reqwest::Method::GET, url);
+            last_commits = match self.send::<CloudPage<CloudCommit>>(req).await {
+                Ok(page) => page.values,
+                // Empty repositories don't have any commits to list.
+                Err(err) if err.kind == ErrorKind::NotFound => Vec::new(),
+                Err(err) => return Err(err),
+            };
+        }
+        let last_commits = last_commits
+            .into_iter()
+            .take(commits as usize)
+            .map(|c| Commit {
+                sha: c.hash,
+                message: c.message,
+                author: match c.author.user {
+                    Some(user) => user.display_name,
+                    None => c.author.raw,
+                },
+                date: c.date,
+            })
+            .collect();
+
+        let (ssh_url, clone_url) = repo.links.clone_urls();
+        let owner = repo
+            .full_name
+            .split_once('/')
+            .map(|x| x.0.to_string())
+            .unwrap_or_default();
+        Ok(Repository {
+            ssh_url: ssh_url.unwrap_or_else(|| format!("git@bitbucket.org:{}.git", repo.full_name)),
+            clone_url: clone_url
+                .unwrap_or_else(|| format!("https://bitbucket.org/{}.git", repo.full_name)),
+            html_url: repo.links.html_url(),
+            name: repo.name,
+            owner,
+            full_name: repo.full_name,
+            description: repo.description.filter(|x| !x.is_empty()),
+            private: repo.is_private,
+            visibility: match repo.is_private {
+                true => Visibility::Private,
+                false => Visibility::Public,
+            },
+            fork: repo.parent.is_some(),
+            parent: repo.parent.and_then(|x| RepoRef::parse(&x.full_name)),
+            default_branch: repo.mainbranch.map(|x| x.name),
+            created_at: repo.created_on,
+            updated_at: repo.updated_on,
+            size: repo.size.map(|x| x / 1024),
+            language: repo.language.filter(|x| !x.is_empty()),
+            last_commits,
+            ..Default::default()
+        })
+    }
+
+    async fn data_center_repo_info(
+        &self,
+        base: &str,
+        repo: DataCenterRepository,
+        commits: u8,
+    ) -> Result<Repository> {
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct DataCenterCommit {
+            id: String,
+            message: String,
+            author: DataCenterAuthor,
+            author_timestamp: i64,
+        }
+        #[derive(Deserialize)]
+        struct DataCenterAuthor {
+            name: String,
+        }
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct DataCenterBranch {
+            display_id: String,
+        }
+
+        let key = &repo.project.key;
+        let mut last_commits = Vec::new();
+        // Data Center only reports the default branch on its own endpoint. It's fetched along with
+        // the commits, so listings without commits don't make a request for every repository.
+        let mut default_branch = None;
+        if commits > 0 {
+            let url = format!("{base}/projects/{key}/repos/{}/default-branch", repo.slug);
+            let req = self.request(reqwest::Method::GET, url);
+            default_branch = match self.send::<DataCenterBranch>(req).await {
+                Ok(branch) => Some(branch.display_id),
+                // Empty repositories don't have one yet.
+                Err(err) if err.kind == ErrorKind::NotFound => None,
+                Err(err) => return Err(err),
+            };
+            let url = format!(
+                "{base}/projects/{key}/repos/{}/commits?limit={commits}",
+                repo.slug
+            );
+            let req = self.request(reqwest::Method::GET, url);
+            last_commits = match self.send::<DataCenterPage<DataCenterCommit>>(req).await {
+                Ok(page) => page.values,
+                // Empty repositories don't have any commits to list.
+                Err(err) if err.kind == ErrorKind::NotFound => Vec::new(),
+                Err(err) => return Err(err),
+            };
+        }
+        let last_commits = last_commits
+            .into_iter()
+            .map(|c| Commit {
+                sha: c.id,
+                message: c.message,
+                author: c.author.name,
+                date: DateTime::from_timestamp_millis(c.author_timestamp).unwrap_or_default(),
+            })
+            .collect();
+
+        let (ssh_url, clone_url) = repo.links.clone_urls();
+        // Personal projects are prefixed with a tilde, but we refer to them by the username.
+        let owner = key.strip_prefix('~').unwrap_or(key).to_string();
+        Ok(Repository {
+            ssh_url: ssh_url
+                .unwrap_or_else(|| self.clone_url_for(&owner, &repo.slug, &CloneProtocol::SSH)),
+            clone_url: clone_url
+                .unwrap_or_else(|| self.clone_url_for(&owner, &repo.slug, &CloneProtocol::HTTPS)),
+            html_url: repo.links.html_url(),
+            full_name: format!("{owner}/{}", repo.slug),
+            name: repo.slug,
+            owner,
+            description: repo.description,
+            private: !repo.public,
+            visibility: match repo.public {
+                true => Visibility::Public,
+                false => Visibility::Private,
+            },
+            fork: repo.origin.is_some(),
+            parent: repo.origin.map(|x| RepoRef {
+                owner: x.project.key.trim_start_matches('~').to_string(),
+                name: x.slug,
+            }),
+            archived: repo.archived,
+            default_branch,
+            last_commits,
+            // Data Center doesn't report timestamps, stars or languages.
+            ..Default::default()
+        })
+    }
+
+    fn clone_url_for(&self, owner: &str, repo_name: &str, protocol: &CloneProtocol) -> String {
+        let url = self.config.url.trim_end_matches('/');
+        let host = url
+            .trim_start_matches("https://")
+            .trim_start_matches("http://")
+            .split('/')
+            .next()
+            .unwrap_or_default();
+        let repo_name = slug(repo_name);
+        match (&self.api, protocol) {
+            (Api::Cloud(_), CloneProtocol::SSH) => {
+                format!("git@bitbucket.org:{owner}/{repo_name}.git")
+            }
+            (Api::Cloud(_), CloneProtocol::HTTPS) => {
+                format!("https://bitbucket.org/{owner}/{repo_name}.git")
+            }
+            // Data Center serves git over SSH on port 7999 by default.
+            (Api::DataCenter(_), CloneProtocol::SSH) => {
+                let key = self.project_key(owner).to_lowercase();
+                format!("ssh://git@{host}:7999/{key}/{repo_name}.git")
+            }
+            (Api::DataCenter(_), CloneProtocol::HTTPS) => {
+                let key = self.project_key(owner).to_lowercase();
+                format!("{url}/scm/{key}/{repo_name}.git")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_api_from_url() {
+        assert_eq!(
+            Api::from_url("https://bitbucket.org"),
+            Api::Cloud("https://api.bitbucket.org/2.0".to_string())
+        );
+        assert_eq!(
+            Api::from_url("http://localhost:8080/2.0/"),
+            Api::Cloud("http://localhost:8080/2.0".to_string())
+        );
+        assert_eq!(
+            Api::from_url("https://bitbucket.example.com"),
+            Api::DataCenter("https://bitbucket.example.com/rest/api/1.0".to_string())
+        );
+    }
+}
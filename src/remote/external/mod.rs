@@ -0,0 +1,1105 @@
+//! Support for provider plugins.
+//!
+//! A provider plugin is an executable called `gritty-provider-<name>` that implements a remote
+//! in-process, so gritty can talk to forges it doesn't support out of the box. It is configured
+//! with the [Provider::External](super::Provider::External) provider:
+//! ```toml
+//! [remotes.work]
+//! provider = { External = { command = "acme" } }
+//! url = "https://forge.acme.example"
+//! username = "alice"
+//! clone_protocol = "ssh"
+//! ```
+//! The plugin is looked up in the `PATH` (`gritty-provider-acme` in this example). If the command
+//! contains a path separator, it is used as the path to the executable instead.
+//!
+//! # Protocol
+//!
+//! Gritty starts the plugin once per remote and speaks [JSON-RPC 2.0](https://www.jsonrpc.org/specification)
+//! with it: every request is a single line of JSON written to the plugin's stdin, and the plugin
+//! answers every request with a single line of JSON on its stdout, in order. Anything the plugin
+//! writes to stderr is shown to the user. The plugin should exit once its stdin is closed, but may
+//! also be killed when gritty no longer needs it.
+//!
+//! The first request is always `initialize`. Its params contain the protocol version gritty speaks
+//! ([PROTOCOL_VERSION]) and the [RemoteConfig] of the remote, including the credentials:
+//! ```json
+//! {"jsonrpc":"2.0","id":1,"method":"initialize","params":{"protocol_version":1,"config":{...}}}
+//! ```
+//! The plugin answers with the protocol version it speaks. Gritty refuses to use plugins speaking
+//! a different version. The version only changes when the meaning of existing methods changes,
+//! not when methods are added:
+//! ```json
+//! {"jsonrpc":"2.0","id":1,"result":{"protocol_version":1}}
+//! ```
+//!
+//! The remaining methods mirror the [Remote] trait, using the serde representation of gritty's
+//! types for params and results:
+//!
+//! | Method          | Params                       | Result              |
+//! |-----------------|------------------------------|---------------------|
+//! | `check_auth`    | `{}`                         | `bool`              |
+//! | `create_repo`   | [RepoCreateInfo]             | [Repository]        |
+//! | `create_fork`   | [RepoForkOption]             | [Repository]        |
+//! | `list_repos`    | [ListReposInfo]              | list of [Repository]|
+//! | `get_repo_info` | `{"owner": ..., "name": ...}`| [Repository]        |
+//! | `update_repo`   | `{"owner": ..., "name": ..., "update": RepoUpdateInfo}` | [Repository] |
+//! | `archive_repo`  | `{"owner": ..., "name": ...}`| [Repository]        |
+//! | `unarchive_repo`| `{"owner": ..., "name": ...}`| [Repository]        |
+//! | `transfer_repo` | `{"owner": ..., "name": ..., "new_owner": ...}` | [Repository] |
+//! | `rename_repo`   | `{"owner": ..., "name": ..., "new_name": ...}` | [Repository] |
+//! | `import_repo`   | [RepoImportInfo]             | [Repository]        |
+//! | `create_push_mirror` | `{"owner": ..., "name": ..., "mirror": MirrorCreateInfo}` | [Mirror] |
+//! | `check_push_mirror_interval` | `{"owner": ..., "name": ..., "interval": ...}` | `null` |
+//! | `list_mirrors`  | `{"owner": ..., "name": ...}`| list of [Mirror]    |
+//! | `sync_fork`     | `{"owner": ..., "name": ..., "branch": ...}` | [SyncStatus] |
+//! | `list_branches` | `{"owner": ..., "name": ...}`| list of [Branch]    |
+//! | `create_branch` | `{"owner": ..., "name": ..., "branch": ..., "from": ...}` | [Branch] |
+//! | `delete_branch` | `{"owner": ..., "name": ..., "branch": ...}` | `null` |
+//! | `protect_branch` | `{"owner": ..., "name": ..., "branch": ..., "protection": BranchProtection}` | list of unsupported settings |
+//! | `list_tags`     | `{"owner": ..., "name": ...}`| list of [Tag]       |
+//! | `create_tag`    | `{"owner": ..., "name": ..., "tag": TagCreateInfo}` | [Tag] |
+//! | `delete_tag`    | `{"owner": ..., "name": ..., "tag": ...}` | `null` |
+//! | `list_webhooks` | `{"owner": ..., "name": ...}`| list of [Webhook]   |
+//! | `create_webhook` | `{"owner": ..., "name": ..., "hook": WebhookCreateInfo}` | [Webhook] |
+//! | `update_webhook` | `{"owner": ..., "name": ..., "id": ..., "update": WebhookUpdateInfo}` | [Webhook] |
+//! | `delete_webhook` | `{"owner": ..., "name": ..., "id": ...}` | `null` |
+//! | `test_webhook`  | `{"owner": ..., "name": ..., "id": ...}` | `null` |
+//! | `list_ssh_keys` | `{}`                         | list of [SshKey]    |
+//! | `add_ssh_key`   | [SshKeyCreateInfo]           | [SshKey]            |
+//! | `delete_ssh_key` | `{"id": ...}`               | `null`              |
+//! | `list_deploy_keys` | `{"owner": ..., "name": ...}` | list of [DeployKey] |
+//! | `add_deploy_key` | `{"owner": ..., "name": ..., "key": SshKeyCreateInfo, "read_only": ...}` | [DeployKey] |
+//! | `delete_deploy_key` | `{"owner": ..., "name": ..., "id": ...}` | `null` |
+//! | `list_labels`   | `{"owner": ..., "name": ...}`| list of [Label]     |
+//! | `create_label`  | `{"owner": ..., "name": ..., "label": Label}` | [Label] |
+//! | `list_milestones` | `{"owner": ..., "name": ...}` | list of [Milestone] |
+//! | `create_milestone` | `{"owner": ..., "name": ..., "milestone": Milestone}` | [Milestone] |
+//! | `list_issues`   | `{"owner": ..., "name": ..., "list_info": ListIssuesInfo}` | list of [Issue] |
+//! | `get_issue`     | `{"owner": ..., "name": ..., "number": ...}` | [Issue] |
+//! | `create_issue`  | `{"owner": ..., "name": ..., "issue": IssueCreateInfo}` | [Issue] |
+//! | `set_issue_state` | `{"owner": ..., "name": ..., "number": ..., "state": IssueState}` | [Issue] |
+//! | `list_issue_comments` | `{"owner": ..., "name": ..., "number": ...}` | list of [Comment] |
+//! | `create_issue_comment` | `{"owner": ..., "name": ..., "number": ..., "body": ...}` | [Comment] |
+//! | `list_pull_requests` | `{"owner": ..., "name": ..., "list_info": ListPullRequestsInfo}` | list of [PullRequest] |
+//! | `get_pull_request` | `{"owner": ..., "name": ..., "number": ...}` | [PullRequest] |
+//! | `create_pull_request` | `{"owner": ..., "name": ..., "pull_request": PullRequestCreateInfo}` | [PullRequest] |
+//! | `create_pull_request_comment` | `{"owner": ..., "name": ..., "number": ..., "body": ...}` | [Comment] |
+//! | `approve_pull_request` | `{"owner": ..., "name": ..., "number": ...}` | `null` |
+//! | `merge_pull_request` | `{"owner": ..., "name": ..., "number": ..., "merge_info": PullRequestMergeInfo}` | [PullRequest] |
+//! | `list_pull_request_comments` | `{"owner": ..., "name": ..., "number": ...}` | list of [Comment] |
+//! | `list_releases` | `{"owner": ..., "name": ...}`| list of [Release]   |
+//! | `create_release` | `{"owner": ..., "name": ..., "release": ReleaseCreateInfo}` | [Release] |
+//! | `get_release`   | `{"owner": ..., "name": ..., "tag": ...}` | [Release] |
+//! | `delete_release` | `{"owner": ..., "name": ..., "tag": ...}` | `null` |
+//! | `upload_release_asset` | `{"owner": ..., "name": ..., "tag": ..., "file_name": ..., "data": base64}` | [ReleaseAsset] |
+//! | `download_release_asset` | `{"owner": ..., "name": ..., "tag": ..., "asset_name": ...}` | base64 string |
+//! | `list_wiki_pages` | `{"owner": ..., "name": ...}` | list of [WikiPage] |
+//! | `create_wiki_page` | `{"owner": ..., "name": ..., "page": WikiPage}` | [WikiPage] |
+//! | `delete_repo`   | `{"owner": ..., "name": ...}`| `null`              |
+//!
+//! Failures are reported as JSON-RPC errors. To give gritty the details, put a serialized
+//! [Error](crate::error::Error) into the `data` field of the error. Otherwise, only the `message`
+//! is used:
+//! ```json
+//! {"jsonrpc":"2.0","id":2,"error":{"code":1,"message":"Not found",
+//!  "data":{"message":"Repository alice/foo not found","kind":"NotFound","status":404}}}
+//! ```
+//! Apart from `initialize`, every method is optional, and new versions of gritty may call methods
+//! a plugin has never heard of. Unknown methods should be answered with the standard `-32601`
+//! (method not found) error code. Gritty reports them as unsupported operations, so plugins only
+//! need to implement the methods their forge supports. The exception is
+//! `check_push_mirror_interval`: without it, every interval is accepted until
+//! `create_push_mirror` is called.
+
+use std::process::Stdio;
+
+use crate::error::{Error, Result};
+use async_trait::async_trait;
+use base64::{prelude::BASE64_STANDARD, Engine};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::{json, Value};
+use tokio::{
+    io::{AsyncBufReadExt, AsyncWriteExt, BufReader},
+    process::{Child, ChildStdin, ChildStdout, Command},
+    sync::Mutex,
+};
+
+use super::{
+    Branch, BranchProtection, Comment, DeployKey, Issue, IssueCreateInfo, IssueState, Label,
+    ListIssuesInfo, ListPullRequestsInfo, ListReposInfo, Milestone, Mirror, MirrorCreateInfo,
+    PullRequest, PullRequestCreateInfo, PullRequestMergeInfo, Release, ReleaseAsset,
+    ReleaseCreateInfo, Remote, RemoteConfig, RepoCreateInfo, RepoForkOption, RepoImportInfo,
+    RepoUpdateInfo, Repository, SshKey, SshKeyCreateInfo, SyncStatus, Tag, TagCreateInfo, Webhook,
+    WebhookCreateInfo, WebhookUpdateInfo, WikiPage,
+};
+
+/// The version of the plugin protocol this version of gritty speaks.
+/// It is bumped on every incompatible change to the protocol.
+pub const PROTOCOL_VERSION: u32 = 1;
+
+/// The JSON-RPC error code for unknown methods.
+pub const METHOD_NOT_FOUND: i64 = -32601;
+
+pub struct ExternalRemote {
+    config: RemoteConfig,
+    connection: Mutex<Connection>,
+}
+
+struct Connection {
+    /// The plugin process. It is killed when the connection is dropped.
+    _child: Child,
+    stdin: ChildStdin,
+    stdout: BufReader<ChildStdout>,
+    next_id: u64,
+}
+
+#[derive(Serialize)]
+struct Request<'a, P: Serialize> {
+    jsonrpc: &'static str,
+    id: u64,
+    method: &'a str,
+    params: P,
+}
+
+#[derive(Deserialize)]
+struct Response {
+    id: Option<u64>,
+    result: Option<Value>,
+    error: Option<ResponseError>,
+}
+
+#[derive(Deserialize)]
+struct ResponseError {
+    code: i64,
+    message: String,
+    data: Option<Value>,
+}
+
+impl From<ResponseError> for Error {
+    fn from(err: ResponseError) -> Self {
+        if let Some(data) = err.data {
+            if let Ok(error) = serde_json::from_value::<Error>(data) {
+                return error;
+            }
+        }
+        match err.code {
+            METHOD_NOT_FOUND => Error::unsupported(format!(
+                "The provider plugin does not support this operation: {}",
+                err.message
+            )),
+            _ => Error::other(err.message),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct InitializeParams<'a> {
+    protocol_version: u32,
+    config: &'a RemoteConfig,
+}
+
+#[derive(Deserialize)]
+struct InitializeResult {
+    protocol_version: u32,
+}
+
+#[derive(Serialize)]
+struct RepoParams<'a> {
+    owner: &'a str,
+    name: &'a str,
+}
+
+#[derive(Serialize)]
+struct UpdateParams<'a> {
+    owner: &'a str,
+    name: &'a str,
+    update: RepoUpdateInfo,
+}
+
+#[derive(Serialize)]
+struct TransferParams<'a> {
+    owner: &'a str,
+    name: &'a str,
+    new_owner: &'a str,
+}
+
+#[derive(Serialize)]
+struct MirrorParams<'a> {
+    owner: &'a str,
+    name: &'a str,
+    mirror: MirrorCreateInfo,
+}
+
+#[derive(Serialize)]
+struct MirrorIntervalParams<'a> {
+    owner: &'a str,
+    name: &'a str,
+    interval: &'a str,
+}
+
+/// Params for methods on a branch.
+#[derive(Serialize)]
+struct BranchParams<'a> {
+    owner: &'a str,
+    name: &'a str,
+    branch: &'a str,
+}
+
+#[derive(Serialize)]
+struct BranchCreateParams<'a> {
+    owner: &'a str,
+    name: &'a str,
+    branch: &'a str,
+    from: Option<&'a str>,
+}
+
+#[derive(Serialize)]
+struct ProtectParams<'a> {
+    owner: &'a str,
+    name: &'a str,
+    branch: &'a str,
+    protection: BranchProtection,
+}
+
+#[derive(Serialize)]
+struct TagCreateParams<'a> {
+    owner: &'a str,
+    name: &'a str,
+    tag: TagCreateInfo,
+}
+
+/// Params for methods on a webhook.
+#[derive(Serialize)]
+struct WebhookParams<'a> {
+    owner: &'a str,
+    name: &'a str,
+    id: u64,
+}
+
+#[derive(Serialize)]
+struct SshKeyParams {
+    id: u64,
+}
+
+#[derive(Serialize)]
+struct DeployKeyParams<'a> {
+    owner: &'a str,
+    name: &'a str,
+    id: u64,
+}
+
+#[derive(Serialize)]
+struct DeployKeyCreateParams<'a> {
+    owner: &'a str,
+    name: &'a str,
+    key: SshKeyCreateInfo,
+    read_only: bool,
+}
+
+#[derive(Serialize)]
+struct WebhookCreateParams<'a> {
+    owner: &'a str,
+    name: &'a str,
+    hook: WebhookCreateInfo,
+}
+
+#[derive(Serialize)]
+struct WebhookUpdateParams<'a> {
+    owner: &'a str,
+    name: &'a str,
+    id: u64,
+    update: WebhookUpdateInfo,
+}
+
+#[derive(Serialize)]
+struct RenameParams<'a> {
+    owner: &'a str,
+    name: &'a str,
+    new_name: &'a str,
+}
+
+#[derive(Serialize)]
+struct LabelParams<'a> {
+    owner: &'a str,
+    name: &'a str,
+    label: Label,
+}
+
+#[derive(Serialize)]
+struct MilestoneParams<'a> {
+    owner: &'a str,
+    name: &'a str,
+    milestone: Milestone,
+}
+
+#[derive(Serialize)]
+struct ListIssuesParams<'a> {
+    owner: &'a str,
+    name: &'a str,
+    list_info: ListIssuesInfo,
+}
+
+#[derive(Serialize)]
+struct IssueParams<'a> {
+    owner: &'a str,
+    name: &'a str,
+    issue: IssueCreateInfo,
+}
+
+/// Params for methods on a single issue or pull request.
+#[derive(Serialize)]
+struct NumberParams<'a> {
+    owner: &'a str,
+    name: &'a str,
+    number: u64,
+}
+
+#[derive(Serialize)]
+struct IssueStateParams<'a> {
+    owner: &'a str,
+    name: &'a str,
+    number: u64,
+    state: IssueState,
+}
+
+#[derive(Serialize)]
+struct CommentParams<'a> {
+    owner: &'a str,
+    name: &'a str,
+    number: u64,
+    body: &'a str,
+}
+
+#[derive(Serialize)]
+struct ListPullRequestsParams<'a> {
+    owner: &'a str,
+    name: &'a str,
+    list_info: ListPullRequestsInfo,
+}
+
+#[derive(Serialize)]
+struct PullRequestParams<'a> {
+    owner: &'a str,
+    name: &'a str,
+    pull_request: PullRequestCreateInfo,
+}
+
+#[derive(Serialize)]
+struct MergeParams<'a> {
+    owner: &'a str,
+    name: &'a str,
+    number: u64,
+    merge_info: PullRequestMergeInfo,
+}
+
+#[derive(Serialize)]
+struct ReleaseParams<'a> {
+    owner: &'a str,
+    name: &'a str,
+    release: ReleaseCreateInfo,
+}
+
+/// Params for methods on a tag or the release for it.
+#[derive(Serialize)]
+struct TagParams<'a> {
+    owner: &'a str,
+    name: &'a str,
+    tag: &'a str,
+}
+
+#[derive(Serialize)]
+struct UploadAssetParams<'a> {
+    owner: &'a str,
+    name: &'a str,
+    tag: &'a str,
+    file_name: &'a str,
+    /// The content of the file, in base64.
+    data: String,
+}
+
+#[derive(Serialize)]
+struct DownloadAssetParams<'a> {
+    owner: &'a str,
+    name: &'a str,
+    tag: &'a str,
+    asset_name: &'a str,
+}
+
+#[derive(Serialize)]
+struct WikiPageParams<'a> {
+    owner: &'a str,
+    name: &'a str,
+    page: WikiPage,
+}
+
+/// Get the executable to run for the given plugin command.
+pub fn plugin_program(command: &str) -> String {
+    if command.contains(['/', '\\']) {
+        command.to_string()
+    } else {
+        format!("gritty-provider-{command}")
+    }
+}
+
+impl ExternalRemote {
+    /// Start the provider plugin for the given command and initialize it.
+    pub async fn spawn(command: &str, config: &RemoteConfig) -> Result<Self> {
+        let program = plugin_program(command);
+        let mut child = Command::new(&program)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::inherit())
+            .kill_on_drop(true)
+            .spawn()
+            .map_err(|err| {
+                Error::other(format!("Could not start provider plugin {program}: {err}"))
+            })?;
+        let stdin = child.stdin.take().expect("stdin must be piped");
+        let stdout = BufReader::new(child.stdout.take().expect("stdout must be piped"));
+
+        let remote = Self {
+            config: config.clone(),
+            connection: Mutex::new(Connection {
+                _child: child,
+                stdin,
+                stdout,
+                next_id: 0,
+            }),
+        };
+
+        let params = InitializeParams {
+            protocol_version: PROTOCOL_VERSION,
+            config,
+        };
+        let result: InitializeResult = remote.call("initialize", params).await?;
+        if result.protocol_version != PROTOCOL_VERSION {
+            return Err(Error::other(format!(
+                "Provider plugin {program} speaks protocol version {}, but gritty requires version {}",
+                result.protocol_version, PROTOCOL_VERSION
+            )));
+        }
+        Ok(remote)
+    }
+
+    /// Send a request to the plugin and wait for its response.
+    async fn call<P: Serialize, R: DeserializeOwned>(&self, method: &str, params: P) -> Result<R> {
+        let result = self.request(method, params).await?.map_err(Error::from)?;
+        Ok(serde_json::from_value(result)?)
+    }
+
+    /// Send a request, returning the result or the error the plugin answered with.
+    async fn request<P: Serialize>(
+        &self,
+        method: &str,
+        params: P,
+    ) -> Result<std::result::Result<Value, ResponseError>> {
+        let mut connection = self.connection.lock().await;
+        connection.next_id += 1;
+        let id = connection.next_id;
+
+        let request = Request {
+            jsonrpc: "2.0",
+            id,
+            method,
+            params,
+        };
+        let mut line = serde_json::to_string(&request)?;
+        line.push('\n');
+        connection.stdin.write_all(line.as_bytes()).await?;
+        connection.stdin.flush().await?;
+
+        let mut line = String::new();
+        if connection.stdout.read_line(&mut line).await? == 0 {
+            return Err(Error::other(format!(
+                "Provider plugin exited before answering '{method}'"
+            )));
+        }
+        let response: Response = serde_json::from_str(&line)?;
+        if response.id != Some(id) {
+            return Err(Error::deserialization(format!(
+                "Provider plugin answered request {id} with id {:?}",
+                response.id
+            )));
+        }
+        Ok(match response.error {
+            Some(err) => Err(err),
+            None => Ok(response.result.unwrap_or(Value::Null)),
+        })
+    }
+}
+
+#[async_trait]
+impl Remote for ExternalRemote {
+    /// Always fails, since the configuration doesn't say which plugin to run.
+    async fn new(_config: &RemoteConfig) -> Result<Self> {
+        Err(Error::other(
+            "Provider plugins need a command, use ExternalRemote::spawn instead",
+        ))
+    }
+
+    async fn check_auth(&self) -> Result<bool> {
+        self.call("check_auth", json!({})).await
+    }
+
+    async fn create_repo(&self, create_info: RepoCreateInfo) -> Result<Repository> {
+        self.call("create_repo", create_info).await
+    }
+
+    async fn create_fork(&self, options: RepoForkOption) -> Result<Repository> {
+        self.call("create_fork", options).await
+    }
+
+    async fn list_repos(&self, list_info: ListReposInfo) -> Result<Vec<Repository>> {
+        self.call("list_repos", list_info).await
+    }
+
+    async fn get_repo_info(&self, owner: &str, name: &str) -> Result<Repository> {
+        self.call("get_repo_info", RepoParams { owner, name }).await
+    }
+
+    async fn update_repo(
+        &self,
+        owner: &str,
+        name: &str,
+        update: RepoUpdateInfo,
+    ) -> Result<Repository> {
+        let params = UpdateParams {
+            owner,
+            name,
+            update,
+        };
+        self.call("update_repo", params).await
+    }
+
+    async fn archive_repo(&self, owner: &str, name: &str) -> Result<Repository> {
+        self.call("archive_repo", RepoParams { owner, name }).await
+    }
+
+    async fn unarchive_repo(&self, owner: &str, name: &str) -> Result<Repository> {
+        self.call("unarchive_repo", RepoParams { owner, name })
+            .await
+    }
+
+    async fn transfer_repo(&self, owner: &str, name: &str, new_owner: &str) -> Result<Repository> {
+        let params = TransferParams {
+            owner,
+            name,
+            new_owner,
+        };
+        self.call("transfer_repo", params).await
+    }
+
+    async fn rename_repo(&self, owner: &str, name: &str, new_name: &str) -> Result<Repository> {
+        let params = RenameParams {
+            owner,
+            name,
+            new_name,
+        };
+        self.call("rename_repo", params).await
+    }
+
+    async fn import_repo(&self, import: RepoImportInfo) -> Result<Repository> {
+        self.call("import_repo", import).await
+    }
+
+    async fn create_push_mirror(
+        &self,
+        owner: &str,
+        name: &str,
+        mirror: MirrorCreateInfo,
+    ) -> Result<Mirror> {
+        let params = MirrorParams {
+            owner,
+            name,
+            mirror,
+        };
+        self.call("create_push_mirror", params).await
+    }
+
+    async fn check_push_mirror_interval(
+        &self,
+        owner: &str,
+        name: &str,
+        interval: &str,
+    ) -> Result<()> {
+        let params = MirrorIntervalParams {
+            owner,
+            name,
+            interval,
+        };
+        match self.request("check_push_mirror_interval", params).await? {
+            Err(err) if err.code == METHOD_NOT_FOUND => Ok(()),
+            Err(err) => Err(err.into()),
+            Ok(_) => Ok(()),
+        }
+    }
+
+    async fn list_mirrors(&self, owner: &str, name: &str) -> Result<Vec<Mirror>> {
+        self.call("list_mirrors", RepoParams { owner, name }).await
+    }
+
+    async fn sync_fork(&self, owner: &str, name: &str, branch: &str) -> Result<SyncStatus> {
+        let params = BranchParams {
+            owner,
+            name,
+            branch,
+        };
+        self.call("sync_fork", params).await
+    }
+
+    async fn list_branches(&self, owner: &str, name: &str) -> Result<Vec<Branch>> {
+        self.call("list_branches", RepoParams { owner, name }).await
+    }
+
+    async fn create_branch(
+        &self,
+        owner: &str,
+        name: &str,
+        branch: &str,
+        from: Option<&str>,
+    ) -> Result<Branch> {
+        let params = BranchCreateParams {
+            owner,
+            name,
+            branch,
+            from,
+        };
+        self.call("create_branch", params).await
+    }
+
+    async fn delete_branch(&self, owner: &str, name: &str, branch: &str) -> Result<()> {
+        let params = BranchParams {
+            owner,
+            name,
+            branch,
+        };
+        self.call("delete_branch", params).await
+    }
+
+    async fn protect_branch(
+        &self,
+        owner: &str,
+        name: &str,
+        branch: &str,
+        protection: BranchProtection,
+    ) -> Result<Vec<String>> {
+        let params = ProtectParams {
+            owner,
+            name,
+            branch,
+            protection,
+        };
+        self.call("protect_branch", params).await
+    }
+
+    async fn list_tags(&self, owner: &str, name: &str) -> Result<Vec<Tag>> {
+        self.call("list_tags", RepoParams { owner, name }).await
+    }
+
+    async fn create_tag(&self, owner: &str, name: &str, tag: TagCreateInfo) -> Result<Tag> {
+        let params = TagCreateParams { owner, name, tag };
+        self.call("create_tag", params).await
+    }
+
+    async fn delete_tag(&self, owner: &str, name: &str, tag: &str) -> Result<()> {
+        self.call("delete_tag", TagParams { owner, name, tag })
+            .await
+    }
+
+    async fn list_webhooks(&self, owner: &str, name: &str) -> Result<Vec<Webhook>> {
+        self.call("list_webhooks", RepoParams { owner, name }).await
+    }
+
+    async fn create_webhook(
+        &self,
+        owner: &str,
+        name: &str,
+        hook: WebhookCreateInfo,
+    ) -> Result<Webhook> {
+        let params = WebhookCreateParams { owner, name, hook };
+        self.call("create_webhook", params).await
+    }
+
+    async fn update_webhook(
+        &self,
+        owner: &str,
+        name: &str,
+        id: u64,
+        update: WebhookUpdateInfo,
+    ) -> Result<Webhook> {
+        let params = WebhookUpdateParams {
+            owner,
+            name,
+            id,
+            update,
+        };
+        self.call("update_webhook", params).await
+    }
+
+    async fn delete_webhook(&self, owner: &str, name: &str, id: u64) -> Result<()> {
+        self.call("delete_webhook", WebhookParams { owner, name, id })
+            .await
+    }
+
+    async fn test_webhook(&self, owner: &str, name: &str, id: u64) -> Result<()> {
+        self.call("test_webhook", WebhookParams { owner, name, id })
+            .await
+    }
+
+    async fn list_ssh_keys(&self) -> Result<Vec<SshKey>> {
+        self.call("list_ssh_keys", json!({})).await
+    }
+
+    async fn add_ssh_key(&self, key: SshKeyCreateInfo) -> Result<SshKey> {
+        self.call("add_ssh_key", key).await
+    }
+
+    async fn delete_ssh_key(&self, id: u64) -> Result<()> {
+        self.call("delete_ssh_key", SshKeyParams { id }).await
+    }
+
+    async fn list_deploy_keys(&self, owner: &str, name: &str) -> Result<Vec<DeployKey>> {
+        self.call("list_deploy_keys", RepoParams { owner, name })
+            .await
+    }
+
+    async fn add_deploy_key(
+        &self,
+        owner: &str,
+        name: &str,
+        key: SshKeyCreateInfo,
+        read_only: bool,
+    ) -> Result<DeployKey> {
+        let params = DeployKeyCreateParams {
+            owner,
+            name,
+            key,
+            read_only,
+        };
+        self.call("add_deploy_key", params).await
+    }
+
+    async fn delete_deploy_key(&self, owner: &str, name: &str, id: u64) -> Result<()> {
+        self.call("delete_deploy_key", DeployKeyParams { owner, name, id })
+            .await
+    }
+
+    async fn list_labels(&self, owner: &str, name: &str) -> Result<Vec<Label>> {
+        self.call("list_labels", RepoParams { owner, name }).await
+    }
+
+    async fn create_label(&self, owner: &str, name: &str, label: Label) -> Result<Label> {
+        let params = LabelParams { owner, name, label };
+        self.call("create_label", params).await
+    }
+
+    async fn list_milestones(&self, owner: &str, name: &str) -> Result<Vec<Milestone>> {
+        self.call("list_milestones", RepoParams { owner, name })
+            .await
+    }
+
+    async fn create_milestone(
+        &self,
+        owner: &str,
+        name: &str,
+        milestone: Milestone,
+    ) -> Result<Milestone> {
+        let params = MilestoneParams {
+            owner,
+            name,
+            milestone,
+        };
+        self.call("create_milestone", params).await
+    }
+
+    async fn list_issues(
+        &self,
+        owner: &str,
+        name: &str,
+        list_info: ListIssuesInfo,
+    ) -> Result<Vec<Issue>> {
+        let params = ListIssuesParams {
+            owner,
+            name,
+            list_info,
+        };
+        self.call("list_issues", params).await
+    }
+
+    async fn get_issue(&self, owner: &str, name: &str, number: u64) -> Result<Issue> {
+        let params = NumberParams {
+            owner,
+            name,
+            number,
+        };
+        self.call("get_issue", params).await
+    }
+
+    async fn create_issue(&self, owner: &str, name: &str, issue: IssueCreateInfo) -> Result<Issue> {
+        let params = IssueParams { owner, name, issue };
+        self.call("create_issue", params).await
+    }
+
+    async fn set_issue_state(
+        &self,
+        owner: &str,
+        name: &str,
+        number: u64,
+        state: IssueState,
+    ) -> Result<Issue> {
+        let params = IssueStateParams {
+            owner,
+            name,
+            number,
+            state,
+        };
+        self.call("set_issue_state", params).await
+    }
+
+    async fn list_issue_comments(
+        &self,
+        owner: &str,
+        name: &str,
+        number: u64,
+    ) -> Result<Vec<Comment>> {
+        let params = NumberParams {
+            owner,
+            name,
+            number,
+        };
+        self.call("list_issue_comments", params).await
+    }
+
+    async fn create_issue_comment(
+        &self,
+        owner: &str,
+        name: &str,
+        number: u64,
+        body: &str,
+    ) -> Result<Comment> {
+        let params = CommentParams {
+            owner,
+            name,
+            number,
+            body,
+        };
+        self.call("create_issue_comment", params).await
+    }
+
+    async fn list_pull_requests(
+        &self,
+        owner: &str,
+        name: &str,
+        list_info: ListPullRequestsInfo,
+    ) -> Result<Vec<PullRequest>> {
+        let params = ListPullRequestsParams {
+            owner,
+            name,
+            list_info,
+        };
+        self.call("list_pull_requests", params).await
+    }
+
+    async fn get_pull_request(&self, owner: &str, name: &str, number: u64) -> Result<PullRequest> {
+        let params = NumberParams {
+            owner,
+            name,
+            number,
+        };
+        self.call("get_pull_request", params).await
+    }
+
+    async fn create_pull_request(
+        &self,
+        owner: &str,
+        name: &str,
+        pull_request: PullRequestCreateInfo,
+    ) -> Result<PullRequest> {
+        let params = PullRequestParams {
+            owner,
+            name,
+            pull_request,
+        };
+        self.call("create_pull_request", params).await
+    }
+
+    async fn create_pull_request_comment(
+        &self,
+        owner: &str,
+        name: &str,
+        number: u64,
+        body: &str,
+    ) -> Result<Comment> {
+        let params = CommentParams {
+            owner,
+            name,
+            number,
+            body,
+        };
+        self.call("create_pull_request_comment", params).await
+    }
+
+    async fn approve_pull_request(&self, owner: &str, name: &str, number: u64) -> Result<()> {
+        let params = NumberParams {
+            owner,
+            name,
+            number,
+        };
+        self.call("approve_pull_request", params).await
+    }
+
+    async fn merge_pull_request(
+        &self,
+        owner: &str,
+        name: &str,
+        number: u64,
+        merge_info: PullRequestMergeInfo,
+    ) -> Result<PullRequest> {
+        let params = MergeParams {
+            owner,
+            name,
+            number,
+            merge_info,
+        };
+        self.call("merge_pull_request", params).await
+    }
+
+    async fn list_pull_request_comments(
+        &self,
+        owner: &str,
+        name: &str,
+        number: u64,
+    ) -> Result<Vec<Comment>> {
+        let params = NumberParams {
+            owner,
+            name,
+            number,
+        };
+        self.call("list_pull_request_comments", params).await
+    }
+
+    async fn list_releases(&self, owner: &str, name: &str) -> Result<Vec<Release>> {
+        self.call("list_releases", RepoParams { owner, name }).await
+    }
+
+    async fn create_release(
+        &self,
+        owner: &str,
+        name: &str,
+        release: ReleaseCreateInfo,
+    ) -> Result<Release> {
+        let params = ReleaseParams {
+            owner,
+            name,
+            release,
+        };
+        self.call("create_release", params).await
+    }
+
+    async fn get_release(&self, owner: &str, name: &str, tag: &str) -> Result<Release> {
+        self.call("get_release", TagParams { owner, name, tag })
+            .await
+    }
+
+    async fn delete_release(&self, owner: &str, name: &str, tag: &str) -> Result<()> {
+        self.call("delete_release", TagParams { owner, name, tag })
+            .await
+    }
+
+    async fn upload_release_asset(
+        &self,
+        owner: &str,
+        name: &str,
+        tag: &str,
+        file_name: &str,
+        data: Vec<u8>,
+    ) -> Result<ReleaseAsset> {
+        let params = UploadAssetParams {
+            owner,
+            name,
+            tag,
+            file_name,
+            data: BASE64_STANDARD.encode(data),
+        };
+        self.call("upload_release_asset", params).await
+    }
+
+    async fn download_release_asset(
+        &self,
+        owner: &str,
+        name: &str,
+        tag: &str,
+        asset_name: &str,
+    ) -> Result<Vec<u8>> {
+        let params = DownloadAssetParams {
+            owner,
+            name,
+            tag,
+            asset_name,
+        };
+        let data: String = self.call("download_release_asset", params).await?;
+        BASE64_STANDARD
+            .decode(data)
+            .map_err(|err| Error::deserialization(format!("Invalid asset content: {err}")))
+    }
+
+    async fn list_wiki_pages(&self, owner: &str, name: &str) -> Result<Vec<WikiPage>> {
+        self.call("list_wiki_pages", RepoParams { owner, name })
+            .await
+    }
+
+    async fn create_wiki_page(&self, owner: &str, name: &str, page: WikiPage) -> Result<WikiPage> {
+        let params = WikiPageParams { owner, name, page };
+        self.call("create_wiki_page", params).await
+    }
+
+    async fn delete_repo(&self, owner: &str, name: &str) -> Result<()> {
+        self.call("delete_repo", RepoParams { owner, name }).await
+    }
+
+    fn get_config(&self) -> &RemoteConfig {
+        &self.config
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::error::ErrorKind;
+
+    #[test]
+    fn test_plugin_program() {
+        assert_eq!(plugin_program("acme"), "gritty-provider-acme");
+        assert_eq!(plugin_program("./plugin"), "./plugin");
+        assert_eq!(plugin_program("/opt/acme/plugin"), "/opt/acme/plugin");
+    }
+
+    #[test]
+    fn test_response_error() {
+        let err: Error = ResponseError {
+            code: 1,
+            message: "Not found".to_string(),
+            data: Some(
+                json!({ "message": "Repository not found", "kind": "NotFound", "status": 404 }),
+            ),
+        }
+        .into();
+        assert_eq!(
+            err,
+            Error {
+                status: Some(404),
+                ..Error::not_found("Repository not found")
+            }
+        );
+
+        let err: Error = ResponseError {
+            code: METHOD_NOT_FOUND,
+            message: "Unknown method".to_string(),
+            data: None,
+        }
+        .into();
+        assert!(err.message.contains("does not support"));
+        assert_eq!(err.kind, ErrorKind::Unsupported);
+    }
+}
@@ -1,17 +1,466 @@
-use std::str::FromStr;
+use std::{str::FromStr, sync::Arc};
 
 use crate::error::{Error, ErrorKind, Result};
 use async_trait::async_trait;
+use base64::{prelude::BASE64_STANDARD, Engine};
+use clap::ValueEnum;
 use gitea_sdk::{
     error::{TeatimeError, TeatimeErrorKind},
     Client,
 };
+use serde::de::DeserializeOwned;
 
 use super::*;
 
 pub struct GiteaRemote {
     config: RemoteConfig,
-    client: Client,
+    /// The API client, shared with the tasks fetching repository details.
+    client: Arc<Client>,
+}
+
+/// A repository as returned by the Gitea API, with the fields gitea-sdk doesn't know about.
+#[derive(Debug, Deserialize)]
+struct GiteaRepository {
+    #[serde(flatten)]
+    repo: gitea_sdk::model::repos::Repository,
+    parent: Option<Box<GiteaRepository>>,
+    #[serde(default)]
+    topics: Vec<String>,
+}
+
+impl From<gitea_sdk::model::repos::Repository> for GiteaRepository {
+    fn from(repo: gitea_sdk::model::repos::Repository) -> Self {
+        Self {
+            repo,
+            parent: None,
+            topics: Vec::new(),
+        }
+    }
+}
+
+/// A push mirror of a repository. Not covered by gitea-sdk.
+#[derive(Debug, Deserialize)]
+struct PushMirror {
+    remote_address: String,
+    interval: Option<String>,
+    last_update: Option<DateTime<Utc>>,
+    last_error: Option<String>,
+}
+
+impl From<PushMirror> for Mirror {
+    fn from(mirror: PushMirror) -> Self {
+        Self {
+            url: mirror.remote_address,
+            direction: MirrorDirection::Push,
+            interval: mirror.interval,
+            last_update: mirror.last_update,
+            last_error: mirror.last_error.filter(|x| !x.is_empty()),
+        }
+    }
+}
+
+/// The parts of a user we need, as returned in issues, comments and releases.
+#[derive(Debug, Deserialize)]
+struct User {
+    login: String,
+}
+
+/// A reference to a label or milestone by name, as returned in issues and pull requests.
+#[derive(Debug, Deserialize)]
+struct Named {
+    #[serde(alias = "title")]
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GiteaLabel {
+    id: u64,
+    name: String,
+    color: String,
+    description: Option<String>,
+}
+
+impl From<GiteaLabel> for Label {
+    fn from(label: GiteaLabel) -> Self {
+        Self {
+            name: label.name,
+            color: label.color.trim_start_matches('#').to_string(),
+            description: label.description.filter(|x| !x.is_empty()),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct GiteaMilestone {
+    id: u64,
+    title: String,
+    description: Option<String>,
+    state: IssueState,
+    due_on: Option<DateTime<Utc>>,
+}
+
+impl From<GiteaMilestone> for Milestone {
+    fn from(milestone: GiteaMilestone) -> Self {
+        Self {
+            id: milestone.id,
+            title: milestone.title,
+            description: milestone.description.filter(|x| !x.is_empty()),
+            state: milestone.state,
+            due_on: milestone.due_on,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct GiteaIssue {
+    number: u64,
+    title: String,
+    body: Option<String>,
+    state: IssueState,
+    user: Option<User>,
+    #[serde(default)]
+    labels: Vec<Named>,
+    /// `null` if there are none.
+    assignees: Option<Vec<User>>,
+    milestone: Option<Named>,
+    html_url: Option<String>,
+    created_at: DateTime<Utc>,
+    updated_at: Option<DateTime<Utc>>,
+    closed_at: Option<DateTime<Utc>>,
+    /// Set if the issue is a pull request.
+    #[serde(default)]
+    pull_request: Option<serde_json::Value>,
+}
+
+impl From<GiteaIssue> for Issue {
+    fn from(issue: GiteaIssue) -> Self {
+        Self {
+            number: issue.number,
+            title: issue.title,
+            body: issue.body.filter(|x| !x.is_empty()),
+            state: issue.state,
+            author: issue.user.map(|x| x.login).unwrap_or_default(),
+            labels: issue.labels.into_iter().map(|x| x.name).collect(),
+            assignees: issue
+                .assignees
+                .unwrap_or_default()
+                .into_iter()
+                .map(|x| x.login)
+                .collect(),
+            milestone: issue.milestone.map(|x| x.name),
+            html_url: issue.html_url,
+            created_at: issue.created_at,
+            updated_at: issue.updated_at,
+            closed_at: issue.closed_at,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct GiteaComment {
+    id: u64,
+    user: Option<User>,
+    body: String,
+    created_at: DateTime<Utc>,
+}
+
+impl From<GiteaComment> for Comment {
+    fn from(comment: GiteaComment) -> Self {
+        Self {
+            id: comment.id,
+            author: comment.user.map(|x| x.login).unwrap_or_default(),
+            body: comment.body,
+            created_at: comment.created_at,
+        }
+    }
+}
+
+/// The branch of a pull request.
+#[derive(Debug, Deserialize)]
+struct Branch {
+    #[serde(rename = "ref")]
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GiteaPullRequest {
+    number: u64,
+    title: String,
+    body: Option<String>,
+    state: IssueState,
+    user: Option<User>,
+    head: Branch,
+    base: Branch,
+    /// Only reported by Gitea 1.22 and later.
+    #[serde(default)]
+    draft: bool,
+    #[serde(default)]
+    merged: bool,
+    #[serde(default)]
+    labels: Vec<Named>,
+    milestone: Option<Named>,
+    html_url: Option<String>,
+    created_at: DateTime<Utc>,
+    updated_at: Option<DateTime<Utc>>,
+    closed_at: Option<DateTime<Utc>>,
+    merged_at: Option<DateTime<Utc>>,
+}
+
+impl From<GiteaPullRequest> for PullRequest {
+    fn from(pull: GiteaPullRequest) -> Self {
+        let state = match (pull.state, pull.merged) {
+            (_, true) => PullRequestState::Merged,
+            (IssueState::Closed, false) => PullRequestState::Closed,
+            (IssueState::Open, false) => PullRequestState::Open,
+        };
+        Self {
+            number: pull.number,
+            title: pull.title,
+            body: pull.body.filter(|x| !x.is_empty()),
+            state,
+            author: pull.user.map(|x| x.login).unwrap_or_default(),
+            head: pull.head.name,
+            base: pull.base.name,
+            draft: pull.draft,
+            labels: pull.labels.into_iter().map(|x| x.name).collect(),
+            milestone: pull.milestone.map(|x| x.name),
+            html_url: pull.html_url,
+            created_at: pull.created_at,
+            updated_at: pull.updated_at,
+            closed_at: pull.closed_at,
+            merged_at: pull.merged_at,
+        }
+    }
+}
+
+/// A branch as returned by the Gitea API. `Branch` is taken by the branch of a pull request.
+#[derive(Debug, Deserialize)]
+struct GiteaBranch {
+    name: String,
+    commit: GiteaBranchCommit,
+    #[serde(default)]
+    protected: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct GiteaBranchCommit {
+    id: String,
+    message: String,
+    author: Named,
+    timestamp: DateTime<Utc>,
+}
+
+impl From<GiteaBranch> for super::Branch {
+    fn from(branch: GiteaBranch) -> Self {
+        let commit = branch.commit;
+        Self {
+            name: branch.name,
+            commit: Commit {
+                sha: commit.id,
+                message: commit.message,
+                author: commit.author.name,
+                date: commit.timestamp,
+            },
+            protected: branch.protected,
+        }
+    }
+}
+
+/// The protection rule of a branch, with the settings gritty manages.
+#[derive(Debug, Deserialize)]
+struct GiteaBranchProtection {
+    #[serde(default)]
+    required_approvals: i64,
+    /// `null` when empty.
+    status_check_contexts: Option<Vec<String>>,
+    /// `null` when empty.
+    push_whitelist_usernames: Option<Vec<String>>,
+    /// Missing before Gitea 1.22.
+    enable_force_push: Option<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GiteaTag {
+    name: String,
+    /// The SHA of the tag object for annotated tags, or of the commit for lightweight ones.
+    id: String,
+    #[serde(default)]
+    message: String,
+    commit: GiteaTagCommit,
+}
+
+#[derive(Debug, Deserialize)]
+struct GiteaTagCommit {
+    sha: String,
+}
+
+impl From<GiteaTag> for Tag {
+    fn from(tag: GiteaTag) -> Self {
+        // Gitea reports the commit message for lightweight tags.
+        let annotated = tag.id != tag.commit.sha;
+        Self {
+            name: tag.name,
+            sha: tag.commit.sha,
+            message: annotated.then(|| tag.message.trim_end().to_string()),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct GiteaHook {
+    id: u64,
+    active: bool,
+    #[serde(default)]
+    events: Vec<String>,
+    config: GiteaHookConfig,
+}
+
+#[derive(Debug, Deserialize)]
+struct GiteaHookConfig {
+    #[serde(default)]
+    url: String,
+    content_type: Option<WebhookContentType>,
+}
+
+impl From<GiteaHook> for Webhook {
+    fn from(hook: GiteaHook) -> Self {
+        let names = &hook.events;
+        let events = WebhookEvent::value_variants()
+            .iter()
+            .copied()
+            .filter(|x| {
+                let wanted = event_names(*x);
+                names.iter().any(|x| wanted.contains(&x.as_str()))
+            })
+            .collect();
+        Self {
+            id: hook.id,
+            url: hook.config.url,
+            content_type: hook.config.content_type.unwrap_or_default(),
+            events,
+            active: hook.active,
+        }
+    }
+}
+
+/// Gitea's names for a webhook event. Tags are only covered by the events for creating and
+/// deleting refs, which include branches.
+fn event_names(event: WebhookEvent) -> &'static [&'static str] {
+    match event {
+        WebhookEvent::Push => &["push"],
+        WebhookEvent::Tag => &["create", "delete"],
+        WebhookEvent::Issues => &["issues"],
+        WebhookEvent::IssueComment => &["issue_comment"],
+        WebhookEvent::PullRequest => &["pull_request"],
+        WebhookEvent::Release => &["release"],
+        WebhookEvent::Wiki => &["wiki"],
+    }
+}
+
+fn hook_events(events: &[WebhookEvent]) -> Vec<&'static str> {
+    events
+        .iter()
+        .flat_map(|x| event_names(*x))
+        .copied()
+        .collect()
+}
+
+#[derive(Debug, Deserialize)]
+struct GiteaRelease {
+    id: u64,
+    tag_name: String,
+    name: Option<String>,
+    body: Option<String>,
+    #[serde(default)]
+    draft: bool,
+    #[serde(default)]
+    prerelease: bool,
+    author: Option<User>,
+    html_url: Option<String>,
+    created_at: Option<DateTime<Utc>>,
+    published_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    assets: Vec<GiteaAsset>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GiteaAsset {
+    name: String,
+    size: Option<u64>,
+    browser_download_url: String,
+}
+
+impl From<GiteaAsset> for ReleaseAsset {
+    fn from(asset: GiteaAsset) -> Self {
+        Self {
+            name: asset.name,
+            size: asset.size,
+            download_url: asset.browser_download_url,
+        }
+    }
+}
+
+impl From<GiteaRelease> for Release {
+    fn from(release: GiteaRelease) -> Self {
+        Self {
+            tag: release.tag_name,
+            name: release.name.filter(|x| !x.is_empty()),
+            body: release.body.filter(|x| !x.is_empty()),
+            draft: release.draft,
+            prerelease: release.prerelease,
+            author: release.author.map(|x| x.login),
+            html_url: release.html_url,
+            created_at: release.created_at,
+            published_at: release.published_at,
+            assets: release.assets.into_iter().map(ReleaseAsset::from).collect(),
+        }
+    }
+}
+
+/// Build a `multipart/form-data` body with a single file, as Gitea expects for attachments.
+/// Returns the content type and the body.
+fn multipart_file(field: &str, file_name: &str, data: &[u8]) -> (String, Vec<u8>) {
+    let nanos = Utc::now().timestamp_nanos_opt().unwrap_or_default();
+    let boundary = format!("gritty-boundary-{nanos:x}");
+    // Quotes and line breaks would end the header, browsers percent-encode them the same way.
+    let file_name = file_name
+        .replace('"', "%22")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A");
+    let mut body = format!(
+        "--{boundary}\r\n\
+         Content-Disposition: form-data; name=\"{field}\"; filename=\"{file_name}\"\r\n\
+         Content-Type: application/octet-stream\r\n\r\n"
+    )
+    .into_bytes();
+    body.extend_from_slice(data);
+    body.extend_from_slice(format!("\r\n--{boundary}--\r\n").as_bytes());
+    (format!("multipart/form-data; boundary={boundary}"), body)
+}
+
+/// A wiki page, with its content encoded in base64.
+#[derive(Debug, Deserialize)]
+struct GiteaWikiPage {
+    title: String,
+    /// The name of the page in URLs. Only present in listings.
+    #[serde(default)]
+    sub_url: String,
+    #[serde(default)]
+    content_base64: String,
+}
+
+impl TryFrom<GiteaWikiPage> for WikiPage {
+    type Error = Error;
+
+    fn try_from(page: GiteaWikiPage) -> Result<Self> {
+        let content = BASE64_STANDARD
+            .decode(&page.content_base64)
+            .map_err(Error::deserialization)?;
+        Ok(Self {
+            title: page.title,
+            content: String::from_utf8_lossy(&content).to_string(),
+        })
+    }
 }
 
 impl From<TeatimeError> for Error {
@@ -36,13 +485,14 @@ impl Remote for GiteaRemote {
         let auth = match config.auth.clone() {
             Auth::Token { token } => gitea_sdk::Auth::Token(token),
             Auth::Basic { username, password } => gitea_sdk::Auth::Basic(username, password),
+            Auth::None => gitea_sdk::Auth::None,
         };
 
         let client = Client::new(config.url.clone(), auth);
 
         Ok(Self {
             config: config.clone(),
-            client,
+            client: Arc::new(client),
         })
     }
 
@@ -58,20 +508,40 @@ impl Remote for GiteaRemote {
     }
 
     async fn create_repo(&self, create_info: RepoCreateInfo) -> Result<Repository> {
-        let repo = self
-            .client
-            .user()
-            .create_repo(create_info.name)
-            .auto_init(create_info.init)
-            .description(create_info.description.unwrap_or_default())
-            .license(create_info.license.unwrap_or_default())
-            .private(create_info.private)
-            .send(&self.client)
-            .await?;
-        self.get_repo_info(repo).await
+        let description = create_info.description.unwrap_or_default();
+        let license = create_info.license.unwrap_or_default();
+        let repo = match create_info.owner {
+            Some(org) => {
+                self.client
+                    .orgs(org)
+                    .create_repo(create_info.name)
+                    .auto_init(create_info.init)
+                    .description(description)
+                    .license(license)
+                    .private(create_info.private)
+                    .send(&self.client)
+                    .await?
+            }
+            None => {
+                self.client
+                    .user()
+                    .create_repo(create_info.name)
+                    .auto_init(create_info.init)
+                    .description(description)
+                    .license(license)
+                    .private(create_info.private)
+                    .send(&self.client)
+                    .await?
+            }
+        };
+        Self::repo_info(&self.client, repo.into(), self.config.commit_limit()).await
     }
 
     async fn create_fork(&self, options: RepoForkOption) -> Result<Repository> {
+        let parent = RepoRef {
+            owner: options.owner.clone(),
+            name: options.repo.clone(),
+        };
         let mut fork = self.client.repos(options.owner, options.repo).create_fork();
         if let Some(name) = options.name.clone() {
             fork = fork.name(name);
@@ -80,55 +550,908 @@ impl Remote for GiteaRemote {
             fork = fork.organization(org);
         }
         let fork = fork.send(&self.client).await?;
-        self.get_repo_info(fork).await
+        let mut repo =
+            Self::repo_info(&self.client, fork.into(), self.config.commit_limit()).await?;
+        repo.parent = Some(parent);
+        Ok(repo)
     }
 
     async fn list_repos(&self, list_info: ListReposInfo) -> Result<Vec<Repository>> {
-        let owner = self.client.user().current().send(&self.client).await?;
-        let repos = self
+        // Organizations are users in Gitea, so we can look them up the same way.
+        let (owner, exclusive) = match &list_info.owner {
+            Some(owner) => (
+                self.client.users(owner).get().send(&self.client).await?,
+                true,
+            ),
+            // Without exclusive, the search includes the repositories the user contributes to.
+            None => (
+                self.client.user().current().send(&self.client).await?,
+                !list_info.affiliated,
+            ),
+        };
+        let search = self
             .client
             .search()
             .repos()
             .private(list_info.private)
             .uid(owner.id)
-            .limit(100)
-            .send(&self.client)
-            .await?;
-        let mut futures = Vec::new();
-        for repo in repos {
-            // SAFETY: We are not moving `self` in the closure, self is guaranteed to be valid as
-            // long as the closure is running and we're not mutating it, so this is safe.
-            let this = unsafe { &*(self as *const Self) };
-            futures.push(tokio::spawn((*this).get_repo_info(repo)));
-        }
-        let mut result = Vec::with_capacity(futures.len());
-        for future in futures {
-            let f = future.await.unwrap()?;
+            .exclusive(exclusive)
+            .sort("updated")
+            .order("desc")
+            .limit(PAGE_SIZE as i32);
+        // Gitea caps the page size at its configured maximum, which may be below ours, so we keep
+        // requesting pages until we get an empty one.
+        let mut repos = Vec::new();
+        for page in 1.. {
+            #[derive(Deserialize)]
+            struct SearchResults {
+                data: Vec<GiteaRepository>,
+            }
+            let req = self
+                .client
+                .get("repos/search")
+                .query(&search.clone().page(page));
+            let batch = self.send::<SearchResults>(req).await?.data;
+            if batch.is_empty() {
+                break;
+            }
             // Filter out forks. We can't filter them out in the search query because the API
             // requires us to make a whole new request to list all forks. I don't want to do that.
-            if !list_info.forks && f.fork {
-                continue;
+            repos.extend(batch.into_iter().filter(|x| {
+                let private = x.repo.private || x.repo.internal;
+                list_info.includes(private, x.repo.fork, x.repo.archived)
+            }));
+            if list_info.is_satisfied(repos.len()) {
+                break;
             }
-            result.push(f);
         }
-        Ok(result)
+        let concurrency = self.config.concurrency_limit();
+        let commits = list_info.commits.count();
+        fetch_concurrently(list_info.paginate(repos), concurrency, |repo| {
+            let client = self.client.clone();
+            async move { Self::repo_info(&client, repo, commits).await }
+        })
+        .await
     }
 
-    async fn get_repo_info(&self, name: &str) -> Result<Repository> {
-        let owner = &self.config.username;
-        let repo = self
+    async fn get_repo_info(&self, owner: &str, name: &str) -> Result<Repository> {
+        let req = self.client.get(format!("repos/{owner}/{name}"));
+        let repo = self.send(req).await?;
+        Self::repo_info(&self.client, repo, self.config.commit_limit()).await
+    }
+
+    async fn update_repo(
+        &self,
+        owner: &str,
+        name: &str,
+        update: RepoUpdateInfo,
+    ) -> Result<Repository> {
+        #[derive(Serialize)]
+        struct EditRepoOption {
+            #[serde(skip_serializing_if = "Option::is_none")]
+            description: Option<String>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            website: Option<String>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            private: Option<bool>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            default_branch: Option<String>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            has_issues: Option<bool>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            has_wiki: Option<bool>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            has_projects: Option<bool>,
+        }
+        // Gitea can't make repositories internal through the API, only the owner's visibility
+        // decides that.
+        let private = match update.visibility {
+            Some(Visibility::Internal) => {
+                return Err(Error::unsupported(
+                    "Gitea does not support making repositories internal",
+                ))
+            }
+            visibility => visibility.map(|x| x == Visibility::Private),
+        };
+        let body = EditRepoOption {
+            description: update.description,
+            website: update.homepage,
+            private,
+            default_branch: update.default_branch,
+            has_issues: update.has_issues,
+            has_wiki: update.has_wiki,
+            has_projects: update.has_projects,
+        };
+        self.edit_repo(owner, name, &body).await
+    }
+
+    async fn archive_repo(&self, owner: &str, name: &str) -> Result<Repository> {
+        let body = serde_json::json!({ "archived": true });
+        self.edit_repo(owner, name, &body).await
+    }
+
+    async fn unarchive_repo(&self, owner: &str, name: &str) -> Result<Repository> {
+        let body = serde_json::json!({ "archived": false });
+        self.edit_repo(owner, name, &body).await
+    }
+
+    async fn transfer_repo(&self, owner: &str, name: &str, new_owner: &str) -> Result<Repository> {
+        let body = serde_json::json!({ "new_owner": new_owner });
+        let req = self
             .client
-            .repos(owner, name)
-            .get()
-            .send(&self.client)
-            .await?;
-        self.get_repo_info(repo).await
+            .post(format!("repos/{owner}/{name}/transfer"))
+            .json(&body);
+        let repo = self.send(req).await?;
+        Self::repo_info(&self.client, repo, self.config.commit_limit()).await
+    }
+
+    async fn rename_repo(&self, owner: &str, name: &str, new_name: &str) -> Result<Repository> {
+        let body = serde_json::json!({ "name": new_name });
+        self.edit_repo(owner, name, &body).await
+    }
+
+    async fn import_repo(&self, import: RepoImportInfo) -> Result<Repository> {
+        #[derive(Serialize)]
+        struct MigrateRepoOptions {
+            clone_addr: String,
+            repo_name: String,
+            repo_owner: String,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            auth_username: Option<String>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            auth_password: Option<String>,
+            description: String,
+            private: bool,
+            mirror: bool,
+        }
+        // Internal repositories can't be created through the API, keep them hidden instead.
+        let body = MigrateRepoOptions {
+            clone_addr: import.clone_url,
+            repo_name: import.name,
+            repo_owner: import.owner.unwrap_or_else(|| self.config.username.clone()),
+            auth_username: import.auth_username,
+            auth_password: import.auth_password,
+            description: import.description.unwrap_or_default(),
+            private: import.visibility != Visibility::Public,
+            mirror: import.mirror,
+        };
+        let req = self.client.post("repos/migrate").json(&body);
+        let repo = self.send(req).await?;
+        Self::repo_info(&self.client, repo, self.config.commit_limit()).await
+    }
+
+    async fn create_push_mirror(
+        &self,
+        owner: &str,
+        name: &str,
+        mirror: MirrorCreateInfo,
+    ) -> Result<Mirror> {
+        #[derive(Serialize)]
+        struct CreatePushMirrorOption {
+            remote_address: String,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            remote_username: Option<String>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            remote_password: Option<String>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            interval: Option<String>,
+            sync_on_commit: bool,
+        }
+        let body = CreatePushMirrorOption {
+            remote_address: mirror.url,
+            remote_username: mirror.auth_username,
+            remote_password: mirror.auth_password,
+            interval: mirror.interval,
+            sync_on_commit: true,
+        };
+        let req = self
+            .client
+            .post(format!("repos/{owner}/{name}/push_mirrors"))
+            .json(&body);
+        let mirror: PushMirror = self.send(req).await?;
+        Ok(mirror.into())
+    }
+
+    async fn list_mirrors(&self, owner: &str, name: &str) -> Result<Vec<Mirror>> {
+        let req = self.client.get(format!("repos/{owner}/{name}"));
+        let repo: GiteaRepository = self.send(req).await?;
+        let mut mirrors = Vec::new();
+        if repo.repo.mirror {
+            mirrors.push(Mirror {
+                url: repo.repo.original_url,
+                direction: MirrorDirection::Pull,
+                interval: Some(repo.repo.mirror_interval),
+                last_update: DateTime::from_str(&repo.repo.mirror_updated).ok(),
+                last_error: None,
+            });
+        }
+        let req = self
+            .client
+            .get(format!("repos/{owner}/{name}/push_mirrors"));
+        let push_mirrors: Vec<PushMirror> = self.send(req).await?;
+        mirrors.extend(push_mirrors.into_iter().map(Mirror::from));
+        Ok(mirrors)
+    }
+
+    async fn sync_fork(&self, owner: &str, name: &str, branch: &str) -> Result<SyncStatus> {
+        #[derive(Deserialize)]
+        struct SyncForkInfo {
+            allowed: bool,
+            commits_behind: u64,
+        }
+        let path = format!("repos/{owner}/{name}/sync_fork/{branch}");
+        let info: SyncForkInfo = self.send(self.client.get(&path)).await?;
+        if info.commits_behind == 0 {
+            return Ok(SyncStatus::UpToDate);
+        }
+        // Gitea only syncs forks it can fast-forward.
+        if !info.allowed {
+            return Ok(SyncStatus::Diverged);
+        }
+        let req = self.client.post(&path).build()?;
+        self.client.make_request(req).await?;
+        Ok(SyncStatus::FastForwarded)
+    }
+
+    async fn list_branches(&self, owner: &str, name: &str) -> Result<Vec<super::Branch>> {
+        let path = format!("repos/{owner}/{name}/branches");
+        let branches: Vec<GiteaBranch> = self.get_pages(&path, &[]).await?;
+        Ok(branches.into_iter().map(super::Branch::from).collect())
+    }
+
+    async fn create_branch(
+        &self,
+        owner: &str,
+        name: &str,
+        branch: &str,
+        from: Option<&str>,
+    ) -> Result<super::Branch> {
+        // `old_ref_name` replaced `old_branch_name` in Gitea 1.22, older versions ignore it.
+        let body = serde_json::json!({
+            "new_branch_name": branch,
+            "old_branch_name": from,
+            "old_ref_name": from,
+        });
+        let req = self
+            .client
+            .post(format!("repos/{owner}/{name}/branches"))
+            .json(&body);
+        let branch: GiteaBranch = self.send(req).await?;
+        Ok(branch.into())
+    }
+
+    async fn delete_branch(&self, owner: &str, name: &str, branch: &str) -> Result<()> {
+        let req = self
+            .client
+            .delete(format!("repos/{owner}/{name}/branches/{branch}"));
+        self.client.make_request(req.build()?).await?;
+        Ok(())
+    }
+
+    async fn protect_branch(
+        &self,
+        owner: &str,
+        name: &str,
+        branch: &str,
+        protection: BranchProtection,
+    ) -> Result<Vec<String>> {
+        let checks = &protection.required_status_checks;
+        let pushers = &protection.push_allowlist;
+        let mut body = serde_json::json!({
+            "required_approvals": protection.required_reviews,
+            "enable_status_check": !checks.is_empty(),
+            "status_check_contexts": checks,
+            "enable_push": true,
+            "enable_push_whitelist": !pushers.is_empty(),
+            "push_whitelist_usernames": pushers,
+            "enable_force_push": !protection.no_force_push,
+        });
+        // Change an existing rule in place, so the branch stays protected if the change fails.
+        let path = format!("repos/{owner}/{name}/branch_protections");
+        let req = self.client.get(format!("{path}/{branch}"));
+        let exists = match self.client.make_request(req.build()?).await {
+            Ok(_) => true,
+            Err(err) => match Error::from(err) {
+                err if err.status == Some(404) => false,
+                err => return Err(err),
+            },
+        };
+        let req = match exists {
+            true => self.client.patch(format!("{path}/{branch}")).json(&body),
+            false => {
+                body["branch_name"] = branch.into();
+                body["rule_name"] = branch.into();
+                self.client.post(path).json(&body)
+            }
+        };
+        let rule: GiteaBranchProtection = self.send(req).await?;
+        let contexts = rule.status_check_contexts.unwrap_or_default();
+        let allowed = rule.push_whitelist_usernames.unwrap_or_default();
+
+        // Gitea drops the settings it can't apply instead of failing.
+        let mut unsupported = Vec::new();
+        if rule.required_approvals != i64::from(protection.required_reviews) {
+            unsupported.push("required reviews".to_string());
+        }
+        if checks.iter().any(|x| !contexts.contains(x)) {
+            unsupported.push("required status checks".to_string());
+        }
+        if pushers.iter().any(|x| !allowed.contains(x)) {
+            unsupported.push("restricted pushers".to_string());
+        }
+        // Before 1.22, Gitea has no setting for it and never allows force pushes.
+        if rule.enable_force_push.is_none() && !protection.no_force_push {
+            unsupported.push("force pushes".to_string());
+        }
+        Ok(unsupported)
     }
 
-    async fn delete_repo(&self, name: &str) -> Result<()> {
+    async fn list_tags(&self, owner: &str, name: &str) -> Result<Vec<Tag>> {
+        let path = format!("repos/{owner}/{name}/tags");
+        let tags: Vec<GiteaTag> = self.get_pages(&path, &[]).await?;
+        Ok(tags.into_iter().map(Tag::from).collect())
+    }
+
+    async fn create_tag(&self, owner: &str, name: &str, tag: TagCreateInfo) -> Result<Tag> {
+        // Gitea tags the default branch without a target, and makes lightweight tags without a
+        // message.
+        let body = serde_json::json!({
+            "tag_name": tag.name,
+            "target": tag.target,
+            "message": tag.message,
+        });
+        let req = self
+            .client
+            .post(format!("repos/{owner}/{name}/tags"))
+            .json(&body);
+        let tag: GiteaTag = self.send(req).await?;
+        Ok(tag.into())
+    }
+
+    async fn delete_tag(&self, owner: &str, name: &str, tag: &str) -> Result<()> {
+        let req = self
+            .client
+            .delete(format!("repos/{owner}/{name}/tags/{tag}"));
+        self.client.make_request(req.build()?).await?;
+        Ok(())
+    }
+
+    async fn list_webhooks(&self, owner: &str, name: &str) -> Result<Vec<Webhook>> {
+        let path = format!("repos/{owner}/{name}/hooks");
+        let hooks: Vec<GiteaHook> = self.get_pages(&path, &[]).await?;
+        Ok(hooks.into_iter().map(Webhook::from).collect())
+    }
+
+    async fn create_webhook(
+        &self,
+        owner: &str,
+        name: &str,
+        hook: WebhookCreateInfo,
+    ) -> Result<Webhook> {
+        let mut config = serde_json::json!({
+            "url": hook.url,
+            "content_type": hook.content_type,
+        });
+        if let Some(secret) = hook.secret {
+            config["secret"] = secret.into();
+        }
+        let body = serde_json::json!({
+            "type": "gitea",
+            "config": config,
+            "events": hook_events(&hook.events),
+            "active": hook.active,
+        });
+        let req = self
+            .client
+            .post(format!("repos/{owner}/{name}/hooks"))
+            .json(&body);
+        let hook: GiteaHook = self.send(req).await?;
+        Ok(hook.into())
+    }
+
+    async fn update_webhook(
+        &self,
+        owner: &str,
+        name: &str,
+        id: u64,
+        update: WebhookUpdateInfo,
+    ) -> Result<Webhook> {
+        // Gitea only changes the settings of the config that are sent.
+        let mut config = serde_json::Map::new();
+        if let Some(url) = update.url {
+            config.insert("url".to_string(), url.into());
+        }
+        if let Some(content_type) = update.content_type {
+            config.insert("content_type".to_string(), serde_json::json!(content_type));
+        }
+        if let Some(secret) = update.secret {
+            config.insert("secret".to_string(), secret.into());
+        }
+        let mut body = serde_json::Map::new();
+        if !config.is_empty() {
+            body.insert("config".to_string(), config.into());
+        }
+        if let Some(events) = update.events {
+            body.insert(
+                "events".to_string(),
+                serde_json::json!(hook_events(&events)),
+            );
+        }
+        if let Some(active) = update.active {
+            body.insert("active".to_string(), active.into());
+        }
+        let req = self
+            .client
+            .patch(format!("repos/{owner}/{name}/hooks/{id}"))
+            .json(&body);
+        let hook: GiteaHook = self.send(req).await?;
+        Ok(hook.into())
+    }
+
+    async fn delete_webhook(&self, owner: &str, name: &str, id: u64) -> Result<()> {
+        let req = self
+            .client
+            .delete(format!("repos/{owner}/{name}/hooks/{id}"));
+        self.client.make_request(req.build()?).await?;
+        Ok(())
+    }
+
+    async fn test_webhook(&self, owner: &str, name: &str, id: u64) -> Result<()> {
+        let req = self
+            .client
+            .post(format!("repos/{owner}/{name}/hooks/{id}/tests"));
+        self.client.make_request(req.build()?).await?;
+        Ok(())
+    }
+
+    async fn list_ssh_keys(&self) -> Result<Vec<SshKey>> {
+        self.get_pages("user/keys", &[]).await
+    }
+
+    async fn add_ssh_key(&self, key: SshKeyCreateInfo) -> Result<SshKey> {
+        let req = self.client.post("user/keys").json(&key);
+        self.send(req).await
+    }
+
+    async fn delete_ssh_key(&self, id: u64) -> Result<()> {
+        let req = self.client.delete(format!("user/keys/{id}"));
+        self.client.make_request(req.build()?).await?;
+        Ok(())
+    }
+
+    async fn list_deploy_keys(&self, owner: &str, name: &str) -> Result<Vec<DeployKey>> {
+        let path = format!("repos/{owner}/{name}/keys");
+        self.get_pages(&path, &[]).await
+    }
+
+    async fn add_deploy_key(
+        &self,
+        owner: &str,
+        name: &str,
+        key: SshKeyCreateInfo,
+        read_only: bool,
+    ) -> Result<DeployKey> {
+        let body = serde_json::json!({
+            "title": key.title,
+            "key": key.key,
+            "read_only": read_only,
+        });
+        let req = self
+            .client
+            .post(format!("repos/{owner}/{name}/keys"))
+            .json(&body);
+        self.send(req).await
+    }
+
+    async fn delete_deploy_key(&self, owner: &str, name: &str, id: u64) -> Result<()> {
+        let req = self
+            .client
+            .delete(format!("repos/{owner}/{name}/keys/{id}"));
+        self.client.make_request(req.build()?).await?;
+        Ok(())
+    }
+
+    async fn list_labels(&self, owner: &str, name: &str) -> Result<Vec<Label>> {
+        let path = format!("repos/{owner}/{name}/labels");
+        let labels: Vec<GiteaLabel> = self.get_pages(&path, &[]).await?;
+        Ok(labels.into_iter().map(Label::from).collect())
+    }
+
+    async fn create_label(&self, owner: &str, name: &str, label: Label) -> Result<Label> {
+        let body = serde_json::json!({
+            "name": label.name,
+            "color": format!("#{}", label.color),
+            "description": label.description.unwrap_or_default(),
+        });
+        let req = self
+            .client
+            .post(format!("repos/{owner}/{name}/labels"))
+            .json(&body);
+        let label: GiteaLabel = self.send(req).await?;
+        Ok(label.into())
+    }
+
+    async fn list_milestones(&self, owner: &str, name: &str) -> Result<Vec<Milestone>> {
+        let path = format!("repos/{owner}/{name}/milestones");
+        let milestones: Vec<GiteaMilestone> = self.get_pages(&path, &[("state", "all")]).await?;
+        Ok(milestones.into_iter().map(Milestone::from).collect())
+    }
+
+    async fn create_milestone(
+        &self,
+        owner: &str,
+        name: &str,
+        milestone: Milestone,
+    ) -> Result<Milestone> {
+        let body = serde_json::json!({
+            "title": milestone.title,
+            "description": milestone.description.unwrap_or_default(),
+            "due_on": milestone.due_on,
+            "state": milestone.state,
+        });
+        let req = self
+            .client
+            .post(format!("repos/{owner}/{name}/milestones"))
+            .json(&body);
+        let milestone: GiteaMilestone = self.send(req).await?;
+        Ok(milestone.into())
+    }
+
+    async fn list_issues(
+        &self,
+        owner: &str,
+        name: &str,
+        list_info: ListIssuesInfo,
+    ) -> Result<Vec<Issue>> {
+        let path = format!("repos/{owner}/{name}/issues");
+        let state = match list_info.state {
+            Some(IssueState::Open) => "open",
+            Some(IssueState::Closed) => "closed",
+            None => "all",
+        };
+        let labels = list_info.labels.join(",");
+        let mut query = vec![("state", state), ("type", "issues")];
+        if !labels.is_empty() {
+            query.push(("labels", labels.as_str()));
+        }
+        if let Some(assignee) = &list_info.assignee {
+            query.push(("assigned_by", assignee.as_str()));
+        }
+        if let Some(author) = &list_info.author {
+            query.push(("created_by", author.as_str()));
+        }
+        let mut issues: Vec<GiteaIssue> = self.get_pages(&path, &query).await?;
+        issues.sort_by_key(|x| x.number);
+        Ok(issues.into_iter().map(Issue::from).collect())
+    }
+
+    async fn get_issue(&self, owner: &str, name: &str, number: u64) -> Result<Issue> {
+        let req = self
+            .client
+            .get(format!("repos/{owner}/{name}/issues/{number}"));
+        let issue: GiteaIssue = self.send(req).await?;
+        // Pull requests share their numbers with issues.
+        if issue.pull_request.is_some() {
+            return Err(Error::not_found(format!(
+                "#{number} of {owner}/{name} is a pull request, not an issue"
+            )));
+        }
+        Ok(issue.into())
+    }
+
+    async fn create_issue(&self, owner: &str, name: &str, issue: IssueCreateInfo) -> Result<Issue> {
+        // Gitea refers to labels by their ID.
+        let labels = if issue.labels.is_empty() {
+            Vec::new()
+        } else {
+            let path = format!("repos/{owner}/{name}/labels");
+            let existing: Vec<GiteaLabel> = self.get_pages(&path, &[]).await?;
+            let mut labels = Vec::with_capacity(issue.labels.len());
+            for label in &issue.labels {
+                match existing.iter().find(|x| &x.name == label) {
+                    Some(x) => labels.push(x.id),
+                    None => {
+                        return Err(Error::not_found(format!(
+                            "Label {label} does not exist in {owner}/{name}"
+                        )))
+                    }
+                }
+            }
+            labels
+        };
+        let body = serde_json::json!({
+            "title": issue.title,
+            "body": issue.body.unwrap_or_default(),
+            "labels": labels,
+            "milestone": issue.milestone,
+            "assignees": issue.assignees,
+        });
+        let req = self
+            .client
+            .post(format!("repos/{owner}/{name}/issues"))
+            .json(&body);
+        let issue: GiteaIssue = self.send(req).await?;
+        Ok(issue.into())
+    }
+
+    async fn set_issue_state(
+        &self,
+        owner: &str,
+        name: &str,
+        number: u64,
+        state: IssueState,
+    ) -> Result<Issue> {
+        let body = serde_json::json!({ "state": state });
+        let req = self
+            .client
+            .patch(format!("repos/{owner}/{name}/issues/{number}"))
+            .json(&body);
+        let issue: GiteaIssue = self.send(req).await?;
+        Ok(issue.into())
+    }
+
+    async fn list_issue_comments(
+        &self,
+        owner: &str,
+        name: &str,
+        number: u64,
+    ) -> Result<Vec<Comment>> {
+        // Not paginated, all comments are returned at once.
+        let req = self
+            .client
+            .get(format!("repos/{owner}/{name}/issues/{number}/comments"));
+        let comments: Vec<GiteaComment> = self.send(req).await?;
+        Ok(comments.into_iter().map(Comment::from).collect())
+    }
+
+    async fn create_issue_comment(
+        &self,
+        owner: &str,
+        name: &str,
+        number: u64,
+        body: &str,
+    ) -> Result<Comment> {
+        let body = serde_json::json!({ "body": body });
+        let req = self
+            .client
+            .post(format!("repos/{owner}/{name}/issues/{number}/comments"))
+            .json(&body);
+        let comment: GiteaComment = self.send(req).await?;
+        Ok(comment.into())
+    }
+
+    async fn list_pull_requests(
+        &self,
+        owner: &str,
+        name: &str,
+        list_info: ListPullRequestsInfo,
+    ) -> Result<Vec<PullRequest>> {
+        let path = format!("repos/{owner}/{name}/pulls");
+        // Merged pull requests are closed ones that were merged.
+        let state = match list_info.state {
+            Some(PullRequestState::Open) => "open",
+            Some(PullRequestState::Closed | PullRequestState::Merged) => "closed",
+            None => "all",
+        };
+        let mut pulls: Vec<GiteaPullRequest> = self.get_pages(&path, &[("state", state)]).await?;
+        pulls.sort_by_key(|x| x.number);
+        Ok(pulls
+            .into_iter()
+            .map(PullRequest::from)
+            .filter(|x| list_info.state.is_none_or(|state| x.state == state))
+            .collect())
+    }
+
+    async fn get_pull_request(&self, owner: &str, name: &str, number: u64) -> Result<PullRequest> {
+        let req = self
+            .client
+            .get(format!("repos/{owner}/{name}/pulls/{number}"));
+        let pull: GiteaPullRequest = self.send(req).await?;
+        Ok(pull.into())
+    }
+
+    async fn create_pull_request(
+        &self,
+        owner: &str,
+        name: &str,
+        pull_request: PullRequestCreateInfo,
+    ) -> Result<PullRequest> {
+        // Gitea marks drafts by their title.
+        let title = match pull_request.draft {
+            true => format!("WIP: {}", pull_request.title),
+            false => pull_request.title,
+        };
+        let body = serde_json::json!({
+            "title": title,
+            "body": pull_request.body.unwrap_or_default(),
+            "head": pull_request.head,
+            "base": pull_request.base,
+        });
+        let req = self
+            .client
+            .post(format!("repos/{owner}/{name}/pulls"))
+            .json(&body);
+        let pull: GiteaPullRequest = self.send(req).await?;
+        Ok(pull.into())
+    }
+
+    /// Pull requests are issues to Gitea, so they are commented on the same way.
+    async fn create_pull_request_comment(
+        &self,
+        owner: &str,
+        name: &str,
+        number: u64,
+        body: &str,
+    ) -> Result<Comment> {
+        self.create_issue_comment(owner, name, number, body).await
+    }
+
+    async fn approve_pull_request(&self, owner: &str, name: &str, number: u64) -> Result<()> {
+        let body = serde_json::json!({ "event": "APPROVED" });
+        let req = self
+            .client
+            .post(format!("repos/{owner}/{name}/pulls/{number}/reviews"))
+            .json(&body);
+        self.client.make_request(req.build()?).await?;
+        Ok(())
+    }
+
+    async fn merge_pull_request(
+        &self,
+        owner: &str,
+        name: &str,
+        number: u64,
+        merge_info: PullRequestMergeInfo,
+    ) -> Result<PullRequest> {
+        let body = serde_json::json!({
+            "Do": merge_info.method,
+            "delete_branch_after_merge": merge_info.delete_branch,
+        });
+        let req = self
+            .client
+            .post(format!("repos/{owner}/{name}/pulls/{number}/merge"))
+            .json(&body);
+        // Gitea answers without a body.
+        self.client.make_request(req.build()?).await?;
+        self.get_pull_request(owner, name, number).await
+    }
+
+    /// Pull requests are issues to Gitea, so their comments are listed the same way.
+    async fn list_pull_request_comments(
+        &self,
+        owner: &str,
+        name: &str,
+        number: u64,
+    ) -> Result<Vec<Comment>> {
+        self.list_issue_comments(owner, name, number).await
+    }
+
+    async fn list_releases(&self, owner: &str, name: &str) -> Result<Vec<Release>> {
+        let path = format!("repos/{owner}/{name}/releases");
+        let releases: Vec<GiteaRelease> = self.get_pages(&path, &[]).await?;
+        Ok(releases.into_iter().map(Release::from).collect())
+    }
+
+    async fn create_release(
+        &self,
+        owner: &str,
+        name: &str,
+        release: ReleaseCreateInfo,
+    ) -> Result<Release> {
+        let body = serde_json::json!({
+            "tag_name": release.tag,
+            "target_commitish": release.target,
+            "name": release.name,
+            "body": release.body,
+            "draft": release.draft,
+            "prerelease": release.prerelease,
+        });
+        let req = self
+            .client
+            .post(format!("repos/{owner}/{name}/releases"))
+            .json(&body);
+        let release: GiteaRelease = self.send(req).await?;
+        Ok(release.into())
+    }
+
+    async fn get_release(&self, owner: &str, name: &str, tag: &str) -> Result<Release> {
+        Ok(self.find_release(owner, name, tag).await?.into())
+    }
+
+    async fn delete_release(&self, owner: &str, name: &str, tag: &str) -> Result<()> {
+        let release = self.find_release(owner, name, tag).await?;
+        let req = self
+            .client
+            .delete(format!("repos/{owner}/{name}/releases/{}", release.id));
+        self.client.make_request(req.build()?).await?;
+        Ok(())
+    }
+
+    async fn upload_release_asset(
+        &self,
+        owner: &str,
+        name: &str,
+        tag: &str,
+        file_name: &str,
+        data: Vec<u8>,
+    ) -> Result<ReleaseAsset> {
+        let release = self.find_release(owner, name, tag).await?;
+        let (content_type, body) = multipart_file("attachment", file_name, &data);
+        let req = self
+            .client
+            .post(format!(
+                "repos/{owner}/{name}/releases/{}/assets",
+                release.id
+            ))
+            .query(&[("name", file_name)])
+            .header(reqwest::header::CONTENT_TYPE, content_type)
+            .body(body);
+        let asset: GiteaAsset = self.send(req).await?;
+        Ok(asset.into())
+    }
+
+    /// Gitea accepts tokens for attachment downloads, so the download URL works for private
+    /// repositories too.
+    async fn download_release_asset(
+        &self,
+        owner: &str,
+        name: &str,
+        tag: &str,
+        asset_name: &str,
+    ) -> Result<Vec<u8>> {
+        let release = self.find_release(owner, name, tag).await?;
+        let Some(asset) = release.assets.iter().find(|x| x.name == asset_name) else {
+            return Err(Error::not_found(format!(
+                "Release {tag} of {owner}/{name} has no asset named {asset_name}"
+            )));
+        };
+        // The client only builds API URLs, but it has the credentials.
+        let mut req = self.client.get("").build()?;
+        *req.url_mut() = asset
+            .browser_download_url
+            .parse()
+            .map_err(|err| Error::other(format!("Invalid download URL: {err}")))?;
+        let res = self.client.make_request(req).await?;
+        Ok(res.bytes().await?.to_vec())
+    }
+
+    async fn list_wiki_pages(&self, owner: &str, name: &str) -> Result<Vec<WikiPage>> {
+        let path = format!("repos/{owner}/{name}/wiki/pages");
+        let pages: Vec<GiteaWikiPage> = match self.get_pages(&path, &[]).await {
+            Ok(pages) => pages,
+            // The wiki doesn't exist until its first page is created.
+            Err(err) if err.status == Some(404) => return Ok(Vec::new()),
+            Err(err) => return Err(err),
+        };
+        // The listing only has the titles, the content has to be fetched page by page.
+        let mut wiki = Vec::with_capacity(pages.len());
+        for page in pages {
+            let req = self
+                .client
+                .get(format!("repos/{owner}/{name}/wiki/page/{}", page.sub_url));
+            let page: GiteaWikiPage = self.send(req).await?;
+            wiki.push(page.try_into()?);
+        }
+        Ok(wiki)
+    }
+
+    async fn create_wiki_page(&self, owner: &str, name: &str, page: WikiPage) -> Result<WikiPage> {
+        let body = serde_json::json!({
+            "title": page.title,
+            "content_base64": BASE64_STANDARD.encode(&page.content),
+        });
+        let req = self
+            .client
+            .post(format!("repos/{owner}/{name}/wiki/new"))
+            .json(&body);
+        let page: GiteaWikiPage = self.send(req).await?;
+        page.try_into()
+    }
+
+    async fn delete_repo(&self, owner: &str, name: &str) -> Result<()> {
         Ok(self
             .client
-            .repos(&self.config.username, name)
+            .repos(owner, name)
             .delete()
             .send(&self.client)
             .await?)
@@ -140,20 +1463,142 @@ impl Remote for GiteaRemote {
 }
 
 impl GiteaRemote {
-    async fn get_repo_info(&self, repo: gitea_sdk::model::repos::Repository) -> Result<Repository> {
-        let owner = &self.config.username;
+    /// Get the release for a tag. Drafts aren't attached to their tag until they're published,
+    /// so they can only be found in the listing.
+    async fn find_release(&self, owner: &str, name: &str, tag: &str) -> Result<GiteaRelease> {
+        let req = self.client.get(format!(
+            "repos/{owner}/{name}/releases/tags/{}",
+            urlencoding::encode(tag)
+        ));
+        match self.send(req).await {
+            Err(err) if err.status == Some(404) => {}
+            result => return result,
+        }
+        let path = format!("repos/{owner}/{name}/releases");
+        let releases: Vec<GiteaRelease> = self.get_pages(&path, &[]).await?;
+        releases
+            .into_iter()
+            .find(|x| x.tag_name == tag)
+            .ok_or_else(|| Error::not_found(format!("No release for {tag} in {owner}/{name}")))
+    }
+
+    /// Get every page of a listing, with `query` added to the query string of every request.
+    async fn get_pages<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        query: &[(&str, &str)],
+    ) -> Result<Vec<T>> {
+        // Gitea caps the page size at its configured maximum, which may be below ours, so we keep
+        // requesting pages until we get an empty one.
+        let mut items = Vec::new();
+        for page in 1.. {
+            let req = self
+                .client
+                .get(path)
+                .query(query)
+                .query(&[("page", page), ("limit", PAGE_SIZE)]);
+            let batch: Vec<T> = self.send(req).await?;
+            if batch.is_empty() {
+                break;
+            }
+            items.extend(batch);
+        }
+        Ok(items)
+    }
+
+    /// Send a request built from the client and parse the response, for endpoints gitea-sdk
+    /// doesn't cover (or doesn't return all fields of).
+    async fn send<T: DeserializeOwned>(&self, req: reqwest::RequestBuilder) -> Result<T> {
+        let res = self.client.make_request(req.build()?).await?;
+        Ok(self.client.parse_response(res).await?)
+    }
+
+    /// Change settings of a repository, returning the updated repository.
+    async fn edit_repo(
+        &self,
+        owner: &str,
+        name: &str,
+        body: &(impl Serialize + Sync),
+    ) -> Result<Repository> {
+        let req = self
+            .client
+            .patch(format!("repos/{owner}/{name}"))
+            .json(body);
+        let repo = self.send(req).await?;
+        Self::repo_info(&self.client, repo, self.config.commit_limit()).await
+    }
+
+    async fn repo_info(client: &Client, repo: GiteaRepository, commits: u8) -> Result<Repository> {
+        let GiteaRepository {
+            repo,
+            parent,
+            topics,
+        } = repo;
+        let owner = &repo.owner.login;
         let name = &repo.name;
+        let last_commits = Self::last_commits(client, owner, name, commits).await?;
+
+        let visibility = if repo.private {
+            Visibility::Private
+        } else if repo.internal {
+            Visibility::Internal
+        } else {
+            Visibility::Public
+        };
+        let parent = parent.map(|x| RepoRef {
+            owner: x.repo.owner.login,
+            name: x.repo.name,
+        });
+        Ok(Repository {
+            name: name.to_string(),
+            owner: owner.to_string(),
+            full_name: repo.full_name,
+            description: Some(repo.description),
+            default_branch: Some(repo.default_branch),
+            private: visibility != Visibility::Public,
+            visibility,
+            fork: repo.fork,
+            parent,
+            archived: repo.archived,
+            mirror: repo.mirror,
+            html_url: Some(repo.html_url),
+            ssh_url: repo.ssh_url,
+            clone_url: repo.clone_url,
+            created_at: DateTime::from_str(&repo.created_at).ok(),
+            updated_at: DateTime::from_str(&repo.updated_at).ok(),
+            // Gitea doesn't track pushes separately from other updates.
+            pushed_at: None,
+            stars: repo.stars_count.max(0) as u64,
+            forks_count: repo.forks_count.max(0) as u64,
+            open_issues: repo.open_issues_count.max(0) as u64,
+            size: Some(repo.size.max(0) as u64),
+            language: Some(repo.language).filter(|x| !x.is_empty()),
+            topics,
+            last_commits,
+        })
+    }
+
+    /// Get the latest `count` commits of the repository, without making a request if none are
+    /// wanted.
+    async fn last_commits(
+        client: &Client,
+        owner: &str,
+        name: &str,
+        count: u8,
+    ) -> Result<Vec<Commit>> {
+        if count == 0 {
+            return Ok(Vec::new());
+        }
         // disable stats, verification, and files to speed up the request.
         // We only care about the commit messages.
-        let commits = self
-            .client
+        let commits = client
             .repos(owner, name)
             .get_commits()
             .stat(false)
             .verification(false)
             .files(false)
-            .limit(super::COMMIT_COUNT as i64)
-            .send(&self.client)
+            .limit(count as i64)
+            .send(client)
             .await;
         let commits = match commits {
             Ok(x) => x,
@@ -171,7 +1616,7 @@ impl GiteaRemote {
                 }
             }
         };
-        let last_commits = commits
+        Ok(commits
             .into_iter()
             .map(|c| Commit {
                 sha: c.sha,
@@ -179,17 +1624,23 @@ impl GiteaRemote {
                 author: c.commit.author.name,
                 date: DateTime::from_str(&c.commit.author.date).unwrap(),
             })
-            .collect();
+            .collect())
+    }
+}
 
-        Ok(Repository {
-            name: name.to_string(),
-            description: Some(repo.description),
-            default_branch: Some(repo.default_branch),
-            private: repo.private,
-            fork: repo.fork,
-            ssh_url: repo.ssh_url,
-            clone_url: repo.clone_url,
-            last_commits,
-        })
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_multipart_file() {
+        let (content_type, body) = multipart_file("file", "a \"b\".txt", b"data");
+        let boundary = content_type
+            .strip_prefix("multipart/form-data; boundary=")
+            .unwrap();
+        let body = String::from_utf8(body).unwrap();
+        assert!(body.starts_with(&format!("--{boundary}\r\n")));
+        assert!(body.contains("name=\"file\"; filename=\"a %22b%22.txt\"\r\n"));
+        assert!(body.ends_with(&format!("\r\n\r\ndata\r\n--{boundary}--\r\n")));
     }
 }
@@ -1,19 +1,367 @@
+use std::sync::Arc;
+
 use crate::error::{Error, ErrorKind, Result};
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use clap::ValueEnum;
 use octocrab::{
     models::{self, repos::CommitAuthor},
+    params::repos::Reference,
     repos::RepoHandler,
     Octocrab,
 };
-use serde::{Deserialize, Serialize};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::json;
 
 use super::{
-    Commit, ListReposInfo, Remote, RemoteConfig, RepoCreateInfo, RepoForkOption, Repository,
+    fetch_concurrently, BranchProtection, Comment, Commit, DeployKey, Issue, IssueCreateInfo,
+    IssueState, Label, ListIssuesInfo, ListPullRequestsInfo, ListReposInfo, Milestone, PullRequest,
+    PullRequestCreateInfo, PullRequestMergeInfo, PullRequestState, Release, ReleaseAsset,
+    ReleaseCreateInfo, Remote, RemoteConfig, RepoCreateInfo, RepoForkOption, RepoRef,
+    RepoUpdateInfo, Repository, SshKey, SshKeyCreateInfo, SyncStatus, Tag, TagCreateInfo,
+    Visibility, Webhook, WebhookContentType, WebhookCreateInfo, WebhookEvent, WebhookUpdateInfo,
+    WikiPage, PAGE_SIZE,
 };
 
 pub struct GitHubRemote {
     config: RemoteConfig,
-    crab: Octocrab,
+    /// The API client, shared with the tasks fetching repository details.
+    crab: Arc<Octocrab>,
+}
+
+/// Get the API endpoint for the given remote URL.
+///
+/// GitHub.com serves its REST API from `api.github.com`, while GitHub Enterprise Server serves it
+/// from `/api/v3` on the instance itself.
+fn api_url(url: &str) -> String {
+    let url = url.trim_end_matches('/');
+    match host(url) {
+        "github.com" | "www.github.com" | "api.github.com" => "https://api.github.com".to_string(),
+        _ if url.ends_with("/api/v3") => url.to_string(),
+        _ => format!("{url}/api/v3"),
+    }
+}
+
+/// Get the login of the repository's owner, falling back to `default` if the API didn't return one.
+fn repo_owner(repo: &models::Repository, default: &str) -> String {
+    match &repo.owner {
+        Some(owner) => owner.login.clone(),
+        None => default.to_string(),
+    }
+}
+
+/// Get the host name of the given remote URL, without the scheme, port and path.
+fn host(url: &str) -> &str {
+    let url = url
+        .trim_start_matches("https://")
+        .trim_start_matches("http://");
+    let authority = url.split('/').next().unwrap_or(url);
+    authority.split(':').next().unwrap_or(authority)
+}
+
+/// The parts of a user we need, as returned in issues, comments and releases.
+#[derive(Debug, Deserialize)]
+struct User {
+    login: String,
+}
+
+/// A reference to a label or milestone by name, as returned in issues and pull requests.
+#[derive(Debug, Deserialize)]
+struct Named {
+    #[serde(alias = "title")]
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitHubMilestone {
+    number: u64,
+    title: String,
+    description: Option<String>,
+    state: IssueState,
+    due_on: Option<DateTime<Utc>>,
+}
+
+impl From<GitHubMilestone> for Milestone {
+    fn from(milestone: GitHubMilestone) -> Self {
+        Self {
+            id: milestone.number,
+            title: milestone.title,
+            description: milestone.description,
+            state: milestone.state,
+            due_on: milestone.due_on,
+        }
+    }
+}
+
+/// An issue, or a pull request in the issue listing.
+#[derive(Debug, Deserialize)]
+struct GitHubIssue {
+    number: u64,
+    title: String,
+    body: Option<String>,
+    state: IssueState,
+    user: Option<User>,
+    #[serde(default)]
+    labels: Vec<Named>,
+    #[serde(default)]
+    assignees: Vec<User>,
+    milestone: Option<Named>,
+    html_url: Option<String>,
+    created_at: DateTime<Utc>,
+    updated_at: Option<DateTime<Utc>>,
+    closed_at: Option<DateTime<Utc>>,
+    /// Only present for pull requests.
+    pull_request: Option<serde_json::Value>,
+}
+
+impl From<GitHubIssue> for Issue {
+    fn from(issue: GitHubIssue) -> Self {
+        Self {
+            number: issue.number,
+            title: issue.title,
+            body: issue.body,
+            state: issue.state,
+            author: issue.user.map(|x| x.login).unwrap_or_default(),
+            labels: issue.labels.into_iter().map(|x| x.name).collect(),
+            assignees: issue.assignees.into_iter().map(|x| x.login).collect(),
+            milestone: issue.milestone.map(|x| x.name),
+            html_url: issue.html_url,
+            created_at: issue.created_at,
+            updated_at: issue.updated_at,
+            closed_at: issue.closed_at,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct GitHubComment {
+    id: u64,
+    user: Option<User>,
+    body: Option<String>,
+    created_at: DateTime<Utc>,
+}
+
+impl From<GitHubComment> for Comment {
+    fn from(comment: GitHubComment) -> Self {
+        Self {
+            id: comment.id,
+            author: comment.user.map(|x| x.login).unwrap_or_default(),
+            body: comment.body.unwrap_or_default(),
+            created_at: comment.created_at,
+        }
+    }
+}
+
+/// The branch of a pull request.
+#[derive(Debug, Deserialize)]
+struct Branch {
+    #[serde(rename = "ref")]
+    name: String,
+    /// `null` if the repository was deleted.
+    #[serde(default)]
+    repo: Option<BranchRepo>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BranchRepo {
+    full_name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitHubPullRequest {
+    number: u64,
+    title: String,
+    body: Option<String>,
+    state: IssueState,
+    user: Option<User>,
+    head: Branch,
+    base: Branch,
+    #[serde(default)]
+    draft: bool,
+    #[serde(default)]
+    labels: Vec<Named>,
+    milestone: Option<Named>,
+    html_url: Option<String>,
+    created_at: DateTime<Utc>,
+    updated_at: Option<DateTime<Utc>>,
+    closed_at: Option<DateTime<Utc>>,
+    merged_at: Option<DateTime<Utc>>,
+}
+
+impl From<GitHubPullRequest> for PullRequest {
+    fn from(pull: GitHubPullRequest) -> Self {
+        let state = match (pull.state, pull.merged_at) {
+            (_, Some(_)) => PullRequestState::Merged,
+            (IssueState::Closed, None) => PullRequestState::Closed,
+            (IssueState::Open, None) => PullRequestState::Open,
+        };
+        Self {
+            number: pull.number,
+            title: pull.title,
+            body: pull.body,
+            state,
+            author: pull.user.map(|x| x.login).unwrap_or_default(),
+            head: pull.head.name,
+            base: pull.base.name,
+            draft: pull.draft,
+            labels: pull.labels.into_iter().map(|x| x.name).collect(),
+            milestone: pull.milestone.map(|x| x.name),
+            html_url: pull.html_url,
+            created_at: pull.created_at,
+            updated_at: pull.updated_at,
+            closed_at: pull.closed_at,
+            merged_at: pull.merged_at,
+        }
+    }
+}
+
+/// A branch as listed by the API, which only has the SHA of its head commit.
+#[derive(Debug, Deserialize)]
+struct GitHubBranch {
+    name: String,
+    commit: GitHubObject,
+    #[serde(default)]
+    protected: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitHubTag {
+    name: String,
+    commit: GitHubObject,
+}
+
+/// A git object referenced by a branch, tag or ref.
+#[derive(Debug, Deserialize)]
+struct GitHubObject {
+    sha: String,
+}
+
+impl From<models::repos::RepoCommit> for Commit {
+    fn from(commit: models::repos::RepoCommit) -> Self {
+        let author = commit.commit.author.unwrap_or(CommitAuthor {
+            name: "unknown".to_string(),
+            email: "unknown".to_string(),
+            date: None,
+        });
+        Self {
+            sha: commit.sha,
+            message: commit.commit.message,
+            author: author.name,
+            date: author.date.unwrap_or_default(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct GitHubHook {
+    id: u64,
+    active: bool,
+    events: Vec<String>,
+    config: GitHubHookConfig,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitHubHookConfig {
+    /// Missing for hooks of GitHub Apps.
+    #[serde(default)]
+    url: String,
+    content_type: Option<WebhookContentType>,
+}
+
+impl From<GitHubHook> for Webhook {
+    fn from(hook: GitHubHook) -> Self {
+        let names = &hook.events;
+        let events = WebhookEvent::value_variants()
+            .iter()
+            .copied()
+            .filter(|x| {
+                let wanted = event_names(*x);
+                names
+                    .iter()
+                    .any(|x| x == "*" || wanted.contains(&x.as_str()))
+            })
+            .collect();
+        Self {
+            id: hook.id,
+            url: hook.config.url,
+            content_type: hook.config.content_type.unwrap_or_default(),
+            events,
+            active: hook.active,
+        }
+    }
+}
+
+/// GitHub's names for a webhook event. Tags are only covered by the events for creating and
+/// deleting refs, which include branches.
+fn event_names(event: WebhookEvent) -> &'static [&'static str] {
+    match event {
+        WebhookEvent::Push => &["push"],
+        WebhookEvent::Tag => &["create", "delete"],
+        WebhookEvent::Issues => &["issues"],
+        WebhookEvent::IssueComment => &["issue_comment"],
+        WebhookEvent::PullRequest => &["pull_request"],
+        WebhookEvent::Release => &["release"],
+        WebhookEvent::Wiki => &["gollum"],
+    }
+}
+
+fn hook_events(events: &[WebhookEvent]) -> Vec<&'static str> {
+    events
+        .iter()
+        .flat_map(|x| event_names(*x))
+        .copied()
+        .collect()
+}
+
+#[derive(Debug, Deserialize)]
+struct GitHubRelease {
+    id: u64,
+    tag_name: String,
+    name: Option<String>,
+    body: Option<String>,
+    #[serde(default)]
+    draft: bool,
+    #[serde(default)]
+    prerelease: bool,
+    author: Option<User>,
+    html_url: Option<String>,
+    created_at: Option<DateTime<Utc>>,
+    published_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    assets: Vec<GitHubAsset>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitHubAsset {
+    id: u64,
+    name: String,
+    size: Option<u64>,
+    browser_download_url: String,
+}
+
+impl From<GitHubRelease> for Release {
+    fn from(release: GitHubRelease) -> Self {
+        Self {
+            tag: release.tag_name,
+            name: release.name.filter(|x| !x.is_empty()),
+            body: release.body,
+            draft: release.draft,
+            prerelease: release.prerelease,
+            author: release.author.map(|x| x.login),
+            html_url: release.html_url,
+            created_at: release.created_at,
+            published_at: release.published_at,
+            assets: release
+                .assets
+                .into_iter()
+                .map(|x| ReleaseAsset {
+                    name: x.name,
+                    size: x.size,
+                    download_url: x.browser_download_url,
+                })
+                .collect(),
+        }
+    }
 }
 
 impl From<octocrab::Error> for Error {
@@ -63,12 +411,13 @@ impl Remote for GitHubRemote {
         crab = match config.auth.clone() {
             Basic { username, password } => crab.basic_auth(username, password),
             Token { token } => crab.personal_token(token),
+            super::Auth::None => crab,
         };
 
-        let crab = crab.build()?;
+        let crab = crab.base_uri(api_url(&config.url))?.build()?;
 
         Ok(Self {
-            crab,
+            crab: Arc::new(crab),
             config: config.clone(), // TODO: remove clone
         })
     }
@@ -104,10 +453,6 @@ impl Remote for GitHubRemote {
             license_template: Option<String>,
             auto_init: bool,
         }
-        #[derive(Serialize, Deserialize)]
-        struct Response {
-            clone_url: String,
-        }
         let req = Request {
             name: create_info.name,
             description: create_info.description,
@@ -116,9 +461,13 @@ impl Remote for GitHubRemote {
             auto_init: create_info.init,
         };
         let body = serde_json::to_value(&req).unwrap();
-        let repo: octocrab::models::Repository = self.crab.post("/user/repos", Some(&body)).await?;
-        let base = self.crab.repos(self.config.username.clone(), req.name);
-        Self::get_repo_info(self.config.username.clone(), base, repo).await
+        let (owner, route) = match create_info.owner {
+            Some(org) => (org.clone(), format!("/orgs/{org}/repos")),
+            None => (self.config.username.clone(), "/user/repos".to_string()),
+        };
+        let repo: octocrab::models::Repository = self.crab.post(route, Some(&body)).await?;
+        let base = self.crab.repos(owner, req.name);
+        Self::get_repo_info(self.config.clone(), base, repo, self.config.commit_limit()).await
     }
 
     async fn create_fork(&self, options: RepoForkOption) -> Result<Repository> {
@@ -138,89 +487,895 @@ impl Remote for GitHubRemote {
         }
         let fork = fork.send().await?;
 
-        let base = self.crab.repos(owner.clone(), repo.clone());
-        Self::get_repo_info(self.config.username.clone(), base, fork).await
+        let fork_owner = options.organization.unwrap_or(self.config.username.clone());
+        let fork_owner = repo_owner(&fork, &fork_owner);
+        let base = self.crab.repos(fork_owner, fork.name.clone());
+        Self::get_repo_info(self.config.clone(), base, fork, self.config.commit_limit()).await
     }
 
     async fn list_repos(&self, list_info: ListReposInfo) -> Result<Vec<Repository>> {
-        let mut query = format!("user:{}", &self.config.username);
-        if list_info.forks {
-            query += " fork:true";
+        let owner = list_info.owner.as_ref().unwrap_or(&self.config.username);
+        let repos = if owner == &self.config.username {
+            // The authenticated listing includes private repositories, unlike the public one.
+            let visibility = if list_info.private { "all" } else { "public" };
+            let affiliation = match list_info.affiliated {
+                true => "owner,collaborator,organization_member",
+                false => "owner",
+            };
+            let params = ListParams {
+                affiliation: Some(affiliation),
+                visibility: Some(visibility),
+                ..Default::default()
+            };
+            self.list_pages("/user/repos", params, &list_info).await?
+        } else {
+            let params = ListParams {
+                kind: Some("all"),
+                ..Default::default()
+            };
+            let route = format!("/orgs/{owner}/repos");
+            match self.list_pages(&route, params.clone(), &list_info).await {
+                // Not an organization, so list the public repositories of the user instead.
+                Err(err) if err.kind == ErrorKind::NotFound => {
+                    let route = format!("/users/{owner}/repos");
+                    self.list_pages(&route, params, &list_info).await?
+                }
+                result => result?,
+            }
+        };
+
+        let owner = owner.clone();
+        let concurrency = self.config.concurrency_limit();
+        let commits = list_info.commits.count();
+        fetch_concurrently(list_info.paginate(repos), concurrency, |repo| {
+            let config = self.config.clone();
+            let crab = self.crab.clone();
+            let owner = repo_owner(&repo, &owner);
+            async move {
+                let base = crab.repos(owner, repo.name.clone());
+                Self::get_repo_info(config, base, repo, commits).await
+            }
+        })
+        .await
+    }
+
+    async fn get_repo_info(&self, owner: &str, name: &str) -> Result<Repository> {
+        let base = self.crab.repos(owner, name);
+        let repo = base.get().await?;
+        Self::get_repo_info(self.config.clone(), base, repo, self.config.commit_limit()).await
+    }
+
+    async fn update_repo(
+        &self,
+        owner: &str,
+        name: &str,
+        update: RepoUpdateInfo,
+    ) -> Result<Repository> {
+        // The settings are named the same in the API, so the update can be sent as is.
+        self.patch_repo(owner, name, &update).await
+    }
+
+    async fn archive_repo(&self, owner: &str, name: &str) -> Result<Repository> {
+        self.patch_repo(owner, name, &json!({ "archived": true }))
+            .await
+    }
+
+    async fn unarchive_repo(&self, owner: &str, name: &str) -> Result<Repository> {
+        self.patch_repo(owner, name, &json!({ "archived": false }))
+            .await
+    }
+
+    async fn transfer_repo(&self, owner: &str, name: &str, new_owner: &str) -> Result<Repository> {
+        let route = format!("/repos/{owner}/{name}/transfer");
+        let body = json!({ "new_owner": new_owner });
+        let repo: models::Repository = self.crab.post(route, Some(&body)).await?;
+        // GitHub moves the repository in the background, so its commits may not be available at
+        // the new location yet.
+        let base = self.crab.repos(new_owner, repo.name.clone());
+        let mut config = self.config.clone();
+        config.username = new_owner.to_string();
+        Self::get_repo_info(config, base, repo, 0).await
+    }
+
+    async fn rename_repo(&self, owner: &str, name: &str, new_name: &str) -> Result<Repository> {
+        self.patch_repo(owner, name, &json!({ "name": new_name }))
+            .await
+    }
+
+    async fn sync_fork(&self, owner: &str, name: &str, branch: &str) -> Result<SyncStatus> {
+        #[derive(Deserialize)]
+        struct MergeUpstream {
+            merge_type: String,
         }
-        let repos = self
+        let route = format!("/repos/{owner}/{name}/merge-upstream");
+        let body = json!({ "branch": branch });
+        let result: std::result::Result<MergeUpstream, Error> = self
             .crab
-            .search()
-            .repositories(&query)
-            .per_page(100)
-            .sort("updated")
-            .order("desc")
-            .send()
+            .post(route, Some(&body))
+            .await
+            .map_err(Error::from);
+        match result {
+            Ok(merge) => Ok(match merge.merge_type.as_str() {
+                "fast-forward" => SyncStatus::FastForwarded,
+                "merge" => SyncStatus::Merged,
+                _ => SyncStatus::UpToDate,
+            }),
+            // GitHub answers with a conflict if the branches can't be merged.
+            Err(err) if err.status == Some(409) => Ok(SyncStatus::Diverged),
+            Err(err) => Err(err),
+        }
+    }
+
+    async fn list_branches(&self, owner: &str, name: &str) -> Result<Vec<super::Branch>> {
+        let route = format!("/repos/{owner}/{name}/branches");
+        let branches: Vec<GitHubBranch> = self.get_pages(&route, "").await?;
+        // The listing only has the SHA of each head commit, so fetch the commits themselves.
+        fetch_concurrently(branches, self.config.concurrency_limit(), |branch| {
+            let crab = self.crab.clone();
+            let route = format!("/repos/{owner}/{name}/commits/{}", branch.commit.sha);
+            async move {
+                let commit: models::repos::RepoCommit = crab.get(route, None::<&()>).await?;
+                Ok(super::Branch {
+                    name: branch.name,
+                    commit: commit.into(),
+                    protected: branch.protected,
+                })
+            }
+        })
+        .await
+    }
+
+    async fn create_branch(
+        &self,
+        owner: &str,
+        name: &str,
+        branch: &str,
+        from: Option<&str>,
+    ) -> Result<super::Branch> {
+        let commit = self.resolve_commit(owner, name, from).await?;
+        self.create_ref(owner, name, &format!("refs/heads/{branch}"), &commit.sha)
             .await?;
-        let mut futures = Vec::new();
-        for repo in repos {
-            // SAFETY: We are not moving `self` in the closure, self is guaranteed to be valid as
-            // long as the closure is running and we're not mutating it, so this is safe.
-            let this = unsafe { &*(self as *const Self) };
-            let base = this
-                .crab
-                .repos(self.config.username.clone(), repo.name.clone());
-            let username = &self.config.username;
-            futures.push(tokio::spawn(Self::get_repo_info(
-                username.clone(),
-                base,
-                repo,
-            )));
+        Ok(super::Branch {
+            name: branch.to_string(),
+            commit: commit.into(),
+            protected: false,
+        })
+    }
+
+    async fn delete_branch(&self, owner: &str, name: &str, branch: &str) -> Result<()> {
+        let branch = Reference::Branch(branch.to_string());
+        self.crab.repos(owner, name).delete_ref(&branch).await?;
+        Ok(())
+    }
+
+    async fn protect_branch(
+        &self,
+        owner: &str,
+        name: &str,
+        branch: &str,
+        protection: BranchProtection,
+    ) -> Result<Vec<String>> {
+        let route = format!(
+            "/repos/{owner}/{name}/branches/{}/protection",
+            urlencoding::encode(branch)
+        );
+        let mut unsupported = Vec::new();
+        let mut pushers = protection.push_allowlist.as_slice();
+        // Only repositories of organizations can restrict who pushes.
+        if !pushers.is_empty() && !self.owned_by_org(owner, name).await? {
+            unsupported.push("restricted pushers".to_string());
+            pushers = &[];
         }
-        let mut result = Vec::with_capacity(futures.len());
-        for future in futures {
-            let f = future.await.unwrap()?;
-            // Skip private repositories if the user doesn't want them. We need to do this here
-            // because the GitHub search API doesn't seem to support filtering by privacy.
-            if !list_info.private && f.private {
-                continue;
+        // Every setting has to be sent, `null` turns it off.
+        let checks = &protection.required_status_checks;
+        let reviews = protection.required_reviews;
+        let body = json!({
+            "required_status_checks": (!checks.is_empty())
+                .then(|| json!({ "strict": false, "contexts": checks })),
+            "enforce_admins": null,
+            "required_pull_request_reviews": (reviews > 0)
+                .then(|| json!({ "required_approving_review_count": reviews })),
+            "restrictions": (!pushers.is_empty())
+                .then(|| json!({ "users": pushers, "teams": [] })),
+            "allow_force_pushes": !protection.no_force_push,
+        });
+        let _: serde_json::Value = self.crab.put(route, Some(&body)).await?;
+        Ok(unsupported)
+    }
+
+    async fn list_tags(&self, owner: &str, name: &str) -> Result<Vec<Tag>> {
+        let route = format!("/repos/{owner}/{name}/tags");
+        let tags: Vec<GitHubTag> = self.get_pages(&route, "").await?;
+        Ok(tags
+            .into_iter()
+            .map(|x| Tag {
+                name: x.name,
+                sha: x.commit.sha,
+                message: None,
+            })
+            .collect())
+    }
+
+    async fn create_tag(&self, owner: &str, name: &str, tag: TagCreateInfo) -> Result<Tag> {
+        let commit = self
+            .resolve_commit(owner, name, tag.target.as_deref())
+            .await?;
+        // Annotated tags are objects of their own, which the ref points to.
+        let target = match &tag.message {
+            Some(message) => {
+                let route = format!("/repos/{owner}/{name}/git/tags");
+                let body = json!({
+                    "tag": tag.name,
+                    "message": message,
+                    "object": commit.sha,
+                    "type": "commit",
+                });
+                let object: GitHubObject = self.crab.post(route, Some(&body)).await?;
+                object.sha
             }
-            result.push(f);
+            None => commit.sha.clone(),
+        };
+        self.create_ref(owner, name, &format!("refs/tags/{}", tag.name), &target)
+            .await?;
+        Ok(Tag {
+            name: tag.name,
+            sha: commit.sha,
+            message: tag.message,
+        })
+    }
+
+    async fn delete_tag(&self, owner: &str, name: &str, tag: &str) -> Result<()> {
+        let tag = Reference::Tag(tag.to_string());
+        self.crab.repos(owner, name).delete_ref(&tag).await?;
+        Ok(())
+    }
+
+    async fn list_webhooks(&self, owner: &str, name: &str) -> Result<Vec<Webhook>> {
+        let route = format!("/repos/{owner}/{name}/hooks");
+        let hooks: Vec<GitHubHook> = self.get_pages(&route, "").await?;
+        Ok(hooks.into_iter().map(Webhook::from).collect())
+    }
+
+    async fn create_webhook(
+        &self,
+        owner: &str,
+        name: &str,
+        hook: WebhookCreateInfo,
+    ) -> Result<Webhook> {
+        let route = format!("/repos/{owner}/{name}/hooks");
+        let body = json!({
+            "name": "web",
+            "config": {
+                "url": hook.url,
+                "content_type": hook.content_type,
+                "secret": hook.secret,
+            },
+            "events": hook_events(&hook.events),
+            "active": hook.active,
+        });
+        let hook: GitHubHook = self.crab.post(route, Some(&body)).await?;
+        Ok(hook.into())
+    }
+
+    async fn update_webhook(
+        &self,
+        owner: &str,
+        name: &str,
+        id: u64,
+        update: WebhookUpdateInfo,
+    ) -> Result<Webhook> {
+        let route = format!("/repos/{owner}/{name}/hooks/{id}");
+        // The config of the hook has its own endpoint, which keeps the settings that aren't sent.
+        // Sending it along with the hook replaces all of them, including the secret.
+        let mut config = serde_json::Map::new();
+        if let Some(url) = update.url {
+            config.insert("url".to_string(), json!(url));
         }
-        Ok(result)
+        if let Some(content_type) = update.content_type {
+            config.insert("content_type".to_string(), json!(content_type));
+        }
+        if let Some(secret) = update.secret {
+            config.insert("secret".to_string(), json!(secret));
+        }
+        if !config.is_empty() {
+            let route = format!("{route}/config");
+            let _: serde_json::Value = self.crab.patch(route, Some(&config)).await?;
+        }
+        let mut body = serde_json::Map::new();
+        if let Some(events) = update.events {
+            body.insert("events".to_string(), json!(hook_events(&events)));
+        }
+        if let Some(active) = update.active {
+            body.insert("active".to_string(), json!(active));
+        }
+        let hook: GitHubHook = self.crab.patch(route, Some(&body)).await?;
+        Ok(hook.into())
     }
 
-    async fn get_repo_info(&self, name: &str) -> Result<Repository> {
-        let base = self.crab.repos(self.config.username.clone(), name);
-        let repo = base.get().await?;
-        Self::get_repo_info(self.config.username.clone(), base, repo).await
+    async fn delete_webhook(&self, owner: &str, name: &str, id: u64) -> Result<()> {
+        let route = format!("/repos/{owner}/{name}/hooks/{id}");
+        let response = self.crab._delete(route, None::<&()>).await?;
+        octocrab::map_github_error(response).await?;
+        Ok(())
+    }
+
+    async fn test_webhook(&self, owner: &str, name: &str, id: u64) -> Result<()> {
+        let route = format!("/repos/{owner}/{name}/hooks/{id}/pings");
+        // GitHub answers without a body.
+        let response = self.crab._post(route, None::<&()>).await?;
+        octocrab::map_github_error(response).await?;
+        Ok(())
+    }
+
+    async fn list_ssh_keys(&self) -> Result<Vec<SshKey>> {
+        self.get_pages("/user/keys", "").await
+    }
+
+    async fn add_ssh_key(&self, key: SshKeyCreateInfo) -> Result<SshKey> {
+        Ok(self.crab.post("/user/keys", Some(&key)).await?)
+    }
+
+    async fn delete_ssh_key(&self, id: u64) -> Result<()> {
+        let response = self
+            .crab
+            ._delete(format!("/user/keys/{id}"), None::<&()>)
+            .await?;
+        octocrab::map_github_error(response).await?;
+        Ok(())
+    }
+
+    async fn list_deploy_keys(&self, owner: &str, name: &str) -> Result<Vec<DeployKey>> {
+        let route = format!("/repos/{owner}/{name}/keys");
+        self.get_pages(&route, "").await
+    }
+
+    async fn add_deploy_key(
+        &self,
+        owner: &str,
+        name: &str,
+        key: SshKeyCreateInfo,
+        read_only: bool,
+    ) -> Result<DeployKey> {
+        let route = format!("/repos/{owner}/{name}/keys");
+        let body = json!({
+            "title": key.title,
+            "key": key.key,
+            "read_only": read_only,
+        });
+        Ok(self.crab.post(route, Some(&body)).await?)
+    }
+
+    async fn delete_deploy_key(&self, owner: &str, name: &str, id: u64) -> Result<()> {
+        let route = format!("/repos/{owner}/{name}/keys/{id}");
+        let response = self.crab._delete(route, None::<&()>).await?;
+        octocrab::map_github_error(response).await?;
+        Ok(())
+    }
+
+    async fn list_labels(&self, owner: &str, name: &str) -> Result<Vec<Label>> {
+        let route = format!("/repos/{owner}/{name}/labels");
+        self.get_pages(&route, "").await
+    }
+
+    async fn create_label(&self, owner: &str, name: &str, label: Label) -> Result<Label> {
+        let route = format!("/repos/{owner}/{name}/labels");
+        Ok(self.crab.post(route, Some(&label)).await?)
+    }
+
+    async fn list_milestones(&self, owner: &str, name: &str) -> Result<Vec<Milestone>> {
+        let route = format!("/repos/{owner}/{name}/milestones");
+        let milestones: Vec<GitHubMilestone> = self.get_pages(&route, "state=all").await?;
+        Ok(milestones.into_iter().map(Milestone::from).collect())
+    }
+
+    async fn create_milestone(
+        &self,
+        owner: &str,
+        name: &str,
+        milestone: Milestone,
+    ) -> Result<Milestone> {
+        let route = format!("/repos/{owner}/{name}/milestones");
+        let body = json!({
+            "title": milestone.title,
+            "state": milestone.state,
+            "description": milestone.description,
+            "due_on": milestone.due_on,
+        });
+        let milestone: GitHubMilestone = self.crab.post(route, Some(&body)).await?;
+        Ok(milestone.into())
+    }
+
+    async fn list_issues(
+        &self,
+        owner: &str,
+        name: &str,
+        list_info: ListIssuesInfo,
+    ) -> Result<Vec<Issue>> {
+        let route = format!("/repos/{owner}/{name}/issues");
+        let state = match list_info.state {
+            Some(IssueState::Open) => "open",
+            Some(IssueState::Closed) => "closed",
+            None => "all",
+        };
+        let mut query = format!("state={state}&sort=created&direction=asc");
+        if !list_info.labels.is_empty() {
+            let labels = list_info.labels.join(",");
+            query.push_str(&format!("&labels={}", urlencoding::encode(&labels)));
+        }
+        if let Some(assignee) = &list_info.assignee {
+            query.push_str(&format!("&assignee={}", urlencoding::encode(assignee)));
+        }
+        if let Some(author) = &list_info.author {
+            query.push_str(&format!("&creator={}", urlencoding::encode(author)));
+        }
+        let issues: Vec<GitHubIssue> = match self.get_pages(&route, &query).await {
+            Ok(issues) => issues,
+            // GitHub answers with 410 Gone if the issue tracker is disabled.
+            Err(err) if err.status == Some(410) => Vec::new(),
+            Err(err) => return Err(err),
+        };
+        // The issue listing includes pull requests.
+        Ok(issues
+            .into_iter()
+            .filter(|x| x.pull_request.is_none())
+            .map(Issue::from)
+            .collect())
+    }
+
+    async fn get_issue(&self, owner: &str, name: &str, number: u64) -> Result<Issue> {
+        let route = format!("/repos/{owner}/{name}/issues/{number}");
+        let issue: GitHubIssue = self.crab.get(route, None::<&()>).await?;
+        // Pull requests share their numbers with issues.
+        if issue.pull_request.is_some() {
+            return Err(Error::not_found(format!(
+                "#{number} of {owner}/{name} is a pull request, not an issue"
+            )));
+        }
+        Ok(issue.into())
+    }
+
+    async fn create_issue(&self, owner: &str, name: &str, issue: IssueCreateInfo) -> Result<Issue> {
+        let route = format!("/repos/{owner}/{name}/issues");
+        // The issue is created with the same fields it has in the API.
+        let issue: GitHubIssue = self.crab.post(route, Some(&issue)).await?;
+        Ok(issue.into())
+    }
+
+    async fn set_issue_state(
+        &self,
+        owner: &str,
+        name: &str,
+        number: u64,
+        state: IssueState,
+    ) -> Result<Issue> {
+        let route = format!("/repos/{owner}/{name}/issues/{number}");
+        let body = json!({ "state": state });
+        let issue: GitHubIssue = self.crab.patch(route, Some(&body)).await?;
+        Ok(issue.into())
+    }
+
+    async fn list_issue_comments(
+        &self,
+        owner: &str,
+        name: &str,
+        number: u64,
+    ) -> Result<Vec<Comment>> {
+        let route = format!("/repos/{owner}/{name}/issues/{number}/comments");
+        let comments: Vec<GitHubComment> = self.get_pages(&route, "").await?;
+        Ok(comments.into_iter().map(Comment::from).collect())
+    }
+
+    async fn create_issue_comment(
+        &self,
+        owner: &str,
+        name: &str,
+        number: u64,
+        body: &str,
+    ) -> Result<Comment> {
+        let route = format!("/repos/{owner}/{name}/issues/{number}/comments");
+        let body = json!({ "body": body });
+        let comment: GitHubComment = self.crab.post(route, Some(&body)).await?;
+        Ok(comment.into())
+    }
+
+    async fn list_pull_requests(
+        &self,
+        owner: &str,
+        name: &str,
+        list_info: ListPullRequestsInfo,
+    ) -> Result<Vec<PullRequest>> {
+        let route = format!("/repos/{owner}/{name}/pulls");
+        // Merged pull requests are closed ones with a merge date.
+        let state = match list_info.state {
+            Some(PullRequestState::Open) => "open",
+            Some(PullRequestState::Closed | PullRequestState::Merged) => "closed",
+            None => "all",
+        };
+        let query = format!("state={state}&sort=created&direction=asc");
+        let pulls: Vec<GitHubPullRequest> = self.get_pages(&route, &query).await?;
+        Ok(pulls
+            .into_iter()
+            .map(PullRequest::from)
+            .filter(|x| list_info.state.is_none_or(|state| x.state == state))
+            .collect())
+    }
+
+    async fn get_pull_request(&self, owner: &str, name: &str, number: u64) -> Result<PullRequest> {
+        let route = format!("/repos/{owner}/{name}/pulls/{number}");
+        let pull: GitHubPullRequest = self.crab.get(route, None::<&()>).await?;
+        Ok(pull.into())
+    }
+
+    async fn create_pull_request(
+        &self,
+        owner: &str,
+        name: &str,
+        pull_request: PullRequestCreateInfo,
+    ) -> Result<PullRequest> {
+        let route = format!("/repos/{owner}/{name}/pulls");
+        // The pull request is created with the same fields it has in the API.
+        let pull: GitHubPullRequest = self.crab.post(route, Some(&pull_request)).await?;
+        Ok(pull.into())
     }
 
-    async fn delete_repo(&self, name: &str) -> Result<()> {
+    /// Pull requests are issues to GitHub, so they are commented on the same way.
+    async fn create_pull_request_comment(
+        &self,
+        owner: &str,
+        name: &str,
+        number: u64,
+        body: &str,
+    ) -> Result<Comment> {
+        self.create_issue_comment(owner, name, number, body).await
+    }
+
+    async fn approve_pull_request(&self, owner: &str, name: &str, number: u64) -> Result<()> {
+        let route = format!("/repos/{owner}/{name}/pulls/{number}/reviews");
+        let body = json!({ "event": "APPROVE" });
+        let _: serde_json::Value = self.crab.post(route, Some(&body)).await?;
+        Ok(())
+    }
+
+    async fn merge_pull_request(
+        &self,
+        owner: &str,
+        name: &str,
+        number: u64,
+        merge_info: PullRequestMergeInfo,
+    ) -> Result<PullRequest> {
+        let route = format!("/repos/{owner}/{name}/pulls/{number}/merge");
+        let body = json!({ "merge_method": merge_info.method });
+        let _: serde_json::Value = self.crab.put(route, Some(&body)).await?;
+        let route = format!("/repos/{owner}/{name}/pulls/{number}");
+        let pull: GitHubPullRequest = self.crab.get(route, None::<&()>).await?;
+        // Branches of forks can't be deleted from here.
+        let full_name = format!("{owner}/{name}");
+        let same_repo = pull.head.repo.as_ref().map(|x| &x.full_name) == Some(&full_name);
+        if merge_info.delete_branch && same_repo {
+            let branch = Reference::Branch(pull.head.name.clone());
+            self.crab.repos(owner, name).delete_ref(&branch).await?;
+        }
+        Ok(pull.into())
+    }
+
+    /// Pull requests are issues to GitHub, so their comments are listed the same way.
+    async fn list_pull_request_comments(
+        &self,
+        owner: &str,
+        name: &str,
+        number: u64,
+    ) -> Result<Vec<Comment>> {
+        self.list_issue_comments(owner, name, number).await
+    }
+
+    async fn list_releases(&self, owner: &str, name: &str) -> Result<Vec<Release>> {
+        let route = format!("/repos/{owner}/{name}/releases");
+        let releases: Vec<GitHubRelease> = self.get_pages(&route, "").await?;
+        Ok(releases.into_iter().map(Release::from).collect())
+    }
+
+    async fn create_release(
+        &self,
+        owner: &str,
+        name: &str,
+        release: ReleaseCreateInfo,
+    ) -> Result<Release> {
+        let route = format!("/repos/{owner}/{name}/releases");
+        let body = json!({
+            "tag_name": release.tag,
+            "target_commitish": release.target,
+            "name": release.name,
+            "body": release.body,
+            "draft": release.draft,
+            "prerelease": release.prerelease,
+        });
+        let release: GitHubRelease = self.crab.post(route, Some(&body)).await?;
+        Ok(release.into())
+    }
+
+    async fn get_release(&self, owner: &str, name: &str, tag: &str) -> Result<Release> {
+        Ok(self.find_release(owner, name, tag).await?.into())
+    }
+
+    async fn delete_release(&self, owner: &str, name: &str, tag: &str) -> Result<()> {
+        let release = self.find_release(owner, name, tag).await?;
         self.crab
-            .repos(self.config.username.clone(), name)
-            .delete()
+            .repos(owner, name)
+            .releases()
+            .delete(release.id)
             .await?;
+        Ok(())
+    }
+
+    async fn upload_release_asset(
+        &self,
+        owner: &str,
+        name: &str,
+        tag: &str,
+        file_name: &str,
+        data: Vec<u8>,
+    ) -> Result<ReleaseAsset> {
+        let release = self.find_release(owner, name, tag).await?;
+        // The name ends up in the query string of the upload URL as is.
+        let file_name = urlencoding::encode(file_name);
+        let asset = self
+            .crab
+            .repos(owner, name)
+            .releases()
+            .upload_asset(release.id, &file_name, data.into())
+            .send()
+            .await?;
+        Ok(ReleaseAsset {
+            name: asset.name,
+            size: u64::try_from(asset.size).ok(),
+            download_url: asset.browser_download_url.to_string(),
+        })
+    }
+
+    /// The browser download URL needs a session for private repositories, so the asset is
+    /// downloaded through the API instead. Octocrab can only parse JSON responses, so this goes
+    /// through reqwest.
+    async fn download_release_asset(
+        &self,
+        owner: &str,
+        name: &str,
+        tag: &str,
+        asset_name: &str,
+    ) -> Result<Vec<u8>> {
+        let release = self.find_release(owner, name, tag).await?;
+        let Some(asset) = release.assets.iter().find(|x| x.name == asset_name) else {
+            return Err(Error::not_found(format!(
+                "Release {tag} of {owner}/{name} has no asset named {asset_name}"
+            )));
+        };
+        let url = format!(
+            "{}/repos/{owner}/{name}/releases/assets/{}",
+            api_url(&self.config.url),
+            asset.id
+        );
+        let req = reqwest::Client::new()
+            .get(url)
+            .header(reqwest::header::ACCEPT, "application/octet-stream")
+            .header(reqwest::header::USER_AGENT, "gritty");
+        let req = match &self.config.auth {
+            super::Auth::Basic { username, password } => req.basic_auth(username, Some(password)),
+            super::Auth::Token { token } => req.bearer_auth(token),
+            super::Auth::None => req,
+        };
+        let res = req.send().await?.error_for_status()?;
+        Ok(res.bytes().await?.to_vec())
+    }
+
+    async fn list_wiki_pages(&self, owner: &str, name: &str) -> Result<Vec<WikiPage>> {
+        Err(Error::unsupported(format!(
+            "GitHub has no API for wikis, clone {} to back up the wiki",
+            self.clone_url(owner, &format!("{name}.wiki"))
+        )))
+    }
+
+    async fn delete_repo(&self, owner: &str, name: &str) -> Result<()> {
+        self.crab.repos(owner, name).delete().await?;
 
         Ok(())
     }
 }
 
+/// Query parameters for the repository listings.
+#[derive(Debug, Clone, Default, Serialize)]
+struct ListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    affiliation: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    visibility: Option<&'a str>,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    kind: Option<&'a str>,
+    sort: &'a str,
+    per_page: u32,
+    page: u32,
+}
+
 impl GitHubRemote {
+    /// Whether the repository belongs to an organization rather than a user.
+    async fn owned_by_org(&self, owner: &str, name: &str) -> Result<bool> {
+        let repo = self.crab.repos(owner, name).get().await?;
+        Ok(repo.owner.is_some_and(|x| x.r#type == "Organization"))
+    }
+
+    /// Get the commit a branch, tag or SHA points to, or the head of the default branch.
+    async fn resolve_commit(
+        &self,
+        owner: &str,
+        name: &str,
+        target: Option<&str>,
+    ) -> Result<models::repos::RepoCommit> {
+        let target = match target {
+            Some(target) => target.to_string(),
+            None => {
+                let repo = self.crab.repos(owner, name).get().await?;
+                repo.default_branch.unwrap_or_else(|| "HEAD".to_string())
+            }
+        };
+        let route = format!(
+            "/repos/{owner}/{name}/commits/{}",
+            urlencoding::encode(&target)
+        );
+        Ok(self.crab.get(route, None::<&()>).await?)
+    }
+
+    async fn create_ref(&self, owner: &str, name: &str, git_ref: &str, sha: &str) -> Result<()> {
+        let route = format!("/repos/{owner}/{name}/git/refs");
+        let body = json!({ "ref": git_ref, "sha": sha });
+        let _: serde_json::Value = self.crab.post(route, Some(&body)).await?;
+        Ok(())
+    }
+
+    /// Get the release for a tag. Drafts aren't attached to their tag until they're published,
+    /// so they can only be found in the listing.
+    async fn find_release(&self, owner: &str, name: &str, tag: &str) -> Result<GitHubRelease> {
+        let route = format!(
+            "/repos/{owner}/{name}/releases/tags/{}",
+            urlencoding::encode(tag)
+        );
+        match self.crab.get(route, None::<&()>).await.map_err(Error::from) {
+            Err(err) if err.kind == ErrorKind::NotFound => {}
+            result => return result,
+        }
+        let route = format!("/repos/{owner}/{name}/releases");
+        let releases: Vec<GitHubRelease> = self.get_pages(&route, "").await?;
+        releases
+            .into_iter()
+            .find(|x| x.tag_name == tag)
+            .ok_or_else(|| Error::not_found(format!("No release for {tag} in {owner}/{name}")))
+    }
+
+    /// Get every page of a listing. `query` is added to the query string of every request.
+    async fn get_pages<T: DeserializeOwned>(&self, route: &str, query: &str) -> Result<Vec<T>> {
+        let mut items = Vec::new();
+        for page in 1.. {
+            let route = format!("{route}?{query}&per_page={PAGE_SIZE}&page={page}");
+            let batch: Vec<T> = self.crab.get(route, None::<&()>).await?;
+            let last = batch.len() < PAGE_SIZE as usize;
+            items.extend(batch);
+            if last {
+                break;
+            }
+        }
+        Ok(items)
+    }
+
+    /// Change settings of a repository, returning the updated repository.
+    async fn patch_repo(
+        &self,
+        owner: &str,
+        name: &str,
+        body: &(impl Serialize + Sync),
+    ) -> Result<Repository> {
+        let route = format!("/repos/{owner}/{name}");
+        let repo: models::Repository = self.crab.patch(route, Some(body)).await?;
+        let base = self.crab.repos(repo_owner(&repo, owner), repo.name.clone());
+        Self::get_repo_info(self.config.clone(), base, repo, self.config.commit_limit()).await
+    }
+
+    /// Page through a repository listing, keeping the repositories matching `list_info` until the
+    /// requested page is filled.
+    async fn list_pages(
+        &self,
+        route: &str,
+        mut params: ListParams<'_>,
+        list_info: &ListReposInfo,
+    ) -> Result<Vec<models::Repository>> {
+        params.sort = "updated";
+        params.per_page = PAGE_SIZE;
+        let mut repos = Vec::new();
+        for page in 1.. {
+            params.page = page;
+            let batch: Vec<models::Repository> = self.crab.get(route, Some(&params)).await?;
+            let last = batch.len() < PAGE_SIZE as usize;
+            repos.extend(batch.into_iter().filter(|x| {
+                list_info.includes(
+                    x.private.unwrap_or(false),
+                    x.fork.unwrap_or(false),
+                    x.archived.unwrap_or(false),
+                )
+            }));
+            if last || list_info.is_satisfied(repos.len()) {
+                break;
+            }
+        }
+        Ok(repos)
+    }
+
     async fn get_repo_info(
-        username: String,
+        config: RemoteConfig,
         base: RepoHandler<'_>,
         repo: models::Repository,
+        commits: u8,
     ) -> Result<Repository> {
-        let commits = base
-            .list_commits()
-            .per_page(super::COMMIT_COUNT)
-            .send()
-            .await;
+        let last_commits = Self::last_commits(base, commits).await?;
+        // Build the fallback URLs from the configured host, so they point to the right instance
+        // on GitHub Enterprise Server.
+        let url = config.url.trim_end_matches('/');
+        let owner = repo_owner(&repo, &config.username);
         let ssh_url = match repo.ssh_url {
             Some(url) => url.to_string(),
-            None => format!("git@github.com/{}:{}.git", &username, repo.name),
+            None => format!("git@{}:{}/{}.git", host(url), owner, repo.name),
         };
 
         let clone_url = match repo.clone_url {
             Some(url) => url.to_string(),
-            None => format!("https://github.com/{}/{}.git", &username, repo.name),
+            None => format!("{}/{}/{}.git", url, owner, repo.name),
         };
 
+        let visibility = match repo.visibility.as_deref() {
+            Some("internal") => Visibility::Internal,
+            Some("private") => Visibility::Private,
+            Some(_) => Visibility::Public,
+            None if repo.private.unwrap_or(false) => Visibility::Private,
+            None => Visibility::Public,
+        };
+        let parent = repo.parent.map(|parent| RepoRef {
+            owner: repo_owner(&parent, ""),
+            name: parent.name,
+        });
+
+        Ok(Repository {
+            full_name: repo
+                .full_name
+                .unwrap_or_else(|| format!("{owner}/{}", repo.name)),
+            name: repo.name,
+            owner,
+            description: repo.description,
+            default_branch: repo.default_branch,
+            private: visibility != Visibility::Public,
+            visibility,
+            fork: repo.fork.unwrap_or(false),
+            parent,
+            archived: repo.archived.unwrap_or(false),
+            mirror: repo.mirror_url.is_some(),
+            html_url: repo.html_url.map(|x| x.to_string()),
+            created_at: repo.created_at,
+            updated_at: repo.updated_at,
+            pushed_at: repo.pushed_at,
+            stars: repo.stargazers_count.unwrap_or(0).into(),
+            forks_count: repo.forks_count.unwrap_or(0).into(),
+            open_issues: repo.open_issues_count.unwrap_or(0).into(),
+            size: repo.size.map(u64::from),
+            language: repo.language.and_then(|x| x.as_str().map(str::to_string)),
+            topics: repo.topics.unwrap_or_default(),
+            last_commits,
+            ssh_url,
+            clone_url,
+        })
+    }
+
+    /// Get the latest `count` commits of the repository, without making a request if none are
+    /// wanted.
+    async fn last_commits(base: RepoHandler<'_>, count: u8) -> Result<Vec<Commit>> {
+        if count == 0 {
+            return Ok(Vec::new());
+        }
+        let commits = base.list_commits().per_page(count).send().await;
+
         use octocrab::Error::GitHub;
         let commits = match commits {
             Ok(x) => x,
@@ -247,33 +1402,6 @@ impl GitHubRemote {
             }
         };
 
-        let last_commits = commits
-            .items
-            .into_iter()
-            .map(|c| {
-                let author = c.commit.author.unwrap_or(CommitAuthor {
-                    name: "unknown".to_string(),
-                    email: "unknown".to_string(),
-                    date: None,
-                });
-                Commit {
-                    sha: c.sha,
-                    message: c.commit.message,
-                    author: author.name,
-                    date: author.date.unwrap_or_default(),
-                }
-            })
-            .collect();
-
-        Ok(Repository {
-            name: repo.name,
-            description: repo.description,
-            default_branch: repo.default_branch,
-            private: repo.private.unwrap_or(false),
-            fork: repo.fork.unwrap_or(false),
-            last_commits,
-            ssh_url,
-            clone_url,
-        })
+        Ok(commits.items.into_iter().map(Commit::from).collect())
     }
 }
@@ -1,10 +1,43 @@
 use crate::error::{Error, ErrorKind};
 use ::gitlab::{
     api::{
+        groups::{projects::GroupProjectsBuilderError, GroupBuilderError},
         projects::{
-            repository::commits::CommitsBuilderError, CreateProjectBuilderError,
-            DeleteProjectBuilderError, ProjectBuilderError, ProjectsBuilderError,
+            deploy_keys::{
+                CreateDeployKeyBuilderError, DeleteDeployKeyBuilderError, DeployKeysBuilderError,
+            },
+            hooks::{
+                CreateHookBuilderError, DeleteHookBuilderError, EditHookBuilderError,
+                HooksBuilderError,
+            },
+            issues::{
+                notes::{CreateIssueNoteBuilderError, IssueNotesBuilderError},
+                CreateIssueBuilderError, EditIssueBuilderError, IssueBuilderError,
+                IssuesBuilderError,
+            },
+            labels::{CreateLabelBuilderError, LabelsBuilderError},
+            merge_requests::{
+                notes::{CreateMergeRequestNoteBuilderError, MergeRequestNotesBuilderError},
+                ApproveMergeRequestBuilderError, CreateMergeRequestBuilderError,
+                MergeMergeRequestBuilderError, MergeRequestBuilderError, MergeRequestsBuilderError,
+            },
+            milestones::CreateProjectMilestoneBuilderError,
+            packages::generic::UploadPackageFileBuilderError,
+            protected_branches::{ProtectBranchBuilderError, UnprotectBranchBuilderError},
+            releases::{
+                links::CreateReleaseLinkBuilderError, CreateReleaseBuilderError,
+                ProjectReleasesBuilderError,
+            },
+            repository::{
+                branches::{BranchesBuilderError, CreateBranchBuilderError},
+                commits::CommitsBuilderError,
+                tags::{CreateTagBuilderError, TagsBuilderError},
+            },
+            ArchiveProjectBuilderError, CreateProjectBuilderError, DeleteProjectBuilderError,
+            EditProjectBuilderError, ProjectBuilderError, ProjectsBuilderError,
+            UnarchiveProjectBuilderError,
         },
+        users::UsersBuilderError,
         ApiError,
     },
     GitlabError, RestError,
@@ -32,7 +65,11 @@ impl<E: StdError + Send + Sync + 'static> From<ApiError<E>> for Error {
                 // data the gitlab client returns, so we have to check for it here.
                 match msg.as_str() {
                     "401 Unauthorized" | "invalid_token" => Error::authentication(msg),
-                    _ => Error::other(msg),
+                    // The status is lost for JSON errors, but GitLab starts its messages with it.
+                    _ => Error {
+                        status: msg.split(' ').next().and_then(|x| x.parse().ok()),
+                        ..Error::other(msg)
+                    },
                 }
             }
             ApiError::GitlabService { status, data: _ } => Error {
@@ -96,6 +133,45 @@ impl From<DeleteProjectBuilderError> for Error {
         }
     }
 }
+impl From<EditProjectBuilderError> for Error {
+    fn from(value: EditProjectBuilderError) -> Self {
+        match value {
+            EditProjectBuilderError::UninitializedField(field) => Error::other(format!(
+                "Could not edit project: field {field} is not initialized"
+            )),
+            EditProjectBuilderError::ValidationError(msg) => {
+                Error::other(format!("Could not edit project: {msg}"))
+            }
+            x => Error::other(format!("Could not edit project: {x}")),
+        }
+    }
+}
+impl From<ArchiveProjectBuilderError> for Error {
+    fn from(value: ArchiveProjectBuilderError) -> Self {
+        match value {
+            ArchiveProjectBuilderError::UninitializedField(field) => Error::other(format!(
+                "Could not archive project: field {field} is not initialized"
+            )),
+            ArchiveProjectBuilderError::ValidationError(msg) => {
+                Error::other(format!("Could not archive project: {msg}"))
+            }
+            x => Error::other(format!("Could not archive project: {x}")),
+        }
+    }
+}
+impl From<UnarchiveProjectBuilderError> for Error {
+    fn from(value: UnarchiveProjectBuilderError) -> Self {
+        match value {
+            UnarchiveProjectBuilderError::UninitializedField(field) => Error::other(format!(
+                "Could not unarchive project: field {field} is not initialized"
+            )),
+            UnarchiveProjectBuilderError::ValidationError(msg) => {
+                Error::other(format!("Could not unarchive project: {msg}"))
+            }
+            x => Error::other(format!("Could not unarchive project: {x}")),
+        }
+    }
+}
 impl From<ProjectBuilderError> for Error {
     fn from(value: ProjectBuilderError) -> Self {
         match value {
@@ -149,6 +225,476 @@ impl From<CreateProjectBuilderError> for Error {
     }
 }
 
+impl From<GroupBuilderError> for Error {
+    fn from(value: GroupBuilderError) -> Self {
+        match value {
+            GroupBuilderError::UninitializedField(field) => Error::other(format!(
+                "Could not get group: field {field} is not initialized"
+            )),
+            GroupBuilderError::ValidationError(msg) => {
+                Error::other(format!("Could not get group: {msg}"))
+            }
+            x => Error::other(format!("Could not get group: {x}")),
+        }
+    }
+}
+impl From<GroupProjectsBuilderError> for Error {
+    fn from(value: GroupProjectsBuilderError) -> Self {
+        match value {
+            GroupProjectsBuilderError::UninitializedField(field) => Error::other(format!(
+                "Could not list group projects: field {field} is not initialized"
+            )),
+            GroupProjectsBuilderError::ValidationError(msg) => {
+                Error::other(format!("Could not list group projects: {msg}"))
+            }
+            x => Error::other(format!("Could not list group projects: {x}")),
+        }
+    }
+}
+
+impl From<LabelsBuilderError> for Error {
+    fn from(value: LabelsBuilderError) -> Self {
+        match value {
+            LabelsBuilderError::UninitializedField(field) => Error::other(format!(
+                "Could not list labels: field {field} is not initialized"
+            )),
+            LabelsBuilderError::ValidationError(msg) => {
+                Error::other(format!("Could not list labels: {msg}"))
+            }
+            x => Error::other(format!("Could not list labels: {x}")),
+        }
+    }
+}
+impl From<CreateLabelBuilderError> for Error {
+    fn from(value: CreateLabelBuilderError) -> Self {
+        match value {
+            CreateLabelBuilderError::UninitializedField(field) => Error::other(format!(
+                "Could not create label: field {field} is not initialized"
+            )),
+            CreateLabelBuilderError::ValidationError(msg) => {
+                Error::other(format!("Could not create label: {msg}"))
+            }
+            x => Error::other(format!("Could not create label: {x}")),
+        }
+    }
+}
+impl From<CreateProjectMilestoneBuilderError> for Error {
+    fn from(value: CreateProjectMilestoneBuilderError) -> Self {
+        match value {
+            CreateProjectMilestoneBuilderError::UninitializedField(field) => Error::other(format!(
+                "Could not create milestone: field {field} is not initialized"
+            )),
+            CreateProjectMilestoneBuilderError::ValidationError(msg) => {
+                Error::other(format!("Could not create milestone: {msg}"))
+            }
+            x => Error::other(format!("Could not create milestone: {x}")),
+        }
+    }
+}
+impl From<IssuesBuilderError> for Error {
+    fn from(value: IssuesBuilderError) -> Self {
+        match value {
+            IssuesBuilderError::UninitializedField(field) => Error::other(format!(
+                "Could not list issues: field {field} is not initialized"
+            )),
+            IssuesBuilderError::ValidationError(msg) => {
+                Error::other(format!("Could not list issues: {msg}"))
+            }
+            x => Error::other(format!("Could not list issues: {x}")),
+        }
+    }
+}
+impl From<CreateIssueBuilderError> for Error {
+    fn from(value: CreateIssueBuilderError) -> Self {
+        match value {
+            CreateIssueBuilderError::UninitializedField(field) => Error::other(format!(
+                "Could not create issue: field {field} is not initialized"
+            )),
+            CreateIssueBuilderError::ValidationError(msg) => {
+                Error::other(format!("Could not create issue: {msg}"))
+            }
+            x => Error::other(format!("Could not create issue: {x}")),
+        }
+    }
+}
+impl From<EditIssueBuilderError> for Error {
+    fn from(value: EditIssueBuilderError) -> Self {
+        match value {
+            EditIssueBuilderError::UninitializedField(field) => Error::other(format!(
+                "Could not edit issue: field {field} is not initialized"
+            )),
+            EditIssueBuilderError::ValidationError(msg) => {
+                Error::other(format!("Could not edit issue: {msg}"))
+            }
+            x => Error::other(format!("Could not edit issue: {x}")),
+        }
+    }
+}
+impl From<IssueNotesBuilderError> for Error {
+    fn from(value: IssueNotesBuilderError) -> Self {
+        match value {
+            IssueNotesBuilderError::UninitializedField(field) => Error::other(format!(
+                "Could not list issue comments: field {field} is not initialized"
+            )),
+            IssueNotesBuilderError::ValidationError(msg) => {
+                Error::other(format!("Could not list issue comments: {msg}"))
+            }
+            x => Error::other(format!("Could not list issue comments: {x}")),
+        }
+    }
+}
+impl From<CreateIssueNoteBuilderError> for Error {
+    fn from(value: CreateIssueNoteBuilderError) -> Self {
+        match value {
+            CreateIssueNoteBuilderError::UninitializedField(field) => Error::other(format!(
+                "Could not create issue comment: field {field} is not initialized"
+            )),
+            CreateIssueNoteBuilderError::ValidationError(msg) => {
+                Error::other(format!("Could not create issue comment: {msg}"))
+            }
+            x => Error::other(format!("Could not create issue comment: {x}")),
+        }
+    }
+}
+impl From<MergeRequestsBuilderError> for Error {
+    fn from(value: MergeRequestsBuilderError) -> Self {
+        match value {
+            MergeRequestsBuilderError::UninitializedField(field) => Error::other(format!(
+                "Could not list merge requests: field {field} is not initialized"
+            )),
+            MergeRequestsBuilderError::ValidationError(msg) => {
+                Error::other(format!("Could not list merge requests: {msg}"))
+            }
+            x => Error::other(format!("Could not list merge requests: {x}")),
+        }
+    }
+}
+impl From<MergeRequestNotesBuilderError> for Error {
+    fn from(value: MergeRequestNotesBuilderError) -> Self {
+        match value {
+            MergeRequestNotesBuilderError::UninitializedField(field) => Error::other(format!(
+                "Could not list merge request comments: field {field} is not initialized"
+            )),
+            MergeRequestNotesBuilderError::ValidationError(msg) => {
+                Error::other(format!("Could not list merge request comments: {msg}"))
+            }
+            x => Error::other(format!("Could not list merge request comments: {x}")),
+        }
+    }
+}
+impl From<ProjectReleasesBuilderError> for Error {
+    fn from(value: ProjectReleasesBuilderError) -> Self {
+        match value {
+            ProjectReleasesBuilderError::UninitializedField(field) => Error::other(format!(
+                "Could not list releases: field {field} is not initialized"
+            )),
+            ProjectReleasesBuilderError::ValidationError(msg) => {
+                Error::other(format!("Could not list releases: {msg}"))
+            }
+            x => Error::other(format!("Could not list releases: {x}")),
+        }
+    }
+}
+impl From<CreateReleaseBuilderError> for Error {
+    fn from(value: CreateReleaseBuilderError) -> Self {
+        match value {
+            CreateReleaseBuilderError::UninitializedField(field) => Error::other(format!(
+                "Could not create release: field {field} is not initialized"
+            )),
+            CreateReleaseBuilderError::ValidationError(msg) => {
+                Error::other(format!("Could not create release: {msg}"))
+            }
+            x => Error::other(format!("Could not create release: {x}")),
+        }
+    }
+}
+impl From<IssueBuilderError> for Error {
+    fn from(value: IssueBuilderError) -> Self {
+        match value {
+            IssueBuilderError::UninitializedField(field) => Error::other(format!(
+                "Could not get issue: field {field} is not initialized"
+            )),
+            IssueBuilderError::ValidationError(msg) => {
+                Error::other(format!("Could not get issue: {msg}"))
+            }
+            x => Error::other(format!("Could not get issue: {x}")),
+        }
+    }
+}
+impl From<UsersBuilderError> for Error {
+    fn from(value: UsersBuilderError) -> Self {
+        match value {
+            UsersBuilderError::UninitializedField(field) => Error::other(format!(
+                "Could not find user: field {field} is not initialized"
+            )),
+            UsersBuilderError::ValidationError(msg) => {
+                Error::other(format!("Could not find user: {msg}"))
+            }
+            x => Error::other(format!("Could not find user: {x}")),
+        }
+    }
+}
+impl From<MergeRequestBuilderError> for Error {
+    fn from(value: MergeRequestBuilderError) -> Self {
+        match value {
+            MergeRequestBuilderError::UninitializedField(field) => Error::other(format!(
+                "Could not get merge request: field {field} is not initialized"
+            )),
+            MergeRequestBuilderError::ValidationError(msg) => {
+                Error::other(format!("Could not get merge request: {msg}"))
+            }
+            x => Error::other(format!("Could not get merge request: {x}")),
+        }
+    }
+}
+impl From<CreateMergeRequestBuilderError> for Error {
+    fn from(value: CreateMergeRequestBuilderError) -> Self {
+        match value {
+            CreateMergeRequestBuilderError::UninitializedField(field) => Error::other(format!(
+                "Could not create merge request: field {field} is not initialized"
+            )),
+            CreateMergeRequestBuilderError::ValidationError(msg) => {
+                Error::other(format!("Could not create merge request: {msg}"))
+            }
+            x => Error::other(format!("Could not create merge request: {x}")),
+        }
+    }
+}
+impl From<CreateMergeRequestNoteBuilderError> for Error {
+    fn from(value: CreateMergeRequestNoteBuilderError) -> Self {
+        match value {
+            CreateMergeRequestNoteBuilderError::UninitializedField(field) => Error::other(format!(
+                "Could not create merge request comment: field {field} is not initialized"
+            )),
+            CreateMergeRequestNoteBuilderError::ValidationError(msg) => {
+                Error::other(format!("Could not create merge request comment: {msg}"))
+            }
+            x => Error::other(format!("Could not create merge request comment: {x}")),
+        }
+    }
+}
+impl From<ApproveMergeRequestBuilderError> for Error {
+    fn from(value: ApproveMergeRequestBuilderError) -> Self {
+        match value {
+            ApproveMergeRequestBuilderError::UninitializedField(field) => Error::other(format!(
+                "Could not approve merge request: field {field} is not initialized"
+            )),
+            ApproveMergeRequestBuilderError::ValidationError(msg) => {
+                Error::other(format!("Could not approve merge request: {msg}"))
+            }
+            x => Error::other(format!("Could not approve merge request: {x}")),
+        }
+    }
+}
+impl From<MergeMergeRequestBuilderError> for Error {
+    fn from(value: MergeMergeRequestBuilderError) -> Self {
+        match value {
+            MergeMergeRequestBuilderError::UninitializedField(field) => Error::other(format!(
+                "Could not merge merge request: field {field} is not initialized"
+            )),
+            MergeMergeRequestBuilderError::ValidationError(msg) => {
+                Error::other(format!("Could not merge merge request: {msg}"))
+            }
+            x => Error::other(format!("Could not merge merge request: {x}")),
+        }
+    }
+}
+impl From<UploadPackageFileBuilderError> for Error {
+    fn from(value: UploadPackageFileBuilderError) -> Self {
+        match value {
+            UploadPackageFileBuilderError::UninitializedField(field) => Error::other(format!(
+                "Could not upload package file: field {field} is not initialized"
+            )),
+            UploadPackageFileBuilderError::ValidationError(msg) => {
+                Error::other(format!("Could not upload package file: {msg}"))
+            }
+            x => Error::other(format!("Could not upload package file: {x}")),
+        }
+    }
+}
+impl From<CreateReleaseLinkBuilderError> for Error {
+    fn from(value: CreateReleaseLinkBuilderError) -> Self {
+        match value {
+            CreateReleaseLinkBuilderError::UninitializedField(field) => Error::other(format!(
+                "Could not create release link: field {field} is not initialized"
+            )),
+            CreateReleaseLinkBuilderError::ValidationError(msg) => {
+                Error::other(format!("Could not create release link: {msg}"))
+            }
+            x => Error::other(format!("Could not create release link: {x}")),
+        }
+    }
+}
+impl From<BranchesBuilderError> for Error {
+    fn from(value: BranchesBuilderError) -> Self {
+        match value {
+            BranchesBuilderError::UninitializedField(field) => Error::other(format!(
+                "Could not list branches: field {field} is not initialized"
+            )),
+            BranchesBuilderError::ValidationError(msg) => {
+                Error::other(format!("Could not list branches: {msg}"))
+            }
+            x => Error::other(format!("Could not list branches: {x}")),
+        }
+    }
+}
+impl From<CreateBranchBuilderError> for Error {
+    fn from(value: CreateBranchBuilderError) -> Self {
+        match value {
+            CreateBranchBuilderError::UninitializedField(field) => Error::other(format!(
+                "Could not create branch: field {field} is not initialized"
+            )),
+            CreateBranchBuilderError::ValidationError(msg) => {
+                Error::other(format!("Could not create branch: {msg}"))
+            }
+            x => Error::other(format!("Could not create branch: {x}")),
+        }
+    }
+}
+impl From<UnprotectBranchBuilderError> for Error {
+    fn from(value: UnprotectBranchBuilderError) -> Self {
+        match value {
+            UnprotectBranchBuilderError::UninitializedField(field) => Error::other(format!(
+                "Could not unprotect branch: field {field} is not initialized"
+            )),
+            UnprotectBranchBuilderError::ValidationError(msg) => {
+                Error::other(format!("Could not unprotect branch: {msg}"))
+            }
+            x => Error::other(format!("Could not unprotect branch: {x}")),
+        }
+    }
+}
+impl From<ProtectBranchBuilderError> for Error {
+    fn from(value: ProtectBranchBuilderError) -> Self {
+        match value {
+            ProtectBranchBuilderError::UninitializedField(field) => Error::other(format!(
+                "Could not protect branch: field {field} is not initialized"
+            )),
+            ProtectBranchBuilderError::ValidationError(msg) => {
+                Error::other(format!("Could not protect branch: {msg}"))
+            }
+            x => Error::other(format!("Could not protect branch: {x}")),
+        }
+    }
+}
+impl From<TagsBuilderError> for Error {
+    fn from(value: TagsBuilderError) -> Self {
+        match value {
+            TagsBuilderError::UninitializedField(field) => Error::other(format!(
+                "Could not list tags: field {field} is not initialized"
+            )),
+            TagsBuilderError::ValidationError(msg) => {
+                Error::other(format!("Could not list tags: {msg}"))
+            }
+            x => Error::other(format!("Could not list tags: {x}")),
+        }
+    }
+}
+impl From<CreateTagBuilderError> for Error {
+    fn from(value: CreateTagBuilderError) -> Self {
+        match value {
+            CreateTagBuilderError::UninitializedField(field) => Error::other(format!(
+                "Could not create tag: field {field} is not initialized"
+            )),
+            CreateTagBuilderError::ValidationError(msg) => {
+                Error::other(format!("Could not create tag: {msg}"))
+            }
+            x => Error::other(format!("Could not create tag: {x}")),
+        }
+    }
+}
+impl From<HooksBuilderError> for Error {
+    fn from(value: HooksBuilderError) -> Self {
+        match value {
+            HooksBuilderError::UninitializedField(field) => Error::other(format!(
+                "Could not list webhooks: field {field} is not initialized"
+            )),
+            HooksBuilderError::ValidationError(msg) => {
+                Error::other(format!("Could not list webhooks: {msg}"))
+            }
+            x => Error::other(format!("Could not list webhooks: {x}")),
+        }
+    }
+}
+impl From<CreateHookBuilderError> for Error {
+    fn from(value: CreateHookBuilderError) -> Self {
+        match value {
+            CreateHookBuilderError::UninitializedField(field) => Error::other(format!(
+                "Could not create webhook: field {field} is not initialized"
+            )),
+            CreateHookBuilderError::ValidationError(msg) => {
+                Error::other(format!("Could not create webhook: {msg}"))
+            }
+            x => Error::other(format!("Could not create webhook: {x}")),
+        }
+    }
+}
+impl From<EditHookBuilderError> for Error {
+    fn from(value: EditHookBuilderError) -> Self {
+        match value {
+            EditHookBuilderError::UninitializedField(field) => Error::other(format!(
+                "Could not update webhook: field {field} is not initialized"
+            )),
+            EditHookBuilderError::ValidationError(msg) => {
+                Error::other(format!("Could not update webhook: {msg}"))
+            }
+            x => Error::other(format!("Could not update webhook: {x}")),
+        }
+    }
+}
+impl From<DeleteHookBuilderError> for Error {
+    fn from(value: DeleteHookBuilderError) -> Self {
+        match value {
+            DeleteHookBuilderError::UninitializedField(field) => Error::other(format!(
+                "Could not delete webhook: field {field} is not initialized"
+            )),
+            DeleteHookBuilderError::ValidationError(msg) => {
+                Error::other(format!("Could not delete webhook: {msg}"))
+            }
+            x => Error::other(format!("Could not delete webhook: {x}")),
+        }
+    }
+}
+impl From<DeployKeysBuilderError> for Error {
+    fn from(value: DeployKeysBuilderError) -> Self {
+        match value {
+            DeployKeysBuilderError::UninitializedField(field) => Error::other(format!(
+                "Could not list deploy keys: field {field} is not initialized"
+            )),
+            DeployKeysBuilderError::ValidationError(msg) => {
+                Error::other(format!("Could not list deploy keys: {msg}"))
+            }
+            x => Error::other(format!("Could not list deploy keys: {x}")),
+        }
+    }
+}
+impl From<CreateDeployKeyBuilderError> for Error {
+    fn from(value: CreateDeployKeyBuilderError) -> Self {
+        match value {
+            CreateDeployKeyBuilderError::UninitializedField(field) => Error::other(format!(
+                "Could not add deploy key: field {field} is not initialized"
+            )),
+            CreateDeployKeyBuilderError::ValidationError(msg) => {
+                Error::other(format!("Could not add deploy key: {msg}"))
+            }
+            x => Error::other(format!("Could not add deploy key: {x}")),
+        }
+    }
+}
+impl From<DeleteDeployKeyBuilderError> for Error {
+    fn from(value: DeleteDeployKeyBuilderError) -> Self {
+        match value {
+            DeleteDeployKeyBuilderError::UninitializedField(field) => Error::other(format!(
+                "Could not remove deploy key: field {field} is not initialized"
+            )),
+            DeleteDeployKeyBuilderError::ValidationError(msg) => {
+                Error::other(format!("Could not remove deploy key: {msg}"))
+            }
+            x => Error::other(format!("Could not remove deploy key: {x}")),
+        }
+    }
+}
+
 impl From<RestError> for Error {
     fn from(value: RestError) -> Self {
         match value {
@@ -1,43 +1,857 @@
 use super::{
-    Auth, ListReposInfo, Remote, RemoteConfig, RepoCreateInfo, RepoForkOption, Repository,
-};
-use crate::{
-    error::{Error, Result},
-    remote::COMMIT_COUNT,
+    fetch_concurrently, Auth, Branch, BranchProtection, Comment, DeployKey, Issue, IssueCreateInfo,
+    IssueState, Label, ListIssuesInfo, ListPullRequestsInfo, ListReposInfo, MergeMethod, Milestone,
+    Mirror, MirrorCreateInfo, MirrorDirection, PullRequest, PullRequestCreateInfo,
+    PullRequestMergeInfo, PullRequestState, Release, ReleaseAsset, ReleaseCreateInfo, Remote,
+    RemoteConfig, RepoCreateInfo, RepoForkOption, RepoImportInfo, RepoRef, RepoUpdateInfo,
+    Repository, SshKey, SshKeyCreateInfo, Tag, TagCreateInfo, Visibility, Webhook,
+    WebhookContentType, WebhookCreateInfo, WebhookEvent, WebhookUpdateInfo, WikiPage,
 };
+use crate::error::{Error, Result};
 use ::gitlab as gl;
 use async_trait::async_trait;
-use chrono::DateTime;
-use gitlab::api::users::CurrentUser;
+use chrono::{DateTime, NaiveDate, Utc};
+use futures::StreamExt;
+use gitlab::api::users::{CurrentUser, Users};
 use gl::api::{
     self,
-    common::VisibilityLevel,
-    endpoint_prelude::Method,
-    projects::{self, repository::commits::Commits, CreateProject, Projects},
-    ApiError, AsyncQuery, Endpoint, Pagination,
+    common::{ProtectedAccessLevel, SortOrder, VisibilityLevel},
+    endpoint_prelude::{BodyError, FormParams, Method, QueryParams},
+    groups::{projects::GroupProjects, Group},
+    projects::{
+        self,
+        deploy_keys::{CreateDeployKey, DeleteDeployKey, DeployKeys},
+        hooks::{CreateHook, DeleteHook, EditHook, Hooks},
+        issues::notes::NoteOrderBy,
+        packages::generic::UploadPackageFile,
+        protected_branches::{ProtectBranch, ProtectedAccess, UnprotectBranch},
+        releases::links::{CreateReleaseLink, LinkType},
+        repository::{
+            branches::{Branches, CreateBranch},
+            commits::Commits,
+            tags::{CreateTag, Tags},
+        },
+        ArchiveProject, CreateProject, EditProject, FeatureAccessLevel, Projects, UnarchiveProject,
+    },
+    ApiError, AsyncQuery, Endpoint, Pageable, Pagination,
 };
-use serde::{de::IgnoredAny, Deserialize};
-use std::{borrow::Cow, str::FromStr};
+use serde::Deserialize;
+use std::{borrow::Cow, pin::pin, sync::Arc};
 
 pub mod error;
 
 pub struct GitlabRemote {
     config: RemoteConfig,
-    client: gl::AsyncGitlab,
+    /// The API client, shared with the tasks fetching repository details.
+    client: Arc<gl::AsyncGitlab>,
 }
 
 /// A project in Gitlab.
 #[derive(Debug, Deserialize)]
 struct Project {
-    name: String,
+    /// The last part of the path, which identifies the project in its namespace. Unlike the
+    /// display name, it can't contain spaces.
+    path: String,
+    path_with_namespace: String,
+    namespace: Namespace,
     description: Option<String>,
     default_branch: Option<String>,
-    forked_from_project: Option<IgnoredAny>,
+    forked_from_project: Option<ForkedFrom>,
     ssh_url_to_repo: String,
     http_url_to_repo: String,
+    web_url: Option<String>,
     visibility: String,
+    #[serde(default)]
+    archived: bool,
+    /// Whether the project is a pull mirror. Only present with GitLab Premium.
+    #[serde(default)]
+    mirror: bool,
+    /// The URL a pull mirror is updated from. Only shown to maintainers.
+    import_url: Option<String>,
     empty_repo: bool,
     id: u64,
+    created_at: Option<DateTime<Utc>>,
+    updated_at: Option<DateTime<Utc>>,
+    last_activity_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    star_count: u64,
+    #[serde(default)]
+    forks_count: u64,
+    /// Only present if the issue tracker is enabled.
+    open_issues_count: Option<u64>,
+    /// Called `tag_list` before GitLab 14.
+    #[serde(default, alias = "tag_list")]
+    topics: Vec<String>,
+}
+
+/// The project a fork was created from.
+#[derive(Debug, Deserialize)]
+struct ForkedFrom {
+    path_with_namespace: String,
+}
+
+/// The namespace (user or group) a project belongs to.
+#[derive(Debug, Deserialize)]
+struct Namespace {
+    /// The full path of the namespace, including any parent groups.
+    full_path: String,
+}
+
+/// Moves a project to another namespace. Not covered by the gitlab crate.
+struct TransferProject {
+    project: String,
+    namespace: String,
+}
+
+impl Endpoint for TransferProject {
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/transfer", urlencoding::encode(&self.project)).into()
+    }
+    fn body(&self) -> std::result::Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+        params.push("namespace", self.namespace.as_str());
+        params.into_body()
+    }
+}
+
+/// Lists the push mirrors of a project. Not covered by the gitlab crate.
+struct RemoteMirrors {
+    project: String,
+}
+
+impl Endpoint for RemoteMirrors {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/remote_mirrors",
+            urlencoding::encode(&self.project)
+        )
+        .into()
+    }
+}
+
+/// Adds a push mirror to a project. Not covered by the gitlab crate.
+struct CreateRemoteMirror {
+    project: String,
+    url: String,
+}
+
+impl Endpoint for CreateRemoteMirror {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/remote_mirrors",
+            urlencoding::encode(&self.project)
+        )
+        .into()
+    }
+    fn body(&self) -> std::result::Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+        params.push("url", self.url.as_str()).push("enabled", true);
+        params.into_body()
+    }
+}
+
+/// A push mirror of a project.
+#[derive(Debug, Deserialize)]
+struct RemoteMirror {
+    /// The URL of the mirror, with the credentials masked.
+    url: String,
+    last_update_at: Option<DateTime<Utc>>,
+    last_error: Option<String>,
+}
+
+impl From<RemoteMirror> for Mirror {
+    fn from(mirror: RemoteMirror) -> Self {
+        Self {
+            url: mirror.url,
+            direction: MirrorDirection::Push,
+            interval: None,
+            last_update: mirror.last_update_at,
+            last_error: mirror.last_error,
+        }
+    }
+}
+
+/// Lists the milestones of a project. Not covered by the gitlab crate.
+struct ProjectMilestones {
+    project: String,
+}
+
+impl Endpoint for ProjectMilestones {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/milestones", urlencoding::encode(&self.project)).into()
+    }
+}
+
+impl Pageable for ProjectMilestones {}
+
+/// Closes a milestone. Not covered by the gitlab crate.
+struct CloseMilestone {
+    project: String,
+    milestone: u64,
+}
+
+impl Endpoint for CloseMilestone {
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/milestones/{}",
+            urlencoding::encode(&self.project),
+            self.milestone
+        )
+        .into()
+    }
+    fn body(&self) -> std::result::Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+        params.push("state_event", "close");
+        params.into_body()
+    }
+}
+
+/// Lists the wiki pages of a project with their content. Not covered by the gitlab crate.
+struct WikiPages {
+    project: String,
+}
+
+impl Endpoint for WikiPages {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/wikis", urlencoding::encode(&self.project)).into()
+    }
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+        params.push("with_content", true);
+        params
+    }
+}
+
+/// Adds a page to the wiki of a project. Not covered by the gitlab crate.
+struct CreateWikiPage {
+    project: String,
+    page: WikiPage,
+}
+
+impl Endpoint for CreateWikiPage {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("projects/{}/wikis", urlencoding::encode(&self.project)).into()
+    }
+    fn body(&self) -> std::result::Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+        params
+            .push("title", self.page.title.as_str())
+            .push("content", self.page.content.as_str());
+        params.into_body()
+    }
+}
+
+/// Gets the release for a tag. Not covered by the gitlab crate.
+struct ProjectRelease {
+    project: String,
+    tag: String,
+}
+
+impl Endpoint for ProjectRelease {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/releases/{}",
+            urlencoding::encode(&self.project),
+            urlencoding::encode(&self.tag)
+        )
+        .into()
+    }
+}
+
+/// Deletes the release for a tag, keeping the tag. Not covered by the gitlab crate.
+struct DeleteRelease {
+    project: String,
+    tag: String,
+}
+
+impl Endpoint for DeleteRelease {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/releases/{}",
+            urlencoding::encode(&self.project),
+            urlencoding::encode(&self.tag)
+        )
+        .into()
+    }
+}
+
+/// Deletes a branch. Not covered by the gitlab crate.
+struct DeleteBranch {
+    project: String,
+    branch: String,
+}
+
+impl Endpoint for DeleteBranch {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/repository/branches/{}",
+            urlencoding::encode(&self.project),
+            urlencoding::encode(&self.branch)
+        )
+        .into()
+    }
+}
+
+/// Deletes a tag. Not covered by the gitlab crate.
+struct DeleteTag {
+    project: String,
+    tag: String,
+}
+
+impl Endpoint for DeleteTag {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/repository/tags/{}",
+            urlencoding::encode(&self.project),
+            urlencoding::encode(&self.tag)
+        )
+        .into()
+    }
+}
+
+/// Sends a test push event to a webhook. Not covered by the gitlab crate.
+struct TestHook {
+    project: String,
+    id: u64,
+}
+
+impl Endpoint for TestHook {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "projects/{}/hooks/{}/test/push_events",
+            urlencoding::encode(&self.project),
+            self.id
+        )
+        .into()
+    }
+}
+
+/// Lists the SSH keys of the authenticated user. Not covered by the gitlab crate.
+struct UserKeys;
+
+impl Endpoint for UserKeys {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    fn endpoint(&self) -> Cow<'static, str> {
+        "user/keys".into()
+    }
+}
+
+impl Pageable for UserKeys {}
+
+/// Adds an SSH key to the authenticated user. Not covered by the gitlab crate.
+struct CreateUserKey {
+    key: SshKeyCreateInfo,
+}
+
+impl Endpoint for CreateUserKey {
+    fn method(&self) -> Method {
+        Method::POST
+    }
+    fn endpoint(&self) -> Cow<'static, str> {
+        "user/keys".into()
+    }
+    fn body(&self) -> std::result::Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = FormParams::default();
+        params
+            .push("title", self.key.title.as_str())
+            .push("key", self.key.key.as_str());
+        params.into_body()
+    }
+}
+
+/// Removes an SSH key from the authenticated user. Not covered by the gitlab crate.
+struct DeleteUserKey {
+    id: u64,
+}
+
+impl Endpoint for DeleteUserKey {
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("user/keys/{}", self.id).into()
+    }
+}
+
+/// A deploy key of a project.
+#[derive(Debug, Deserialize)]
+struct GitlabDeployKey {
+    id: u64,
+    title: String,
+    key: String,
+    can_push: bool,
+}
+
+impl From<GitlabDeployKey> for DeployKey {
+    fn from(key: GitlabDeployKey) -> Self {
+        Self {
+            id: key.id,
+            title: key.title,
+            key: key.key,
+            read_only: !key.can_push,
+        }
+    }
+}
+
+/// Gets an API path as is, for the URLs of release links. Not covered by the gitlab crate.
+struct ApiPath {
+    /// The path below `/api/v4/`, already escaped.
+    path: String,
+}
+
+impl Endpoint for ApiPath {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    fn endpoint(&self) -> Cow<'static, str> {
+        self.path.clone().into()
+    }
+}
+
+/// The parts of a user we need, as returned in issues, notes and releases.
+#[derive(Debug, Deserialize)]
+struct User {
+    username: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitlabLabel {
+    name: String,
+    /// The color with a leading `#`.
+    color: String,
+    description: Option<String>,
+}
+
+impl From<GitlabLabel> for Label {
+    fn from(label: GitlabLabel) -> Self {
+        Self {
+            name: label.name,
+            color: label.color.trim_start_matches('#').to_string(),
+            description: label.description.filter(|x| !x.is_empty()),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct GitlabMilestone {
+    id: u64,
+    title: String,
+    description: Option<String>,
+    /// `active` or `closed`.
+    state: String,
+    due_date: Option<NaiveDate>,
+}
+
+impl From<GitlabMilestone> for Milestone {
+    fn from(milestone: GitlabMilestone) -> Self {
+        Self {
+            id: milestone.id,
+            title: milestone.title,
+            description: milestone.description.filter(|x| !x.is_empty()),
+            state: issue_state(&milestone.state),
+            due_on: milestone
+                .due_date
+                .and_then(|x| x.and_hms_opt(0, 0, 0))
+                .map(|x| x.and_utc()),
+        }
+    }
+}
+
+/// A reference to a milestone, as returned in issues and merge requests.
+#[derive(Debug, Deserialize)]
+struct MilestoneRef {
+    title: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitlabIssue {
+    iid: u64,
+    title: String,
+    description: Option<String>,
+    /// `opened` or `closed`.
+    state: String,
+    author: User,
+    #[serde(default)]
+    labels: Vec<String>,
+    #[serde(default)]
+    assignees: Vec<User>,
+    milestone: Option<MilestoneRef>,
+    web_url: Option<String>,
+    created_at: DateTime<Utc>,
+    updated_at: Option<DateTime<Utc>>,
+    closed_at: Option<DateTime<Utc>>,
+}
+
+impl From<GitlabIssue> for Issue {
+    fn from(issue: GitlabIssue) -> Self {
+        Self {
+            number: issue.iid,
+            title: issue.title,
+            body: issue.description,
+            state: issue_state(&issue.state),
+            author: issue.author.username,
+            labels: issue.labels,
+            assignees: issue.assignees.into_iter().map(|x| x.username).collect(),
+            milestone: issue.milestone.map(|x| x.title),
+            html_url: issue.web_url,
+            created_at: issue.created_at,
+            updated_at: issue.updated_at,
+            closed_at: issue.closed_at,
+        }
+    }
+}
+
+/// A comment on an issue or merge request.
+#[derive(Debug, Deserialize)]
+struct Note {
+    id: u64,
+    body: String,
+    author: User,
+    created_at: DateTime<Utc>,
+    /// Whether GitLab added the note itself, for changes like labels or assignees.
+    #[serde(default)]
+    system: bool,
+}
+
+impl From<Note> for Comment {
+    fn from(note: Note) -> Self {
+        Self {
+            id: note.id,
+            author: note.author.username,
+            body: note.body,
+            created_at: note.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct MergeRequest {
+    iid: u64,
+    title: String,
+    description: Option<String>,
+    /// `opened`, `closed`, `locked` or `merged`.
+    state: String,
+    author: User,
+    source_branch: String,
+    target_branch: String,
+    /// Called `work_in_progress` before GitLab 14.
+    #[serde(default, alias = "work_in_progress")]
+    draft: bool,
+    #[serde(default)]
+    labels: Vec<String>,
+    milestone: Option<MilestoneRef>,
+    web_url: Option<String>,
+    created_at: DateTime<Utc>,
+    updated_at: Option<DateTime<Utc>>,
+    closed_at: Option<DateTime<Utc>>,
+    merged_at: Option<DateTime<Utc>>,
+}
+
+impl From<MergeRequest> for PullRequest {
+    fn from(merge_request: MergeRequest) -> Self {
+        let state = match merge_request.state.as_str() {
+            "merged" => PullRequestState::Merged,
+            "closed" => PullRequestState::Closed,
+            _ => PullRequestState::Open,
+        };
+        Self {
+            number: merge_request.iid,
+            title: merge_request.title,
+            body: merge_request.description,
+            state,
+            author: merge_request.author.username,
+            head: merge_request.source_branch,
+            base: merge_request.target_branch,
+            draft: merge_request.draft,
+            labels: merge_request.labels,
+            milestone: merge_request.milestone.map(|x| x.title),
+            html_url: merge_request.web_url,
+            created_at: merge_request.created_at,
+            updated_at: merge_request.updated_at,
+            closed_at: merge_request.closed_at,
+            merged_at: merge_request.merged_at,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct GitlabRelease {
+    tag_name: String,
+    name: Option<String>,
+    description: Option<String>,
+    author: Option<User>,
+    created_at: Option<DateTime<Utc>>,
+    released_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    assets: ReleaseAssets,
+    #[serde(default, rename = "_links")]
+    links: ReleaseLinks,
+}
+
+/// The assets of a release. Only the links are files, the rest are generated source archives.
+#[derive(Debug, Default, Deserialize)]
+struct ReleaseAssets {
+    #[serde(default)]
+    links: Vec<ReleaseLink>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ReleaseLink {
+    name: String,
+    url: String,
+    /// The permanent URL of the asset, which redirects to `url`.
+    direct_asset_url: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct ReleaseLinks {
+    #[serde(rename = "self")]
+    html: Option<String>,
+}
+
+impl From<GitlabRelease> for Release {
+    fn from(release: GitlabRelease) -> Self {
+        Self {
+            name: release.name.filter(|x| x != &release.tag_name),
+            tag: release.tag_name,
+            body: release.description.filter(|x| !x.is_empty()),
+            // GitLab has neither drafts nor prereleases.
+            draft: false,
+            prerelease: false,
+            author: release.author.map(|x| x.username),
+            html_url: release.links.html,
+            created_at: release.created_at,
+            published_at: release.released_at,
+            assets: release
+                .assets
+                .links
+                .into_iter()
+                .map(|x| ReleaseAsset {
+                    name: x.name,
+                    size: None,
+                    download_url: x.direct_asset_url.unwrap_or(x.url),
+                })
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct GitlabCommit {
+    id: String,
+    message: String,
+    author_name: String,
+    committed_date: DateTime<Utc>,
+}
+
+impl From<GitlabCommit> for super::Commit {
+    fn from(commit: GitlabCommit) -> Self {
+        Self {
+            sha: commit.id,
+            message: commit.message,
+            author: commit.author_name,
+            date: commit.committed_date,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct GitlabBranch {
+    name: String,
+    commit: GitlabCommit,
+    #[serde(default)]
+    protected: bool,
+}
+
+impl From<GitlabBranch> for Branch {
+    fn from(branch: GitlabBranch) -> Self {
+        Self {
+            name: branch.name,
+            commit: branch.commit.into(),
+            protected: branch.protected,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct GitlabTag {
+    name: String,
+    /// Only set for annotated tags.
+    message: Option<String>,
+    commit: GitlabTagCommit,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitlabTagCommit {
+    id: String,
+}
+
+impl From<GitlabTag> for Tag {
+    fn from(tag: GitlabTag) -> Self {
+        Self {
+            name: tag.name,
+            sha: tag.commit.id,
+            message: tag.message.filter(|x| !x.is_empty()),
+        }
+    }
+}
+
+/// The parts of a protected branch we need, to check who was allowed to push.
+#[derive(Debug, Deserialize)]
+struct ProtectedBranch {
+    #[serde(default)]
+    push_access_levels: Vec<PushAccessLevel>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PushAccessLevel {
+    user_id: Option<u64>,
+}
+
+/// A webhook, with a flag for every event instead of a list.
+#[derive(Debug, Deserialize)]
+struct GitlabHook {
+    id: u64,
+    url: String,
+    push_events: bool,
+    tag_push_events: bool,
+    issues_events: bool,
+    note_events: bool,
+    merge_requests_events: bool,
+    #[serde(default)]
+    releases_events: bool,
+    wiki_page_events: bool,
+    /// `disabled` once Gitlab gave up on a hook that kept failing.
+    alert_status: Option<String>,
+}
+
+impl From<GitlabHook> for Webhook {
+    fn from(hook: GitlabHook) -> Self {
+        let flags = [
+            (WebhookEvent::Push, hook.push_events),
+            (WebhookEvent::Tag, hook.tag_push_events),
+            (WebhookEvent::Issues, hook.issues_events),
+            (WebhookEvent::IssueComment, hook.note_events),
+            (WebhookEvent::PullRequest, hook.merge_requests_events),
+            (WebhookEvent::Release, hook.releases_events),
+            (WebhookEvent::Wiki, hook.wiki_page_events),
+        ];
+        Self {
+            id: hook.id,
+            url: hook.url,
+            content_type: WebhookContentType::Json,
+            events: flags.into_iter().filter(|x| x.1).map(|x| x.0).collect(),
+            active: hook.alert_status.as_deref() != Some("disabled"),
+        }
+    }
+}
+
+/// Gitlab always delivers JSON, and hooks can't be turned off by hand.
+fn check_webhook(content_type: Option<WebhookContentType>, active: Option<bool>) -> Result<()> {
+    if content_type == Some(WebhookContentType::Form) {
+        return Err(Error::unsupported("Gitlab only delivers webhooks as JSON"));
+    }
+    if active == Some(false) {
+        return Err(Error::unsupported("Gitlab can't deactivate webhooks"));
+    }
+    Ok(())
+}
+
+#[derive(Debug, Deserialize)]
+struct GitlabWikiPage {
+    title: String,
+    #[serde(default)]
+    content: String,
+}
+
+/// Gitlab identifies projects by their path, which is what repositories are named after.
+/// Nested groups are supported, since the owner may contain slashes (`group/subgroup`).
+fn project_path(owner: &str, name: &str) -> String {
+    format!("{owner}/{name}")
+}
+
+/// Get the path segment Gitlab derives from a project name.
+fn path_segment(name: &str) -> String {
+    name.replace(' ', "-").to_lowercase()
+}
+
+/// Issues are `opened` and milestones `active` while they are open.
+fn issue_state(state: &str) -> IssueState {
+    match state {
+        "closed" => IssueState::Closed,
+        _ => IssueState::Open,
+    }
+}
+
+/// Drop the notes GitLab adds for changes like labels or assignees.
+fn user_comments(notes: Vec<Note>) -> Vec<Comment> {
+    notes
+        .into_iter()
+        .filter(|x| !x.system)
+        .map(Comment::from)
+        .collect()
+}
+
+fn visibility_level(visibility: Visibility) -> VisibilityLevel {
+    match visibility {
+        Visibility::Public => VisibilityLevel::Public,
+        Visibility::Private => VisibilityLevel::Private,
+        Visibility::Internal => VisibilityLevel::Internal,
+    }
+}
+
+/// Gitlab only takes the credentials for other repositories (imports and mirrors) as part of
+/// their URL.
+fn credential_url(url: &str, username: Option<&str>, password: Option<&str>) -> String {
+    let (Some(username), Some(password)) = (username, password) else {
+        return url.to_string();
+    };
+    match url.split_once("://") {
+        Some((scheme, rest)) => format!(
+            "{scheme}://{}:{}@{rest}",
+            urlencoding::encode(username),
+            urlencoding::encode(password)
+        ),
+        None => url.to_string(),
+    }
 }
 
 #[async_trait]
@@ -51,12 +865,19 @@ impl Remote for GitlabRemote {
                 ))
             }
         };
-        let client = gl::GitlabBuilder::new(config.url.clone().replace("https://", ""), token)
-            .build_async()
-            .await?;
+        let builder = match config.url.strip_prefix("http://") {
+            Some(host) => {
+                // Instances without TLS, like local test servers.
+                let mut builder = gl::GitlabBuilder::new(host, token);
+                builder.insecure();
+                builder
+            }
+            None => gl::GitlabBuilder::new(config.url.replace("https://", ""), token),
+        };
+        let client = builder.build_async().await?;
         Ok(Self {
             config: config.clone(),
-            client,
+            client: Arc::new(client),
         })
     }
     #[allow(dead_code)]
@@ -68,7 +889,7 @@ impl Remote for GitlabRemote {
         let endpoint = CurrentUser::builder()
             .build()
             .expect("building user should always work");
-        let _: User = match endpoint.query_async(&self.client).await {
+        let _: User = match endpoint.query_async(self.client.as_ref()).await {
             Ok(x) => x,
             Err(err) => {
                 if let ApiError::GitlabService { status, data: _ } = &err {
@@ -87,17 +908,21 @@ impl Remote for GitlabRemote {
             false => VisibilityLevel::Public,
         };
         if create_info.license.is_some() {
-            println!("License is not supported by Gitlab. Ignoring.");
+            eprintln!("Warning: License is not supported by Gitlab. Ignoring.");
         }
-        let project = CreateProject::builder()
+        let mut project = CreateProject::builder();
+        project
             .name(create_info.name)
             .visibility(visibility)
             .description(create_info.description.unwrap_or_default())
-            .initialize_with_readme(create_info.init)
-            .build()?;
+            .initialize_with_readme(create_info.init);
+        if let Some(owner) = &create_info.owner {
+            project.namespace_id(self.group_id(owner).await?);
+        }
+        let project = project.build()?;
 
-        let project: Project = project.query_async(&self.client).await?;
-        self.get_project_info(project).await
+        let project: Project = project.query_async(self.client.as_ref()).await?;
+        Self::project_info(&self.client, project, self.config.commit_limit()).await
     }
     async fn create_fork(&self, options: RepoForkOption) -> Result<Repository> {
         #[derive(Debug, Deserialize)]
@@ -120,70 +945,895 @@ impl Remote for GitlabRemote {
                 format!("/projects/{}/fork", self.id).into()
             }
         }
-        let path = options.repo.replace(' ', "-").to_lowercase();
-        let encoded_path = format!(
-            "{}/{}",
-            urlencoding::encode(&options.owner),
-            urlencoding::encode(&path)
-        );
         let endpoint = ForkEndpoint {
-            id: encoded_path,
+            id: urlencoding::encode(&project_path(&options.owner, &options.repo)).into_owned(),
             name: options.name,
             namespace_path: options.organization,
         };
-        let project = endpoint.query_async(&self.client).await?;
-        self.get_project_info(project).await
+        let project = endpoint.query_async(self.client.as_ref()).await?;
+        Self::project_info(&self.client, project, self.config.commit_limit()).await
     }
     async fn list_repos(&self, list_info: ListReposInfo) -> Result<Vec<Repository>> {
-        let mut projects = Projects::builder();
-        projects.owned(true).include_hidden(list_info.private);
-        if !list_info.private {
-            projects.visibility(VisibilityLevel::Public);
-        }
-
-        let projects = projects.build()?;
-        let projects: Vec<Project> = projects.query_async(&self.client).await?;
-
-        let mut futures = Vec::new();
-        for project in projects {
-            // SAFETY: We are not moving `self` in the closure, self is guaranteed to be valid as
-            // long as the closure is running and we're not mutating it, so this is safe.
-            let this = unsafe { &*(self as *const Self) };
-            futures.push(tokio::spawn(this.get_project_info(project)));
-        }
-        let mut result = Vec::with_capacity(futures.len());
-        for future in futures {
-            let f = future.await.unwrap()?;
-            // Filter out forks if the user doesn't want them
-            if !list_info.forks && f.fork {
-                continue;
+        let projects = match &list_info.owner {
+            // Anything but the authenticated user is treated as a group. This includes
+            // projects in nested subgroups.
+            Some(group) if group != &self.config.username => {
+                let mut projects = GroupProjects::builder();
+                projects.group(group.as_str()).include_subgroups(true);
+                if !list_info.private {
+                    projects.visibility(VisibilityLevel::Public);
+                }
+                self.list_projects(projects.build()?, &list_info).await?
             }
-            result.push(f);
+            _ => {
+                let mut projects = Projects::builder();
+                match list_info.affiliated {
+                    true => projects.membership(true),
+                    false => projects.owned(true),
+                };
+                projects.include_hidden(list_info.private);
+                if !list_info.private {
+                    projects.visibility(VisibilityLevel::Public);
+                }
+                self.list_projects(projects.build()?, &list_info).await?
+            }
+        };
+
+        let concurrency = self.config.concurrency_limit();
+        let commits = list_info.commits.count();
+        fetch_concurrently(list_info.paginate(projects), concurrency, |project| {
+            let client = self.client.clone();
+            async move { Self::project_info(&client, project, commits).await }
+        })
+        .await
+    }
+    async fn get_repo_info(&self, owner: &str, name: &str) -> Result<Repository> {
+        let path = project_path(owner, name);
+        let project = projects::Project::builder().project(path).build()?;
+        let project: Project = project.query_async(self.client.as_ref()).await?;
+        Self::project_info(&self.client, project, self.config.commit_limit()).await
+    }
+    async fn update_repo(
+        &self,
+        owner: &str,
+        name: &str,
+        update: RepoUpdateInfo,
+    ) -> Result<Repository> {
+        if update.homepage.is_some() {
+            eprintln!("Warning: Homepage is not supported by Gitlab. Ignoring.");
+        }
+        if update.has_projects.is_some() {
+            eprintln!("Warning: Projects are not supported by Gitlab. Ignoring.");
         }
-        Ok(result)
+        let access_level = |enabled| match enabled {
+            true => FeatureAccessLevel::Enabled,
+            false => FeatureAccessLevel::Disabled,
+        };
+        let mut project = EditProject::builder();
+        project.project(project_path(owner, name));
+        if let Some(description) = update.description {
+            project.description(description);
+        }
+        if let Some(visibility) = update.visibility {
+            project.visibility(visibility_level(visibility));
+        }
+        if let Some(branch) = update.default_branch {
+            project.default_branch(branch);
+        }
+        if let Some(issues) = update.has_issues {
+            project.issues_access_level(access_level(issues));
+        }
+        if let Some(wiki) = update.has_wiki {
+            project.wiki_access_level(access_level(wiki));
+        }
+        let project: Project = project.build()?.query_async(self.client.as_ref()).await?;
+        Self::project_info(&self.client, project, self.config.commit_limit()).await
     }
-    async fn get_repo_info(&self, name: &str) -> Result<Repository> {
-        let path = name.replace(' ', "-").to_lowercase();
-        let encoded_path = format!(
-            "{}/{}",
-            urlencoding::encode(&self.config.username),
-            urlencoding::encode(&path)
-        );
-        let project = projects::Project::builder().project(encoded_path).build()?;
-        let project: Project = project.query_async(&self.client).await?;
-        self.get_project_info(project).await
-    }
-    async fn delete_repo(&self, name: &str) -> Result<()> {
-        let path = name.replace(' ', "-").to_lowercase();
-        let encoded_path = format!(
-            "{}/{}",
-            urlencoding::encode(&self.config.username),
-            urlencoding::encode(&path)
+    async fn archive_repo(&self, owner: &str, name: &str) -> Result<Repository> {
+        let endpoint = ArchiveProject::builder()
+            .project(project_path(owner, name))
+            .build()?;
+        let project: Project = endpoint.query_async(self.client.as_ref()).await?;
+        Self::project_info(&self.client, project, self.config.commit_limit()).await
+    }
+    async fn unarchive_repo(&self, owner: &str, name: &str) -> Result<Repository> {
+        let endpoint = UnarchiveProject::builder()
+            .project(project_path(owner, name))
+            .build()?;
+        let project: Project = endpoint.query_async(self.client.as_ref()).await?;
+        Self::project_info(&self.client, project, self.config.commit_limit()).await
+    }
+    async fn transfer_repo(&self, owner: &str, name: &str, new_owner: &str) -> Result<Repository> {
+        let endpoint = TransferProject {
+            project: project_path(owner, name),
+            namespace: new_owner.to_string(),
+        };
+        let project: Project = endpoint.query_async(self.client.as_ref()).await?;
+        Self::project_info(&self.client, project, self.config.commit_limit()).await
+    }
+    async fn rename_repo(&self, owner: &str, name: &str, new_name: &str) -> Result<Repository> {
+        // The path is part of the clone URL, so rename it along with the name.
+        let project = EditProject::builder()
+            .project(project_path(owner, name))
+            .name(new_name)
+            .path(path_segment(new_name))
+            .build()?;
+        let project: Project = project.query_async(self.client.as_ref()).await?;
+        Self::project_info(&self.client, project, self.config.commit_limit()).await
+    }
+    async fn import_repo(&self, import: RepoImportInfo) -> Result<Repository> {
+        let mut project = CreateProject::builder();
+        project
+            .name(import.name.as_str())
+            .visibility(visibility_level(import.visibility))
+            .description(import.description.as_deref().unwrap_or_default())
+            .import_url(credential_url(
+                &import.clone_url,
+                import.auth_username.as_deref(),
+                import.auth_password.as_deref(),
+            ))
+            .mirror(import.mirror);
+        if let Some(owner) = &import.owner {
+            project.namespace_id(self.group_id(owner).await?);
+        }
+        let project: Project = project.build()?.query_async(self.client.as_ref()).await?;
+        // The import runs in the background, there are no commits to show yet.
+        Self::project_info(&self.client, project, 0).await
+    }
+    async fn create_push_mirror(
+        &self,
+        owner: &str,
+        name: &str,
+        mirror: MirrorCreateInfo,
+    ) -> Result<Mirror> {
+        if let Some(interval) = &mirror.interval {
+            self.check_push_mirror_interval(owner, name, interval)
+                .await?;
+        }
+        let endpoint = CreateRemoteMirror {
+            project: project_path(owner, name),
+            url: credential_url(
+                &mirror.url,
+                mirror.auth_username.as_deref(),
+                mirror.auth_password.as_deref(),
+            ),
+        };
+        let mirror: RemoteMirror = endpoint.query_async(self.client.as_ref()).await?;
+        Ok(mirror.into())
+    }
+    async fn check_push_mirror_interval(
+        &self,
+        _owner: &str,
+        _name: &str,
+        _interval: &str,
+    ) -> Result<()> {
+        // Gitlab pushes on every change, there is no schedule to set.
+        Err(Error::unsupported(
+            "Mirror intervals are not supported by Gitlab",
+        ))
+    }
+    async fn list_mirrors(&self, owner: &str, name: &str) -> Result<Vec<Mirror>> {
+        let project = projects::Project::builder()
+            .project(project_path(owner, name))
+            .build()?;
+        let project: Project = project.query_async(self.client.as_ref()).await?;
+        let mut mirrors = Vec::new();
+        if project.mirror {
+            mirrors.push(Mirror {
+                url: project.import_url.unwrap_or_default(),
+                direction: MirrorDirection::Pull,
+                ..Default::default()
+            });
+        }
+        let endpoint = RemoteMirrors {
+            project: project_path(owner, name),
+        };
+        let push_mirrors: Vec<RemoteMirror> = endpoint.query_async(self.client.as_ref()).await?;
+        mirrors.extend(push_mirrors.into_iter().map(Mirror::from));
+        Ok(mirrors)
+    }
+    async fn list_branches(&self, owner: &str, name: &str) -> Result<Vec<Branch>> {
+        let endpoint = Branches::builder()
+            .project(project_path(owner, name))
+            .build()?;
+        let branches: Vec<GitlabBranch> = api::paged(endpoint, Pagination::All)
+            .query_async(self.client.as_ref())
+            .await?;
+        Ok(branches.into_iter().map(Branch::from).collect())
+    }
+    async fn create_branch(
+        &self,
+        owner: &str,
+        name: &str,
+        branch: &str,
+        from: Option<&str>,
+    ) -> Result<Branch> {
+        let from = match from {
+            Some(from) => from.to_string(),
+            None => self.default_branch(owner, name).await?,
+        };
+        let endpoint = CreateBranch::builder()
+            .project(project_path(owner, name))
+            .branch(branch)
+            .ref_(from)
+            .build()?;
+        let branch: GitlabBranch = endpoint.query_async(self.client.as_ref()).await?;
+        Ok(branch.into())
+    }
+    async fn delete_branch(&self, owner: &str, name: &str, branch: &str) -> Result<()> {
+        let endpoint = DeleteBranch {
+            project: project_path(owner, name),
+            branch: branch.to_string(),
+        };
+        api::ignore(endpoint)
+            .query_async(self.client.as_ref())
+            .await?;
+        Ok(())
+    }
+    /// Merge request approvals and required pipelines are project settings in Gitlab, so only
+    /// the push rules are set on the branch.
+    async fn protect_branch(
+        &self,
+        owner: &str,
+        name: &str,
+        branch: &str,
+        protection: BranchProtection,
+    ) -> Result<Vec<String>> {
+        let path = project_path(owner, name);
+        let mut unsupported = Vec::new();
+        if protection.required_reviews > 0 {
+            unsupported.push("required reviews".to_string());
+        }
+        if !protection.required_status_checks.is_empty() {
+            unsupported.push("required status checks".to_string());
+        }
+        let mut users = Vec::new();
+        for username in &protection.push_allowlist {
+            users.push(self.user_id(username).await?);
+        }
+
+        let protected = self
+            .replace_protection(&path, branch, &protection, &users)
+            .await?;
+        let allowed: Vec<_> = protected
+            .push_access_levels
+            .iter()
+            .filter_map(|x| x.user_id)
+            .collect();
+        // Gitlab's free tier ignores the users, which would leave nobody allowed to push.
+        if users.iter().any(|x| !allowed.contains(x)) {
+            self.replace_protection(&path, branch, &protection, &[])
+                .await?;
+            unsupported.push("restricted pushers".to_string());
+        }
+        Ok(unsupported)
+    }
+    async fn list_tags(&self, owner: &str, name: &str) -> Result<Vec<Tag>> {
+        let endpoint = Tags::builder().project(project_path(owner, name)).build()?;
+        let tags: Vec<GitlabTag> = api::paged(endpoint, Pagination::All)
+            .query_async(self.client.as_ref())
+            .await?;
+        Ok(tags.into_iter().map(Tag::from).collect())
+    }
+    async fn create_tag(&self, owner: &str, name: &str, tag: TagCreateInfo) -> Result<Tag> {
+        let target = match tag.target {
+            Some(target) => target,
+            None => self.default_branch(owner, name).await?,
+        };
+        let mut endpoint = CreateTag::builder();
+        endpoint
+            .project(project_path(owner, name))
+            .tag_name(tag.name.as_str())
+            .ref_(target);
+        if let Some(message) = &tag.message {
+            endpoint.message(message.as_str());
+        }
+        let tag: GitlabTag = endpoint.build()?.query_async(self.client.as_ref()).await?;
+        Ok(tag.into())
+    }
+    async fn delete_tag(&self, owner: &str, name: &str, tag: &str) -> Result<()> {
+        let endpoint = DeleteTag {
+            project: project_path(owner, name),
+            tag: tag.to_string(),
+        };
+        api::ignore(endpoint)
+            .query_async(self.client.as_ref())
+            .await?;
+        Ok(())
+    }
+    async fn list_webhooks(&self, owner: &str, name: &str) -> Result<Vec<Webhook>> {
+        let endpoint = Hooks::builder()
+            .project(project_path(owner, name))
+            .build()?;
+        let hooks: Vec<GitlabHook> = api::paged(endpoint, Pagination::All)
+            .query_async(self.client.as_ref())
+            .await?;
+        Ok(hooks.into_iter().map(Webhook::from).collect())
+    }
+    async fn create_webhook(
+        &self,
+        owner: &str,
+        name: &str,
+        hook: WebhookCreateInfo,
+    ) -> Result<Webhook> {
+        check_webhook(Some(hook.content_type), Some(hook.active))?;
+        // Push events are on unless turned off, so every event is set.
+        let has = |x| hook.events.contains(&x);
+        let mut endpoint = CreateHook::builder();
+        endpoint
+            .project(project_path(owner, name))
+            .url(hook.url.as_str())
+            .push_events(has(WebhookEvent::Push))
+            .tag_push_events(has(WebhookEvent::Tag))
+            .issues_events(has(WebhookEvent::Issues))
+            .note_events(has(WebhookEvent::IssueComment))
+            .merge_requests_events(has(WebhookEvent::PullRequest))
+            .releases_events(has(WebhookEvent::Release))
+            .wiki_page_events(has(WebhookEvent::Wiki));
+        if let Some(secret) = &hook.secret {
+            endpoint.token(secret.as_str());
+        }
+        let hook: GitlabHook = endpoint.build()?.query_async(self.client.as_ref()).await?;
+        Ok(hook.into())
+    }
+    async fn update_webhook(
+        &self,
+        owner: &str,
+        name: &str,
+        id: u64,
+        update: WebhookUpdateInfo,
+    ) -> Result<Webhook> {
+        check_webhook(update.content_type, update.active)?;
+        let mut endpoint = EditHook::builder();
+        endpoint.project(project_path(owner, name)).hook_id(id);
+        if let Some(url) = &update.url {
+            endpoint.url(url.as_str());
+        }
+        if let Some(secret) = &update.secret {
+            endpoint.token(secret.as_str());
+        }
+        if let Some(events) = &update.events {
+            let has = |x| events.contains(&x);
+            endpoint
+                .push_events(has(WebhookEvent::Push))
+                .tag_push_events(has(WebhookEvent::Tag))
+                .issues_events(has(WebhookEvent::Issues))
+                .note_events(has(WebhookEvent::IssueComment))
+                .merge_requests_events(has(WebhookEvent::PullRequest))
+                .releases_events(has(WebhookEvent::Release))
+                .wiki_page_events(has(WebhookEvent::Wiki));
+        }
+        let hook: GitlabHook = endpoint.build()?.query_async(self.client.as_ref()).await?;
+        Ok(hook.into())
+    }
+    async fn delete_webhook(&self, owner: &str, name: &str, id: u64) -> Result<()> {
+        let endpoint = DeleteHook::builder()
+            .project(project_path(owner, name))
+            .hook_id(id)
+            .build()?;
+        api::ignore(endpoint)
+            .query_async(self.client.as_ref())
+            .await?;
+        Ok(())
+    }
+    async fn test_webhook(&self, owner: &str, name: &str, id: u64) -> Result<()> {
+        let endpoint = TestHook {
+            project: project_path(owner, name),
+            id,
+        };
+        api::ignore(endpoint)
+            .query_async(self.client.as_ref())
+            .await?;
+        Ok(())
+    }
+    async fn list_ssh_keys(&self) -> Result<Vec<SshKey>> {
+        let keys = api::paged(UserKeys, Pagination::All)
+            .query_async(self.client.as_ref())
+            .await?;
+        Ok(keys)
+    }
+    async fn add_ssh_key(&self, key: SshKeyCreateInfo) -> Result<SshKey> {
+        let key = CreateUserKey { key }
+            .query_async(self.client.as_ref())
+            .await?;
+        Ok(key)
+    }
+    async fn delete_ssh_key(&self, id: u64) -> Result<()> {
+        api::ignore(DeleteUserKey { id })
+            .query_async(self.client.as_ref())
+            .await?;
+        Ok(())
+    }
+    async fn list_deploy_keys(&self, owner: &str, name: &str) -> Result<Vec<DeployKey>> {
+        let endpoint = DeployKeys::builder()
+            .project(project_path(owner, name))
+            .build()?;
+        let keys: Vec<GitlabDeployKey> = api::paged(endpoint, Pagination::All)
+            .query_async(self.client.as_ref())
+            .await?;
+        Ok(keys.into_iter().map(DeployKey::from).collect())
+    }
+    async fn add_deploy_key(
+        &self,
+        owner: &str,
+        name: &str,
+        key: SshKeyCreateInfo,
+        read_only: bool,
+    ) -> Result<DeployKey> {
+        let endpoint = CreateDeployKey::builder()
+            .project(project_path(owner, name))
+            .title(key.title)
+            .key(key.key)
+            .can_push(!read_only)
+            .build()?;
+        let key: GitlabDeployKey = endpoint.query_async(self.client.as_ref()).await?;
+        Ok(key.into())
+    }
+    async fn delete_deploy_key(&self, owner: &str, name: &str, id: u64) -> Result<()> {
+        let endpoint = DeleteDeployKey::builder()
+            .project(project_path(owner, name))
+            .deploy_key(id)
+            .build()?;
+        api::ignore(endpoint)
+            .query_async(self.client.as_ref())
+            .await?;
+        Ok(())
+    }
+    async fn list_labels(&self, owner: &str, name: &str) -> Result<Vec<Label>> {
+        let endpoint = projects::labels::Labels::builder()
+            .project(project_path(owner, name))
+            .build()?;
+        let labels: Vec<GitlabLabel> = api::paged(endpoint, Pagination::All)
+            .query_async(self.client.as_ref())
+            .await?;
+        Ok(labels.into_iter().map(Label::from).collect())
+    }
+    async fn create_label(&self, owner: &str, name: &str, label: Label) -> Result<Label> {
+        let mut endpoint = projects::labels::CreateLabel::builder();
+        endpoint
+            .project(project_path(owner, name))
+            .name(label.name.as_str())
+            .color(format!("#{}", label.color));
+        if let Some(description) = &label.description {
+            endpoint.description(description.as_str());
+        }
+        let label: GitlabLabel = endpoint.build()?.query_async(self.client.as_ref()).await?;
+        Ok(label.into())
+    }
+    async fn list_milestones(&self, owner: &str, name: &str) -> Result<Vec<Milestone>> {
+        let endpoint = ProjectMilestones {
+            project: project_path(owner, name),
+        };
+        let milestones: Vec<GitlabMilestone> = api::paged(endpoint, Pagination::All)
+            .query_async(self.client.as_ref())
+            .await?;
+        Ok(milestones.into_iter().map(Milestone::from).collect())
+    }
+    async fn create_milestone(
+        &self,
+        owner: &str,
+        name: &str,
+        milestone: Milestone,
+    ) -> Result<Milestone> {
+        let path = project_path(owner, name);
+        let mut endpoint = projects::milestones::CreateProjectMilestone::builder();
+        endpoint
+            .project(path.as_str())
+            .title(milestone.title.as_str());
+        if let Some(description) = &milestone.description {
+            endpoint.description(description.as_str());
+        }
+        if let Some(due_on) = milestone.due_on {
+            endpoint.due_date(due_on.date_naive());
+        }
+        let created: GitlabMilestone = endpoint.build()?.query_async(self.client.as_ref()).await?;
+        if milestone.state == IssueState::Open {
+            return Ok(created.into());
+        }
+        // Milestones can only be closed once they exist.
+        let endpoint = CloseMilestone {
+            project: path,
+            milestone: created.id,
+        };
+        let closed: GitlabMilestone = endpoint.query_async(self.client.as_ref()).await?;
+        Ok(closed.into())
+    }
+    async fn list_issues(
+        &self,
+        owner: &str,
+        name: &str,
+        list_info: ListIssuesInfo,
+    ) -> Result<Vec<Issue>> {
+        let mut endpoint = projects::issues::Issues::builder();
+        endpoint
+            .project(project_path(owner, name))
+            .order_by(projects::issues::IssueOrderBy::CreatedAt)
+            .sort(SortOrder::Ascending);
+        match list_info.state {
+            Some(IssueState::Open) => endpoint.state(projects::issues::IssueState::Opened),
+            Some(IssueState::Closed) => endpoint.state(projects::issues::IssueState::Closed),
+            None => &mut endpoint,
+        };
+        endpoint.labels(list_info.labels.iter().map(String::as_str));
+        if let Some(assignee) = &list_info.assignee {
+            endpoint.assignee(assignee.as_str());
+        }
+        if let Some(author) = &list_info.author {
+            endpoint.author(author.as_str());
+        }
+        let issues: Vec<GitlabIssue> = api::paged(endpoint.build()?, Pagination::All)
+            .query_async(self.client.as_ref())
+            .await?;
+        Ok(issues.into_iter().map(Issue::from).collect())
+    }
+    async fn get_issue(&self, owner: &str, name: &str, number: u64) -> Result<Issue> {
+        let endpoint = projects::issues::Issue::builder()
+            .project(project_path(owner, name))
+            .issue(number)
+            .build()?;
+        let issue: GitlabIssue = endpoint.query_async(self.client.as_ref()).await?;
+        Ok(issue.into())
+    }
+    async fn create_issue(&self, owner: &str, name: &str, issue: IssueCreateInfo) -> Result<Issue> {
+        // Gitlab assigns users by their ID.
+        let mut assignees = Vec::with_capacity(issue.assignees.len());
+        for username in &issue.assignees {
+            assignees.push(self.user_id(username).await?);
+        }
+        let mut endpoint = projects::issues::CreateIssue::builder();
+        endpoint
+            .project(project_path(owner, name))
+            .title(issue.title.as_str())
+            .labels(issue.labels.iter().map(String::as_str))
+            .assignee_ids(assignees.into_iter());
+        if let Some(body) = &issue.body {
+            endpoint.description(body.as_str());
+        }
+        if let Some(milestone) = issue.milestone {
+            endpoint.milestone_id(milestone);
+        }
+        let issue: GitlabIssue = endpoint.build()?.query_async(self.client.as_ref()).await?;
+        Ok(issue.into())
+    }
+    async fn set_issue_state(
+        &self,
+        owner: &str,
+        name: &str,
+        number: u64,
+        state: IssueState,
+    ) -> Result<Issue> {
+        let event = match state {
+            IssueState::Open => projects::issues::IssueStateEvent::Reopen,
+            IssueState::Closed => projects::issues::IssueStateEvent::Close,
+        };
+        let endpoint = projects::issues::EditIssue::builder()
+            .project(project_path(owner, name))
+            .issue(number)
+            .state_event(event)
+            .build()?;
+        let issue: GitlabIssue = endpoint.query_async(self.client.as_ref()).await?;
+        Ok(issue.into())
+    }
+    async fn list_issue_comments(
+        &self,
+        owner: &str,
+        name: &str,
+        number: u64,
+    ) -> Result<Vec<Comment>> {
+        let endpoint = projects::issues::notes::IssueNotes::builder()
+            .project(project_path(owner, name))
+            .issue(number)
+            .order_by(NoteOrderBy::CreatedAt)
+            .sort(SortOrder::Ascending)
+            .build()?;
+        let notes: Vec<Note> = api::paged(endpoint, Pagination::All)
+            .query_async(self.client.as_ref())
+            .await?;
+        Ok(user_comments(notes))
+    }
+    async fn create_issue_comment(
+        &self,
+        owner: &str,
+        name: &str,
+        number: u64,
+        body: &str,
+    ) -> Result<Comment> {
+        let endpoint = projects::issues::notes::CreateIssueNote::builder()
+            .project(project_path(owner, name))
+            .issue(number)
+            .body(body)
+            .build()?;
+        let note: Note = endpoint.query_async(self.client.as_ref()).await?;
+        Ok(note.into())
+    }
+    async fn list_pull_requests(
+        &self,
+        owner: &str,
+        name: &str,
+        list_info: ListPullRequestsInfo,
+    ) -> Result<Vec<PullRequest>> {
+        use projects::merge_requests::{MergeRequestOrderBy, MergeRequestState, MergeRequests};
+
+        let mut endpoint = MergeRequests::builder();
+        endpoint
+            .project(project_path(owner, name))
+            .order_by(MergeRequestOrderBy::CreatedAt)
+            .sort(SortOrder::Ascending);
+        match list_info.state {
+            Some(PullRequestState::Open) => endpoint.state(MergeRequestState::Opened),
+            Some(PullRequestState::Closed) => endpoint.state(MergeRequestState::Closed),
+            Some(PullRequestState::Merged) => endpoint.state(MergeRequestState::Merged),
+            None => &mut endpoint,
+        };
+        let merge_requests: Vec<MergeRequest> = api::paged(endpoint.build()?, Pagination::All)
+            .query_async(self.client.as_ref())
+            .await?;
+        Ok(merge_requests.into_iter().map(PullRequest::from).collect())
+    }
+    async fn get_pull_request(&self, owner: &str, name: &str, number: u64) -> Result<PullRequest> {
+        let endpoint = projects::merge_requests::MergeRequest::builder()
+            .project(project_path(owner, name))
+            .merge_request(number)
+            .build()?;
+        let merge_request: MergeRequest = endpoint.query_async(self.client.as_ref()).await?;
+        Ok(merge_request.into())
+    }
+    async fn create_pull_request(
+        &self,
+        owner: &str,
+        name: &str,
+        pull_request: PullRequestCreateInfo,
+    ) -> Result<PullRequest> {
+        // Gitlab marks drafts by their title.
+        let title = match pull_request.draft {
+            true => format!("Draft: {}", pull_request.title),
+            false => pull_request.title,
+        };
+        let mut endpoint = projects::merge_requests::CreateMergeRequest::builder();
+        endpoint
+            .project(project_path(owner, name))
+            .source_branch(pull_request.head.as_str())
+            .target_branch(pull_request.base.as_str())
+            .title(title.as_str());
+        if let Some(body) = &pull_request.body {
+            endpoint.description(body.as_str());
+        }
+        let merge_request: MergeRequest =
+            endpoint.build()?.query_async(self.client.as_ref()).await?;
+        Ok(merge_request.into())
+    }
+    async fn create_pull_request_comment(
+        &self,
+        owner: &str,
+        name: &str,
+        number: u64,
+        body: &str,
+    ) -> Result<Comment> {
+        let endpoint = projects::merge_requests::notes::CreateMergeRequestNote::builder()
+            .project(project_path(owner, name))
+            .merge_request(number)
+            .body(body)
+            .build()?;
+        let note: Note = endpoint.query_async(self.client.as_ref()).await?;
+        Ok(note.into())
+    }
+    async fn approve_pull_request(&self, owner: &str, name: &str, number: u64) -> Result<()> {
+        let endpoint = projects::merge_requests::ApproveMergeRequest::builder()
+            .project(project_path(owner, name))
+            .merge_request(number)
+            .build()?;
+        api::ignore(endpoint)
+            .query_async(self.client.as_ref())
+            .await?;
+        Ok(())
+    }
+    async fn merge_pull_request(
+        &self,
+        owner: &str,
+        name: &str,
+        number: u64,
+        merge_info: PullRequestMergeInfo,
+    ) -> Result<PullRequest> {
+        let squash =
+            match merge_info.method {
+                MergeMethod::Merge => false,
+                MergeMethod::Squash => true,
+                // Whether merges rebase is a setting of the project.
+                MergeMethod::Rebase => return Err(Error::unsupported(
+                    "Gitlab merges with the merge method of the project, choose merge or squash",
+                )),
+            };
+        let endpoint = projects::merge_requests::MergeMergeRequest::builder()
+            .project(project_path(owner, name))
+            .merge_request(number)
+            .squash(squash)
+            .should_remove_source_branch(merge_info.delete_branch)
+            .build()?;
+        let merge_request: MergeRequest = endpoint.query_async(self.client.as_ref()).await?;
+        Ok(merge_request.into())
+    }
+    fn pull_request_ref(&self, number: u64) -> String {
+        format!("refs/merge-requests/{number}/head")
+    }
+    async fn list_pull_request_comments(
+        &self,
+        owner: &str,
+        name: &str,
+        number: u64,
+    ) -> Result<Vec<Comment>> {
+        let endpoint = projects::merge_requests::notes::MergeRequestNotes::builder()
+            .project(project_path(owner, name))
+            .merge_request(number)
+            .order_by(NoteOrderBy::CreatedAt)
+            .sort(SortOrder::Ascending)
+            .build()?;
+        let notes: Vec<Note> = api::paged(endpoint, Pagination::All)
+            .query_async(self.client.as_ref())
+            .await?;
+        Ok(user_comments(notes))
+    }
+    async fn list_releases(&self, owner: &str, name: &str) -> Result<Vec<Release>> {
+        let endpoint = projects::releases::ProjectReleases::builder()
+            .project(project_path(owner, name))
+            .build()?;
+        let releases: Vec<GitlabRelease> = api::paged(endpoint, Pagination::All)
+            .query_async(self.client.as_ref())
+            .await?;
+        Ok(releases.into_iter().map(Release::from).collect())
+    }
+    async fn create_release(
+        &self,
+        owner: &str,
+        name: &str,
+        release: ReleaseCreateInfo,
+    ) -> Result<Release> {
+        // Publishing a release that was meant to stay hidden can't be undone.
+        if release.draft {
+            return Err(Error::unsupported(
+                "Draft releases are not supported by Gitlab",
+            ));
+        }
+        if release.prerelease {
+            eprintln!("Warning: Prereleases are not supported by Gitlab. Publishing as a release.");
+        }
+        let mut endpoint = projects::releases::CreateRelease::builder();
+        endpoint
+            .project(project_path(owner, name))
+            .tag_name(release.tag.as_str());
+        if let Some(target) = &release.target {
+            endpoint.ref_sha(target.as_str());
+        }
+        if let Some(title) = &release.name {
+            endpoint.name(title.as_str());
+        }
+        if let Some(body) = &release.body {
+            endpoint.description(body.as_str());
+        }
+        let release: GitlabRelease = endpoint.build()?.query_async(self.client.as_ref()).await?;
+        Ok(release.into())
+    }
+    async fn get_release(&self, owner: &str, name: &str, tag: &str) -> Result<Release> {
+        let endpoint = ProjectRelease {
+            project: project_path(owner, name),
+            tag: tag.to_string(),
+        };
+        let release: GitlabRelease = endpoint.query_async(self.client.as_ref()).await?;
+        Ok(release.into())
+    }
+    async fn delete_release(&self, owner: &str, name: &str, tag: &str) -> Result<()> {
+        let endpoint = DeleteRelease {
+            project: project_path(owner, name),
+            tag: tag.to_string(),
+        };
+        api::ignore(endpoint)
+            .query_async(self.client.as_ref())
+            .await?;
+        Ok(())
+    }
+    /// Releases only link to files, so the file is uploaded to the generic package registry of
+    /// the project, as a package named after the project with the tag as its version.
+    async fn upload_release_asset(
+        &self,
+        owner: &str,
+        name: &str,
+        tag: &str,
+        file_name: &str,
+        data: Vec<u8>,
+    ) -> Result<ReleaseAsset> {
+        let project = project_path(owner, name);
+        let size = data.len() as u64;
+        let endpoint = UploadPackageFile::builder()
+            .project(project.as_str())
+            .package_name(name)
+            .package_version(tag)
+            .file_name(file_name)
+            .contents(data)
+            .build()?;
+        api::ignore(endpoint)
+            .query_async(self.client.as_ref())
+            .await?;
+
+        let url = format!(
+            "{}/projects/{}/packages/generic/{}/{}/{}",
+            self.api_url(),
+            urlencoding::encode(&project),
+            urlencoding::encode(name),
+            urlencoding::encode(tag),
+            urlencoding::encode(file_name)
         );
-        let endpoint = projects::DeleteProject::builder()
-            .project(encoded_path)
+        let endpoint = CreateReleaseLink::builder()
+            .project(project.as_str())
+            .tag_name(tag)
+            .name(file_name)
+            .url(url)
+            .link_type(LinkType::Package)
             .build()?;
-        api::ignore(endpoint).query_async(&self.client).await?;
+        let link: ReleaseLink = endpoint.query_async(self.client.as_ref()).await?;
+        Ok(ReleaseAsset {
+            name: link.name,
+            size: Some(size),
+            download_url: link.direct_asset_url.unwrap_or(link.url),
+        })
+    }
+    /// Links into the API of the instance, like packages, are downloaded with the token. Links to
+    /// other places are downloaded without credentials.
+    async fn download_release_asset(
+        &self,
+        owner: &str,
+        name: &str,
+        tag: &str,
+        asset_name: &str,
+    ) -> Result<Vec<u8>> {
+        let endpoint = ProjectRelease {
+            project: project_path(owner, name),
+            tag: tag.to_string(),
+        };
+        let release: GitlabRelease = endpoint.query_async(self.client.as_ref()).await?;
+        let Some(link) = release.assets.links.iter().find(|x| x.name == asset_name) else {
+            return Err(Error::not_found(format!(
+                "Release {tag} of {owner}/{name} has no asset named {asset_name}"
+            )));
+        };
+        let api_prefix = format!("{}/", self.api_url());
+        if let Some(path) = link.url.strip_prefix(&api_prefix) {
+            let endpoint = ApiPath {
+                path: path.to_string(),
+            };
+            return Ok(api::raw(endpoint).query_async(self.client.as_ref()).await?);
+        }
+        let res = reqwest::get(&link.url).await?.error_for_status()?;
+        Ok(res.bytes().await?.to_vec())
+    }
+    async fn list_wiki_pages(&self, owner: &str, name: &str) -> Result<Vec<WikiPage>> {
+        let endpoint = WikiPages {
+            project: project_path(owner, name),
+        };
+        let pages: Vec<GitlabWikiPage> = endpoint.query_async(self.client.as_ref()).await?;
+        Ok(pages
+            .into_iter()
+            .map(|x| WikiPage {
+                title: x.title,
+                content: x.content,
+            })
+            .collect())
+    }
+    async fn create_wiki_page(&self, owner: &str, name: &str, page: WikiPage) -> Result<WikiPage> {
+        let endpoint = CreateWikiPage {
+            project: project_path(owner, name),
+            page,
+        };
+        let page: GitlabWikiPage = endpoint.query_async(self.client.as_ref()).await?;
+        Ok(WikiPage {
+            title: page.title,
+            content: page.content,
+        })
+    }
+    async fn delete_repo(&self, owner: &str, name: &str) -> Result<()> {
+        let path = project_path(owner, name);
+        let endpoint = projects::DeleteProject::builder().project(path).build()?;
+        api::ignore(endpoint)
+            .query_async(self.client.as_ref())
+            .await?;
         Ok(())
     }
     fn get_config(&self) -> &RemoteConfig {
@@ -192,43 +1842,159 @@ impl Remote for GitlabRemote {
 }
 
 impl GitlabRemote {
-    async fn get_project_info(&self, project: Project) -> Result<Repository> {
-        #[derive(Debug, Deserialize)]
-        struct Commit {
-            id: String,
-            message: String,
-            author_name: String,
-            committed_date: String,
+    /// The base URL of the REST API, without a trailing slash.
+    fn api_url(&self) -> String {
+        format!("{}/api/v4", self.config.url.trim_end_matches('/'))
+    }
+
+    /// Get the default branch of a project, to create branches and tags from.
+    async fn default_branch(&self, owner: &str, name: &str) -> Result<String> {
+        let endpoint = projects::Project::builder()
+            .project(project_path(owner, name))
+            .build()?;
+        let project: Project = endpoint.query_async(self.client.as_ref()).await?;
+        project
+            .default_branch
+            .ok_or_else(|| Error::other(format!("Repository {owner}/{name} has no default branch")))
+    }
+
+    /// Remove the protection of a branch, if any, and protect it again. When `users` are given,
+    /// only they may push.
+    async fn replace_protection(
+        &self,
+        project: &str,
+        branch: &str,
+        protection: &BranchProtection,
+        users: &[u64],
+    ) -> Result<ProtectedBranch> {
+        let endpoint = UnprotectBranch::builder()
+            .project(project)
+            .name(branch)
+            .build()?;
+        match api::ignore(endpoint)
+            .query_async(self.client.as_ref())
+            .await
+            .map_err(Error::from)
+        {
+            Err(err) if err.status == Some(404) => {}
+            result => result?,
         }
 
-        let mut last_commits;
-        if project.empty_repo {
+        // Without restrictions, anyone who can push to the project can push to the branch.
+        let level = match users.is_empty() {
+            true => ProtectedAccessLevel::Developer,
+            false => ProtectedAccessLevel::NoAccess,
+        };
+        let mut endpoint = ProtectBranch::builder();
+        endpoint
+            .project(project)
+            .name(branch)
+            .push_access_level(level)
+            .merge_access_level(ProtectedAccessLevel::Developer)
+            .allow_force_push(!protection.no_force_push);
+        for user in users {
+            endpoint.allowed_to_push(ProtectedAccess::User(*user));
+        }
+        Ok(endpoint.build()?.query_async(self.client.as_ref()).await?)
+    }
+
+    /// Get the ID of a group from its full path.
+    async fn group_id(&self, group: &str) -> Result<u64> {
+        #[derive(Deserialize)]
+        struct GroupInfo {
+            id: u64,
+        }
+        let group = Group::builder().group(group).build()?;
+        let group: GroupInfo = group.query_async(self.client.as_ref()).await?;
+        Ok(group.id)
+    }
+
+    /// Get the ID of a user from their username.
+    async fn user_id(&self, username: &str) -> Result<u64> {
+        #[derive(Deserialize)]
+        struct UserInfo {
+            id: u64,
+        }
+        let endpoint = Users::builder().username(username).build()?;
+        let users: Vec<UserInfo> = endpoint.query_async(self.client.as_ref()).await?;
+        match users.first() {
+            Some(user) => Ok(user.id),
+            None => Err(Error::not_found(format!("User {username} does not exist"))),
+        }
+    }
+
+    /// Page through the projects of the given endpoint, keeping the ones matching `list_info`
+    /// until the requested page is filled.
+    async fn list_projects<E: Endpoint + Pageable + Sync>(
+        &self,
+        endpoint: E,
+        list_info: &ListReposInfo,
+    ) -> Result<Vec<Project>> {
+        let paged = api::paged(endpoint, Pagination::All);
+        let mut stream = pin!(paged.iter_async::<_, Project>(self.client.as_ref()));
+        let mut projects = Vec::new();
+        while let Some(project) = stream.next().await {
+            let project = project?;
+            let private = project.visibility != "public";
+            let fork = project.forked_from_project.is_some();
+            if list_info.includes(private, fork, project.archived) {
+                projects.push(project);
+            }
+            if list_info.is_satisfied(projects.len()) {
+                break;
+            }
+        }
+        Ok(projects)
+    }
+
+    async fn project_info(
+        client: &gl::AsyncGitlab,
+        project: Project,
+        commits: u8,
+    ) -> Result<Repository> {
+        let last_commits;
+        if project.empty_repo || commits == 0 {
             last_commits = Vec::new();
         } else {
-            let commits = Commits::builder().project(project.id).build()?;
-            let commits = api::paged(commits, Pagination::Limit(COMMIT_COUNT as usize));
-            let commits: Vec<Commit> = commits.query_async(&self.client).await?;
-
-            last_commits = Vec::with_capacity(commits.len());
-            for commit in commits {
-                last_commits.push(super::Commit {
-                    sha: commit.id,
-                    message: commit.message,
-                    author: commit.author_name,
-                    // TODO: handle error
-                    date: DateTime::from_str(&commit.committed_date).unwrap(),
-                });
-            }
+            let endpoint = Commits::builder().project(project.id).build()?;
+            let endpoint = api::paged(endpoint, Pagination::Limit(commits as usize));
+            let commits: Vec<GitlabCommit> = endpoint.query_async(client).await?;
+            last_commits = commits.into_iter().map(super::Commit::from).collect();
         }
 
+        let visibility = match project.visibility.as_str() {
+            "private" => Visibility::Private,
+            "internal" => Visibility::Internal,
+            _ => Visibility::Public,
+        };
         Ok(Repository {
-            name: project.name,
+            name: project.path,
+            owner: project.namespace.full_path,
+            full_name: project.path_with_namespace,
             description: project.description,
-            private: project.visibility == "private",
+            private: visibility != Visibility::Public,
+            visibility,
             default_branch: project.default_branch,
             fork: project.forked_from_project.is_some(),
+            parent: project
+                .forked_from_project
+                .and_then(|x| RepoRef::parse(&x.path_with_namespace)),
+            archived: project.archived,
+            mirror: project.mirror,
+            html_url: project.web_url,
             ssh_url: project.ssh_url_to_repo,
             clone_url: project.http_url_to_repo,
+            created_at: project.created_at,
+            updated_at: project.updated_at.or(project.last_activity_at),
+            // The last activity includes pushes, but also issues and merge requests.
+            pushed_at: None,
+            stars: project.star_count,
+            forks_count: project.forks_count,
+            open_issues: project.open_issues_count.unwrap_or(0),
+            // Both are only available through separate requests.
+            size: None,
+            language: None,
+            topics: project.topics,
             last_commits,
         })
     }
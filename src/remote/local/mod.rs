@@ -0,0 +1,644 @@
+//! Support for plain directories of bare git repositories.
+//!
+//! A local remote treats a directory as a git server: every owner is a subdirectory, and every
+//! repository is a bare repository inside it (`<root>/<owner>/<name>.git`). The directory can live
+//! on this machine, or on another one that is reachable over SSH (like a gitolite or plain
+//! `git-shell` server), in which case all commands are run through `ssh`.
+//!
+//! The remote URL selects where the repositories live:
+//! - `/srv/git`, `~/git` or `file:///srv/git` - a directory on this machine.
+//! - `ssh://git@example.com:2222/srv/git` - a directory on another machine.
+//! - `git@example.com:repositories` - the same, using the scp-like syntax git understands.
+//!
+//! Since there is no server to store any metadata, the usual git conventions are used instead:
+//! - The description is stored in the `description` file of the repository (like gitweb does).
+//! - Repositories are public if they contain a `git-daemon-export-ok` file (like git-daemon does).
+//! - Forks are bare clones, so a repository is a fork if it has an `origin` remote.
+
+use std::process::Output;
+
+use crate::error::{Error, Result};
+use async_trait::async_trait;
+use chrono::DateTime;
+use tokio::{io::AsyncWriteExt, process::Command};
+
+use super::{
+    Commit, ListReposInfo, Remote, RemoteConfig, RepoCreateInfo, RepoForkOption, RepoRef,
+    Repository, Visibility,
+};
+
+/// The description git puts into new repositories.
+const DEFAULT_DESCRIPTION: &str = "Unnamed repository;";
+
+pub struct LocalRemote {
+    config: RemoteConfig,
+    /// The directory containing the repositories.
+    root: String,
+    /// The machine the repositories live on, if it is not this one.
+    host: Option<SshHost>,
+}
+
+#[derive(Debug, PartialEq)]
+struct SshHost {
+    destination: String,
+    port: Option<String>,
+}
+
+/// Split the remote URL into the SSH host (if any) and the root directory of the repositories.
+fn parse_url(url: &str) -> (Option<SshHost>, String) {
+    if let Some(path) = url.strip_prefix("file://") {
+        return (None, path.to_string());
+    }
+    if let Some(rest) = url.strip_prefix("ssh://") {
+        let (authority, path) = match rest.find('/') {
+            Some(i) => rest.split_at(i),
+            None => (rest, ""),
+        };
+        // Only split off the port after the user, in case the user contains a colon.
+        let at = authority.rfind('@').map(|i| i + 1).unwrap_or(0);
+        let (destination, port) = match authority[at..].split_once(':') {
+            Some((host, port)) => (
+                format!("{}{host}", &authority[..at]),
+                Some(port.to_string()),
+            ),
+            None => (authority.to_string(), None),
+        };
+        let host = SshHost { destination, port };
+        return (Some(host), path.to_string());
+    }
+    // scp-like syntax (`user@host:path`). Anything with a slash before the colon is a path, and
+    // single letters before the colon are Windows drive letters.
+    if let Some((destination, path)) = url.split_once(':') {
+        if destination.len() > 1 && !destination.contains(['/', '\\']) {
+            let host = SshHost {
+                destination: destination.to_string(),
+                port: None,
+            };
+            return (Some(host), path.to_string());
+        }
+    }
+    if let Some(path) = url.strip_prefix("~/") {
+        if let Ok(home) = std::env::var("HOME") {
+            return (None, format!("{home}/{path}"));
+        }
+    }
+    (None, url.to_string())
+}
+
+/// Quote an argument for the shell on the other end of an SSH connection.
+fn quote(arg: &str) -> String {
+    format!("'{}'", arg.replace('\'', r"'\''"))
+}
+
+/// Make sure a repository name or owner can't escape the root directory.
+fn check_path(path: &str) -> Result<()> {
+    let valid = !path.is_empty()
+        && path
+            .split('/')
+            .all(|x| !x.is_empty() && !x.starts_with('.') && !x.contains('\\'));
+    if !valid {
+        return Err(Error::other(format!("Invalid repository path '{path}'")));
+    }
+    Ok(())
+}
+
+fn parse_commits(log: &str) -> Vec<Commit> {
+    log.split('\x1e')
+        .filter_map(|entry| {
+            let mut fields = entry.trim_start_matches('\n').splitn(4, '\x1f');
+            let sha = fields.next()?;
+            let author = fields.next()?;
+            let date = DateTime::parse_from_rfc3339(fields.next()?).ok()?;
+            let message = fields.next()?;
+            Some(Commit {
+                sha: sha.to_string(),
+                author: author.to_string(),
+                date: date.into(),
+                message: message.trim_end().to_string(),
+            })
+        })
+        .collect()
+}
+
+#[async_trait]
+impl Remote for LocalRemote {
+    async fn new(config: &RemoteConfig) -> Result<Self> {
+        let (host, root) = parse_url(&config.url);
+        let root = match root.trim_end_matches('/') {
+            "" if root.starts_with('/') => "/".to_string(),
+            root => root.to_string(),
+        };
+        Ok(Self {
+            config: config.clone(),
+            root,
+            host,
+        })
+    }
+
+    async fn check_auth(&self) -> Result<bool> {
+        // There's nothing to authenticate with, but we can check that the directory is reachable.
+        self.exists(&self.root).await
+    }
+
+    async fn create_repo(&self, create_info: RepoCreateInfo) -> Result<Repository> {
+        if create_info.license.is_some() {
+            return Err(Error::other(
+                "License templates are not supported for local remotes",
+            ));
+        }
+        let owner = create_info
+            .owner
+            .unwrap_or_else(|| self.config.username.clone());
+        let name = create_info.name.trim_end_matches(".git");
+        check_path(&owner)?;
+        check_path(name)?;
+
+        let path = self.repo_path(&owner, name);
+        if self.exists(&path).await? {
+            return Err(Error::other(format!(
+                "Repository {owner}/{name} already exists"
+            )));
+        }
+        self.run_checked("git", &["init", "--bare", "--quiet", &path], None)
+            .await?;
+
+        if let Some(description) = &create_info.description {
+            self.write_file(&format!("{path}/description"), &format!("{description}\n"))
+                .await?;
+        }
+        if !create_info.private {
+            self.write_file(&format!("{path}/git-daemon-export-ok"), "")
+                .await?;
+        }
+        if create_info.init {
+            self.init_readme(&path, name, create_info.description.as_deref())
+                .await?;
+        }
+
+        self.repo_info(&owner, name, &path, self.config.commit_limit())
+            .await
+    }
+
+    async fn create_fork(&self, options: RepoForkOption) -> Result<Repository> {
+        let source = self
+            .existing_repo_path(&options.owner, &options.repo)
+            .await?;
+        let owner = options
+            .organization
+            .unwrap_or_else(|| self.config.username.clone());
+        let name = options.name.unwrap_or(options.repo);
+        check_path(&owner)?;
+        check_path(&name)?;
+
+        let path = self.repo_path(&owner, &name);
+        if self.exists(&path).await? {
+            return Err(Error::other(format!(
+                "Repository {owner}/{name} already exists"
+            )));
+        }
+        let mut args = vec!["clone", "--bare", "--quiet"];
+        if options.default_branch_only.unwrap_or(false) {
+            args.push("--single-branch");
+        }
+        args.extend([source.as_str(), path.as_str()]);
+        self.run_checked("git", &args, None).await?;
+
+        // A fork inherits the description and visibility of its parent.
+        if let Some(description) = self.read_file(&format!("{source}/description")).await {
+            self.write_file(&format!("{path}/description"), &description)
+                .await?;
+        }
+        if self
+            .exists(&format!("{source}/git-daemon-export-ok"))
+            .await?
+        {
+            self.write_file(&format!("{path}/git-daemon-export-ok"), "")
+                .await?;
+        }
+
+        self.repo_info(&owner, &name, &path, self.config.commit_limit())
+            .await
+    }
+
+    async fn list_repos(&self, list_info: ListReposInfo) -> Result<Vec<Repository>> {
+        let owner = list_info
+            .owner
+            .clone()
+            .unwrap_or_else(|| self.config.username.clone());
+        check_path(&owner)?;
+
+        let dir = format!("{}/{owner}", self.root);
+        if !self.exists(&dir).await? {
+            // A user without any repositories doesn't have a directory yet.
+            if owner == self.config.username {
+                return Ok(vec![]);
+            }
+            return Err(Error::not_found(format!("Could not find owner {owner}")));
+        }
+
+        let mut names: Vec<String> = self
+            .list_dir(&dir)
+            .await?
+            .into_iter()
+            .filter_map(|x| x.strip_suffix(".git").map(str::to_string))
+            .collect();
+        names.sort();
+
+        let mut repos = Vec::with_capacity(names.len());
+        for name in names {
+            let path = self.repo_path(&owner, &name);
+            let repo = self
+                .repo_info(&owner, &name, &path, list_info.commits.count())
+                .await?;
+            if list_info.includes(repo.private, repo.fork, repo.archived) {
+                repos.push(repo);
+            }
+            if list_info.is_satisfied(repos.len()) {
+                break;
+            }
+        }
+        Ok(list_info.paginate(repos))
+    }
+
+    async fn get_repo_info(&self, owner: &str, name: &str) -> Result<Repository> {
+        let path = self.existing_repo_path(owner, name).await?;
+        self.repo_info(owner, name, &path, self.config.commit_limit())
+            .await
+    }
+
+    async fn transfer_repo(&self, owner: &str, name: &str, new_owner: &str) -> Result<Repository> {
+        self.move_repo(owner, name, new_owner, name).await
+    }
+
+    async fn rename_repo(&self, owner: &str, name: &str, new_name: &str) -> Result<Repository> {
+        self.move_repo(owner, name, owner, new_name.trim_end_matches(".git"))
+            .await
+    }
+
+    async fn delete_repo(&self, owner: &str, name: &str) -> Result<()> {
+        let path = self.existing_repo_path(owner, name).await?;
+        match &self.host {
+            Some(_) => {
+                self.run_checked("rm", &["-rf", "--", &path], None).await?;
+            }
+            None => tokio::fs::remove_dir_all(&path).await?,
+        }
+        Ok(())
+    }
+
+    fn get_config(&self) -> &RemoteConfig {
+        &self.config
+    }
+
+    fn clone_url(&self, owner: &str, repo_name: &str) -> String {
+        match &self.host {
+            // Keep the URL as configured, so the same SSH config (and port) is used.
+            Some(_) => {
+                let url = self.config.url.trim_end_matches('/');
+                match url.ends_with(':') {
+                    true => format!("{url}{owner}/{repo_name}.git"),
+                    false => format!("{url}/{owner}/{repo_name}.git"),
+                }
+            }
+            None => self.repo_path(owner, repo_name),
+        }
+    }
+}
+
+impl LocalRemote {
+    fn repo_path(&self, owner: &str, name: &str) -> String {
+        match self.root.as_str() {
+            "" => format!("{owner}/{name}.git"),
+            root => format!("{}/{owner}/{name}.git", root.trim_end_matches('/')),
+        }
+    }
+
+    async fn existing_repo_path(&self, owner: &str, name: &str) -> Result<String> {
+        check_path(owner)?;
+        check_path(name)?;
+        let path = self.repo_path(owner, name);
+        if !self.exists(&path).await? {
+            return Err(Error::not_found(format!(
+                "Could not find repository {owner}/{name}"
+            )));
+        }
+        Ok(path)
+    }
+
+    /// Move a repository to another owner or name, creating the owner's directory if needed.
+    async fn move_repo(
+        &self,
+        owner: &str,
+        name: &str,
+        new_owner: &str,
+        new_name: &str,
+    ) -> Result<Repository> {
+        let path = self.existing_repo_path(owner, name).await?;
+        check_path(new_owner)?;
+        check_path(new_name)?;
+        let new_path = self.repo_path(new_owner, new_name);
+        if self.exists(&new_path).await? {
+            return Err(Error::other(format!(
+                "Repository {new_owner}/{new_name} already exists"
+            )));
+        }
+        let (dir, _) = new_path
+            .rsplit_once('/')
+            .expect("repository paths contain the owner");
+        match &self.host {
+            Some(_) => {
+                self.run_checked("mkdir", &["-p", "--", dir], None).await?;
+                self.run_checked("mv", &["--", &path, &new_path], None)
+                    .await?;
+            }
+            None => {
+                tokio::fs::create_dir_all(dir).await?;
+                tokio::fs::rename(&path, &new_path).await?;
+            }
+        }
+        self.repo_info(new_owner, new_name, &new_path, self.config.commit_limit())
+            .await
+    }
+
+    /// Run a program, either on this machine or on the SSH host.
+    async fn run(&self, program: &str, args: &[&str], input: Option<&str>) -> Result<Output> {
+        let mut cmd = match &self.host {
+            Some(host) => {
+                let mut cmd = Command::new("ssh");
+                if let Some(port) = &host.port {
+                    cmd.args(["-p", port]);
+                }
+                let remote_cmd: Vec<String> = std::iter::once(program)
+                    .chain(args.iter().copied())
+                    .map(quote)
+                    .collect();
+                cmd.args([host.destination.as_str(), "--", &remote_cmd.join(" ")]);
+                cmd
+            }
+            None => {
+                let mut cmd = Command::new(program);
+                cmd.args(args);
+                cmd
+            }
+        };
+        cmd.stdin(std::process::Stdio::piped())
+            .stdout(std::process::Stdio::piped())
+            .stderr(std::process::Stdio::piped());
+
+        let mut child = cmd.spawn()?;
+        let mut stdin = child.stdin.take().expect("stdin must be piped");
+        if let Some(input) = input {
+            stdin.write_all(input.as_bytes()).await?;
+        }
+        drop(stdin);
+        Ok(child.wait_with_output().await?)
+    }
+
+    /// Run a program and return its output, failing if it didn't exit successfully.
+    async fn run_checked(
+        &self,
+        program: &str,
+        args: &[&str],
+        input: Option<&str>,
+    ) -> Result<String> {
+        let output = self.run(program, args, input).await?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(Error::other(format!(
+                "Failed to run {program} {}: {}",
+                args.join(" "),
+                stderr.trim()
+            )));
+        }
+        Ok(String::from_utf8_lossy(&output.stdout)
+            .trim_end()
+            .to_string())
+    }
+
+    /// Run a git command inside the repository at `path`.
+    async fn git(&self, path: &str, args: &[&str], input: Option<&str>) -> Result<String> {
+        let args: Vec<&str> = ["-C", path]
+            .into_iter()
+            .chain(args.iter().copied())
+            .collect();
+        self.run_checked("git", &args, input).await
+    }
+
+    async fn exists(&self, path: &str) -> Result<bool> {
+        match &self.host {
+            Some(_) => Ok(self
+                .run("test", &["-e", path], None)
+                .await?
+                .status
+                .success()),
+            None => Ok(tokio::fs::try_exists(path).await?),
+        }
+    }
+
+    async fn read_file(&self, path: &str) -> Option<String> {
+        match &self.host {
+            Some(_) => self.run_checked("cat", &[path], None).await.ok(),
+            None => tokio::fs::read_to_string(path).await.ok(),
+        }
+    }
+
+    async fn write_file(&self, path: &str, contents: &str) -> Result<()> {
+        match &self.host {
+            Some(_) => {
+                self.run_checked("tee", &[path], Some(contents)).await?;
+            }
+            None => tokio::fs::write(path, contents).await?,
+        }
+        Ok(())
+    }
+
+    async fn list_dir(&self, path: &str) -> Result<Vec<String>> {
+        match &self.host {
+            Some(_) => {
+                let output = self.run_checked("ls", &["-1", "--", path], None).await?;
+                Ok(output.lines().map(str::to_string).collect())
+            }
+            None => {
+                let mut entries = tokio::fs::read_dir(path).await?;
+                let mut names = vec![];
+                while let Some(entry) = entries.next_entry().await? {
+                    names.push(entry.file_name().to_string_lossy().to_string());
+                }
+                Ok(names)
+            }
+        }
+    }
+
+    /// Create an initial commit containing a README, without needing a working tree.
+    async fn init_readme(&self, path: &str, name: &str, description: Option<&str>) -> Result<()> {
+        let readme = match description {
+            Some(description) => format!("# {name}\n\n{description}\n"),
+            None => format!("# {name}\n"),
+        };
+        let blob = self
+            .git(path, &["hash-object", "-w", "--stdin"], Some(&readme))
+            .await?;
+        let entry = format!("100644 blob {blob}\tREADME.md\n");
+        let tree = self.git(path, &["mktree"], Some(&entry)).await?;
+        let commit = self
+            .git(path, &["commit-tree", &tree, "-m", "Initial commit"], None)
+            .await?;
+        self.git(path, &["update-ref", "HEAD", &commit], None)
+            .await?;
+        Ok(())
+    }
+
+    async fn repo_info(
+        &self,
+        owner: &str,
+        name: &str,
+        path: &str,
+        commits: u8,
+    ) -> Result<Repository> {
+        let description = self
+            .read_file(&format!("{path}/description"))
+            .await
+            .map(|x| x.trim().to_string())
+            .filter(|x| !x.is_empty() && !x.starts_with(DEFAULT_DESCRIPTION));
+        let private = !self.exists(&format!("{path}/git-daemon-export-ok")).await?;
+        let origin = self
+            .git(path, &["config", "--get", "remote.origin.url"], None)
+            .await
+            .ok()
+            .filter(|x| !x.is_empty());
+        let default_branch = self
+            .git(path, &["symbolic-ref", "--short", "HEAD"], None)
+            .await
+            .ok();
+
+        // Bare repositories are only changed by pushes, so the newest ref is the last push.
+        let pushed_at = self
+            .git(
+                path,
+                &[
+                    "for-each-ref",
+                    "--sort=-committerdate",
+                    "--count=1",
+                    "--format=%(committerdate:iso-strict)",
+                ],
+                None,
+            )
+            .await?;
+        let pushed_at = DateTime::parse_from_rfc3339(pushed_at.trim())
+            .ok()
+            .map(|x| x.to_utc());
+
+        let mut last_commits = Vec::new();
+        if commits > 0 {
+            // `--ignore-missing` makes an empty repository log nothing instead of failing.
+            let count = format!("-n{commits}");
+            let log = self
+                .git(
+                    path,
+                    &[
+                        "log",
+                        &count,
+                        "--format=%H%x1f%an%x1f%aI%x1f%B%x1e",
+                        "--ignore-missing",
+                        "HEAD",
+                        "--",
+                    ],
+                    None,
+                )
+                .await?;
+            last_commits = parse_commits(&log);
+        }
+
+        let url = self.clone_url(owner, name);
+        Ok(Repository {
+            name: name.to_string(),
+            owner: owner.to_string(),
+            full_name: format!("{owner}/{name}"),
+            description,
+            private,
+            visibility: match private {
+                true => Visibility::Private,
+                false => Visibility::Public,
+            },
+            fork: origin.is_some(),
+            parent: origin.as_deref().and_then(parent_from_url),
+            default_branch,
+            ssh_url: url.clone(),
+            clone_url: url,
+            pushed_at,
+            last_commits,
+            ..Default::default()
+        })
+    }
+}
+
+/// Get the repository a fork was cloned from, assuming it follows the `<owner>/<name>.git` layout.
+fn parent_from_url(url: &str) -> Option<RepoRef> {
+    let path = url.trim_end_matches('/').trim_end_matches(".git");
+    let (rest, name) = path.rsplit_once('/')?;
+    let owner = rest.rsplit(['/', ':']).next()?;
+    Some(RepoRef {
+        owner: owner.to_string(),
+        name: name.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn ssh(destination: &str, port: Option<&str>) -> Option<SshHost> {
+        Some(SshHost {
+            destination: destination.to_string(),
+            port: port.map(str::to_string),
+        })
+    }
+
+    #[test]
+    fn test_parse_url() {
+        assert_eq!(parse_url("/srv/git"), (None, "/srv/git".to_string()));
+        assert_eq!(parse_url("file:///srv/git"), (None, "/srv/git".to_string()));
+        assert_eq!(parse_url("C:/git"), (None, "C:/git".to_string()));
+        assert_eq!(
+            parse_url("ssh://git@example.com:2222/srv/git"),
+            (ssh("git@example.com", Some("2222")), "/srv/git".to_string())
+        );
+        assert_eq!(
+            parse_url("ssh://example.com/srv/git"),
+            (ssh("example.com", None), "/srv/git".to_string())
+        );
+        assert_eq!(
+            parse_url("git@example.com:repositories"),
+            (ssh("git@example.com", None), "repositories".to_string())
+        );
+    }
+
+    #[test]
+    fn test_check_path() {
+        assert!(check_path("alice").is_ok());
+        assert!(check_path("group/subgroup").is_ok());
+        assert!(check_path("").is_err());
+        assert!(check_path("../etc").is_err());
+        assert!(check_path("/etc").is_err());
+        assert!(check_path("alice/.git").is_err());
+    }
+
+    #[test]
+    fn test_parent_from_url() {
+        let parent = |owner: &str, name: &str| {
+            Some(RepoRef {
+                owner: owner.to_string(),
+                name: name.to_string(),
+            })
+        };
+        assert_eq!(
+            parent_from_url("/srv/git/alice/tool.git"),
+            parent("alice", "tool")
+        );
+        assert_eq!(
+            parent_from_url("git@example.com:alice/tool.git/"),
+            parent("alice", "tool")
+        );
+        assert_eq!(parent_from_url("tool.git"), None);
+    }
+}
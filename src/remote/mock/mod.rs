@@ -0,0 +1,2021 @@
+//! An in-memory remote for testing code that works with remotes, without needing a live forge.
+//!
+//! [MockRemote] stores repositories, forks and commits in memory, records every call it receives
+//! and can be told to fail specific methods:
+//! ```
+//! # async fn run() {
+//! use gritty::remote::{Remote, RepoCreateInfo};
+//! use gritty::remote::mock::{Call, Failure, Method, MockRemote};
+//!
+//! let remote = MockRemote::default();
+//! let repo = remote
+//!     .create_repo(RepoCreateInfo {
+//!         name: "hello".to_string(),
+//!         init: true,
+//!         ..Default::default()
+//!     })
+//!     .await
+//!     .unwrap();
+//! assert_eq!(repo.last_commits.len(), 1);
+//!
+//! remote.fail(Method::GetRepoInfo, Failure::NotFound);
+//! assert!(remote.get_repo_info("mock", "hello").await.is_err());
+//! assert!(matches!(remote.calls()[1], Call::GetRepoInfo { .. }));
+//! # }
+//! ```
+//!
+//! The mock is only available with the `mock` feature.
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::{Mutex, MutexGuard},
+};
+
+use crate::error::{Error, ErrorKind, Result};
+use async_trait::async_trait;
+use chrono::Utc;
+
+use super::{
+    Auth, Branch, BranchProtection, CloneProtocol, Comment, Commit, DeployKey, Issue,
+    IssueCreateInfo, IssueState, Label, ListIssuesInfo, ListPullRequestsInfo, ListReposInfo,
+    Milestone, Mirror, MirrorCreateInfo, MirrorDirection, PullRequest, PullRequestCreateInfo,
+    PullRequestMergeInfo, PullRequestState, Release, ReleaseAsset, ReleaseCreateInfo, Remote,
+    RemoteConfig, RepoCreateInfo, RepoForkOption, RepoImportInfo, RepoRef, RepoUpdateInfo,
+    Repository, SshKey, SshKeyCreateInfo, SyncStatus, Tag, TagCreateInfo, Visibility, Webhook,
+    WebhookCreateInfo, WebhookUpdateInfo, WikiPage,
+};
+
+/// The methods of [Remote] a [Failure] can be injected into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Method {
+    CheckAuth,
+    CreateRepo,
+    CreateFork,
+    ListRepos,
+    GetRepoInfo,
+    UpdateRepo,
+    ArchiveRepo,
+    UnarchiveRepo,
+    TransferRepo,
+    RenameRepo,
+    ImportRepo,
+    CreatePushMirror,
+    CheckPushMirrorInterval,
+    ListMirrors,
+    SyncFork,
+    ListBranches,
+    CreateBranch,
+    DeleteBranch,
+    ProtectBranch,
+    ListTags,
+    CreateTag,
+    DeleteTag,
+    ListWebhooks,
+    CreateWebhook,
+    UpdateWebhook,
+    DeleteWebhook,
+    TestWebhook,
+    ListSshKeys,
+    AddSshKey,
+    DeleteSshKey,
+    ListDeployKeys,
+    AddDeployKey,
+    DeleteDeployKey,
+    ListLabels,
+    CreateLabel,
+    ListMilestones,
+    CreateMilestone,
+    ListIssues,
+    GetIssue,
+    CreateIssue,
+    SetIssueState,
+    ListIssueComments,
+    CreateIssueComment,
+    ListPullRequests,
+    GetPullRequest,
+    CreatePullRequest,
+    CreatePullRequestComment,
+    ApprovePullRequest,
+    MergePullRequest,
+    ListPullRequestComments,
+    ListReleases,
+    CreateRelease,
+    GetRelease,
+    DeleteRelease,
+    UploadReleaseAsset,
+    DownloadReleaseAsset,
+    ListWikiPages,
+    CreateWikiPage,
+    DeleteRepo,
+    CloneRepo,
+    AddRemote,
+}
+
+/// A failure to inject into a method of the [MockRemote].
+#[derive(Debug, Clone, PartialEq)]
+pub enum Failure {
+    /// The credentials are rejected (401). [Remote::check_auth] returns `false` instead of failing.
+    Unauthorized,
+    /// The repository doesn't exist (404).
+    NotFound,
+    /// The repository is empty (409), which is how GitHub answers requests for the commits of an
+    /// empty repository.
+    EmptyRepository,
+    /// Fail with the given error.
+    Error(Error),
+}
+
+impl Failure {
+    fn error(&self) -> Error {
+        let (message, kind, status) = match self {
+            Failure::Unauthorized => ("Bad credentials", ErrorKind::Authentication, 401),
+            Failure::NotFound => ("Not Found", ErrorKind::NotFound, 404),
+            Failure::EmptyRepository => ("Git Repository is empty.", ErrorKind::Other, 409),
+            Failure::Error(err) => return err.clone(),
+        };
+        Error {
+            message: message.to_string(),
+            kind,
+            status: Some(status),
+        }
+    }
+}
+
+/// A call received by the [MockRemote], along with its arguments.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Call {
+    CheckAuth,
+    CreateRepo(RepoCreateInfo),
+    CreateFork(RepoForkOption),
+    ListRepos(ListReposInfo),
+    GetRepoInfo {
+        owner: String,
+        name: String,
+    },
+    UpdateRepo {
+        owner: String,
+        name: String,
+        update: RepoUpdateInfo,
+    },
+    ArchiveRepo {
+        owner: String,
+        name: String,
+    },
+    UnarchiveRepo {
+        owner: String,
+        name: String,
+    },
+    TransferRepo {
+        owner: String,
+        name: String,
+        new_owner: String,
+    },
+    RenameRepo {
+        owner: String,
+        name: String,
+        new_name: String,
+    },
+    ImportRepo(RepoImportInfo),
+    CreatePushMirror {
+        owner: String,
+        name: String,
+        mirror: MirrorCreateInfo,
+    },
+    CheckPushMirrorInterval {
+        owner: String,
+        name: String,
+        interval: String,
+    },
+    ListMirrors {
+        owner: String,
+        name: String,
+    },
+    SyncFork {
+        owner: String,
+        name: String,
+        branch: String,
+    },
+    ListBranches {
+        owner: String,
+        name: String,
+    },
+    CreateBranch {
+        owner: String,
+        name: String,
+        branch: String,
+        from: Option<String>,
+    },
+    DeleteBranch {
+        owner: String,
+        name: String,
+        branch: String,
+    },
+    ProtectBranch {
+        owner: String,
+        name: String,
+        branch: String,
+        protection: BranchProtection,
+    },
+    ListTags {
+        owner: String,
+        name: String,
+    },
+    CreateTag {
+        owner: String,
+        name: String,
+        tag: TagCreateInfo,
+    },
+    DeleteTag {
+        owner: String,
+        name: String,
+        tag: String,
+    },
+    ListWebhooks {
+        owner: String,
+        name: String,
+    },
+    CreateWebhook {
+        owner: String,
+        name: String,
+        hook: WebhookCreateInfo,
+    },
+    UpdateWebhook {
+        owner: String,
+        name: String,
+        id: u64,
+        update: WebhookUpdateInfo,
+    },
+    DeleteWebhook {
+        owner: String,
+        name: String,
+        id: u64,
+    },
+    TestWebhook {
+        owner: String,
+        name: String,
+        id: u64,
+    },
+    ListSshKeys,
+    AddSshKey(SshKeyCreateInfo),
+    DeleteSshKey {
+        id: u64,
+    },
+    ListDeployKeys {
+        owner: String,
+        name: String,
+    },
+    AddDeployKey {
+        owner: String,
+        name: String,
+        key: SshKeyCreateInfo,
+        read_only: bool,
+    },
+    DeleteDeployKey {
+        owner: String,
+        name: String,
+        id: u64,
+    },
+    ListLabels {
+        owner: String,
+        name: String,
+    },
+    CreateLabel {
+        owner: String,
+        name: String,
+        label: Label,
+    },
+    ListMilestones {
+        owner: String,
+        name: String,
+    },
+    CreateMilestone {
+        owner: String,
+        name: String,
+        milestone: Milestone,
+    },
+    ListIssues {
+        owner: String,
+        name: String,
+        list_info: ListIssuesInfo,
+    },
+    GetIssue {
+        owner: String,
+        name: String,
+        number: u64,
+    },
+    CreateIssue {
+        owner: String,
+        name: String,
+        issue: IssueCreateInfo,
+    },
+    SetIssueState {
+        owner: String,
+        name: String,
+        number: u64,
+        state: IssueState,
+    },
+    ListIssueComments {
+        owner: String,
+        name: String,
+        number: u64,
+    },
+    CreateIssueComment {
+        owner: String,
+        name: String,
+        number: u64,
+        body: String,
+    },
+    ListPullRequests {
+        owner: String,
+        name: String,
+        list_info: ListPullRequestsInfo,
+    },
+    GetPullRequest {
+        owner: String,
+        name: String,
+        number: u64,
+    },
+    CreatePullRequest {
+        owner: String,
+        name: String,
+        pull_request: PullRequestCreateInfo,
+    },
+    CreatePullRequestComment {
+        owner: String,
+        name: String,
+        number: u64,
+        body: String,
+    },
+    ApprovePullRequest {
+        owner: String,
+        name: String,
+        number: u64,
+    },
+    MergePullRequest {
+        owner: String,
+        name: String,
+        number: u64,
+        merge_info: PullRequestMergeInfo,
+    },
+    ListPullRequestComments {
+        owner: String,
+        name: String,
+        number: u64,
+    },
+    ListReleases {
+        owner: String,
+        name: String,
+    },
+    CreateRelease {
+        owner: String,
+        name: String,
+        release: ReleaseCreateInfo,
+    },
+    GetRelease {
+        owner: String,
+        name: String,
+        tag: String,
+    },
+    DeleteRelease {
+        owner: String,
+        name: String,
+        tag: String,
+    },
+    UploadReleaseAsset {
+        owner: String,
+        name: String,
+        tag: String,
+        file_name: String,
+        data: Vec<u8>,
+    },
+    DownloadReleaseAsset {
+        owner: String,
+        name: String,
+        tag: String,
+        asset_name: String,
+    },
+    ListWikiPages {
+        owner: String,
+        name: String,
+    },
+    CreateWikiPage {
+        owner: String,
+        name: String,
+        page: WikiPage,
+    },
+    DeleteRepo {
+        owner: String,
+        name: String,
+    },
+    CloneRepo {
+        owner: String,
+        name: String,
+        path: String,
+        recursive: bool,
+    },
+    AddRemote {
+        owner: String,
+        name: String,
+        branch: Option<String>,
+    },
+}
+
+impl Call {
+    fn method(&self) -> Method {
+        match self {
+            Call::CheckAuth => Method::CheckAuth,
+            Call::CreateRepo(_) => Method::CreateRepo,
+            Call::CreateFork(_) => Method::CreateFork,
+            Call::ListRepos(_) => Method::ListRepos,
+            Call::GetRepoInfo { .. } => Method::GetRepoInfo,
+            Call::UpdateRepo { .. } => Method::UpdateRepo,
+            Call::ArchiveRepo { .. } => Method::ArchiveRepo,
+            Call::UnarchiveRepo { .. } => Method::UnarchiveRepo,
+            Call::TransferRepo { .. } => Method::TransferRepo,
+            Call::RenameRepo { .. } => Method::RenameRepo,
+            Call::ImportRepo(_) => Method::ImportRepo,
+            Call::CreatePushMirror { .. } => Method::CreatePushMirror,
+            Call::CheckPushMirrorInterval { .. } => Method::CheckPushMirrorInterval,
+            Call::ListMirrors { .. } => Method::ListMirrors,
+            Call::SyncFork { .. } => Method::SyncFork,
+            Call::ListBranches { .. } => Method::ListBranches,
+            Call::CreateBranch { .. } => Method::CreateBranch,
+            Call::DeleteBranch { .. } => Method::DeleteBranch,
+            Call::ProtectBranch { .. } => Method::ProtectBranch,
+            Call::ListTags { .. } => Method::ListTags,
+            Call::CreateTag { .. } => Method::CreateTag,
+            Call::DeleteTag { .. } => Method::DeleteTag,
+            Call::ListWebhooks { .. } => Method::ListWebhooks,
+            Call::CreateWebhook { .. } => Method::CreateWebhook,
+            Call::UpdateWebhook { .. } => Method::UpdateWebhook,
+            Call::DeleteWebhook { .. } => Method::DeleteWebhook,
+            Call::TestWebhook { .. } => Method::TestWebhook,
+            Call::ListSshKeys => Method::ListSshKeys,
+            Call::AddSshKey(_) => Method::AddSshKey,
+            Call::DeleteSshKey { .. } => Method::DeleteSshKey,
+            Call::ListDeployKeys { .. } => Method::ListDeployKeys,
+            Call::AddDeployKey { .. } => Method::AddDeployKey,
+            Call::DeleteDeployKey { .. } => Method::DeleteDeployKey,
+            Call::ListLabels { .. } => Method::ListLabels,
+            Call::CreateLabel { .. } => Method::CreateLabel,
+            Call::ListMilestones { .. } => Method::ListMilestones,
+            Call::CreateMilestone { .. } => Method::CreateMilestone,
+            Call::ListIssues { .. } => Method::ListIssues,
+            Call::GetIssue { .. } => Method::GetIssue,
+            Call::CreateIssue { .. } => Method::CreateIssue,
+            Call::SetIssueState { .. } => Method::SetIssueState,
+            Call::ListIssueComments { .. } => Method::ListIssueComments,
+            Call::CreateIssueComment { .. } => Method::CreateIssueComment,
+            Call::ListPullRequests { .. } => Method::ListPullRequests,
+            Call::GetPullRequest { .. } => Method::GetPullRequest,
+            Call::CreatePullRequest { .. } => Method::CreatePullRequest,
+            Call::CreatePullRequestComment { .. } => Method::CreatePullRequestComment,
+            Call::ApprovePullRequest { .. } => Method::ApprovePullRequest,
+            Call::MergePullRequest { .. } => Method::MergePullRequest,
+            Call::ListPullRequestComments { .. } => Method::ListPullRequestComments,
+            Call::ListReleases { .. } => Method::ListReleases,
+            Call::CreateRelease { .. } => Method::CreateRelease,
+            Call::GetRelease { .. } => Method::GetRelease,
+            Call::DeleteRelease { .. } => Method::DeleteRelease,
+            Call::UploadReleaseAsset { .. } => Method::UploadReleaseAsset,
+            Call::DownloadReleaseAsset { .. } => Method::DownloadReleaseAsset,
+            Call::ListWikiPages { .. } => Method::ListWikiPages,
+            Call::CreateWikiPage { .. } => Method::CreateWikiPage,
+            Call::DeleteRepo { .. } => Method::DeleteRepo,
+            Call::CloneRepo { .. } => Method::CloneRepo,
+            Call::AddRemote { .. } => Method::AddRemote,
+        }
+    }
+}
+
+type Key = (String, String);
+
+/// The refs, issue tracker, releases and wiki of a repository.
+#[derive(Default)]
+struct Tracker {
+    /// The branches besides the default branch, which follows the commits of the repository.
+    branches: Vec<Branch>,
+    tags: Vec<Tag>,
+    /// The protection rules, by branch name.
+    protections: HashMap<String, BranchProtection>,
+    hooks: Vec<Webhook>,
+    deploy_keys: Vec<DeployKey>,
+    labels: Vec<Label>,
+    milestones: Vec<Milestone>,
+    issues: Vec<Issue>,
+    pull_requests: Vec<PullRequest>,
+    /// The comments on issues and pull requests, by number.
+    comments: BTreeMap<u64, Vec<Comment>>,
+    releases: Vec<Release>,
+    /// The content of release assets, by tag and asset name.
+    assets: HashMap<Key, Vec<u8>>,
+    wiki: Vec<WikiPage>,
+}
+
+impl Tracker {
+    /// Issues and pull requests are numbered together, like on GitHub and Gitea.
+    fn next_number(&self) -> u64 {
+        let issues = self.issues.iter().map(|x| x.number);
+        let pulls = self.pull_requests.iter().map(|x| x.number);
+        issues.chain(pulls).max().unwrap_or(0) + 1
+    }
+
+    fn issue(&mut self, owner: &str, name: &str, number: u64) -> Result<&mut Issue> {
+        self.issues
+            .iter_mut()
+            .find(|x| x.number == number)
+            .ok_or_else(|| Error {
+                status: Some(404),
+                ..Error::not_found(format!("Issue {owner}/{name}#{number} not found"))
+            })
+    }
+
+    fn pull_request(&mut self, owner: &str, name: &str, number: u64) -> Result<&mut PullRequest> {
+        self.pull_requests
+            .iter_mut()
+            .find(|x| x.number == number)
+            .ok_or_else(|| Error {
+                status: Some(404),
+                ..Error::not_found(format!("Pull request {owner}/{name}#{number} not found"))
+            })
+    }
+
+    fn release(&mut self, owner: &str, name: &str, tag: &str) -> Result<&mut Release> {
+        self.releases
+            .iter_mut()
+            .find(|x| x.tag == tag)
+            .ok_or_else(|| Error {
+                status: Some(404),
+                ..Error::not_found(format!("Release {tag} of {owner}/{name} not found"))
+            })
+    }
+
+    fn webhook(&mut self, owner: &str, name: &str, id: u64) -> Result<&mut Webhook> {
+        self.hooks
+            .iter_mut()
+            .find(|x| x.id == id)
+            .ok_or_else(|| Error {
+                status: Some(404),
+                ..Error::not_found(format!("Webhook {id} of {owner}/{name} not found"))
+            })
+    }
+
+    /// Add a comment to the issue or pull request with the given number.
+    fn comment(&mut self, number: u64, author: String, body: &str) -> Comment {
+        let id = self.comments.values().map(Vec::len).sum::<usize>() as u64 + 1;
+        let comment = Comment {
+            id,
+            author,
+            body: body.to_string(),
+            created_at: Utc::now(),
+        };
+        self.comments
+            .entry(number)
+            .or_default()
+            .push(comment.clone());
+        comment
+    }
+}
+
+#[derive(Default)]
+struct State {
+    repos: BTreeMap<Key, Repository>,
+    /// Maps forks to the repository they were forked from.
+    parents: HashMap<Key, Key>,
+    /// The mirrors of every repository that has any.
+    mirrors: HashMap<Key, Vec<Mirror>>,
+    trackers: HashMap<Key, Tracker>,
+    failures: HashMap<Method, Failure>,
+    calls: Vec<Call>,
+    commit_count: u64,
+    /// The SSH keys of the user.
+    ssh_keys: Vec<SshKey>,
+}
+
+impl State {
+    fn commit(&mut self, author: &str, message: &str) -> Commit {
+        self.commit_count += 1;
+        Commit {
+            sha: format!("{:040x}", self.commit_count),
+            message: message.to_string(),
+            author: author.to_string(),
+            date: Utc::now(),
+        }
+    }
+}
+
+pub struct MockRemote {
+    config: RemoteConfig,
+    state: Mutex<State>,
+}
+
+impl Default for MockRemote {
+    /// A mock remote for the user `mock` on `https://mock.invalid`.
+    fn default() -> Self {
+        Self::with_config(RemoteConfig::new(
+            "mock",
+            CloneProtocol::HTTPS,
+            "https://mock.invalid",
+            Auth::None,
+        ))
+    }
+}
+
+fn key(owner: &str, name: &str) -> Key {
+    (owner.to_string(), name.to_string())
+}
+
+fn not_found(owner: &str, name: &str) -> Error {
+    Error {
+        status: Some(404),
+        ..Error::not_found(format!("Repository {owner}/{name} not found"))
+    }
+}
+
+impl MockRemote {
+    pub fn with_config(config: RemoteConfig) -> Self {
+        Self {
+            config,
+            state: Mutex::default(),
+        }
+    }
+
+    fn state(&self) -> MutexGuard<'_, State> {
+        self.state.lock().expect("mock state poisoned")
+    }
+
+    /// Make every following call to `method` fail, until [MockRemote::succeed] is called.
+    pub fn fail(&self, method: Method, failure: Failure) {
+        self.state().failures.insert(method, failure);
+    }
+
+    /// Stop failing calls to `method`.
+    pub fn succeed(&self, method: Method) {
+        self.state().failures.remove(&method);
+    }
+
+    /// All calls received so far, in order.
+    pub fn calls(&self) -> Vec<Call> {
+        self.state().calls.clone()
+    }
+
+    /// Store a repository, replacing any repository with the same owner and name.
+    pub fn insert(&self, repo: Repository) {
+        let key = key(&repo.owner, &repo.name);
+        self.state().repos.insert(key, repo);
+    }
+
+    /// Get a stored repository, without recording a call.
+    pub fn repo(&self, owner: &str, name: &str) -> Option<Repository> {
+        self.state().repos.get(&key(owner, name)).cloned()
+    }
+
+    /// Get the owner and name of the repository the given fork was created from.
+    pub fn parent(&self, owner: &str, name: &str) -> Option<(String, String)> {
+        self.state().parents.get(&key(owner, name)).cloned()
+    }
+
+    /// Add a commit by the configured user to a stored repository.
+    pub fn commit(&self, owner: &str, name: &str, message: &str) -> Result<Commit> {
+        let mut state = self.state();
+        let commit = state.commit(&self.config.username, message);
+        let repo = state
+            .repos
+            .get_mut(&key(owner, name))
+            .ok_or_else(|| not_found(owner, name))?;
+        repo.last_commits.insert(0, commit.clone());
+        repo.pushed_at = Some(commit.date);
+        Ok(commit)
+    }
+
+    /// Store a pull request in a stored repository, numbered after its issues and pull requests.
+    /// Returns the stored pull request.
+    pub fn insert_pull_request(
+        &self,
+        owner: &str,
+        name: &str,
+        pull_request: PullRequest,
+    ) -> Result<PullRequest> {
+        self.with_tracker(owner, name, |tracker| {
+            let pull_request = PullRequest {
+                number: tracker.next_number(),
+                ..pull_request
+            };
+            tracker.pull_requests.push(pull_request.clone());
+            Ok(pull_request)
+        })
+    }
+
+    /// Get every branch of a stored repository, starting with the default branch once the
+    /// repository has commits.
+    fn branches(&self, owner: &str, name: &str) -> Result<Vec<Branch>> {
+        let mut state = self.state();
+        let repo = state
+            .repos
+            .get(&key(owner, name))
+            .ok_or_else(|| not_found(owner, name))?;
+        let default = repo
+            .default_branch
+            .clone()
+            .zip(repo.last_commits.first().cloned());
+        let tracker = state.trackers.entry(key(owner, name)).or_default();
+        let mut branches = Vec::new();
+        if let Some((name, commit)) = default {
+            branches.push(Branch {
+                name,
+                commit,
+                protected: false,
+            });
+        }
+        branches.extend(tracker.branches.iter().cloned());
+        for branch in &mut branches {
+            branch.protected = tracker.protections.contains_key(&branch.name);
+        }
+        Ok(branches)
+    }
+
+    /// Get the commit a branch, tag or SHA of a stored repository points to.
+    fn resolve(&self, owner: &str, name: &str, target: &str) -> Result<Commit> {
+        let branches = self.branches(owner, name)?;
+        if let Some(branch) = branches.into_iter().find(|x| x.name == target) {
+            return Ok(branch.commit);
+        }
+        let mut state = self.state();
+        let repo = state
+            .repos
+            .get(&key(owner, name))
+            .cloned()
+            .unwrap_or_default();
+        let tracker = state.trackers.entry(key(owner, name)).or_default();
+        let sha = tracker
+            .tags
+            .iter()
+            .find(|x| x.name == target)
+            .map_or(target, |x| x.sha.as_str());
+        let commits = tracker.branches.iter().map(|x| &x.commit);
+        commits
+            .chain(&repo.last_commits)
+            .find(|x| x.sha == sha)
+            .cloned()
+            .ok_or_else(|| Error {
+                status: Some(404),
+                ..Error::not_found(format!("Reference {target} of {owner}/{name} not found"))
+            })
+    }
+
+    /// Run `f` on the tracker of a stored repository.
+    fn with_tracker<T>(
+        &self,
+        owner: &str,
+        name: &str,
+        f: impl FnOnce(&mut Tracker) -> Result<T>,
+    ) -> Result<T> {
+        let mut state = self.state();
+        if !state.repos.contains_key(&key(owner, name)) {
+            return Err(not_found(owner, name));
+        }
+        f(state.trackers.entry(key(owner, name)).or_default())
+    }
+
+    /// Record a call and return the injected failure for its method, if any.
+    fn record(&self, call: Call) -> Result<()> {
+        let mut state = self.state();
+        let failure = state.failures.get(&call.method()).cloned();
+        state.calls.push(call);
+        match failure {
+            Some(failure) => Err(failure.error()),
+            None => Ok(()),
+        }
+    }
+
+    fn new_repo(&self, owner: String, name: String) -> Repository {
+        Repository {
+            ssh_url: self.clone_url_for(&owner, &name, &CloneProtocol::SSH),
+            clone_url: self.clone_url_for(&owner, &name, &CloneProtocol::HTTPS),
+            full_name: format!("{owner}/{name}"),
+            name,
+            owner,
+            default_branch: Some("main".to_string()),
+            created_at: Some(Utc::now()),
+            updated_at: Some(Utc::now()),
+            ..Default::default()
+        }
+    }
+
+    fn clone_url_for(&self, owner: &str, name: &str, protocol: &CloneProtocol) -> String {
+        let url = &self.config.url;
+        let host = url.replace("https://", "").replace("http://", "");
+        match protocol {
+            CloneProtocol::SSH => format!("git@{host}:{owner}/{name}.git"),
+            CloneProtocol::HTTPS => format!("{url}/{owner}/{name}.git"),
+        }
+    }
+
+    fn set_archived(&self, owner: &str, name: &str, archived: bool) -> Result<Repository> {
+        let mut state = self.state();
+        let repo = state
+            .repos
+            .get_mut(&key(owner, name))
+            .ok_or_else(|| not_found(owner, name))?;
+        repo.archived = archived;
+        repo.updated_at = Some(Utc::now());
+        Ok(repo.clone())
+    }
+
+    /// Move a stored repository to another owner or name, keeping everything else.
+    fn move_repo(
+        &self,
+        owner: &str,
+        name: &str,
+        new_owner: &str,
+        new_name: &str,
+    ) -> Result<Repository> {
+        let old = self
+            .repo(owner, name)
+            .ok_or_else(|| not_found(owner, name))?;
+        let moved = self.new_repo(new_owner.to_string(), new_name.to_string());
+        let repo = self.insert_new(Repository {
+            name: moved.name,
+            owner: moved.owner,
+            full_name: moved.full_name,
+            ssh_url: moved.ssh_url,
+            clone_url: moved.clone_url,
+            updated_at: moved.updated_at,
+            ..old
+        })?;
+        let mut state = self.state();
+        state.repos.remove(&key(owner, name));
+        if let Some(parent) = state.parents.remove(&key(owner, name)) {
+            state.parents.insert(key(new_owner, new_name), parent);
+        }
+        if let Some(mirrors) = state.mirrors.remove(&key(owner, name)) {
+            state.mirrors.insert(key(new_owner, new_name), mirrors);
+        }
+        if let Some(tracker) = state.trackers.remove(&key(owner, name)) {
+            state.trackers.insert(key(new_owner, new_name), tracker);
+        }
+        Ok(repo)
+    }
+
+    fn insert_new(&self, repo: Repository) -> Result<Repository> {
+        let mut state = self.state();
+        let key = key(&repo.owner, &repo.name);
+        if state.repos.contains_key(&key) {
+            return Err(Error {
+                status: Some(409),
+                ..Error::other(format!("Repository {}/{} already exists", key.0, key.1))
+            });
+        }
+        state.repos.insert(key, repo.clone());
+        Ok(repo)
+    }
+}
+
+#[async_trait]
+impl Remote for MockRemote {
+    async fn new(config: &RemoteConfig) -> Result<Self> {
+        Ok(Self::with_config(config.clone()))
+    }
+
+    async fn check_auth(&self) -> Result<bool> {
+        match self.record(Call::CheckAuth) {
+            Ok(()) => Ok(true),
+            Err(err) if err.kind == ErrorKind::Authentication => Ok(false),
+            Err(err) => Err(err),
+        }
+    }
+
+    async fn create_repo(&self, create_info: RepoCreateInfo) -> Result<Repository> {
+        self.record(Call::CreateRepo(create_info.clone()))?;
+        let owner = create_info
+            .owner
+            .unwrap_or_else(|| self.config.username.clone());
+        let mut repo = self.new_repo(owner, create_info.name);
+        repo.description = create_info.description;
+        repo.private = create_info.private;
+        if create_info.private {
+            repo.visibility = Visibility::Private;
+        }
+        if create_info.init {
+            let commit = self.state().commit(&self.config.username, "Initial commit");
+            repo.last_commits.push(commit);
+        }
+        self.insert_new(repo)
+    }
+
+    async fn create_fork(&self, options: RepoForkOption) -> Result<Repository> {
+        self.record(Call::CreateFork(options.clone()))?;
+        let parent = self
+            .repo(&options.owner, &options.repo)
+            .ok_or_else(|| not_found(&options.owner, &options.repo))?;
+        let owner = options
+            .organization
+            .unwrap_or_else(|| self.config.username.clone());
+        let name = options.name.unwrap_or(options.repo);
+
+        let mut repo = self.new_repo(owner, name);
+        repo.description = parent.description;
+        repo.private = parent.private;
+        repo.visibility = parent.visibility;
+        repo.fork = true;
+        repo.parent = Some(RepoRef {
+            owner: parent.owner.clone(),
+            name: parent.name.clone(),
+        });
+        repo.default_branch = parent.default_branch;
+        repo.last_commits = parent.last_commits;
+        let repo = self.insert_new(repo)?;
+        self.state().parents.insert(
+            key(&repo.owner, &repo.name),
+            key(&parent.owner, &parent.name),
+        );
+        Ok(repo)
+    }
+
+    async fn list_repos(&self, list_info: ListReposInfo) -> Result<Vec<Repository>> {
+        self.record(Call::ListRepos(list_info.clone()))?;
+        let owner = list_info.owner.as_ref().unwrap_or(&self.config.username);
+        let commits = list_info.commits.count() as usize;
+        let repos = self
+            .state()
+            .repos
+            .values()
+            .filter(|x| &x.owner == owner || list_info.affiliated && list_info.owner.is_none())
+            .filter(|x| list_info.includes(x.private, x.fork, x.archived))
+            .map(|x| Repository {
+                last_commits: x.last_commits.iter().take(commits).cloned().collect(),
+                ..x.clone()
+            })
+            .collect();
+        Ok(list_info.paginate(repos))
+    }
+
+    async fn get_repo_info(&self, owner: &str, name: &str) -> Result<Repository> {
+        self.record(Call::GetRepoInfo {
+            owner: owner.to_string(),
+            name: name.to_string(),
+        })?;
+        self.repo(owner, name).ok_or_else(|| not_found(owner, name))
+    }
+
+    /// Applies the settings the [Repository] has fields for, the others are only recorded.
+    async fn update_repo(
+        &self,
+        owner: &str,
+        name: &str,
+        update: RepoUpdateInfo,
+    ) -> Result<Repository> {
+        self.record(Call::UpdateRepo {
+            owner: owner.to_string(),
+            name: name.to_string(),
+            update: update.clone(),
+        })?;
+        let mut state = self.state();
+        let repo = state
+            .repos
+            .get_mut(&key(owner, name))
+            .ok_or_else(|| not_found(owner, name))?;
+        if let Some(description) = update.description {
+            repo.description = Some(description);
+        }
+        if let Some(visibility) = update.visibility {
+            repo.visibility = visibility;
+            repo.private = visibility != Visibility::Public;
+        }
+        if let Some(branch) = update.default_branch {
+            repo.default_branch = Some(branch);
+        }
+        repo.updated_at = Some(Utc::now());
+        Ok(repo.clone())
+    }
+
+    async fn archive_repo(&self, owner: &str, name: &str) -> Result<Repository> {
+        self.record(Call::ArchiveRepo {
+            owner: owner.to_string(),
+            name: name.to_string(),
+        })?;
+        self.set_archived(owner, name, true)
+    }
+
+    async fn unarchive_repo(&self, owner: &str, name: &str) -> Result<Repository> {
+        self.record(Call::UnarchiveRepo {
+            owner: owner.to_string(),
+            name: name.to_string(),
+        })?;
+        self.set_archived(owner, name, false)
+    }
+
+    async fn transfer_repo(&self, owner: &str, name: &str, new_owner: &str) -> Result<Repository> {
+        self.record(Call::TransferRepo {
+            owner: owner.to_string(),
+            name: name.to_string(),
+            new_owner: new_owner.to_string(),
+        })?;
+        self.move_repo(owner, name, new_owner, name)
+    }
+
+    async fn rename_repo(&self, owner: &str, name: &str, new_name: &str) -> Result<Repository> {
+        self.record(Call::RenameRepo {
+            owner: owner.to_string(),
+            name: name.to_string(),
+            new_name: new_name.to_string(),
+        })?;
+        self.move_repo(owner, name, owner, new_name)
+    }
+
+    /// Creates an empty repository, the mock can't fetch the source.
+    async fn import_repo(&self, import: RepoImportInfo) -> Result<Repository> {
+        self.record(Call::ImportRepo(import.clone()))?;
+        let owner = import.owner.unwrap_or_else(|| self.config.username.clone());
+        let mut repo = self.new_repo(owner, import.name);
+        repo.description = import.description;
+        repo.visibility = import.visibility;
+        repo.private = import.visibility != Visibility::Public;
+        repo.mirror = import.mirror;
+        let repo = self.insert_new(repo)?;
+        if import.mirror {
+            let mirror = Mirror {
+                url: import.clone_url,
+                direction: MirrorDirection::Pull,
+                ..Default::default()
+            };
+            let key = key(&repo.owner, &repo.name);
+            self.state().mirrors.insert(key, vec![mirror]);
+        }
+        Ok(repo)
+    }
+
+    async fn create_push_mirror(
+        &self,
+        owner: &str,
+        name: &str,
+        mirror: MirrorCreateInfo,
+    ) -> Result<Mirror> {
+        self.record(Call::CreatePushMirror {
+            owner: owner.to_string(),
+            name: name.to_string(),
+            mirror: mirror.clone(),
+        })?;
+        if self.repo(owner, name).is_none() {
+            return Err(not_found(owner, name));
+        }
+        let mirror = Mirror {
+            url: mirror.url,
+            direction: MirrorDirection::Push,
+            interval: mirror.interval,
+            ..Default::default()
+        };
+        let mut state = self.state();
+        let mirrors = state.mirrors.entry(key(owner, name)).or_default();
+        mirrors.push(mirror.clone());
+        Ok(mirror)
+    }
+
+    async fn check_push_mirror_interval(
+        &self,
+        owner: &str,
+        name: &str,
+        interval: &str,
+    ) -> Result<()> {
+        self.record(Call::CheckPushMirrorInterval {
+            owner: owner.to_string(),
+            name: name.to_string(),
+            interval: interval.to_string(),
+        })
+    }
+
+    async fn list_mirrors(&self, owner: &str, name: &str) -> Result<Vec<Mirror>> {
+        self.record(Call::ListMirrors {
+            owner: owner.to_string(),
+            name: name.to_string(),
+        })?;
+        if self.repo(owner, name).is_none() {
+            return Err(not_found(owner, name));
+        }
+        let mirrors = self.state().mirrors.get(&key(owner, name)).cloned();
+        Ok(mirrors.unwrap_or_default())
+    }
+
+    /// Compares the commits of the fork and its parent, ignoring the branch.
+    async fn sync_fork(&self, owner: &str, name: &str, branch: &str) -> Result<SyncStatus> {
+        self.record(Call::SyncFork {
+            owner: owner.to_string(),
+            name: name.to_string(),
+            branch: branch.to_string(),
+        })?;
+        let mut state = self.state();
+        let parent = state
+            .parents
+            .get(&key(owner, name))
+            .and_then(|x| state.repos.get(x))
+            .map(|x| x.last_commits.clone())
+            .ok_or_else(|| Error::other(format!("Repository {owner}/{name} is not a fork")))?;
+        let fork = state
+            .repos
+            .get_mut(&key(owner, name))
+            .ok_or_else(|| not_found(owner, name))?;
+        // Commits are stored newest first, so a branch contains another if it ends with it.
+        if fork.last_commits.ends_with(&parent) {
+            Ok(SyncStatus::UpToDate)
+        } else if parent.ends_with(&fork.last_commits) {
+            fork.last_commits = parent;
+            Ok(SyncStatus::FastForwarded)
+        } else {
+            Ok(SyncStatus::Diverged)
+        }
+    }
+
+    async fn list_branches(&self, owner: &str, name: &str) -> Result<Vec<Branch>> {
+        self.record(Call::ListBranches {
+            owner: owner.to_string(),
+            name: name.to_string(),
+        })?;
+        self.branches(owner, name)
+    }
+
+    async fn create_branch(
+        &self,
+        owner: &str,
+        name: &str,
+        branch: &str,
+        from: Option<&str>,
+    ) -> Result<Branch> {
+        self.record(Call::CreateBranch {
+            owner: owner.to_string(),
+            name: name.to_string(),
+            branch: branch.to_string(),
+            from: from.map(str::to_string),
+        })?;
+        if self.branches(owner, name)?.iter().any(|x| x.name == branch) {
+            return Err(Error {
+                status: Some(422),
+                ..Error::other(format!("Branch {branch} already exists"))
+            });
+        }
+        let from = match from {
+            Some(from) => from.to_string(),
+            None => self
+                .repo(owner, name)
+                .and_then(|x| x.default_branch)
+                .unwrap_or_default(),
+        };
+        let branch = Branch {
+            name: branch.to_string(),
+            commit: self.resolve(owner, name, &from)?,
+            protected: false,
+        };
+        self.with_tracker(owner, name, |tracker| {
+            tracker.branches.push(branch.clone());
+            Ok(branch)
+        })
+    }
+
+    async fn delete_branch(&self, owner: &str, name: &str, branch: &str) -> Result<()> {
+        self.record(Call::DeleteBranch {
+            owner: owner.to_string(),
+            name: name.to_string(),
+            branch: branch.to_string(),
+        })?;
+        if self
+            .repo(owner, name)
+            .and_then(|x| x.default_branch)
+            .as_deref()
+            == Some(branch)
+        {
+            return Err(Error {
+                status: Some(422),
+                ..Error::other(format!("Branch {branch} is the default branch"))
+            });
+        }
+        self.with_tracker(owner, name, |tracker| {
+            if !tracker.branches.iter().any(|x| x.name == branch) {
+                return Err(Error {
+                    status: Some(404),
+                    ..Error::not_found(format!("Branch {branch} of {owner}/{name} not found"))
+                });
+            }
+            tracker.branches.retain(|x| x.name != branch);
+            tracker.protections.remove(branch);
+            Ok(())
+        })
+    }
+
+    async fn protect_branch(
+        &self,
+        owner: &str,
+        name: &str,
+        branch: &str,
+        protection: BranchProtection,
+    ) -> Result<Vec<String>> {
+        self.record(Call::ProtectBranch {
+            owner: owner.to_string(),
+            name: name.to_string(),
+            branch: branch.to_string(),
+            protection: protection.clone(),
+        })?;
+        if !self.branches(owner, name)?.iter().any(|x| x.name == branch) {
+            return Err(Error {
+                status: Some(404),
+                ..Error::not_found(format!("Branch {branch} of {owner}/{name} not found"))
+            });
+        }
+        self.with_tracker(owner, name, |tracker| {
+            tracker.protections.insert(branch.to_string(), protection);
+            Ok(Vec::new())
+        })
+    }
+
+    async fn list_tags(&self, owner: &str, name: &str) -> Result<Vec<Tag>> {
+        self.record(Call::ListTags {
+            owner: owner.to_string(),
+            name: name.to_string(),
+        })?;
+        self.with_tracker(owner, name, |tracker| Ok(tracker.tags.clone()))
+    }
+
+    async fn create_tag(&self, owner: &str, name: &str, tag: TagCreateInfo) -> Result<Tag> {
+        self.record(Call::CreateTag {
+            owner: owner.to_string(),
+            name: name.to_string(),
+            tag: tag.clone(),
+        })?;
+        let target = match &tag.target {
+            Some(target) => target.clone(),
+            None => self
+                .repo(owner, name)
+                .and_then(|x| x.default_branch)
+                .unwrap_or_default(),
+        };
+        let commit = self.resolve(owner, name, &target)?;
+        self.with_tracker(owner, name, |tracker| {
+            if tracker.tags.iter().any(|x| x.name == tag.name) {
+                return Err(Error {
+                    status: Some(422),
+                    ..Error::other(format!("Tag {} already exists", tag.name))
+                });
+            }
+            let tag = Tag {
+                name: tag.name,
+                sha: commit.sha,
+                message: tag.message,
+            };
+            tracker.tags.push(tag.clone());
+            Ok(tag)
+        })
+    }
+
+    async fn delete_tag(&self, owner: &str, name: &str, tag: &str) -> Result<()> {
+        self.record(Call::DeleteTag {
+            owner: owner.to_string(),
+            name: name.to_string(),
+            tag: tag.to_string(),
+        })?;
+        self.with_tracker(owner, name, |tracker| {
+            if !tracker.tags.iter().any(|x| x.name == tag) {
+                return Err(Error {
+                    status: Some(404),
+                    ..Error::not_found(format!("Tag {tag} of {owner}/{name} not found"))
+                });
+            }
+            tracker.tags.retain(|x| x.name != tag);
+            Ok(())
+        })
+    }
+
+    async fn list_webhooks(&self, owner: &str, name: &str) -> Result<Vec<Webhook>> {
+        self.record(Call::ListWebhooks {
+            owner: owner.to_string(),
+            name: name.to_string(),
+        })?;
+        self.with_tracker(owner, name, |tracker| Ok(tracker.hooks.clone()))
+    }
+
+    async fn create_webhook(
+        &self,
+        owner: &str,
+        name: &str,
+        hook: WebhookCreateInfo,
+    ) -> Result<Webhook> {
+        self.record(Call::CreateWebhook {
+            owner: owner.to_string(),
+            name: name.to_string(),
+            hook: hook.clone(),
+        })?;
+        self.with_tracker(owner, name, |tracker| {
+            let hook = Webhook {
+                id: tracker.hooks.iter().map(|x| x.id).max().unwrap_or(0) + 1,
+                url: hook.url,
+                content_type: hook.content_type,
+                events: hook.events,
+                active: hook.active,
+            };
+            tracker.hooks.push(hook.clone());
+            Ok(hook)
+        })
+    }
+
+    async fn update_webhook(
+        &self,
+        owner: &str,
+        name: &str,
+        id: u64,
+        update: WebhookUpdateInfo,
+    ) -> Result<Webhook> {
+        self.record(Call::UpdateWebhook {
+            owner: owner.to_string(),
+            name: name.to_string(),
+            id,
+            update: update.clone(),
+        })?;
+        self.with_tracker(owner, name, |tracker| {
+            let hook = tracker.webhook(owner, name, id)?;
+            if let Some(url) = update.url {
+                hook.url = url;
+            }
+            if let Some(content_type) = update.content_type {
+                hook.content_type = content_type;
+            }
+            if let Some(events) = update.events {
+                hook.events = events;
+            }
+            if let Some(active) = update.active {
+                hook.active = active;
+            }
+            Ok(hook.clone())
+        })
+    }
+
+    async fn delete_webhook(&self, owner: &str, name: &str, id: u64) -> Result<()> {
+        self.record(Call::DeleteWebhook {
+            owner: owner.to_string(),
+            name: name.to_string(),
+            id,
+        })?;
+        self.with_tracker(owner, name, |tracker| {
+            tracker.webhook(owner, name, id)?;
+            tracker.hooks.retain(|x| x.id != id);
+            Ok(())
+        })
+    }
+
+    /// Only checks that the webhook exists, nothing is delivered.
+    async fn test_webhook(&self, owner: &str, name: &str, id: u64) -> Result<()> {
+        self.record(Call::TestWebhook {
+            owner: owner.to_string(),
+            name: name.to_string(),
+            id,
+        })?;
+        self.with_tracker(owner, name, |tracker| {
+            tracker.webhook(owner, name, id)?;
+            Ok(())
+        })
+    }
+
+    async fn list_ssh_keys(&self) -> Result<Vec<SshKey>> {
+        self.record(Call::ListSshKeys)?;
+        Ok(self.state().ssh_keys.clone())
+    }
+
+    async fn add_ssh_key(&self, key: SshKeyCreateInfo) -> Result<SshKey> {
+        self.record(Call::AddSshKey(key.clone()))?;
+        let mut state = self.state();
+        if state.ssh_keys.iter().any(|x| x.key == key.key) {
+            return Err(Error {
+                status: Some(422),
+                ..Error::other("Key is already in use")
+            });
+        }
+        let key = SshKey {
+            id: state.ssh_keys.iter().map(|x| x.id).max().unwrap_or(0) + 1,
+            title: key.title,
+            key: key.key,
+        };
+        state.ssh_keys.push(key.clone());
+        Ok(key)
+    }
+
+    async fn delete_ssh_key(&self, id: u64) -> Result<()> {
+        self.record(Call::DeleteSshKey { id })?;
+        let mut state = self.state();
+        if !state.ssh_keys.iter().any(|x| x.id == id) {
+            return Err(Error {
+                status: Some(404),
+                ..Error::not_found(format!("SSH key {id} not found"))
+            });
+        }
+        state.ssh_keys.retain(|x| x.id != id);
+        Ok(())
+    }
+
+    async fn list_deploy_keys(&self, owner: &str, name: &str) -> Result<Vec<DeployKey>> {
+        self.record(Call::ListDeployKeys {
+            owner: owner.to_string(),
+            name: name.to_string(),
+        })?;
+        self.with_tracker(owner, name, |tracker| Ok(tracker.deploy_keys.clone()))
+    }
+
+    async fn add_deploy_key(
+        &self,
+        owner: &str,
+        name: &str,
+        key: SshKeyCreateInfo,
+        read_only: bool,
+    ) -> Result<DeployKey> {
+        self.record(Call::AddDeployKey {
+            owner: owner.to_string(),
+            name: name.to_string(),
+            key: key.clone(),
+            read_only,
+        })?;
+        self.with_tracker(owner, name, |tracker| {
+            if tracker.deploy_keys.iter().any(|x| x.key == key.key) {
+                return Err(Error {
+                    status: Some(422),
+                    ..Error::other("Key is already in use")
+                });
+            }
+            let key = DeployKey {
+                id: tracker.deploy_keys.iter().map(|x| x.id).max().unwrap_or(0) + 1,
+                title: key.title,
+                key: key.key,
+                read_only,
+            };
+            tracker.deploy_keys.push(key.clone());
+            Ok(key)
+        })
+    }
+
+    async fn delete_deploy_key(&self, owner: &str, name: &str, id: u64) -> Result<()> {
+        self.record(Call::DeleteDeployKey {
+            owner: owner.to_string(),
+            name: name.to_string(),
+            id,
+        })?;
+        self.with_tracker(owner, name, |tracker| {
+            if !tracker.deploy_keys.iter().any(|x| x.id == id) {
+                return Err(Error {
+                    status: Some(404),
+                    ..Error::not_found(format!("Deploy key {id} of {owner}/{name} not found"))
+                });
+            }
+            tracker.deploy_keys.retain(|x| x.id != id);
+            Ok(())
+        })
+    }
+
+    async fn list_labels(&self, owner: &str, name: &str) -> Result<Vec<Label>> {
+        self.record(Call::ListLabels {
+            owner: owner.to_string(),
+            name: name.to_string(),
+        })?;
+        self.with_tracker(owner, name, |tracker| Ok(tracker.labels.clone()))
+    }
+
+    async fn create_label(&self, owner: &str, name: &str, label: Label) -> Result<Label> {
+        self.record(Call::CreateLabel {
+            owner: owner.to_string(),
+            name: name.to_string(),
+            label: label.clone(),
+        })?;
+        self.with_tracker(owner, name, |tracker| {
+            if tracker.labels.iter().any(|x| x.name == label.name) {
+                return Err(Error {
+                    status: Some(422),
+                    ..Error::other(format!("Label {} already exists", label.name))
+                });
+            }
+            tracker.labels.push(label.clone());
+            Ok(label)
+        })
+    }
+
+    async fn list_milestones(&self, owner: &str, name: &str) -> Result<Vec<Milestone>> {
+        self.record(Call::ListMilestones {
+            owner: owner.to_string(),
+            name: name.to_string(),
+        })?;
+        self.with_tracker(owner, name, |tracker| Ok(tracker.milestones.clone()))
+    }
+
+    async fn create_milestone(
+        &self,
+        owner: &str,
+        name: &str,
+        milestone: Milestone,
+    ) -> Result<Milestone> {
+        self.record(Call::CreateMilestone {
+            owner: owner.to_string(),
+            name: name.to_string(),
+            milestone: milestone.clone(),
+        })?;
+        self.with_tracker(owner, name, |tracker| {
+            let milestone = Milestone {
+                id: tracker.milestones.len() as u64 + 1,
+                ..milestone
+            };
+            tracker.milestones.push(milestone.clone());
+            Ok(milestone)
+        })
+    }
+
+    async fn list_issues(
+        &self,
+        owner: &str,
+        name: &str,
+        list_info: ListIssuesInfo,
+    ) -> Result<Vec<Issue>> {
+        self.record(Call::ListIssues {
+            owner: owner.to_string(),
+            name: name.to_string(),
+            list_info: list_info.clone(),
+        })?;
+        self.with_tracker(owner, name, |tracker| {
+            Ok(tracker
+                .issues
+                .iter()
+                .filter(|x| list_info.state.is_none_or(|state| x.state == state))
+                .filter(|x| {
+                    list_info
+                        .labels
+                        .iter()
+                        .all(|label| x.labels.contains(label))
+                })
+                .filter(|x| {
+                    list_info
+                        .assignee
+                        .as_ref()
+                        .is_none_or(|assignee| x.assignees.contains(assignee))
+                })
+                .filter(|x| list_info.author.as_ref().is_none_or(|a| &x.author == a))
+                .cloned()
+                .collect())
+        })
+    }
+
+    async fn get_issue(&self, owner: &str, name: &str, number: u64) -> Result<Issue> {
+        self.record(Call::GetIssue {
+            owner: owner.to_string(),
+            name: name.to_string(),
+            number,
+        })?;
+        self.with_tracker(owner, name, |tracker| {
+            Ok(tracker.issue(owner, name, number)?.clone())
+        })
+    }
+
+    /// Fails if a label or the milestone doesn't exist, like the forges do.
+    async fn create_issue(&self, owner: &str, name: &str, issue: IssueCreateInfo) -> Result<Issue> {
+        self.record(Call::CreateIssue {
+            owner: owner.to_string(),
+            name: name.to_string(),
+            issue: issue.clone(),
+        })?;
+        let author = self.config.username.clone();
+        self.with_tracker(owner, name, |tracker| {
+            if let Some(label) = issue
+                .labels
+                .iter()
+                .find(|x| !tracker.labels.iter().any(|label| &&label.name == x))
+            {
+                return Err(Error::not_found(format!(
+                    "Label {label} does not exist in {owner}/{name}"
+                )));
+            }
+            let milestone = match issue.milestone {
+                Some(id) => match tracker.milestones.iter().find(|x| x.id == id) {
+                    Some(milestone) => Some(milestone.title.clone()),
+                    None => {
+                        return Err(Error::not_found(format!(
+                            "Milestone {id} does not exist in {owner}/{name}"
+                        )))
+                    }
+                },
+                None => None,
+            };
+            let number = tracker.next_number();
+            let issue = Issue {
+                number,
+                title: issue.title,
+                body: issue.body,
+                author,
+                labels: issue.labels,
+                assignees: issue.assignees,
+                milestone,
+                html_url: Some(format!(
+                    "{}/{owner}/{name}/issues/{number}",
+                    self.config.url
+                )),
+                created_at: Utc::now(),
+                updated_at: Some(Utc::now()),
+                ..Default::default()
+            };
+            tracker.issues.push(issue.clone());
+            Ok(issue)
+        })
+    }
+
+    async fn set_issue_state(
+        &self,
+        owner: &str,
+        name: &str,
+        number: u64,
+        state: IssueState,
+    ) -> Result<Issue> {
+        self.record(Call::SetIssueState {
+            owner: owner.to_string(),
+            name: name.to_string(),
+            number,
+            state,
+        })?;
+        self.with_tracker(owner, name, |tracker| {
+            let issue = tracker.issue(owner, name, number)?;
+            issue.state = state;
+            issue.updated_at = Some(Utc::now());
+            issue.closed_at = (state == IssueState::Closed).then(Utc::now);
+            Ok(issue.clone())
+        })
+    }
+
+    async fn list_issue_comments(
+        &self,
+        owner: &str,
+        name: &str,
+        number: u64,
+    ) -> Result<Vec<Comment>> {
+        self.record(Call::ListIssueComments {
+            owner: owner.to_string(),
+            name: name.to_string(),
+            number,
+        })?;
+        self.with_tracker(owner, name, |tracker| {
+            tracker.issue(owner, name, number)?;
+            Ok(tracker.comments.get(&number).cloned().unwrap_or_default())
+        })
+    }
+
+    async fn create_issue_comment(
+        &self,
+        owner: &str,
+        name: &str,
+        number: u64,
+        body: &str,
+    ) -> Result<Comment> {
+        self.record(Call::CreateIssueComment {
+            owner: owner.to_string(),
+            name: name.to_string(),
+            number,
+            body: body.to_string(),
+        })?;
+        let author = self.config.username.clone();
+        self.with_tracker(owner, name, |tracker| {
+            tracker.issue(owner, name, number)?;
+            Ok(tracker.comment(number, author, body))
+        })
+    }
+
+    async fn list_pull_requests(
+        &self,
+        owner: &str,
+        name: &str,
+        list_info: ListPullRequestsInfo,
+    ) -> Result<Vec<PullRequest>> {
+        self.record(Call::ListPullRequests {
+            owner: owner.to_string(),
+            name: name.to_string(),
+            list_info: list_info.clone(),
+        })?;
+        self.with_tracker(owner, name, |tracker| {
+            Ok(tracker
+                .pull_requests
+                .iter()
+                .filter(|x| list_info.state.is_none_or(|state| x.state == state))
+                .cloned()
+                .collect())
+        })
+    }
+
+    async fn get_pull_request(&self, owner: &str, name: &str, number: u64) -> Result<PullRequest> {
+        self.record(Call::GetPullRequest {
+            owner: owner.to_string(),
+            name: name.to_string(),
+            number,
+        })?;
+        self.with_tracker(owner, name, |tracker| {
+            Ok(tracker.pull_request(owner, name, number)?.clone())
+        })
+    }
+
+    /// Doesn't check that the branches exist, the mock has no git data.
+    async fn create_pull_request(
+        &self,
+        owner: &str,
+        name: &str,
+        pull_request: PullRequestCreateInfo,
+    ) -> Result<PullRequest> {
+        self.record(Call::CreatePullRequest {
+            owner: owner.to_string(),
+            name: name.to_string(),
+            pull_request: pull_request.clone(),
+        })?;
+        let author = self.config.username.clone();
+        self.with_tracker(owner, name, |tracker| {
+            let number = tracker.next_number();
+            let pull_request = PullRequest {
+                number,
+                title: pull_request.title,
+                body: pull_request.body,
+                author,
+                head: pull_request.head,
+                base: pull_request.base,
+                draft: pull_request.draft,
+                html_url: Some(format!("{}/{owner}/{name}/pulls/{number}", self.config.url)),
+                created_at: Utc::now(),
+                updated_at: Some(Utc::now()),
+                ..Default::default()
+            };
+            tracker.pull_requests.push(pull_request.clone());
+            Ok(pull_request)
+        })
+    }
+
+    async fn create_pull_request_comment(
+        &self,
+        owner: &str,
+        name: &str,
+        number: u64,
+        body: &str,
+    ) -> Result<Comment> {
+        self.record(Call::CreatePullRequestComment {
+            owner: owner.to_string(),
+            name: name.to_string(),
+            number,
+            body: body.to_string(),
+        })?;
+        let author = self.config.username.clone();
+        self.with_tracker(owner, name, |tracker| {
+            tracker.pull_request(owner, name, number)?;
+            Ok(tracker.comment(number, author, body))
+        })
+    }
+
+    async fn approve_pull_request(&self, owner: &str, name: &str, number: u64) -> Result<()> {
+        self.record(Call::ApprovePullRequest {
+            owner: owner.to_string(),
+            name: name.to_string(),
+            number,
+        })?;
+        self.with_tracker(owner, name, |tracker| {
+            tracker.pull_request(owner, name, number).map(drop)
+        })
+    }
+
+    /// Fails if the pull request isn't open, like the forges do.
+    async fn merge_pull_request(
+        &self,
+        owner: &str,
+        name: &str,
+        number: u64,
+        merge_info: PullRequestMergeInfo,
+    ) -> Result<PullRequest> {
+        self.record(Call::MergePullRequest {
+            owner: owner.to_string(),
+            name: name.to_string(),
+            number,
+            merge_info,
+        })?;
+        self.with_tracker(owner, name, |tracker| {
+            let pull_request = tracker.pull_request(owner, name, number)?;
+            if pull_request.state != PullRequestState::Open {
+                return Err(Error {
+                    status: Some(405),
+                    ..Error::other(format!("Pull request {owner}/{name}#{number} is not open"))
+                });
+            }
+            pull_request.state = PullRequestState::Merged;
+            pull_request.merged_at = Some(Utc::now());
+            pull_request.closed_at = pull_request.merged_at;
+            pull_request.updated_at = pull_request.merged_at;
+            Ok(pull_request.clone())
+        })
+    }
+
+    async fn list_pull_request_comments(
+        &self,
+        owner: &str,
+        name: &str,
+        number: u64,
+    ) -> Result<Vec<Comment>> {
+        self.record(Call::ListPullRequestComments {
+            owner: owner.to_string(),
+            name: name.to_string(),
+            number,
+        })?;
+        self.with_tracker(owner, name, |tracker| {
+            Ok(tracker.comments.get(&number).cloned().unwrap_or_default())
+        })
+    }
+
+    async fn list_releases(&self, owner: &str, name: &str) -> Result<Vec<Release>> {
+        self.record(Call::ListReleases {
+            owner: owner.to_string(),
+            name: name.to_string(),
+        })?;
+        self.with_tracker(owner, name, |tracker| {
+            Ok(tracker.releases.iter().rev().cloned().collect())
+        })
+    }
+
+    async fn create_release(
+        &self,
+        owner: &str,
+        name: &str,
+        release: ReleaseCreateInfo,
+    ) -> Result<Release> {
+        self.record(Call::CreateRelease {
+            owner: owner.to_string(),
+            name: name.to_string(),
+            release: release.clone(),
+        })?;
+        let author = self.config.username.clone();
+        self.with_tracker(owner, name, |tracker| {
+            if tracker.releases.iter().any(|x| x.tag == release.tag) {
+                return Err(Error {
+                    status: Some(422),
+                    ..Error::other(format!("A release for {} already exists", release.tag))
+                });
+            }
+            let release = Release {
+                tag: release.tag,
+                name: release.name,
+                body: release.body,
+                draft: release.draft,
+                prerelease: release.prerelease,
+                author: Some(author),
+                created_at: Some(Utc::now()),
+                published_at: (!release.draft).then(Utc::now),
+                ..Default::default()
+            };
+            tracker.releases.push(release.clone());
+            Ok(release)
+        })
+    }
+
+    async fn get_release(&self, owner: &str, name: &str, tag: &str) -> Result<Release> {
+        self.record(Call::GetRelease {
+            owner: owner.to_string(),
+            name: name.to_string(),
+            tag: tag.to_string(),
+        })?;
+        self.with_tracker(owner, name, |tracker| {
+            Ok(tracker.release(owner, name, tag)?.clone())
+        })
+    }
+
+    async fn delete_release(&self, owner: &str, name: &str, tag: &str) -> Result<()> {
+        self.record(Call::DeleteRelease {
+            owner: owner.to_string(),
+            name: name.to_string(),
+            tag: tag.to_string(),
+        })?;
+        self.with_tracker(owner, name, |tracker| {
+            tracker.release(owner, name, tag)?;
+            tracker.releases.retain(|x| x.tag != tag);
+            tracker.assets.retain(|(x, _), _| x != tag);
+            Ok(())
+        })
+    }
+
+    async fn upload_release_asset(
+        &self,
+        owner: &str,
+        name: &str,
+        tag: &str,
+        file_name: &str,
+        data: Vec<u8>,
+    ) -> Result<ReleaseAsset> {
+        self.record(Call::UploadReleaseAsset {
+            owner: owner.to_string(),
+            name: name.to_string(),
+            tag: tag.to_string(),
+            file_name: file_name.to_string(),
+            data: data.clone(),
+        })?;
+        let download_url = format!(
+            "{}/{owner}/{name}/releases/download/{tag}/{file_name}",
+            self.config.url
+        );
+        self.with_tracker(owner, name, |tracker| {
+            let release = tracker.release(owner, name, tag)?;
+            if release.assets.iter().any(|x| x.name == file_name) {
+                return Err(Error {
+                    status: Some(422),
+                    ..Error::other(format!("Release {tag} already has an asset {file_name}"))
+                });
+            }
+            let asset = ReleaseAsset {
+                name: file_name.to_string(),
+                size: Some(data.len() as u64),
+                download_url,
+            };
+            release.assets.push(asset.clone());
+            tracker.assets.insert(key(tag, file_name), data);
+            Ok(asset)
+        })
+    }
+
+    async fn download_release_asset(
+        &self,
+        owner: &str,
+        name: &str,
+        tag: &str,
+        asset_name: &str,
+    ) -> Result<Vec<u8>> {
+        self.record(Call::DownloadReleaseAsset {
+            owner: owner.to_string(),
+            name: name.to_string(),
+            tag: tag.to_string(),
+            asset_name: asset_name.to_string(),
+        })?;
+        self.with_tracker(owner, name, |tracker| {
+            tracker.release(owner, name, tag)?;
+            tracker
+                .assets
+                .get(&key(tag, asset_name))
+                .cloned()
+                .ok_or_else(|| Error {
+                    status: Some(404),
+                    ..Error::not_found(format!("Release {tag} has no asset named {asset_name}"))
+                })
+        })
+    }
+
+    async fn list_wiki_pages(&self, owner: &str, name: &str) -> Result<Vec<WikiPage>> {
+        self.record(Call::ListWikiPages {
+            owner: owner.to_string(),
+            name: name.to_string(),
+        })?;
+        self.with_tracker(owner, name, |tracker| Ok(tracker.wiki.clone()))
+    }
+
+    async fn create_wiki_page(&self, owner: &str, name: &str, page: WikiPage) -> Result<WikiPage> {
+        self.record(Call::CreateWikiPage {
+            owner: owner.to_string(),
+            name: name.to_string(),
+            page: page.clone(),
+        })?;
+        self.with_tracker(owner, name, |tracker| {
+            tracker.wiki.push(page.clone());
+            Ok(page)
+        })
+    }
+
+    async fn delete_repo(&self, owner: &str, name: &str) -> Result<()> {
+        self.record(Call::DeleteRepo {
+            owner: owner.to_string(),
+            name: name.to_string(),
+        })?;
+        let mut state = self.state();
+        let key = key(owner, name);
+        state.parents.remove(&key);
+        state.mirrors.remove(&key);
+        state.trackers.remove(&key);
+        match state.repos.remove(&key) {
+            Some(_) => Ok(()),
+            None => Err(not_found(owner, name)),
+        }
+    }
+
+    fn get_config(&self) -> &RemoteConfig {
+        &self.config
+    }
+
+    /// Only records the call, nothing is cloned.
+    async fn clone_repo(&self, owner: &str, name: &str, path: &str, recursive: bool) -> Result<()> {
+        self.record(Call::CloneRepo {
+            owner: owner.to_string(),
+            name: name.to_string(),
+            path: path.to_string(),
+            recursive,
+        })?;
+        self.repo(owner, name)
+            .ok_or_else(|| not_found(owner, name))?;
+        Ok(())
+    }
+
+    /// Only records the call, the local repository is left untouched.
+    async fn add_remote(&self, owner: &str, repo_name: &str, branch: Option<String>) -> Result<()> {
+        self.record(Call::AddRemote {
+            owner: owner.to_string(),
+            name: repo_name.to_string(),
+            branch,
+        })?;
+        self.repo(owner, repo_name)
+            .ok_or_else(|| not_found(owner, repo_name))?;
+        Ok(())
+    }
+
+    fn clone_url(&self, owner: &str, repo_name: &str) -> String {
+        self.clone_url_for(owner, repo_name, &self.config.clone_protocol)
+    }
+}
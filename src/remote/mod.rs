@@ -5,6 +5,11 @@
 //! - [github::GitHubRemote]
 //! - [gitlab::GitlabRemote]
 //! - [gitea::GiteaRemote]
+//! - [bitbucket::BitbucketRemote]
+//! - [local::LocalRemote]
+//!
+//! Other providers can be added with provider plugins, see [external].
+//! For tests, the `mock` feature provides an in-memory remote, see `mock::MockRemote`.
 //!
 //! The [Remote] trait provides a common interface for interacting with remotes:
 //! ```
@@ -12,12 +17,12 @@
 //! use gritty::remote::{self, Remote, RemoteConfig, Provider, Auth, CloneProtocol};
 //! use gritty::remote::github::GitHubRemote;
 //!
-//! let config = RemoteConfig {
-//!     username: "octocat".to_string(),
-//!     clone_protocol: CloneProtocol::HTTPS,
-//!     url: "https://github.com".to_string(),
-//!     auth: Auth::Token { token: "your-gh-token".to_string() },
-//! };
+//! let config = RemoteConfig::new(
+//!     "octocat",
+//!     CloneProtocol::HTTPS,
+//!     "https://github.com",
+//!     Auth::Token { token: "your-gh-token".to_string() },
+//! );
 //!
 //! let remote = remote::create_remote(&config, Provider::GitHub).await.unwrap();
 //! # }
@@ -28,15 +33,15 @@
 //! # async fn run() {
 //! # use gritty::remote::{
 //!       self, Remote, RemoteConfig, Provider, Auth, CloneProtocol,
-//!       RepoCreateInfo, ListReposInfo, RepoForkOption,
+//!       RepoCreateInfo, ListReposInfo, RepoForkOption, CommitHistory,
 //!   };
 //! # use gritty::remote::github::GitHubRemote;
-//! # let config = RemoteConfig {
-//! #     username: "octocat".to_string(),
-//! #     clone_protocol: CloneProtocol::HTTPS,
-//! #     url: "https://github.com".to_string(),
-//! #     auth: Auth::Token { token: "your-gh-token".to_string() },
-//! # };
+//! # let config = RemoteConfig::new(
+//! #     "octocat",
+//! #     CloneProtocol::HTTPS,
+//! #     "https://github.com",
+//! #     Auth::Token { token: "your-gh-token".to_string() },
+//! # );
 //! # let remote = remote::create_remote(&config, Provider::GitHub).await.unwrap();
 //!
 //! // Check if we're authenticated
@@ -47,11 +52,12 @@
 //! }
 //!
 //! // Get information about octocat/hello-world
-//! let repo = remote.get_repo_info("hello-world").await.unwrap();
+//! let repo = remote.get_repo_info("octocat", "hello-world").await.unwrap();
 //!
 //! // Create a new repository
 //! let repo_create_info = RepoCreateInfo {
 //!     name: "a-new-repo".to_string(),
+//!     owner: None, // Create the repository in the user's account
 //!     description: Some("A new repository for testing".to_string()),
 //!     license: Some("MIT".to_string()),
 //!     private: false,
@@ -61,8 +67,14 @@
 //!
 //! // List all repositories, including private ones
 //! let list_repos_info = ListReposInfo {
+//!     owner: None, // List the authenticated user's repositories
 //!     private: true, // Include private repositories
 //!     forks: false, // Exclude forked repositories
+//!     limit: None, // List all repositories instead of a single page
+//!     page: None,
+//!     commits: CommitHistory::Latest, // Fetch the latest commit of every repository
+//!     archived: Some(false), // Exclude archived repositories
+//!     affiliated: false, // Only list repositories the user owns
 //! };
 //! let private_repos = remote.list_repos(list_repos_info).await.unwrap();
 //!
@@ -76,18 +88,24 @@
 //! # }
 //! ```
 
-use std::fmt::Debug;
+use std::{fmt::Debug, future::Future};
 
 use crate::error::{Error, Result};
 
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use clap::ValueEnum;
+use futures::{stream, StreamExt, TryStreamExt};
 use serde::{Deserialize, Serialize};
 
+pub mod bitbucket;
+pub mod external;
 pub mod gitea;
 pub mod github;
 pub mod gitlab;
+pub mod local;
+#[cfg(any(test, feature = "mock"))]
+pub mod mock;
 
 /// The supported providers for remotes.
 /// Each provider has its own implementation of the [Remote] trait.
@@ -97,6 +115,15 @@ pub enum Provider {
     GitHub,
     GitLab,
     Gitea,
+    Bitbucket,
+    /// A directory of bare repositories, on this machine or reachable over SSH.
+    Local,
+    /// A provider plugin, implemented by the `gritty-provider-<command>` executable.
+    /// See [external] for the protocol plugins have to speak.
+    #[value(skip)]
+    External {
+        command: String,
+    },
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -105,6 +132,8 @@ pub enum Auth {
     Basic { username: String, password: String },
     /// Authenticate using a token.
     Token { token: String },
+    /// Don't authenticate at all.
+    None,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ValueEnum)]
@@ -115,26 +144,191 @@ pub enum CloneProtocol {
     HTTPS,
 }
 
+/// The configuration of a remote. Create one with [RemoteConfig::new], since more settings may
+/// be added later.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct RemoteConfig {
     pub username: String,
     pub clone_protocol: CloneProtocol,
     pub url: String,
     pub auth: Auth,
+    /// The maximum number of requests to make at the same time when fetching the details of
+    /// many repositories. Defaults to [DEFAULT_CONCURRENCY].
+    #[serde(default)]
+    pub concurrency: Option<usize>,
+    /// The number of commits to fetch when getting a single repository. Defaults to
+    /// [COMMIT_COUNT].
+    #[serde(default)]
+    pub commit_count: Option<u8>,
+}
+
+impl RemoteConfig {
+    /// A configuration with the default concurrency and commit count.
+    pub fn new(
+        username: impl Into<String>,
+        clone_protocol: CloneProtocol,
+        url: impl Into<String>,
+        auth: Auth,
+    ) -> Self {
+        Self {
+            username: username.into(),
+            clone_protocol,
+            url: url.into(),
+            auth,
+            concurrency: None,
+            commit_count: None,
+        }
+    }
+
+    /// The configured concurrency, falling back to [DEFAULT_CONCURRENCY].
+    pub fn concurrency_limit(&self) -> usize {
+        self.concurrency.unwrap_or(DEFAULT_CONCURRENCY).max(1)
+    }
+
+    /// The username and password (or token) to give other forges so they can access this
+    /// remote over HTTPS, if there are credentials.
+    pub fn https_credentials(&self) -> Option<(String, String)> {
+        match &self.auth {
+            Auth::Basic { username, password } => Some((username.clone(), password.clone())),
+            Auth::Token { token } => Some((self.username.clone(), token.clone())),
+            Auth::None => None,
+        }
+    }
+
+    /// The configured number of commits, falling back to [COMMIT_COUNT].
+    pub fn commit_limit(&self) -> u8 {
+        self.commit_count.unwrap_or(COMMIT_COUNT)
+    }
+}
+
+/// The number of requests made at the same time if the remote doesn't configure a concurrency.
+pub const DEFAULT_CONCURRENCY: usize = 8;
+
+/// Run `fetch` for every item on its own task, with at most `concurrency` tasks running at once.
+/// The results are returned in the order of `items`.
+pub(crate) async fn fetch_concurrently<T, R, F, Fut>(
+    items: Vec<T>,
+    concurrency: usize,
+    fetch: F,
+) -> Result<Vec<R>>
+where
+    F: Fn(T) -> Fut,
+    Fut: Future<Output = Result<R>> + Send + 'static,
+    R: Send + 'static,
+{
+    // The stream is lazy, so a task is only spawned once there is room for it.
+    let mut results: Vec<(usize, R)> = stream::iter(items.into_iter().enumerate())
+        .map(|(i, item)| {
+            let task = tokio::spawn(fetch(item));
+            async move {
+                let result = task
+                    .await
+                    .map_err(|err| Error::other(format!("Request task failed: {err}")))?;
+                result.map(|x| (i, x))
+            }
+        })
+        .buffer_unordered(concurrency.max(1))
+        .try_collect()
+        .await?;
+    results.sort_by_key(|(i, _)| *i);
+    Ok(results.into_iter().map(|(_, x)| x).collect())
 }
 
 #[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Repository {
     pub name: String,
+    /// The user, organization or group that owns the repository.
+    /// For nested GitLab groups, this is the full path of the group (`group/subgroup`).
+    pub owner: String,
+    /// The owner and name of the repository, as `owner/name`.
+    #[serde(default)]
+    pub full_name: String,
     pub description: Option<String>,
+    /// Whether the repository is hidden from the public, i.e. private or internal.
     pub private: bool,
+    /// Who can see the repository.
+    #[serde(default)]
+    pub visibility: Visibility,
     pub fork: bool,
+    /// The repository this one was forked from, if the provider reports it.
+    #[serde(default)]
+    pub parent: Option<RepoRef>,
+    /// Whether the repository is archived, i.e. read-only.
+    #[serde(default)]
+    pub archived: bool,
+    /// Whether the repository is a pull mirror, kept up to date with another repository.
+    #[serde(default)]
+    pub mirror: bool,
     pub default_branch: Option<String>,
+    /// The web page of the repository.
+    #[serde(default)]
+    pub html_url: Option<String>,
     pub ssh_url: String,
     pub clone_url: String,
+    #[serde(default)]
+    pub created_at: Option<DateTime<Utc>>,
+    /// When the repository or its settings were last updated, if the provider reports it.
+    #[serde(default)]
+    pub updated_at: Option<DateTime<Utc>>,
+    /// When the repository was last pushed to, if the provider reports it.
+    #[serde(default)]
+    pub pushed_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub stars: u64,
+    #[serde(default)]
+    pub forks_count: u64,
+    #[serde(default)]
+    pub open_issues: u64,
+    /// The size of the repository in kilobytes, if the provider reports it.
+    #[serde(default)]
+    pub size: Option<u64>,
+    /// The primary language of the repository.
+    #[serde(default)]
+    pub language: Option<String>,
+    #[serde(default)]
+    pub topics: Vec<String>,
+    /// The most recent commits on the default branch, newest first. Listings only fetch them if
+    /// asked to, see [ListReposInfo::commits].
     pub last_commits: Vec<Commit>,
 }
 
+impl Repository {
+    /// When the repository last changed, preferring the last push over other updates.
+    pub fn last_activity(&self) -> Option<DateTime<Utc>> {
+        self.pushed_at.or(self.updated_at)
+    }
+}
+
+/// Who can see a repository.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum Visibility {
+    #[default]
+    Public,
+    Private,
+    /// Visible to every member of the instance or enterprise (GitLab, Gitea and GitHub Enterprise).
+    Internal,
+}
+
+/// A reference to a repository by its owner and name.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RepoRef {
+    pub owner: String,
+    pub name: String,
+}
+
+impl RepoRef {
+    /// Split a full name like `owner/name` or `group/subgroup/name` into owner and name.
+    pub fn parse(full_name: &str) -> Option<Self> {
+        let (owner, name) = full_name.rsplit_once('/')?;
+        Some(Self {
+            owner: owner.to_string(),
+            name: name.to_string(),
+        })
+    }
+}
+
 #[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Commit {
     pub sha: String,
@@ -147,6 +341,10 @@ pub struct Commit {
 pub struct RepoCreateInfo {
     /// The name of the repository.
     pub name: String,
+    /// The organization or group to create the repository in.
+    /// If not provided, the repository will be created in the user's account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
     /// An optional description of the repository.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
@@ -158,6 +356,123 @@ pub struct RepoCreateInfo {
     pub init: bool,
 }
 
+/// The settings to change on an existing repository. Settings that are `None` are left untouched.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RepoUpdateInfo {
+    /// The new description of the repository.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The URL of the project's website.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub homepage: Option<String>,
+    /// The new visibility of the repository.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<Visibility>,
+    /// The branch to make the default branch. It must already exist.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_branch: Option<String>,
+    /// Whether the issue tracker is enabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_issues: Option<bool>,
+    /// Whether the wiki is enabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_wiki: Option<bool>,
+    /// Whether projects (project boards) are enabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_projects: Option<bool>,
+}
+
+impl RepoUpdateInfo {
+    /// Whether there is nothing to change.
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+}
+
+/// A repository to copy from another forge with the server-side importer of a remote.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RepoImportInfo {
+    /// The HTTP(S) URL the remote clones the source repository from.
+    pub clone_url: String,
+    /// The username to authenticate with at the source.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auth_username: Option<String>,
+    /// The password or token to authenticate with at the source.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auth_password: Option<String>,
+    /// The name of the new repository.
+    pub name: String,
+    /// The organization or group to create the repository in.
+    /// If not provided, the repository will be created in the user's account.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+    /// An optional description of the repository.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Who can see the new repository.
+    #[serde(default)]
+    pub visibility: Visibility,
+    /// Keep the new repository as a pull mirror of the source, instead of a one-off copy.
+    #[serde(default)]
+    pub mirror: bool,
+}
+
+/// A mirror relationship between a repository and a repository elsewhere.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Mirror {
+    /// The URL of the other repository, without credentials.
+    pub url: String,
+    /// Whether the repository is pushed to the other one, or pulled from it.
+    pub direction: MirrorDirection,
+    /// How often the mirror is updated, in the provider's format (ex: `8h0m0s`).
+    #[serde(default)]
+    pub interval: Option<String>,
+    #[serde(default)]
+    pub last_update: Option<DateTime<Utc>>,
+    /// Why the last update failed, if it did.
+    #[serde(default)]
+    pub last_error: Option<String>,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MirrorDirection {
+    #[default]
+    Push,
+    Pull,
+}
+
+/// The outcome of syncing a fork with the repository it was forked from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SyncStatus {
+    /// The fork already had every commit of its parent, nothing changed.
+    UpToDate,
+    /// The fork was behind its parent and has been fast-forwarded.
+    FastForwarded,
+    /// The fork had commits of its own, and its parent was merged into it (GitHub).
+    Merged,
+    /// The fork and its parent have diverged, the fork was left untouched.
+    Diverged,
+}
+
+/// A push mirror to add to a repository.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MirrorCreateInfo {
+    /// The HTTP(S) URL of the repository to push to.
+    pub url: String,
+    /// The username to authenticate with at the other repository.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auth_username: Option<String>,
+    /// The password or token to authenticate with at the other repository.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auth_password: Option<String>,
+    /// How often to push, in the provider's format (ex: `8h0m0s`). Not every provider supports
+    /// it, the provider's default is used if not provided.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub interval: Option<String>,
+}
+
 #[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
 pub struct RepoForkOption {
     /// The owner of the repository to fork.
@@ -175,17 +490,559 @@ pub struct RepoForkOption {
 
 #[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ListReposInfo {
+    /// The user, organization or group whose repositories to list.
+    /// If not provided, the repositories of the authenticated user will be listed.
+    pub owner: Option<String>,
     /// Whether to include private repositories in the list.
     pub private: bool,
     /// Whether to include forked repositories in the list.
     pub forks: bool,
+    /// The maximum number of repositories to return. If not provided, all repositories are listed.
+    #[serde(default)]
+    pub limit: Option<u32>,
+    /// The page of `limit` repositories to return, starting at 1. Ignored without a limit.
+    #[serde(default)]
+    pub page: Option<u32>,
+    /// How many commits to fetch for every repository. Fetching commits takes an extra request
+    /// per repository on most providers, so none are fetched by default.
+    #[serde(default)]
+    pub commits: CommitHistory,
+    /// Only list archived repositories (`Some(true)`), or leave them out (`Some(false)`).
+    /// Both are listed by default.
+    #[serde(default)]
+    pub archived: Option<bool>,
+    /// Also list the repositories the authenticated user can access as a member of an
+    /// organization or group, or as a collaborator. Ignored when an owner is given.
+    #[serde(default)]
+    pub affiliated: bool,
+}
+
+/// How much of the commit history to fetch for every listed repository.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CommitHistory {
+    /// Don't fetch any commits.
+    #[default]
+    None,
+    /// Only fetch the latest commit.
+    Latest,
+    /// Fetch up to the given number of commits.
+    Count(u8),
+}
+
+impl CommitHistory {
+    /// The number of commits to fetch.
+    pub fn count(self) -> u8 {
+        match self {
+            CommitHistory::None => 0,
+            CommitHistory::Latest => 1,
+            CommitHistory::Count(count) => count,
+        }
+    }
+}
+
+impl From<u8> for CommitHistory {
+    fn from(count: u8) -> Self {
+        match count {
+            0 => CommitHistory::None,
+            1 => CommitHistory::Latest,
+            count => CommitHistory::Count(count),
+        }
+    }
+}
+
+impl ListReposInfo {
+    /// Whether a repository with the given visibility, fork and archive status should be listed.
+    pub fn includes(&self, private: bool, fork: bool, archived: bool) -> bool {
+        (self.private || !private)
+            && (self.forks || !fork)
+            && self.archived.is_none_or(|x| x == archived)
+    }
+
+    /// Whether `count` matching repositories are enough to fill the requested page,
+    /// so providers can stop fetching further pages.
+    pub fn is_satisfied(&self, count: usize) -> bool {
+        match self.limit {
+            Some(limit) => count >= self.offset() + limit as usize,
+            None => false,
+        }
+    }
+
+    /// Select the requested page from all matching repositories.
+    pub fn paginate<T>(&self, items: Vec<T>) -> Vec<T> {
+        match self.limit {
+            Some(limit) => items
+                .into_iter()
+                .skip(self.offset())
+                .take(limit as usize)
+                .collect(),
+            None => items,
+        }
+    }
+
+    fn offset(&self) -> usize {
+        match self.limit {
+            Some(limit) => self.page.unwrap_or(1).saturating_sub(1) as usize * limit as usize,
+            None => 0,
+        }
+    }
+}
+
+/// Whether an issue or milestone is still open.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum IssueState {
+    #[default]
+    Open,
+    Closed,
+}
+
+/// A label that can be put on issues and pull requests.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Label {
+    pub name: String,
+    /// The color of the label as a hex code, without the `#` (ex: `d73a4a`).
+    pub color: String,
+    #[serde(default)]
+    pub description: Option<String>,
 }
 
-pub static COMMIT_COUNT: u8 = 25;
+/// A milestone that issues and pull requests can be grouped by.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Milestone {
+    /// The number (GitHub) or ID (GitLab, Gitea) of the milestone, which issues are assigned to
+    /// it by. Only meaningful on the remote the milestone was read from.
+    pub id: u64,
+    pub title: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub state: IssueState,
+    #[serde(default)]
+    pub due_on: Option<DateTime<Utc>>,
+}
+
+/// A comment on an issue or pull request.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Comment {
+    pub id: u64,
+    /// The username of the author.
+    pub author: String,
+    pub body: String,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Issue {
+    /// The number of the issue in its repository (the IID on GitLab).
+    pub number: u64,
+    pub title: String,
+    #[serde(default)]
+    pub body: Option<String>,
+    #[serde(default)]
+    pub state: IssueState,
+    /// The username of the author.
+    pub author: String,
+    /// The names of the labels on the issue.
+    #[serde(default)]
+    pub labels: Vec<String>,
+    /// The usernames of the assignees.
+    #[serde(default)]
+    pub assignees: Vec<String>,
+    /// The title of the milestone the issue belongs to.
+    #[serde(default)]
+    pub milestone: Option<String>,
+    /// The web page of the issue.
+    #[serde(default)]
+    pub html_url: Option<String>,
+    pub created_at: DateTime<Utc>,
+    #[serde(default)]
+    pub updated_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub closed_at: Option<DateTime<Utc>>,
+}
+
+/// A new issue to open.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IssueCreateInfo {
+    pub title: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    /// The names of the labels to put on the issue. They must already exist.
+    #[serde(default)]
+    pub labels: Vec<String>,
+    /// The [Milestone::id] of the milestone to add the issue to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub milestone: Option<u64>,
+    /// The usernames of the users to assign to the issue.
+    #[serde(default)]
+    pub assignees: Vec<String>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ListIssuesInfo {
+    /// Only list issues in this state. Issues in any state are listed by default.
+    #[serde(default)]
+    pub state: Option<IssueState>,
+    /// Only list issues that have all of these labels.
+    #[serde(default)]
+    pub labels: Vec<String>,
+    /// Only list issues assigned to this user.
+    #[serde(default)]
+    pub assignee: Option<String>,
+    /// Only list issues opened by this user.
+    #[serde(default)]
+    pub author: Option<String>,
+}
+
+/// Whether a pull request is still open, and how it was closed.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum PullRequestState {
+    #[default]
+    Open,
+    /// Closed without merging.
+    Closed,
+    Merged,
+}
+
+/// A pull request (GitHub, Gitea) or merge request (GitLab).
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PullRequest {
+    /// The number of the pull request in its repository (the IID on GitLab).
+    pub number: u64,
+    pub title: String,
+    #[serde(default)]
+    pub body: Option<String>,
+    #[serde(default)]
+    pub state: PullRequestState,
+    /// The username of the author.
+    pub author: String,
+    /// The branch with the changes.
+    pub head: String,
+    /// The branch the changes are merged into.
+    pub base: String,
+    #[serde(default)]
+    pub draft: bool,
+    /// The names of the labels on the pull request.
+    #[serde(default)]
+    pub labels: Vec<String>,
+    /// The title of the milestone the pull request belongs to.
+    #[serde(default)]
+    pub milestone: Option<String>,
+    /// The web page of the pull request.
+    #[serde(default)]
+    pub html_url: Option<String>,
+    pub created_at: DateTime<Utc>,
+    #[serde(default)]
+    pub updated_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub closed_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub merged_at: Option<DateTime<Utc>>,
+}
+
+/// A new pull request to open.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PullRequestCreateInfo {
+    pub title: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    /// The branch with the changes. It must already be pushed to the repository.
+    pub head: String,
+    /// The branch to merge the changes into.
+    pub base: String,
+    #[serde(default)]
+    pub draft: bool,
+}
+
+/// How the commits of a pull request end up on its base branch.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum MergeMethod {
+    /// Create a merge commit.
+    #[default]
+    Merge,
+    /// Squash the commits into a single commit.
+    Squash,
+    /// Rebase the commits onto the base branch.
+    Rebase,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PullRequestMergeInfo {
+    #[serde(default)]
+    pub method: MergeMethod,
+    /// Delete the head branch once the pull request is merged.
+    #[serde(default)]
+    pub delete_branch: bool,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ListPullRequestsInfo {
+    /// Only list pull requests in this state. Pull requests in any state are listed by default.
+    #[serde(default)]
+    pub state: Option<PullRequestState>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Release {
+    /// The tag the release is for.
+    pub tag: String,
+    #[serde(default)]
+    pub name: Option<String>,
+    /// The release notes.
+    #[serde(default)]
+    pub body: Option<String>,
+    #[serde(default)]
+    pub draft: bool,
+    #[serde(default)]
+    pub prerelease: bool,
+    /// The username of the author.
+    #[serde(default)]
+    pub author: Option<String>,
+    /// The web page of the release.
+    #[serde(default)]
+    pub html_url: Option<String>,
+    #[serde(default)]
+    pub created_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub published_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub assets: Vec<ReleaseAsset>,
+}
+
+/// A file attached to a release.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReleaseAsset {
+    pub name: String,
+    /// The size in bytes, if the provider reports it.
+    #[serde(default)]
+    pub size: Option<u64>,
+    pub download_url: String,
+}
+
+/// A new release to publish.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReleaseCreateInfo {
+    /// The tag to create the release for.
+    pub tag: String,
+    /// The branch or commit to create the tag from, if it doesn't exist yet.
+    /// Defaults to the default branch.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The release notes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    #[serde(default)]
+    pub draft: bool,
+    #[serde(default)]
+    pub prerelease: bool,
+}
+
+/// A page of the wiki of a repository.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WikiPage {
+    pub title: String,
+    /// The content of the page, in Markdown.
+    pub content: String,
+}
+
+/// A branch of a repository.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Branch {
+    pub name: String,
+    /// The commit the branch points to.
+    pub commit: Commit,
+    /// Whether the branch has protection rules.
+    #[serde(default)]
+    pub protected: bool,
+}
+
+/// A tag of a repository.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Tag {
+    pub name: String,
+    /// The SHA of the commit the tag points to.
+    pub sha: String,
+    /// The message of an annotated tag, when the provider includes it in listings.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// A tag to create.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TagCreateInfo {
+    pub name: String,
+    /// The branch, tag or commit to tag. Defaults to the default branch.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target: Option<String>,
+    /// Create an annotated tag with this message instead of a lightweight one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// Protection rules for a branch. Settings left at their default aren't enforced.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BranchProtection {
+    /// The number of approving reviews a pull request needs before it can be merged.
+    #[serde(default)]
+    pub required_reviews: u32,
+    /// Status checks that must pass before a pull request can be merged.
+    #[serde(default)]
+    pub required_status_checks: Vec<String>,
+    /// Reject force-pushes to the branch.
+    #[serde(default)]
+    pub no_force_push: bool,
+    /// Only these users may push to the branch. When empty, anyone with write access can.
+    #[serde(default)]
+    pub push_allowlist: Vec<String>,
+}
+
+/// Events a webhook can be delivered for. Each provider has its own names for them.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, ValueEnum,
+)]
+#[serde(rename_all = "snake_case")]
+#[value(rename_all = "snake_case")]
+pub enum WebhookEvent {
+    /// Commits pushed to a branch.
+    Push,
+    /// Tags created or deleted. GitHub and Gitea also deliver these for branches.
+    Tag,
+    /// Issues opened, edited or closed.
+    Issues,
+    /// Comments on issues and pull requests.
+    IssueComment,
+    /// Pull requests opened, edited, merged or closed.
+    PullRequest,
+    /// Releases published, edited or deleted.
+    Release,
+    /// Wiki pages created or edited.
+    Wiki,
+}
+
+/// How the payload of a webhook delivery is encoded.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum WebhookContentType {
+    /// `application/json`
+    #[default]
+    Json,
+    /// `application/x-www-form-urlencoded`, with the JSON payload in the `payload` field.
+    Form,
+}
+
+/// A webhook of a repository.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Webhook {
+    pub id: u64,
+    /// The URL deliveries are sent to.
+    pub url: String,
+    #[serde(default)]
+    pub content_type: WebhookContentType,
+    pub events: Vec<WebhookEvent>,
+    pub active: bool,
+}
+
+/// A webhook to add to a repository. Also the format of the hooks in the `hooks` sections of the
+/// config.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WebhookCreateInfo {
+    /// The URL to send deliveries to.
+    pub url: String,
+    /// Used to sign deliveries, so the receiver can check where they come from. Providers never
+    /// send it back.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secret: Option<String>,
+    #[serde(default)]
+    pub content_type: WebhookContentType,
+    /// Defaults to push events only.
+    #[serde(default = "default_webhook_events")]
+    pub events: Vec<WebhookEvent>,
+    /// Whether deliveries are sent. Default is true.
+    #[serde(default = "default_true")]
+    pub active: bool,
+}
+
+impl Default for WebhookCreateInfo {
+    fn default() -> Self {
+        Self {
+            url: String::new(),
+            secret: None,
+            content_type: WebhookContentType::default(),
+            events: default_webhook_events(),
+            active: true,
+        }
+    }
+}
+
+fn default_webhook_events() -> Vec<WebhookEvent> {
+    vec![WebhookEvent::Push]
+}
+
+fn default_true() -> bool {
+    true
+}
+
+/// Changes to make to a webhook. Settings that are `None` are left as they are.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WebhookUpdateInfo {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secret: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_type: Option<WebhookContentType>,
+    /// Replaces the events of the webhook.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub events: Option<Vec<WebhookEvent>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+}
+
+/// An SSH key of the authenticated user.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SshKey {
+    pub id: u64,
+    pub title: String,
+    /// The public key, in the OpenSSH format. Providers may leave out the comment.
+    pub key: String,
+}
+
+/// An SSH key that gives access to a single repository.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DeployKey {
+    pub id: u64,
+    pub title: String,
+    /// The public key, in the OpenSSH format. Providers may leave out the comment.
+    pub key: String,
+    /// Whether the key can only pull, or also push.
+    pub read_only: bool,
+}
+
+/// An SSH key to add to a user or repository.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SshKeyCreateInfo {
+    pub title: String,
+    /// The public key, in the OpenSSH format (ex: 'ssh-ed25519 AAAA... me@laptop').
+    pub key: String,
+}
+
+/// The number of repositories to request per page from providers that paginate their listings.
+pub(crate) const PAGE_SIZE: u32 = 100;
+
+/// The number of commits fetched for a single repository, if the remote doesn't configure it.
+pub const COMMIT_COUNT: u8 = 25;
 
 #[async_trait]
 pub trait Remote: Sync {
     /// Create a new remote with the given configuration.
+    ///
+    /// Provider plugins also need the command to run, which isn't part of the configuration, so
+    /// [external::ExternalRemote] always fails here. Use [create_remote] or
+    /// [external::ExternalRemote::spawn] for them.
     async fn new(config: &RemoteConfig) -> Result<Self>
     where
         Self: Sized;
@@ -201,16 +1058,520 @@ pub trait Remote: Sync {
     /// List all repositories.
     async fn list_repos(&self, list_info: ListReposInfo) -> Result<Vec<Repository>>;
     /// Get the information of a repository.
-    async fn get_repo_info(&self, name: &str) -> Result<Repository>;
+    async fn get_repo_info(&self, owner: &str, name: &str) -> Result<Repository>;
     /// Delete a repository.
     /// WARNING: Operation does not prompt for confirmation and is irreversible.
-    async fn delete_repo(&self, name: &str) -> Result<()>;
+    async fn delete_repo(&self, owner: &str, name: &str) -> Result<()>;
+    /// Change the settings of a repository.
+    /// Returns the updated repository.
+    async fn update_repo(
+        &self,
+        owner: &str,
+        name: &str,
+        update: RepoUpdateInfo,
+    ) -> Result<Repository> {
+        let _ = (owner, name, update);
+        Err(Error::unsupported(
+            "Editing repositories is not supported by this remote",
+        ))
+    }
+    /// Archive a repository, making it read-only.
+    /// Returns the archived repository.
+    async fn archive_repo(&self, owner: &str, name: &str) -> Result<Repository> {
+        let _ = (owner, name);
+        Err(Error::unsupported(
+            "Archiving repositories is not supported by this remote",
+        ))
+    }
+    /// Unarchive a repository, making it writable again.
+    /// Returns the unarchived repository.
+    async fn unarchive_repo(&self, owner: &str, name: &str) -> Result<Repository> {
+        let _ = (owner, name);
+        Err(Error::unsupported(
+            "Archiving repositories is not supported by this remote",
+        ))
+    }
+    /// Transfer a repository to another user, organization or group, keeping its name.
+    /// Returns the transferred repository.
+    async fn transfer_repo(&self, owner: &str, name: &str, new_owner: &str) -> Result<Repository> {
+        let _ = (owner, name, new_owner);
+        Err(Error::unsupported(
+            "Transferring repositories is not supported by this remote",
+        ))
+    }
+    /// Rename a repository. The owner stays the same.
+    /// Returns the renamed repository.
+    async fn rename_repo(&self, owner: &str, name: &str, new_name: &str) -> Result<Repository> {
+        let _ = (owner, name, new_name);
+        Err(Error::unsupported(
+            "Renaming repositories is not supported by this remote",
+        ))
+    }
+    /// Copy a repository from another forge with the remote's server-side importer.
+    /// The import may still be running when this returns.
+    /// Returns the new repository.
+    async fn import_repo(&self, import: RepoImportInfo) -> Result<Repository> {
+        let _ = import;
+        Err(Error::unsupported(
+            "Importing repositories is not supported by this remote",
+        ))
+    }
+    /// Add a push mirror to a repository, so the remote pushes it to another repository whenever
+    /// it changes.
+    async fn create_push_mirror(
+        &self,
+        owner: &str,
+        name: &str,
+        mirror: MirrorCreateInfo,
+    ) -> Result<Mirror> {
+        let _ = (owner, name, mirror);
+        Err(Error::unsupported(
+            "Push mirrors are not supported by this remote",
+        ))
+    }
+    /// Check that a push mirror of the repository can be updated every `interval`, before
+    /// anything is created for it. Remotes that can't tell ahead of time accept any interval and
+    /// leave it to [Remote::create_push_mirror] to refuse it.
+    async fn check_push_mirror_interval(
+        &self,
+        owner: &str,
+        name: &str,
+        interval: &str,
+    ) -> Result<()> {
+        let _ = (owner, name, interval);
+        Ok(())
+    }
+    /// List the mirrors of a repository: the repositories it is pushed to, and the repository
+    /// it is pulled from if it is a pull mirror.
+    async fn list_mirrors(&self, owner: &str, name: &str) -> Result<Vec<Mirror>> {
+        let _ = (owner, name);
+        Err(Error::unsupported(
+            "Mirrors are not supported by this remote",
+        ))
+    }
+    /// Bring a branch of a fork up to date with the same branch of the repository it was forked
+    /// from.
+    async fn sync_fork(&self, owner: &str, name: &str, branch: &str) -> Result<SyncStatus> {
+        let _ = (owner, name, branch);
+        Err(Error::unsupported(
+            "Syncing forks is not supported by this remote",
+        ))
+    }
+    /// List the branches of a repository, with their head commits.
+    async fn list_branches(&self, owner: &str, name: &str) -> Result<Vec<Branch>> {
+        let _ = (owner, name);
+        Err(Error::unsupported(
+            "Branches are not supported by this remote",
+        ))
+    }
+    /// Create a branch from another branch, tag or commit, or from the default branch.
+    async fn create_branch(
+        &self,
+        owner: &str,
+        name: &str,
+        branch: &str,
+        from: Option<&str>,
+    ) -> Result<Branch> {
+        let _ = (owner, name, branch, from);
+        Err(Error::unsupported(
+            "Branches are not supported by this remote",
+        ))
+    }
+    /// Delete a branch of a repository.
+    async fn delete_branch(&self, owner: &str, name: &str, branch: &str) -> Result<()> {
+        let _ = (owner, name, branch);
+        Err(Error::unsupported(
+            "Branches are not supported by this remote",
+        ))
+    }
+    /// Replace the protection rules of a branch.
+    ///
+    /// Returns the settings the provider can't represent, which were left out.
+    async fn protect_branch(
+        &self,
+        owner: &str,
+        name: &str,
+        branch: &str,
+        protection: BranchProtection,
+    ) -> Result<Vec<String>> {
+        let _ = (owner, name, branch, protection);
+        Err(Error::unsupported(
+            "Branch protection is not supported by this remote",
+        ))
+    }
+    /// List the tags of a repository.
+    async fn list_tags(&self, owner: &str, name: &str) -> Result<Vec<Tag>> {
+        let _ = (owner, name);
+        Err(Error::unsupported("Tags are not supported by this remote"))
+    }
+    /// Create a tag in a repository.
+    async fn create_tag(&self, owner: &str, name: &str, tag: TagCreateInfo) -> Result<Tag> {
+        let _ = (owner, name, tag);
+        Err(Error::unsupported("Tags are not supported by this remote"))
+    }
+    /// Delete a tag of a repository.
+    async fn delete_tag(&self, owner: &str, name: &str, tag: &str) -> Result<()> {
+        let _ = (owner, name, tag);
+        Err(Error::unsupported("Tags are not supported by this remote"))
+    }
+    /// List the webhooks of a repository.
+    async fn list_webhooks(&self, owner: &str, name: &str) -> Result<Vec<Webhook>> {
+        let _ = (owner, name);
+        Err(Error::unsupported(
+            "Webhooks are not supported by this remote",
+        ))
+    }
+    /// Add a webhook to a repository.
+    async fn create_webhook(
+        &self,
+        owner: &str,
+        name: &str,
+        hook: WebhookCreateInfo,
+    ) -> Result<Webhook> {
+        let _ = (owner, name, hook);
+        Err(Error::unsupported(
+            "Webhooks are not supported by this remote",
+        ))
+    }
+    /// Change the settings of a webhook, returning the updated webhook.
+    async fn update_webhook(
+        &self,
+        owner: &str,
+        name: &str,
+        id: u64,
+        update: WebhookUpdateInfo,
+    ) -> Result<Webhook> {
+        let _ = (owner, name, id, update);
+        Err(Error::unsupported(
+            "Webhooks are not supported by this remote",
+        ))
+    }
+    /// Remove a webhook from a repository.
+    async fn delete_webhook(&self, owner: &str, name: &str, id: u64) -> Result<()> {
+        let _ = (owner, name, id);
+        Err(Error::unsupported(
+            "Webhooks are not supported by this remote",
+        ))
+    }
+    /// Send a test delivery to a webhook. What is sent depends on the provider: GitHub sends a
+    /// ping, GitLab and Gitea a push of the latest commit.
+    async fn test_webhook(&self, owner: &str, name: &str, id: u64) -> Result<()> {
+        let _ = (owner, name, id);
+        Err(Error::unsupported(
+            "Webhooks are not supported by this remote",
+        ))
+    }
+    /// List the SSH keys of the authenticated user.
+    async fn list_ssh_keys(&self) -> Result<Vec<SshKey>> {
+        Err(Error::unsupported(
+            "SSH keys are not supported by this remote",
+        ))
+    }
+    /// Add an SSH key to the authenticated user.
+    async fn add_ssh_key(&self, key: SshKeyCreateInfo) -> Result<SshKey> {
+        let _ = key;
+        Err(Error::unsupported(
+            "SSH keys are not supported by this remote",
+        ))
+    }
+    /// Remove an SSH key from the authenticated user.
+    async fn delete_ssh_key(&self, id: u64) -> Result<()> {
+        let _ = id;
+        Err(Error::unsupported(
+            "SSH keys are not supported by this remote",
+        ))
+    }
+    /// List the deploy keys of a repository.
+    async fn list_deploy_keys(&self, owner: &str, name: &str) -> Result<Vec<DeployKey>> {
+        let _ = (owner, name);
+        Err(Error::unsupported(
+            "Deploy keys are not supported by this remote",
+        ))
+    }
+    /// Add a deploy key to a repository. Read-only keys can only pull.
+    async fn add_deploy_key(
+        &self,
+        owner: &str,
+        name: &str,
+        key: SshKeyCreateInfo,
+        read_only: bool,
+    ) -> Result<DeployKey> {
+        let _ = (owner, name, key, read_only);
+        Err(Error::unsupported(
+            "Deploy keys are not supported by this remote",
+        ))
+    }
+    /// Remove a deploy key from a repository.
+    async fn delete_deploy_key(&self, owner: &str, name: &str, id: u64) -> Result<()> {
+        let _ = (owner, name, id);
+        Err(Error::unsupported(
+            "Deploy keys are not supported by this remote",
+        ))
+    }
+    /// List the labels of a repository.
+    async fn list_labels(&self, owner: &str, name: &str) -> Result<Vec<Label>> {
+        let _ = (owner, name);
+        Err(Error::unsupported(
+            "Labels are not supported by this remote",
+        ))
+    }
+    /// Create a label in a repository.
+    async fn create_label(&self, owner: &str, name: &str, label: Label) -> Result<Label> {
+        let _ = (owner, name, label);
+        Err(Error::unsupported(
+            "Labels are not supported by this remote",
+        ))
+    }
+    /// List the open and closed milestones of a repository.
+    async fn list_milestones(&self, owner: &str, name: &str) -> Result<Vec<Milestone>> {
+        let _ = (owner, name);
+        Err(Error::unsupported(
+            "Milestones are not supported by this remote",
+        ))
+    }
+    /// Create a milestone in a repository. The [Milestone::id] is ignored.
+    /// Returns the new milestone.
+    async fn create_milestone(
+        &self,
+        owner: &str,
+        name: &str,
+        milestone: Milestone,
+    ) -> Result<Milestone> {
+        let _ = (owner, name, milestone);
+        Err(Error::unsupported(
+            "Milestones are not supported by this remote",
+        ))
+    }
+    /// List the issues of a repository, oldest first. Pull requests are not included.
+    async fn list_issues(
+        &self,
+        owner: &str,
+        name: &str,
+        list_info: ListIssuesInfo,
+    ) -> Result<Vec<Issue>> {
+        let _ = (owner, name, list_info);
+        Err(Error::unsupported(
+            "Issues are not supported by this remote",
+        ))
+    }
+    /// Get a single issue by its number.
+    async fn get_issue(&self, owner: &str, name: &str, number: u64) -> Result<Issue> {
+        let _ = (owner, name, number);
+        Err(Error::unsupported(
+            "Issues are not supported by this remote",
+        ))
+    }
+    /// Open an issue.
+    /// Returns the new issue.
+    async fn create_issue(&self, owner: &str, name: &str, issue: IssueCreateInfo) -> Result<Issue> {
+        let _ = (owner, name, issue);
+        Err(Error::unsupported(
+            "Issues are not supported by this remote",
+        ))
+    }
+    /// Close or reopen an issue.
+    /// Returns the updated issue.
+    async fn set_issue_state(
+        &self,
+        owner: &str,
+        name: &str,
+        number: u64,
+        state: IssueState,
+    ) -> Result<Issue> {
+        let _ = (owner, name, number, state);
+        Err(Error::unsupported(
+            "Issues are not supported by this remote",
+        ))
+    }
+    /// List the comments on an issue, oldest first.
+    async fn list_issue_comments(
+        &self,
+        owner: &str,
+        name: &str,
+        number: u64,
+    ) -> Result<Vec<Comment>> {
+        let _ = (owner, name, number);
+        Err(Error::unsupported(
+            "Issues are not supported by this remote",
+        ))
+    }
+    /// Comment on an issue.
+    /// Returns the new comment.
+    async fn create_issue_comment(
+        &self,
+        owner: &str,
+        name: &str,
+        number: u64,
+        body: &str,
+    ) -> Result<Comment> {
+        let _ = (owner, name, number, body);
+        Err(Error::unsupported(
+            "Issues are not supported by this remote",
+        ))
+    }
+    /// List the pull requests (merge requests on GitLab) of a repository, oldest first.
+    async fn list_pull_requests(
+        &self,
+        owner: &str,
+        name: &str,
+        list_info: ListPullRequestsInfo,
+    ) -> Result<Vec<PullRequest>> {
+        let _ = (owner, name, list_info);
+        Err(Error::unsupported(
+            "Pull requests are not supported by this remote",
+        ))
+    }
+    /// Get a single pull request by its number.
+    async fn get_pull_request(&self, owner: &str, name: &str, number: u64) -> Result<PullRequest> {
+        let _ = (owner, name, number);
+        Err(Error::unsupported(
+            "Pull requests are not supported by this remote",
+        ))
+    }
+    /// Open a pull request.
+    /// Returns the new pull request.
+    async fn create_pull_request(
+        &self,
+        owner: &str,
+        name: &str,
+        pull_request: PullRequestCreateInfo,
+    ) -> Result<PullRequest> {
+        let _ = (owner, name, pull_request);
+        Err(Error::unsupported(
+            "Pull requests are not supported by this remote",
+        ))
+    }
+    /// Comment on a pull request.
+    /// Returns the new comment.
+    async fn create_pull_request_comment(
+        &self,
+        owner: &str,
+        name: &str,
+        number: u64,
+        body: &str,
+    ) -> Result<Comment> {
+        let _ = (owner, name, number, body);
+        Err(Error::unsupported(
+            "Pull requests are not supported by this remote",
+        ))
+    }
+    /// Approve a pull request as the authenticated user.
+    async fn approve_pull_request(&self, owner: &str, name: &str, number: u64) -> Result<()> {
+        let _ = (owner, name, number);
+        Err(Error::unsupported(
+            "Pull requests are not supported by this remote",
+        ))
+    }
+    /// Merge a pull request.
+    /// Returns the merged pull request.
+    async fn merge_pull_request(
+        &self,
+        owner: &str,
+        name: &str,
+        number: u64,
+        merge_info: PullRequestMergeInfo,
+    ) -> Result<PullRequest> {
+        let _ = (owner, name, number, merge_info);
+        Err(Error::unsupported(
+            "Pull requests are not supported by this remote",
+        ))
+    }
+    /// The ref the head of a pull request can be fetched from, in the repository it was opened
+    /// in. Used to check out pull requests, including ones from forks.
+    fn pull_request_ref(&self, number: u64) -> String {
+        format!("refs/pull/{number}/head")
+    }
+    /// List the comments on a pull request, oldest first. Review comments on the code are not
+    /// included.
+    async fn list_pull_request_comments(
+        &self,
+        owner: &str,
+        name: &str,
+        number: u64,
+    ) -> Result<Vec<Comment>> {
+        let _ = (owner, name, number);
+        Err(Error::unsupported(
+            "Pull requests are not supported by this remote",
+        ))
+    }
+    /// List the releases of a repository, newest first.
+    async fn list_releases(&self, owner: &str, name: &str) -> Result<Vec<Release>> {
+        let _ = (owner, name);
+        Err(Error::unsupported(
+            "Releases are not supported by this remote",
+        ))
+    }
+    /// Publish a release.
+    /// Returns the new release.
+    async fn create_release(
+        &self,
+        owner: &str,
+        name: &str,
+        release: ReleaseCreateInfo,
+    ) -> Result<Release> {
+        let _ = (owner, name, release);
+        Err(Error::unsupported(
+            "Releases are not supported by this remote",
+        ))
+    }
+    /// Get the release for a tag.
+    async fn get_release(&self, owner: &str, name: &str, tag: &str) -> Result<Release> {
+        let _ = (owner, name, tag);
+        Err(Error::unsupported(
+            "Releases are not supported by this remote",
+        ))
+    }
+    /// Delete the release for a tag. The tag itself is kept.
+    async fn delete_release(&self, owner: &str, name: &str, tag: &str) -> Result<()> {
+        let _ = (owner, name, tag);
+        Err(Error::unsupported(
+            "Releases are not supported by this remote",
+        ))
+    }
+    /// Attach a file to the release for a tag.
+    /// Returns the new asset.
+    async fn upload_release_asset(
+        &self,
+        owner: &str,
+        name: &str,
+        tag: &str,
+        file_name: &str,
+        data: Vec<u8>,
+    ) -> Result<ReleaseAsset> {
+        let _ = (owner, name, tag, file_name, data);
+        Err(Error::unsupported(
+            "Release assets are not supported by this remote",
+        ))
+    }
+    /// Download the content of an asset of the release for a tag.
+    async fn download_release_asset(
+        &self,
+        owner: &str,
+        name: &str,
+        tag: &str,
+        asset_name: &str,
+    ) -> Result<Vec<u8>> {
+        let _ = (owner, name, tag, asset_name);
+        Err(Error::unsupported(
+            "Release assets are not supported by this remote",
+        ))
+    }
+    /// List the pages of the wiki of a repository, with their content.
+    async fn list_wiki_pages(&self, owner: &str, name: &str) -> Result<Vec<WikiPage>> {
+        let _ = (owner, name);
+        Err(Error::unsupported("Wikis are not supported by this remote"))
+    }
+    /// Add a page to the wiki of a repository.
+    /// Returns the new page.
+    async fn create_wiki_page(&self, owner: &str, name: &str, page: WikiPage) -> Result<WikiPage> {
+        let _ = (owner, name, page);
+        Err(Error::unsupported("Wikis are not supported by this remote"))
+    }
     /// Get the configuration of the remote.
     fn get_config(&self) -> &RemoteConfig;
     /// Clone a repository to the given path.
-    async fn clone_repo(&self, name: &str, path: &str, recursive: bool) -> Result<()> {
-        let config = self.get_config();
-        let url = self.clone_url(&config.username, name);
+    async fn clone_repo(&self, owner: &str, name: &str, path: &str, recursive: bool) -> Result<()> {
+        let url = self.clone_url(owner, name);
 
         let mut cmd = std::process::Command::new("git");
         cmd.args(["clone", &url, path]);
@@ -227,9 +1588,8 @@ pub trait Remote: Sync {
     }
     /// Add a remote to the local git repository. If the current directory is not a git repository,
     /// it will be initialized as one.
-    async fn add_remote(&self, repo_name: &str, branch: Option<String>) -> Result<()> {
-        let config = self.get_config();
-        let url = self.clone_url(&config.username, repo_name);
+    async fn add_remote(&self, owner: &str, repo_name: &str, branch: Option<String>) -> Result<()> {
+        let url = self.clone_url(owner, repo_name);
 
         if !std::path::Path::new(".git").exists() {
             let cmd = std::process::Command::new("git").arg("init").status()?;
@@ -263,12 +1623,12 @@ pub trait Remote: Sync {
         Ok(())
     }
     /// Get the clone URL for the given repository.
-    fn clone_url(&self, username: &str, repo_name: &str) -> String {
+    fn clone_url(&self, owner: &str, repo_name: &str) -> String {
         let config = self.get_config();
         let clean_url = config.url.replace("https://", "").replace("http://", "");
         match config.clone_protocol {
-            CloneProtocol::SSH => format!("git@{}:{}/{}.git", clean_url, username, repo_name),
-            CloneProtocol::HTTPS => format!("{}/{}/{}.git", config.url, username, repo_name),
+            CloneProtocol::SSH => format!("git@{}:{}/{}.git", clean_url, owner, repo_name),
+            CloneProtocol::HTTPS => format!("{}/{}/{}.git", config.url, owner, repo_name),
         }
     }
 }
@@ -279,5 +1639,51 @@ pub async fn create_remote(config: &RemoteConfig, provider: Provider) -> Result<
         GitHub => Box::new(github::GitHubRemote::new(config).await?),
         Gitea => Box::new(gitea::GiteaRemote::new(config).await?),
         GitLab => Box::new(gitlab::GitlabRemote::new(config).await?),
+        Bitbucket => Box::new(bitbucket::BitbucketRemote::new(config).await?),
+        Local => Box::new(local::LocalRemote::new(config).await?),
+        External { command } => Box::new(external::ExternalRemote::spawn(&command, config).await?),
     })
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_fetch_concurrently() {
+        let running = Arc::new(AtomicUsize::new(0));
+        let peak = Arc::new(AtomicUsize::new(0));
+        let items: Vec<u64> = (0..10).collect();
+        let results = fetch_concurrently(items, 3, |i| {
+            let running = running.clone();
+            let peak = peak.clone();
+            async move {
+                let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+                peak.fetch_max(now, Ordering::SeqCst);
+                // Finish in reverse order, so the results have to be reordered.
+                tokio::time::sleep(Duration::from_millis(20 - i)).await;
+                running.fetch_sub(1, Ordering::SeqCst);
+                Ok(i * 2)
+            }
+        })
+        .await
+        .unwrap();
+        assert_eq!(results, (0..10).map(|i| i * 2).collect::<Vec<_>>());
+        assert_eq!(peak.load(Ordering::SeqCst), 3);
+
+        let err = fetch_concurrently(vec![1, 2], 1, |i| async move {
+            match i {
+                2 => Err(Error::not_found("missing")),
+                _ => Ok(i),
+            }
+        })
+        .await
+        .unwrap_err();
+        assert_eq!(err.kind, crate::error::ErrorKind::NotFound);
+    }
+}
@@ -0,0 +1,336 @@
+//! Tests for [BitbucketRemote] against a mock server, for both Bitbucket Cloud and Data Center.
+
+mod common;
+
+use common::MockServer;
+use gritty::{
+    error::ErrorKind,
+    remote::{
+        bitbucket::BitbucketRemote, Auth, CloneProtocol, ListReposInfo, Remote, RemoteConfig,
+        RepoCreateInfo, RepoForkOption, RepoRef,
+    },
+};
+use serde_json::{json, Value};
+
+fn config(url: String) -> RemoteConfig {
+    RemoteConfig::new(
+        "alice",
+        CloneProtocol::HTTPS,
+        url,
+        Auth::Token {
+            token: "bb-token".to_string(),
+        },
+    )
+}
+
+fn cloud_repo(name: &str, private: bool, fork: bool) -> Value {
+    let mut repo = json!({
+        "name": name,
+        "full_name": format!("alice/{name}"),
+        "description": "",
+        "is_private": private,
+        "mainbranch": { "name": "main" },
+        "links": {
+            "clone": [
+                { "name": "https", "href": format!("https://bitbucket.org/alice/{name}.git") },
+                { "name": "ssh", "href": format!("git@bitbucket.org:alice/{name}.git") },
+            ],
+        },
+    });
+    if fork {
+        repo["parent"] = json!({ "full_name": format!("upstream/{name}") });
+    }
+    repo
+}
+
+fn data_center_repo(server: &MockServer, key: &str, slug: &str, public: bool) -> Value {
+    json!({
+        "slug": slug,
+        "name": slug,
+        "public": public,
+        "project": { "key": key },
+        "links": {
+            "clone": [
+                { "name": "http", "href": format!("{}/scm/{key}/{slug}.git", server.url()) },
+            ],
+        },
+    })
+}
+
+#[tokio::test]
+async fn cloud_check_auth() {
+    let server = MockServer::start().await;
+    server.route("GET", "/2.0/user", 200, json!({ "username": "alice" }));
+
+    let remote = BitbucketRemote::new(&config(format!("{}/2.0", server.url())))
+        .await
+        .unwrap();
+    assert!(remote.check_auth().await.unwrap());
+    let request = &server.requests_to("GET", "/2.0/user")[0];
+    assert_eq!(request.header("authorization"), Some("Bearer bb-token"));
+
+    server.route(
+        "GET",
+        "/2.0/user",
+        401,
+        json!({ "type": "error", "error": { "message": "Unauthorized" } }),
+    );
+    assert!(!remote.check_auth().await.unwrap());
+}
+
+#[tokio::test]
+async fn cloud_create_repo() {
+    let server = MockServer::start().await;
+    server.route(
+        "POST",
+        "/2.0/repositories/alice/my-repo",
+        200,
+        cloud_repo("my-repo", true, false),
+    );
+    server.route(
+        "GET",
+        "/2.0/repositories/alice/my-repo/commits",
+        200,
+        json!({
+            "values": [{
+                "hash": "0123456789abcdef",
+                "message": "Initial commit",
+                "date": "2024-01-01T00:00:00+00:00",
+                "author": { "raw": "Alice <alice@example.com>", "user": { "display_name": "Alice" } },
+            }],
+        }),
+    );
+
+    let remote = BitbucketRemote::new(&config(format!("{}/2.0", server.url())))
+        .await
+        .unwrap();
+    let repo = remote
+        .create_repo(RepoCreateInfo {
+            name: "My Repo".to_string(),
+            private: true,
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+
+    let body = server.requests_to("POST", "/2.0/repositories/alice/my-repo")[0].json();
+    assert_eq!(body["scm"], "git");
+    assert_eq!(body["is_private"], true);
+
+    assert_eq!(repo.owner, "alice");
+    assert_eq!(repo.default_branch.as_deref(), Some("main"));
+    assert_eq!(repo.ssh_url, "git@bitbucket.org:alice/my-repo.git");
+    assert_eq!(repo.last_commits.len(), 1);
+    assert_eq!(repo.last_commits[0].author, "Alice");
+}
+
+#[tokio::test]
+async fn cloud_list_repos_follows_pages() {
+    let server = MockServer::start().await;
+    let base = format!("{}/2.0", server.url());
+    server.route(
+        "GET",
+        "/2.0/repositories/alice?pagelen=100",
+        200,
+        json!({
+            "values": [cloud_repo("first", false, false), cloud_repo("private", true, false)],
+            "next": format!("{base}/repositories/alice?pagelen=100&page=2"),
+        }),
+    );
+    server.route(
+        "GET",
+        "/2.0/repositories/alice?pagelen=100&page=2",
+        200,
+        json!({ "values": [cloud_repo("forked", false, true)] }),
+    );
+    for name in ["first", "private", "forked"] {
+        let path = format!("/2.0/repositories/alice/{name}/commits");
+        server.route("GET", &path, 200, json!({ "values": [] }));
+    }
+
+    let remote = BitbucketRemote::new(&config(base)).await.unwrap();
+    let repos = remote
+        .list_repos(ListReposInfo {
+            forks: true,
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    let names: Vec<_> = repos.iter().map(|x| x.name.as_str()).collect();
+    assert_eq!(names, ["first", "forked"]);
+    assert!(repos[1].fork);
+    assert!(server
+        .requests_to("GET", "/2.0/repositories/alice/private/commits")
+        .is_empty());
+
+    // A limit that is filled by the first page doesn't fetch the second one.
+    let repos = remote
+        .list_repos(ListReposInfo {
+            forks: true,
+            limit: Some(1),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(repos.len(), 1);
+    assert_eq!(repos[0].name, "first");
+    assert_eq!(
+        server.requests_to("GET", "/2.0/repositories/alice").len(),
+        3
+    );
+}
+
+#[tokio::test]
+async fn cloud_fork_into_workspace() {
+    let server = MockServer::start().await;
+    let mut fork = cloud_repo("tool", false, true);
+    fork["full_name"] = json!("team/tool");
+    server.route("POST", "/2.0/repositories/upstream/tool/forks", 201, fork);
+    server.route(
+        "GET",
+        "/2.0/repositories/team/tool/commits",
+        200,
+        json!({ "values": [] }),
+    );
+
+    let remote = BitbucketRemote::new(&config(format!("{}/2.0", server.url())))
+        .await
+        .unwrap();
+    let repo = remote
+        .create_fork(RepoForkOption {
+            owner: "upstream".to_string(),
+            repo: "tool".to_string(),
+            organization: Some("team".to_string()),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+
+    let body = server.requests_to("POST", "/2.0/repositories/upstream/tool/forks")[0].json();
+    assert_eq!(body["workspace"]["slug"], "team");
+    assert_eq!(repo.owner, "team");
+    assert!(repo.fork);
+    assert_eq!(repo.parent, RepoRef::parse("upstream/tool"));
+}
+
+#[tokio::test]
+async fn data_center_get_and_delete_repo() {
+    let server = MockServer::start().await;
+    let path = "/rest/api/1.0/projects/~alice/repos/my-repo";
+    server.route(
+        "GET",
+        path,
+        200,
+        data_center_repo(&server, "~alice", "my-repo", false),
+    );
+    server.route(
+        "GET",
+        &format!("{path}/commits"),
+        200,
+        json!({
+            "values": [{
+                "id": "0123456789abcdef",
+                "message": "Initial commit",
+                "author": { "name": "alice" },
+                "authorTimestamp": 1704067200000i64,
+            }],
+            "isLastPage": true,
+        }),
+    );
+    server.route(
+        "GET",
+        &format!("{path}/default-branch"),
+        200,
+        json!({ "id": "refs/heads/main", "displayId": "main", "type": "BRANCH" }),
+    );
+    server.route("DELETE", path, 202, json!({}));
+
+    let remote = BitbucketRemote::new(&config(server.url())).await.unwrap();
+    let repo = remote.get_repo_info("alice", "my-repo").await.unwrap();
+    assert_eq!(repo.owner, "alice");
+    assert!(repo.private);
+    assert_eq!(
+        repo.clone_url,
+        format!("{}/scm/~alice/my-repo.git", server.url())
+    );
+    assert_eq!(
+        repo.last_commits[0].date.to_rfc3339(),
+        "2024-01-01T00:00:00+00:00"
+    );
+    assert_eq!(repo.default_branch.as_deref(), Some("main"));
+
+    remote.delete_repo("alice", "my-repo").await.unwrap();
+    assert_eq!(server.requests_to("DELETE", path).len(), 1);
+}
+
+#[tokio::test]
+async fn data_center_create_repo_in_project() {
+    let server = MockServer::start().await;
+    server.route(
+        "POST",
+        "/rest/api/1.0/projects/TEAM/repos",
+        201,
+        data_center_repo(&server, "TEAM", "service", true),
+    );
+    // An empty repository has no commits to list.
+    server.route(
+        "GET",
+        "/rest/api/1.0/projects/TEAM/repos/service/commits",
+        404,
+        json!({ "errors": [{ "message": "Repository is empty" }] }),
+    );
+
+    let remote = BitbucketRemote::new(&config(server.url())).await.unwrap();
+    let repo = remote
+        .create_repo(RepoCreateInfo {
+            name: "service".to_string(),
+            owner: Some("TEAM".to_string()),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+
+    let body = server.requests_to("POST", "/rest/api/1.0/projects/TEAM/repos")[0].json();
+    assert_eq!(body["public"], true);
+    assert_eq!(repo.owner, "TEAM");
+    assert!(repo.last_commits.is_empty());
+    assert_eq!(repo.default_branch, None);
+}
+
+#[tokio::test]
+async fn data_center_not_found() {
+    let server = MockServer::start().await;
+    let remote = BitbucketRemote::new(&config(server.url())).await.unwrap();
+    let err = remote.get_repo_info("alice", "missing").await.unwrap_err();
+    assert_eq!(err.kind, ErrorKind::NotFound);
+    assert_eq!(err.status, Some(404));
+}
+
+#[tokio::test]
+async fn archive_repo() {
+    let server = MockServer::start().await;
+    let path = "/rest/api/1.0/projects/~alice/repos/my-repo";
+    let mut repo = data_center_repo(&server, "~alice", "my-repo", false);
+    repo["archived"] = json!(true);
+    server.route("PUT", path, 200, repo);
+    server.route(
+        "GET",
+        &format!("{path}/commits"),
+        200,
+        json!({ "values": [], "isLastPage": true }),
+    );
+
+    let remote = BitbucketRemote::new(&config(server.url())).await.unwrap();
+    let repo = remote.archive_repo("alice", "my-repo").await.unwrap();
+    assert!(repo.archived);
+    assert_eq!(
+        server.requests_to("PUT", path)[0].json(),
+        json!({ "archived": true })
+    );
+
+    let cloud = BitbucketRemote::new(&config(format!("{}/2.0", server.url())))
+        .await
+        .unwrap();
+    let err = cloud.archive_repo("alice", "my-repo").await.unwrap_err();
+    assert_eq!(err.kind, ErrorKind::Unsupported);
+}
@@ -0,0 +1,157 @@
+//! Tests for provider plugins, using the reference plugin from `examples/gritty-provider-memory.rs`.
+
+use std::path::PathBuf;
+
+use gritty::{
+    error::ErrorKind,
+    remote::{
+        create_remote, Auth, CloneProtocol, ListReposInfo, Provider, Remote, RemoteConfig,
+        RepoCreateInfo, RepoForkOption, RepoUpdateInfo,
+    },
+};
+
+/// The reference plugin is built as an example, next to the directory of the test binary.
+fn plugin() -> String {
+    let exe = std::env::current_exe().unwrap();
+    let dir = exe.parent().unwrap().parent().unwrap();
+    let path: PathBuf = dir.join("examples").join(format!(
+        "gritty-provider-memory{}",
+        std::env::consts::EXE_SUFFIX
+    ));
+    assert!(
+        path.exists(),
+        "reference plugin not found at {}",
+        path.display()
+    );
+    path.to_string_lossy().to_string()
+}
+
+fn config() -> RemoteConfig {
+    RemoteConfig::new(
+        "alice",
+        CloneProtocol::HTTPS,
+        "https://forge.example.com",
+        Auth::Token {
+            token: "secret".to_string(),
+        },
+    )
+}
+
+async fn remote() -> Box<dyn Remote> {
+    let provider = Provider::External { command: plugin() };
+    create_remote(&config(), provider).await.unwrap()
+}
+
+#[tokio::test]
+async fn round_trip() {
+    let remote = remote().await;
+    assert!(remote.check_auth().await.unwrap());
+
+    let repo = remote
+        .create_repo(RepoCreateInfo {
+            name: "hello".to_string(),
+            description: Some("Hello from a plugin".to_string()),
+            private: true,
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(repo.owner, "alice");
+    assert_eq!(repo.description.as_deref(), Some("Hello from a plugin"));
+    assert_eq!(repo.clone_url, "https://forge.example.com/alice/hello.git");
+
+    let fork = remote
+        .create_fork(RepoForkOption {
+            owner: "alice".to_string(),
+            repo: "hello".to_string(),
+            organization: Some("team".to_string()),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    assert!(fork.fork);
+    assert_eq!(fork.owner, "team");
+
+    let repo = remote
+        .update_repo(
+            "alice",
+            "hello",
+            RepoUpdateInfo {
+                description: Some("Still hello".to_string()),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(repo.description.as_deref(), Some("Still hello"));
+    assert_eq!(remote.get_repo_info("alice", "hello").await.unwrap(), repo);
+    let list = |private| {
+        remote.list_repos(ListReposInfo {
+            owner: None,
+            private,
+            ..Default::default()
+        })
+    };
+    assert!(list(false).await.unwrap().is_empty());
+    assert_eq!(list(true).await.unwrap(), vec![repo]);
+
+    remote.delete_repo("alice", "hello").await.unwrap();
+    assert!(list(true).await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn errors_keep_their_kind() {
+    let remote = remote().await;
+    let err = remote.get_repo_info("alice", "missing").await.unwrap_err();
+    assert_eq!(err.kind, ErrorKind::NotFound);
+    assert_eq!(err.status, Some(404));
+    assert_eq!(err.message, "Repository alice/missing not found");
+
+    let info = RepoCreateInfo {
+        name: "twice".to_string(),
+        ..Default::default()
+    };
+    remote.create_repo(info.clone()).await.unwrap();
+    let err = remote.create_repo(info).await.unwrap_err();
+    assert_eq!(err.status, Some(409));
+}
+
+#[tokio::test]
+async fn mirror_interval_accepted_without_check() {
+    let remote = remote().await;
+    remote
+        .check_push_mirror_interval("alice", "hello", "1h")
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn missing_plugin() {
+    let provider = Provider::External {
+        command: "does-not-exist-anywhere".to_string(),
+    };
+    let err = create_remote(&config(), provider).await.err().unwrap();
+    assert!(err
+        .message
+        .contains("Could not start provider plugin gritty-provider-does-not-exist-anywhere"));
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn protocol_version_mismatch() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let path = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("gritty-provider-future");
+    std::fs::write(
+        &path,
+        "#!/bin/sh\nread line\necho '{\"jsonrpc\":\"2.0\",\"id\":1,\"result\":{\"protocol_version\":999}}'\n",
+    )
+    .unwrap();
+    std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+    let provider = Provider::External {
+        command: path.to_string_lossy().to_string(),
+    };
+    let err = create_remote(&config(), provider).await.err().unwrap();
+    assert!(err.message.contains("speaks protocol version 999"));
+}
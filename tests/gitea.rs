@@ -0,0 +1,241 @@
+//! Tests for [GiteaRemote] against a mock server posing as a Gitea instance.
+
+mod common;
+
+use common::MockServer;
+use gritty::remote::{
+    gitea::GiteaRemote, Auth, BranchProtection, CloneProtocol, MirrorCreateInfo, MirrorDirection,
+    Remote, RemoteConfig, RepoImportInfo, SyncStatus, Visibility,
+};
+use serde_json::{json, Value};
+
+fn config(server: &MockServer) -> RemoteConfig {
+    RemoteConfig::new(
+        "alice",
+        CloneProtocol::HTTPS,
+        server.url(),
+        Auth::Token {
+            token: "gitea-token".to_string(),
+        },
+    )
+}
+
+/// A repository of alice without commits.
+fn repo(name: &str) -> Value {
+    json!({
+        "name": name,
+        "full_name": format!("alice/{name}"),
+        "owner": { "login": "alice" },
+        "default_branch": "main",
+        "clone_url": format!("https://gitea.example.com/alice/{name}.git"),
+        "ssh_url": format!("git@gitea.example.com:alice/{name}.git"),
+        "html_url": format!("https://gitea.example.com/alice/{name}"),
+        "empty": true,
+    })
+}
+
+/// Answer the commit listing that comes with every repository.
+fn route_commits(server: &MockServer, owner: &str, name: &str) {
+    let path = format!("/api/v1/repos/{owner}/{name}/commits");
+    server.route("GET", &path, 200, json!([]));
+}
+
+#[tokio::test]
+async fn protect_branch_changes_existing_rule_in_place() {
+    let server = MockServer::start().await;
+    let route = "/api/v1/repos/alice/hello/branch_protections/main";
+    server.route("GET", route, 200, json!({ "rule_name": "main" }));
+    // Gitea leaves out users without write access.
+    let rule = json!({
+        "rule_name": "main",
+        "required_approvals": 1,
+        "status_check_contexts": ["ci"],
+        "push_whitelist_usernames": null,
+        "enable_force_push": false,
+    });
+    server.route("PATCH", route, 200, rule);
+
+    let remote = GiteaRemote::new(&config(&server)).await.unwrap();
+    let protection = BranchProtection {
+        required_reviews: 1,
+        required_status_checks: vec!["ci".to_string()],
+        no_force_push: true,
+        push_allowlist: vec!["bob".to_string()],
+    };
+    let unsupported = remote
+        .protect_branch("alice", "hello", "main", protection)
+        .await
+        .unwrap();
+    assert_eq!(unsupported, vec!["restricted pushers"]);
+    let body = server.requests_to("PATCH", route)[0].json();
+    assert_eq!(body["push_whitelist_usernames"], json!(["bob"]));
+    assert_eq!(body["enable_force_push"], false);
+    assert!(server.requests_to("DELETE", route).is_empty());
+}
+
+#[tokio::test]
+async fn protect_branch_creates_missing_rule() {
+    let server = MockServer::start().await;
+    let route = "/api/v1/repos/alice/hello/branch_protections";
+    let rule = format!("{route}/main");
+    server.route("GET", &rule, 404, json!({ "message": "Not Found" }));
+    server.route("POST", route, 201, json!({ "rule_name": "main" }));
+
+    let remote = GiteaRemote::new(&config(&server)).await.unwrap();
+    let unsupported = remote
+        .protect_branch("alice", "hello", "main", BranchProtection::default())
+        .await
+        .unwrap();
+    // Gitea before 1.22 can't allow force pushes on a protected branch.
+    assert_eq!(unsupported, vec!["force pushes"]);
+    let body = server.requests_to("POST", route)[0].json();
+    assert_eq!(body["rule_name"], "main");
+    assert_eq!(body["enable_force_push"], true);
+}
+
+#[tokio::test]
+async fn archive_and_unarchive_edit_the_repository() {
+    let server = MockServer::start().await;
+    let mut archived = repo("hello");
+    archived["archived"] = json!(true);
+    server.route("PATCH", "/api/v1/repos/alice/hello", 200, archived);
+    route_commits(&server, "alice", "hello");
+
+    let remote = GiteaRemote::new(&config(&server)).await.unwrap();
+    let repo = remote.archive_repo("alice", "hello").await.unwrap();
+    assert!(repo.archived);
+    remote.unarchive_repo("alice", "hello").await.unwrap();
+    let requests = server.requests_to("PATCH", "/api/v1/repos/alice/hello");
+    assert_eq!(requests[0].json(), json!({ "archived": true }));
+    assert_eq!(requests[1].json(), json!({ "archived": false }));
+}
+
+#[tokio::test]
+async fn transfer_repo_returns_the_moved_repository() {
+    let server = MockServer::start().await;
+    let mut moved = repo("hello");
+    moved["owner"] = json!({ "login": "team" });
+    moved["full_name"] = json!("team/hello");
+    let route = "/api/v1/repos/alice/hello/transfer";
+    server.route("POST", route, 202, moved);
+    route_commits(&server, "team", "hello");
+
+    let remote = GiteaRemote::new(&config(&server)).await.unwrap();
+    let repo = remote
+        .transfer_repo("alice", "hello", "team")
+        .await
+        .unwrap();
+    assert_eq!(repo.owner, "team");
+    assert_eq!(repo.full_name, "team/hello");
+    let body = server.requests_to("POST", route)[0].json();
+    assert_eq!(body, json!({ "new_owner": "team" }));
+}
+
+#[tokio::test]
+async fn import_repo_migrates_with_credentials() {
+    let server = MockServer::start().await;
+    server.route("POST", "/api/v1/repos/migrate", 201, repo("hello"));
+    route_commits(&server, "alice", "hello");
+
+    let remote = GiteaRemote::new(&config(&server)).await.unwrap();
+    let import = RepoImportInfo {
+        clone_url: "https://github.com/bob/hello.git".to_string(),
+        auth_username: Some("bob".to_string()),
+        auth_password: Some("pass".to_string()),
+        name: "hello".to_string(),
+        visibility: Visibility::Internal,
+        ..Default::default()
+    };
+    let repo = remote.import_repo(import).await.unwrap();
+    assert_eq!(repo.full_name, "alice/hello");
+    let body = server.requests_to("POST", "/api/v1/repos/migrate")[0].json();
+    assert_eq!(body["clone_addr"], "https://github.com/bob/hello.git");
+    assert_eq!(body["repo_owner"], "alice");
+    assert_eq!(body["auth_username"], "bob");
+    assert_eq!(body["auth_password"], "pass");
+    // Gitea can't create internal repositories through the API.
+    assert_eq!(body["private"], true);
+    assert_eq!(body["mirror"], false);
+}
+
+#[tokio::test]
+async fn push_mirrors_are_created_and_listed() {
+    let server = MockServer::start().await;
+    let mut mirrored = repo("hello");
+    mirrored["mirror"] = json!(true);
+    mirrored["original_url"] = json!("https://github.com/bob/hello.git");
+    mirrored["mirror_interval"] = json!("8h0m0s");
+    server.route("GET", "/api/v1/repos/alice/hello", 200, mirrored);
+    let route = "/api/v1/repos/alice/hello/push_mirrors";
+    let mirror = json!({
+        "remote_address": "https://codeberg.org/alice/hello.git",
+        "interval": "1h0m0s",
+        "last_update": null,
+        "last_error": "",
+    });
+    server.route("POST", route, 200, mirror.clone());
+    server.route("GET", route, 200, json!([mirror]));
+
+    let remote = GiteaRemote::new(&config(&server)).await.unwrap();
+    let create = MirrorCreateInfo {
+        url: "https://codeberg.org/alice/hello.git".to_string(),
+        auth_username: Some("alice".to_string()),
+        auth_password: Some("pass".to_string()),
+        interval: Some("1h0m0s".to_string()),
+    };
+    let mirror = remote
+        .create_push_mirror("alice", "hello", create)
+        .await
+        .unwrap();
+    assert_eq!(mirror.interval.as_deref(), Some("1h0m0s"));
+    let body = server.requests_to("POST", route)[0].json();
+    assert_eq!(body["remote_username"], "alice");
+    assert_eq!(body["remote_password"], "pass");
+    assert_eq!(body["interval"], "1h0m0s");
+
+    let mirrors = remote.list_mirrors("alice", "hello").await.unwrap();
+    assert_eq!(mirrors.len(), 2);
+    assert_eq!(mirrors[0].direction, MirrorDirection::Pull);
+    assert_eq!(mirrors[0].interval.as_deref(), Some("8h0m0s"));
+    assert_eq!(mirrors[1].direction, MirrorDirection::Push);
+    assert_eq!(mirrors[1].last_error, None);
+}
+
+#[tokio::test]
+async fn sync_fork_only_fast_forwards() {
+    let server = MockServer::start().await;
+    let route = "/api/v1/repos/alice/hello/sync_fork";
+    let main = format!("{route}/main");
+    server.route(
+        "GET",
+        &main,
+        200,
+        json!({ "allowed": true, "commits_behind": 2 }),
+    );
+    server.route("POST", &main, 204, Value::Null);
+    let dev = format!("{route}/dev");
+    server.route(
+        "GET",
+        &dev,
+        200,
+        json!({ "allowed": true, "commits_behind": 0 }),
+    );
+    let old = format!("{route}/old");
+    server.route(
+        "GET",
+        &old,
+        200,
+        json!({ "allowed": false, "commits_behind": 3 }),
+    );
+
+    let remote = GiteaRemote::new(&config(&server)).await.unwrap();
+    let status = remote.sync_fork("alice", "hello", "main").await.unwrap();
+    assert_eq!(status, SyncStatus::FastForwarded);
+    assert_eq!(server.requests_to("POST", &main).len(), 1);
+    let status = remote.sync_fork("alice", "hello", "dev").await.unwrap();
+    assert_eq!(status, SyncStatus::UpToDate);
+    let status = remote.sync_fork("alice", "hello", "old").await.unwrap();
+    assert_eq!(status, SyncStatus::Diverged);
+    assert!(server.requests_to("POST", &dev).is_empty());
+    assert!(server.requests_to("POST", &old).is_empty());
+}
@@ -0,0 +1,689 @@
+//! Tests for [GitHubRemote] against a mock server posing as a GitHub Enterprise Server instance.
+
+mod common;
+
+use common::MockServer;
+use gritty::remote::{
+    github::GitHubRemote, Auth, BranchProtection, CloneProtocol, CommitHistory, IssueState,
+    ListIssuesInfo, ListReposInfo, MergeMethod, PullRequestMergeInfo, PullRequestState, Remote,
+    RemoteConfig, RepoCreateInfo, RepoUpdateInfo, SshKeyCreateInfo, SyncStatus, TagCreateInfo,
+    Visibility, WebhookContentType, WebhookEvent, WebhookUpdateInfo,
+};
+use serde_json::{json, Value};
+
+fn config(server: &MockServer) -> RemoteConfig {
+    RemoteConfig::new(
+        "octocat",
+        CloneProtocol::HTTPS,
+        server.url(),
+        Auth::Token {
+            token: "ghe-token".to_string(),
+        },
+    )
+}
+
+fn user(server: &MockServer) -> Value {
+    let url = server.url();
+    json!({
+        "login": "octocat",
+        "id": 1,
+        "node_id": "MDQ6VXNlcjE=",
+        "avatar_url": format!("{url}/avatars/octocat"),
+        "gravatar_id": "",
+        "url": format!("{url}/api/v3/users/octocat"),
+        "html_url": format!("{url}/octocat"),
+        "followers_url": format!("{url}/api/v3/users/octocat/followers"),
+        "following_url": format!("{url}/api/v3/users/octocat/following"),
+        "gists_url": format!("{url}/api/v3/users/octocat/gists"),
+        "starred_url": format!("{url}/api/v3/users/octocat/starred"),
+        "subscriptions_url": format!("{url}/api/v3/users/octocat/subscriptions"),
+        "organizations_url": format!("{url}/api/v3/users/octocat/orgs"),
+        "repos_url": format!("{url}/api/v3/users/octocat/repos"),
+        "events_url": format!("{url}/api/v3/users/octocat/events"),
+        "received_events_url": format!("{url}/api/v3/users/octocat/received_events"),
+        "type": "User",
+        "site_admin": false,
+        "name": null,
+        "company": null,
+        "blog": "",
+        "location": null,
+        "email": null,
+        "hireable": null,
+        "bio": null,
+        "twitter_username": null,
+        "public_repos": 1,
+        "public_gists": 0,
+        "followers": 0,
+        "following": 0,
+        "created_at": "2024-01-01T00:00:00Z",
+        "updated_at": "2024-01-01T00:00:00Z",
+    })
+}
+
+/// A repository payload without clone URLs, so the remote has to build them itself.
+fn repository(server: &MockServer, name: &str, private: bool) -> Value {
+    json!({
+        "id": 42,
+        "name": name,
+        "url": format!("{}/api/v3/repos/octocat/{name}", server.url()),
+        "description": "A test repository",
+        "private": private,
+        "fork": false,
+        "default_branch": "main",
+    })
+}
+
+#[tokio::test]
+async fn check_auth_uses_enterprise_api() {
+    let server = MockServer::start().await;
+    server.route("GET", "/api/v3/user", 200, user(&server));
+
+    let remote = GitHubRemote::new(&config(&server)).await.unwrap();
+    assert!(remote.check_auth().await.unwrap());
+
+    let requests = server.requests_to("GET", "/api/v3/user");
+    assert_eq!(requests.len(), 1);
+    assert_eq!(
+        requests[0].header("authorization"),
+        Some("Bearer ghe-token")
+    );
+}
+
+#[tokio::test]
+async fn check_auth_unauthorized() {
+    let server = MockServer::start().await;
+    server.route(
+        "GET",
+        "/api/v3/user",
+        401,
+        json!({ "message": "Bad credentials" }),
+    );
+
+    let remote = GitHubRemote::new(&config(&server)).await.unwrap();
+    assert!(!remote.check_auth().await.unwrap());
+}
+
+#[tokio::test]
+async fn create_repo_uses_enterprise_host() {
+    let server = MockServer::start().await;
+    server.route(
+        "POST",
+        "/api/v3/user/repos",
+        201,
+        repository(&server, "hello", true),
+    );
+    server.route("GET", "/api/v3/repos/octocat/hello/commits", 200, json!([]));
+
+    let remote = GitHubRemote::new(&config(&server)).await.unwrap();
+    let repo = remote
+        .create_repo(RepoCreateInfo {
+            name: "hello".to_string(),
+            description: Some("A test repository".to_string()),
+            private: true,
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+
+    let body = server.requests_to("POST", "/api/v3/user/repos")[0].json();
+    assert_eq!(body["name"], "hello");
+    assert_eq!(body["private"], true);
+
+    assert_eq!(repo.name, "hello");
+    assert_eq!(repo.owner, "octocat");
+    assert!(repo.private);
+    assert_eq!(repo.ssh_url, "git@127.0.0.1:octocat/hello.git");
+    assert_eq!(
+        repo.clone_url,
+        format!("{}/octocat/hello.git", server.url())
+    );
+}
+
+#[tokio::test]
+async fn update_repo_sends_only_changed_settings() {
+    let server = MockServer::start().await;
+    let mut repo = repository(&server, "hello", false);
+    repo["visibility"] = json!("internal");
+    repo["has_wiki"] = json!(false);
+    server.route("PATCH", "/api/v3/repos/octocat/hello", 200, repo);
+    server.route("GET", "/api/v3/repos/octocat/hello/commits", 200, json!([]));
+
+    let remote = GitHubRemote::new(&config(&server)).await.unwrap();
+    let repo = remote
+        .update_repo(
+            "octocat",
+            "hello",
+            RepoUpdateInfo {
+                visibility: Some(Visibility::Internal),
+                has_wiki: Some(false),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+    let body = server.requests_to("PATCH", "/api/v3/repos/octocat/hello")[0].json();
+    assert_eq!(body, json!({ "visibility": "internal", "has_wiki": false }));
+    assert_eq!(repo.visibility, Visibility::Internal);
+    assert!(repo.private);
+}
+
+#[tokio::test]
+async fn transfer_repo_to_organization() {
+    let server = MockServer::start().await;
+    let mut repo = repository(&server, "hello", false);
+    repo["full_name"] = json!("octo-org/hello");
+    server.route("POST", "/api/v3/repos/octocat/hello/transfer", 202, repo);
+
+    let remote = GitHubRemote::new(&config(&server)).await.unwrap();
+    let repo = remote
+        .transfer_repo("octocat", "hello", "octo-org")
+        .await
+        .unwrap();
+
+    let body = server.requests_to("POST", "/api/v3/repos/octocat/hello/transfer")[0].json();
+    assert_eq!(body, json!({ "new_owner": "octo-org" }));
+    assert_eq!(repo.owner, "octo-org");
+    assert_eq!(repo.full_name, "octo-org/hello");
+    // The transfer happens in the background, so no commits are requested.
+    assert!(server
+        .requests_to("GET", "/api/v3/repos/octo-org/hello/commits")
+        .is_empty());
+}
+
+#[tokio::test]
+async fn sync_fork_merges_upstream() {
+    let server = MockServer::start().await;
+    let route = "/api/v3/repos/octocat/hello/merge-upstream";
+    let merged = json!({ "message": "ok", "merge_type": "fast-forward", "base_branch": "x:main" });
+    server.route("POST", route, 200, merged);
+    let remote = GitHubRemote::new(&config(&server)).await.unwrap();
+    let status = remote.sync_fork("octocat", "hello", "main").await.unwrap();
+    assert_eq!(status, SyncStatus::FastForwarded);
+    let body = server.requests_to("POST", route)[0].json();
+    assert_eq!(body, json!({ "branch": "main" }));
+
+    let server = MockServer::start().await;
+    server.route("POST", route, 409, json!({ "message": "Merge conflict" }));
+    let remote = GitHubRemote::new(&config(&server)).await.unwrap();
+    let status = remote.sync_fork("octocat", "hello", "main").await.unwrap();
+    assert_eq!(status, SyncStatus::Diverged);
+}
+
+#[tokio::test]
+async fn list_repos_uses_enterprise_api() {
+    let server = MockServer::start().await;
+    let mut public = repository(&server, "public-repo", false);
+    public["pushed_at"] = json!("2024-05-01T12:00:00Z");
+    public["mirror_url"] = json!("https://git.example.com/octocat/public-repo.git");
+    server.route(
+        "GET",
+        "/api/v3/user/repos",
+        200,
+        json!([public, repository(&server, "private-repo", true),]),
+    );
+
+    let remote = GitHubRemote::new(&config(&server)).await.unwrap();
+    let repos = remote.list_repos(ListReposInfo::default()).await.unwrap();
+
+    assert_eq!(repos.len(), 1);
+    assert_eq!(repos[0].name, "public-repo");
+    let requests = server.requests_to("GET", "/api/v3/user/repos");
+    assert_eq!(requests.len(), 1);
+    assert!(requests[0].path.contains("affiliation=owner"));
+    assert!(requests[0].path.contains("visibility=public"));
+    assert!(server
+        .requests_to("GET", "/api/v3/search/repositories")
+        .is_empty());
+    // The listing is enough to tell when the repository was last pushed to.
+    assert_eq!(
+        repos[0].pushed_at.unwrap().to_rfc3339(),
+        "2024-05-01T12:00:00+00:00"
+    );
+    assert!(repos[0].mirror);
+    let commits = "/api/v3/repos/octocat/public-repo/commits";
+    assert!(server.requests_to("GET", commits).is_empty());
+}
+
+#[tokio::test]
+async fn list_repos_includes_affiliations() {
+    let server = MockServer::start().await;
+    server.route("GET", "/api/v3/user/repos", 200, json!([]));
+
+    let remote = GitHubRemote::new(&config(&server)).await.unwrap();
+    let list_info = ListReposInfo {
+        affiliated: true,
+        ..Default::default()
+    };
+    remote.list_repos(list_info).await.unwrap();
+
+    let requests = server.requests_to("GET", "/api/v3/user/repos");
+    assert!(requests[0]
+        .path
+        .contains("affiliation=owner%2Ccollaborator%2Corganization_member"));
+}
+
+#[tokio::test]
+async fn list_repos_follows_pages() {
+    let server = MockServer::start().await;
+    let query = "type=all&sort=updated&per_page=100";
+    let first: Vec<_> = (0..100)
+        .map(|i| repository(&server, &format!("repo-{i}"), false))
+        .collect();
+    let path = format!("/api/v3/orgs/octo-org/repos?{query}&page=1");
+    server.route("GET", &path, 200, json!(first));
+    let path = format!("/api/v3/orgs/octo-org/repos?{query}&page=2");
+    let second = [
+        repository(&server, "repo-100", false),
+        repository(&server, "repo-101", false),
+    ];
+    server.route("GET", &path, 200, json!(second));
+    for name in ["repo-100", "repo-101"] {
+        let path = format!("/api/v3/repos/octo-org/{name}/commits");
+        server.route("GET", &path, 200, json!([]));
+    }
+
+    let remote = GitHubRemote::new(&config(&server)).await.unwrap();
+    let repos = remote
+        .list_repos(ListReposInfo {
+            owner: Some("octo-org".to_string()),
+            limit: Some(50),
+            page: Some(3),
+            commits: CommitHistory::Latest,
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+
+    let names: Vec<_> = repos.iter().map(|x| x.name.as_str()).collect();
+    assert_eq!(names, ["repo-100", "repo-101"]);
+    assert_eq!(
+        server
+            .requests_to("GET", "/api/v3/orgs/octo-org/repos")
+            .len(),
+        2
+    );
+    // Commits are only fetched for the repositories on the requested page.
+    assert!(server
+        .requests_to("GET", "/api/v3/repos/octo-org/repo-0/commits")
+        .is_empty());
+}
+
+#[tokio::test]
+async fn create_repo_in_organization() {
+    let server = MockServer::start().await;
+    let mut repo = repository(&server, "hello", false);
+    repo["owner"] = json!({
+        "login": "octo-org",
+        "id": 2,
+        "node_id": "MDEyOk9yZ2FuaXphdGlvbjI=",
+        "avatar_url": format!("{}/avatars/octo-org", server.url()),
+        "gravatar_id": "",
+        "url": format!("{}/api/v3/users/octo-org", server.url()),
+        "html_url": format!("{}/octo-org", server.url()),
+        "followers_url": format!("{}/api/v3/users/octo-org/followers", server.url()),
+        "following_url": format!("{}/api/v3/users/octo-org/following", server.url()),
+        "gists_url": format!("{}/api/v3/users/octo-org/gists", server.url()),
+        "starred_url": format!("{}/api/v3/users/octo-org/starred", server.url()),
+        "subscriptions_url": format!("{}/api/v3/users/octo-org/subscriptions", server.url()),
+        "organizations_url": format!("{}/api/v3/users/octo-org/orgs", server.url()),
+        "repos_url": format!("{}/api/v3/users/octo-org/repos", server.url()),
+        "events_url": format!("{}/api/v3/users/octo-org/events", server.url()),
+        "received_events_url": format!("{}/api/v3/users/octo-org/received_events", server.url()),
+        "type": "Organization",
+        "site_admin": false,
+    });
+    server.route("POST", "/api/v3/orgs/octo-org/repos", 201, repo);
+    server.route(
+        "GET",
+        "/api/v3/repos/octo-org/hello/commits",
+        200,
+        json!([]),
+    );
+
+    let remote = GitHubRemote::new(&config(&server)).await.unwrap();
+    let repo = remote
+        .create_repo(RepoCreateInfo {
+            name: "hello".to_string(),
+            owner: Some("octo-org".to_string()),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(repo.owner, "octo-org");
+    assert_eq!(
+        repo.clone_url,
+        format!("{}/octo-org/hello.git", server.url())
+    );
+    assert!(server.requests_to("POST", "/api/v3/user/repos").is_empty());
+}
+
+#[tokio::test]
+async fn list_issues_skips_pull_requests() {
+    let server = MockServer::start().await;
+    let issue = |number: u64, pull_request: Option<Value>| {
+        json!({
+            "number": number,
+            "title": format!("Issue {number}"),
+            "body": null,
+            "state": "open",
+            "user": { "login": "octocat" },
+            "labels": [{ "name": "bug", "color": "d73a4a" }],
+            "assignees": [],
+            "milestone": null,
+            "html_url": format!("{}/octocat/hello/issues/{number}", server.url()),
+            "created_at": "2024-01-02T03:04:05Z",
+            "updated_at": "2024-01-02T03:04:05Z",
+            "closed_at": null,
+            "pull_request": pull_request,
+        })
+    };
+    let issues = json!([issue(1, None), issue(2, Some(json!({ "url": "x" })))]);
+    server.route("GET", "/api/v3/repos/octocat/hello/issues", 200, issues);
+
+    let remote = GitHubRemote::new(&config(&server)).await.unwrap();
+    let list_info = ListIssuesInfo {
+        state: Some(IssueState::Open),
+        labels: vec!["bug".to_string(), "good first issue".to_string()],
+        assignee: Some("hubot".to_string()),
+        ..Default::default()
+    };
+    let issues = remote
+        .list_issues("octocat", "hello", list_info)
+        .await
+        .unwrap();
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0].number, 1);
+    assert_eq!(issues[0].author, "octocat");
+    assert_eq!(issues[0].labels, ["bug"]);
+    let request = &server.requests_to("GET", "/api/v3/repos/octocat/hello/issues")[0];
+    assert!(request.path.contains("state=open"));
+    assert!(request.path.contains("labels=bug%2Cgood%20first%20issue"));
+    assert!(request.path.contains("assignee=hubot"));
+    assert!(!request.path.contains("creator="));
+}
+
+#[tokio::test]
+async fn merge_pull_request_deletes_branch() {
+    let server = MockServer::start().await;
+    let pull = json!({
+        "number": 3,
+        "title": "Fix",
+        "body": null,
+        "state": "closed",
+        "user": { "login": "octocat" },
+        "head": { "ref": "fix", "repo": { "full_name": "octocat/hello" } },
+        "base": { "ref": "main", "repo": { "full_name": "octocat/hello" } },
+        "labels": [],
+        "milestone": null,
+        "html_url": format!("{}/octocat/hello/pull/3", server.url()),
+        "created_at": "2024-01-02T03:04:05Z",
+        "updated_at": "2024-01-03T03:04:05Z",
+        "closed_at": "2024-01-03T03:04:05Z",
+        "merged_at": "2024-01-03T03:04:05Z",
+    });
+    let merged =
+        json!({ "sha": "abc", "merged": true, "message": "Pull Request successfully merged" });
+    server.route(
+        "PUT",
+        "/api/v3/repos/octocat/hello/pulls/3/merge",
+        200,
+        merged,
+    );
+    server.route("GET", "/api/v3/repos/octocat/hello/pulls/3", 200, pull);
+    let branch = "/api/v3/repos/octocat/hello/git/refs/heads/fix";
+    server.route("DELETE", branch, 200, Value::Null);
+
+    let remote = GitHubRemote::new(&config(&server)).await.unwrap();
+    let merge_info = PullRequestMergeInfo {
+        method: MergeMethod::Squash,
+        delete_branch: true,
+    };
+    let pull = remote
+        .merge_pull_request("octocat", "hello", 3, merge_info)
+        .await
+        .unwrap();
+    assert_eq!(pull.state, PullRequestState::Merged);
+    let request = &server.requests_to("PUT", "/api/v3/repos/octocat/hello/pulls/3/merge")[0];
+    assert_eq!(request.json()["merge_method"], "squash");
+    assert_eq!(server.requests_to("DELETE", branch).len(), 1);
+}
+
+/// A release payload with a single asset.
+fn release(server: &MockServer, draft: bool) -> Value {
+    json!({
+        "id": 7,
+        "tag_name": "v1.0.0",
+        "name": "First",
+        "body": "Notes",
+        "draft": draft,
+        "prerelease": false,
+        "author": { "login": "octocat" },
+        "html_url": format!("{}/octocat/hello/releases/tag/v1.0.0", server.url()),
+        "created_at": "2024-01-02T03:04:05Z",
+        "published_at": null,
+        "assets": [{
+            "id": 9,
+            "name": "hello.tar.gz",
+            "size": 6,
+            "browser_download_url":
+                format!("{}/octocat/hello/releases/download/v1.0.0/hello.tar.gz", server.url()),
+        }],
+    })
+}
+
+#[tokio::test]
+async fn delete_release_finds_drafts() {
+    let server = MockServer::start().await;
+    // Drafts aren't attached to their tag yet.
+    let by_tag = "/api/v3/repos/octocat/hello/releases/tags/v1.0.0";
+    server.route("GET", by_tag, 404, json!({ "message": "Not Found" }));
+    let releases = json!([release(&server, true)]);
+    server.route("GET", "/api/v3/repos/octocat/hello/releases", 200, releases);
+    let by_id = "/api/v3/repos/octocat/hello/releases/7";
+    server.route("DELETE", by_id, 204, Value::Null);
+
+    let remote = GitHubRemote::new(&config(&server)).await.unwrap();
+    remote
+        .delete_release("octocat", "hello", "v1.0.0")
+        .await
+        .unwrap();
+    assert_eq!(server.requests_to("DELETE", by_id).len(), 1);
+}
+
+#[tokio::test]
+async fn download_release_asset_uses_api() {
+    let server = MockServer::start().await;
+    let by_tag = "/api/v3/repos/octocat/hello/releases/tags/v1.0.0";
+    server.route("GET", by_tag, 200, release(&server, false));
+    let asset = "/api/v3/repos/octocat/hello/releases/assets/9";
+    server.route("GET", asset, 200, json!("binary"));
+
+    let remote = GitHubRemote::new(&config(&server)).await.unwrap();
+    let data = remote
+        .download_release_asset("octocat", "hello", "v1.0.0", "hello.tar.gz")
+        .await
+        .unwrap();
+    assert_eq!(data, b"\"binary\"");
+    let request = &server.requests_to("GET", asset)[0];
+    assert_eq!(request.header("accept"), Some("application/octet-stream"));
+    assert_eq!(request.header("authorization"), Some("Bearer ghe-token"));
+}
+
+/// A repository owned by `owner`, which is a user or an organization.
+fn owned_repository(server: &MockServer, owner: &str, kind: &str) -> Value {
+    let mut repo = repository(server, "hello", false);
+    let mut user = user(server);
+    user["login"] = owner.into();
+    user["type"] = kind.into();
+    repo["owner"] = user;
+    repo
+}
+
+#[tokio::test]
+async fn protect_branch_sends_every_setting() {
+    let server = MockServer::start().await;
+    let repo = owned_repository(&server, "acme", "Organization");
+    server.route("GET", "/api/v3/repos/acme/hello", 200, repo);
+    let route = "/api/v3/repos/acme/hello/branches/main/protection";
+    server.route("PUT", route, 200, json!({}));
+
+    let remote = GitHubRemote::new(&config(&server)).await.unwrap();
+    let protection = BranchProtection {
+        required_reviews: 1,
+        no_force_push: true,
+        push_allowlist: vec!["octocat".to_string()],
+        ..Default::default()
+    };
+    let unsupported = remote
+        .protect_branch("acme", "hello", "main", protection)
+        .await
+        .unwrap();
+    assert!(unsupported.is_empty());
+    let body = server.requests_to("PUT", route)[0].json();
+    assert_eq!(body["required_status_checks"], Value::Null);
+    assert_eq!(
+        body["required_pull_request_reviews"]["required_approving_review_count"],
+        1
+    );
+    assert_eq!(body["restrictions"]["users"], json!(["octocat"]));
+    assert_eq!(body["allow_force_pushes"], false);
+}
+
+#[tokio::test]
+async fn protect_branch_reports_pushers_on_user_repos() {
+    let server = MockServer::start().await;
+    let repo = owned_repository(&server, "octocat", "User");
+    server.route("GET", "/api/v3/repos/octocat/hello", 200, repo);
+    let route = "/api/v3/repos/octocat/hello/branches/main/protection";
+    server.route("PUT", route, 200, json!({}));
+
+    let remote = GitHubRemote::new(&config(&server)).await.unwrap();
+    let protection = BranchProtection {
+        push_allowlist: vec!["octocat".to_string()],
+        ..Default::default()
+    };
+    let unsupported = remote
+        .protect_branch("octocat", "hello", "main", protection)
+        .await
+        .unwrap();
+    assert_eq!(unsupported, vec!["restricted pushers"]);
+    let body = server.requests_to("PUT", route)[0].json();
+    assert_eq!(body["restrictions"], Value::Null);
+}
+
+#[tokio::test]
+async fn create_annotated_tag_points_ref_at_tag_object() {
+    let server = MockServer::start().await;
+    let url = format!("{}/api/v3/repos/octocat/hello", server.url());
+    let commit = json!({
+        "url": format!("{url}/commits/abc123"),
+        "sha": "abc123",
+        "node_id": "C_abc123",
+        "html_url": format!("{}/octocat/hello/commit/abc123", server.url()),
+        "comments_url": format!("{url}/commits/abc123/comments"),
+        "commit": {
+            "url": format!("{url}/git/commits/abc123"),
+            "author": null,
+            "committer": null,
+            "message": "Fix",
+            "comment_count": 0,
+            "tree": { "sha": "789abc", "url": format!("{url}/git/trees/789abc") },
+        },
+        "author": null,
+        "committer": null,
+        "parents": [],
+    });
+    server.route("GET", "/api/v3/repos/octocat/hello/commits/v1", 200, commit);
+    let tags = "/api/v3/repos/octocat/hello/git/tags";
+    server.route("POST", tags, 201, json!({ "sha": "def456" }));
+    let refs = "/api/v3/repos/octocat/hello/git/refs";
+    server.route("POST", refs, 201, json!({}));
+
+    let remote = GitHubRemote::new(&config(&server)).await.unwrap();
+    let tag = TagCreateInfo {
+        name: "v1.0.0".to_string(),
+        target: Some("v1".to_string()),
+        message: Some("First".to_string()),
+    };
+    let tag = remote.create_tag("octocat", "hello", tag).await.unwrap();
+    assert_eq!(tag.sha, "abc123");
+    assert_eq!(
+        server.requests_to("POST", tags)[0].json()["object"],
+        "abc123"
+    );
+    let git_ref = server.requests_to("POST", refs)[0].json();
+    assert_eq!(
+        git_ref,
+        json!({ "ref": "refs/tags/v1.0.0", "sha": "def456" })
+    );
+}
+
+#[tokio::test]
+async fn update_webhook_keeps_config_it_does_not_send() {
+    let server = MockServer::start().await;
+    let route = "/api/v3/repos/octocat/hello/hooks/7";
+    let config_route = "/api/v3/repos/octocat/hello/hooks/7/config";
+    server.route("PATCH", config_route, 200, json!({}));
+    let hook = json!({
+        "id": 7,
+        "active": true,
+        "events": ["create", "delete", "gollum"],
+        "config": { "url": "https://ci.example.com/hook", "content_type": "form" },
+    });
+    server.route("PATCH", route, 200, hook);
+
+    let remote = GitHubRemote::new(&config(&server)).await.unwrap();
+    let update = WebhookUpdateInfo {
+        secret: Some("hunter2".to_string()),
+        events: Some(vec![WebhookEvent::Tag, WebhookEvent::Wiki]),
+        ..Default::default()
+    };
+    let hook = remote
+        .update_webhook("octocat", "hello", 7, update)
+        .await
+        .unwrap();
+    assert_eq!(hook.events, vec![WebhookEvent::Tag, WebhookEvent::Wiki]);
+    assert_eq!(hook.content_type, WebhookContentType::Form);
+    // Only the secret is replaced, the URL and content type are left alone.
+    assert_eq!(
+        server.requests_to("PATCH", config_route)[0].json(),
+        json!({ "secret": "hunter2" })
+    );
+    assert_eq!(
+        server.requests_to("PATCH", route)[0].json(),
+        json!({ "events": ["create", "delete", "gollum"] })
+    );
+}
+
+#[tokio::test]
+async fn add_deploy_key_is_read_only_by_default() {
+    let server = MockServer::start().await;
+    let route = "/api/v3/repos/octocat/hello/keys";
+    let key = json!({
+        "id": 3,
+        "title": "ci",
+        "key": "ssh-ed25519 AAAAC3NzaC1lZDI1NTE5AAAAI",
+        "read_only": true,
+        "verified": true,
+        "created_at": "2024-01-01T00:00:00Z",
+    });
+    server.route("POST", route, 201, key);
+
+    let remote = GitHubRemote::new(&config(&server)).await.unwrap();
+    let key = SshKeyCreateInfo {
+        title: "ci".to_string(),
+        key: "ssh-ed25519 AAAAC3NzaC1lZDI1NTE5AAAAI ci@build".to_string(),
+    };
+    let key = remote
+        .add_deploy_key("octocat", "hello", key, true)
+        .await
+        .unwrap();
+    assert_eq!(key.id, 3);
+    assert!(key.read_only);
+    assert_eq!(
+        server.requests_to("POST", route)[0].json()["read_only"],
+        true
+    );
+}
@@ -0,0 +1,519 @@
+//! Tests for [LocalRemote], and end-to-end tests of the `repo` commands using a local remote.
+//!
+//! Everything happens in temporary directories, so these tests don't need network access.
+
+use std::{
+    path::{Path, PathBuf},
+    process::Command,
+    sync::Once,
+};
+
+use gritty::{
+    error::ErrorKind,
+    remote::{
+        local::LocalRemote, Auth, CloneProtocol, ListReposInfo, Remote, RemoteConfig,
+        RepoCreateInfo, RepoForkOption, Repository,
+    },
+};
+
+/// Create an empty temporary directory for a test.
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join(format!("local-{name}"));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+/// Commits need an identity, which may not be configured on the machine running the tests.
+fn git_identity() {
+    static INIT: Once = Once::new();
+    INIT.call_once(|| {
+        for (key, value) in [
+            ("GIT_AUTHOR_NAME", "Alice"),
+            ("GIT_AUTHOR_EMAIL", "alice@example.com"),
+            ("GIT_COMMITTER_NAME", "Alice"),
+            ("GIT_COMMITTER_EMAIL", "alice@example.com"),
+        ] {
+            std::env::set_var(key, value);
+        }
+    });
+}
+
+fn git(dir: &Path, args: &[&str]) {
+    let status = Command::new("git")
+        .current_dir(dir)
+        .args(args)
+        .status()
+        .unwrap();
+    assert!(status.success(), "git {} failed", args.join(" "));
+}
+
+async fn remote(root: &Path) -> LocalRemote {
+    git_identity();
+    LocalRemote::new(&RemoteConfig::new(
+        "alice",
+        CloneProtocol::SSH,
+        root.to_string_lossy(),
+        Auth::None,
+    ))
+    .await
+    .unwrap()
+}
+
+async fn create(remote: &LocalRemote, name: &str, private: bool) -> Repository {
+    remote
+        .create_repo(RepoCreateInfo {
+            name: name.to_string(),
+            private,
+            ..Default::default()
+        })
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn create_and_get_repo() {
+    let root = temp_dir("create");
+    let remote = remote(&root).await;
+    assert!(remote.check_auth().await.unwrap());
+
+    let repo = remote
+        .create_repo(RepoCreateInfo {
+            name: "hello".to_string(),
+            description: Some("Says hello".to_string()),
+            init: true,
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+
+    let path = root.join("alice/hello.git");
+    assert!(path.join("HEAD").exists());
+    assert!(path.join("git-daemon-export-ok").exists());
+    assert_eq!(repo.owner, "alice");
+    assert_eq!(repo.description.as_deref(), Some("Says hello"));
+    assert!(!repo.private);
+    assert!(!repo.fork);
+    assert_eq!(repo.clone_url, path.to_string_lossy());
+    assert_eq!(repo.last_commits.len(), 1);
+    assert_eq!(repo.last_commits[0].message, "Initial commit");
+    assert_eq!(repo.last_commits[0].author, "Alice");
+
+    let info = remote.get_repo_info("alice", "hello").await.unwrap();
+    assert_eq!(info, repo);
+
+    let err = remote
+        .create_repo(RepoCreateInfo {
+            name: "hello".to_string(),
+            ..Default::default()
+        })
+        .await
+        .unwrap_err();
+    assert!(err.message.contains("already exists"));
+}
+
+#[tokio::test]
+async fn list_repos_filters_private_and_forks() {
+    let root = temp_dir("list");
+    let remote = remote(&root).await;
+    assert!(remote
+        .list_repos(ListReposInfo::default())
+        .await
+        .unwrap()
+        .is_empty());
+
+    create(&remote, "public", false).await;
+    let private = create(&remote, "secret", true).await;
+    assert!(private.private);
+    assert!(private.last_commits.is_empty());
+    remote
+        .create_fork(RepoForkOption {
+            owner: "alice".to_string(),
+            repo: "public".to_string(),
+            name: Some("public-fork".to_string()),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+
+    let names = |repos: Vec<Repository>| repos.into_iter().map(|x| x.name).collect::<Vec<_>>();
+    let list = |private, forks| {
+        remote.list_repos(ListReposInfo {
+            owner: None,
+            private,
+            forks,
+            ..Default::default()
+        })
+    };
+    assert_eq!(names(list(false, false).await.unwrap()), ["public"]);
+    assert_eq!(
+        names(list(true, true).await.unwrap()),
+        ["public", "public-fork", "secret"]
+    );
+
+    let err = remote
+        .list_repos(ListReposInfo {
+            owner: Some("bob".to_string()),
+            ..Default::default()
+        })
+        .await
+        .unwrap_err();
+    assert_eq!(err.kind, ErrorKind::NotFound);
+}
+
+#[tokio::test]
+async fn fork_into_organization() {
+    let root = temp_dir("fork");
+    let remote = remote(&root).await;
+    remote
+        .create_repo(RepoCreateInfo {
+            name: "tool".to_string(),
+            description: Some("A tool".to_string()),
+            init: true,
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+
+    let fork = remote
+        .create_fork(RepoForkOption {
+            owner: "alice".to_string(),
+            repo: "tool".to_string(),
+            organization: Some("team".to_string()),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(fork.owner, "team");
+    assert_eq!(fork.name, "tool");
+    assert!(fork.fork);
+    assert_eq!(fork.description.as_deref(), Some("A tool"));
+    assert_eq!(fork.last_commits.len(), 1);
+    assert!(root.join("team/tool.git").exists());
+}
+
+#[tokio::test]
+async fn delete_repo() {
+    let root = temp_dir("delete");
+    let remote = remote(&root).await;
+    create(&remote, "doomed", false).await;
+
+    remote.delete_repo("alice", "doomed").await.unwrap();
+    assert!(!root.join("alice/doomed.git").exists());
+
+    let err = remote.delete_repo("alice", "doomed").await.unwrap_err();
+    assert_eq!(err.kind, ErrorKind::NotFound);
+    let err = remote.get_repo_info("alice", "..").await.unwrap_err();
+    assert!(err.message.contains("Invalid repository path"));
+}
+
+#[tokio::test]
+async fn rename_repo() {
+    let root = temp_dir("rename");
+    let remote = remote(&root).await;
+    create(&remote, "old", false).await;
+    create(&remote, "taken", false).await;
+
+    let repo = remote.rename_repo("alice", "old", "new").await.unwrap();
+    assert_eq!(repo.name, "new");
+    assert_eq!(repo.full_name, "alice/new");
+    assert!(!root.join("alice/old.git").exists());
+    assert!(root.join("alice/new.git").exists());
+
+    let err = remote
+        .rename_repo("alice", "new", "taken")
+        .await
+        .unwrap_err();
+    assert!(err.message.contains("already exists"));
+}
+
+#[tokio::test]
+async fn transfer_repo() {
+    let root = temp_dir("transfer");
+    let remote = remote(&root).await;
+    create(&remote, "tool", false).await;
+
+    let repo = remote.transfer_repo("alice", "tool", "team").await.unwrap();
+    assert_eq!(repo.owner, "team");
+    assert_eq!(repo.full_name, "team/tool");
+    assert!(!root.join("alice/tool.git").exists());
+    assert!(root.join("team/tool.git").exists());
+}
+
+/// Write a config with a local remote for every name and root directory to `dir/config.toml`.
+fn write_config(dir: &Path, remotes: &[(&str, &Path)]) {
+    let mut config = String::from("[secrets]\ntype = \"Plaintext\"\n");
+    for (name, root) in remotes {
+        config += &format!(
+            r#"
+[remotes.{name}]
+provider = "Local"
+url = "{}"
+username = "alice"
+clone_protocol = "ssh"
+"#,
+            root.to_string_lossy()
+        );
+    }
+    std::fs::write(dir.join("config.toml"), config).unwrap();
+}
+
+/// Run the gritty binary with the config written by [write_config].
+fn gritty(dir: &Path, args: &[&str]) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_gritty"))
+        .current_dir(dir)
+        .env("NO_COLOR", "1")
+        .args(["--config", "config.toml", "--color", "never"])
+        .args(args)
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+    assert!(
+        output.status.success(),
+        "gritty {} failed: {stdout}{}",
+        args.join(" "),
+        String::from_utf8_lossy(&output.stderr)
+    );
+    stdout
+}
+
+#[tokio::test]
+async fn repo_commands_end_to_end() {
+    git_identity();
+    let dir = temp_dir("e2e");
+    let root = dir.join("server");
+    std::fs::create_dir_all(&root).unwrap();
+    write_config(&dir, &[("local", &root)]);
+
+    let output = gritty(&dir, &["repo", "create", "project", "local", "--init"]);
+    assert!(output.contains("Repository created at"));
+
+    gritty(&dir, &["repo", "clone", "project", "local"]);
+    let clone = dir.join("project");
+    assert!(clone.join("README.md").exists());
+    std::fs::write(clone.join("main.rs"), "fn main() {}\n").unwrap();
+    git(&clone, &["add", "main.rs"]);
+    git(&clone, &["commit", "--quiet", "-m", "Add main"]);
+    git(&clone, &["push", "--quiet", "origin", "HEAD"]);
+
+    let output = gritty(&dir, &["repo", "list", "local", "--format", "json"]);
+    let repos: Vec<Repository> = serde_json::from_str(&output).unwrap();
+    assert_eq!(repos.len(), 1);
+    assert!(repos[0].last_commits.is_empty());
+    assert!(repos[0].pushed_at.is_some());
+
+    let args = ["repo", "list", "local", "--commits", "--format", "json"];
+    let repos: Vec<Repository> = serde_json::from_str(&gritty(&dir, &args)).unwrap();
+    assert_eq!(repos[0].last_commits.len(), 1);
+    assert_eq!(repos[0].last_commits[0].message, "Add main");
+
+    gritty(
+        &dir,
+        &["repo", "fork", "alice/project", "local", "--target", "fork"],
+    );
+    let output = gritty(&dir, &["repo", "list", "local", "--forks"]);
+    assert!(output.contains("^ "));
+
+    gritty(&dir, &["repo", "delete", "project", "local", "--force"]);
+    assert!(!root.join("alice/project.git").exists());
+
+    let output = gritty(&dir, &["auth", "status"]);
+    assert!(output.contains("authenticated"));
+}
+
+#[tokio::test]
+async fn migrate_mirrors_between_local_remotes() {
+    git_identity();
+    let dir = temp_dir("migrate");
+    let (old, new) = (dir.join("old"), dir.join("new"));
+    write_config(&dir, &[("old", &old), ("new", &new)]);
+    let source = remote(&old).await;
+    create(&source, "tool", false).await;
+    let clone = dir.join("tool");
+    source
+        .clone_repo("alice", "tool", &clone.to_string_lossy(), false)
+        .await
+        .unwrap();
+    git(
+        &clone,
+        &["commit", "--quiet", "--allow-empty", "-m", "Release"],
+    );
+    git(&clone, &["tag", "v1.0"]);
+    git(&clone, &["push", "--quiet", "origin", "HEAD", "v1.0"]);
+    create(&source, "docs", true).await;
+
+    let output = gritty(
+        &dir,
+        &["repo", "migrate", "tool", "--from", "old", "--to", "new"],
+    );
+    assert!(output.contains("migrated to alice/tool"));
+    let target = remote(&new).await;
+    let repo = target.get_repo_info("alice", "tool").await.unwrap();
+    assert_eq!(repo.last_commits[0].message, "Release");
+    let tags = Command::new("git")
+        .args(["-C", &new.join("alice/tool.git").to_string_lossy(), "tag"])
+        .output()
+        .unwrap();
+    assert_eq!(String::from_utf8_lossy(&tags.stdout).trim(), "v1.0");
+
+    std::fs::remove_dir_all(new.join("alice/tool.git")).unwrap();
+    gritty(
+        &dir,
+        &["repo", "migrate", "--all", "--from", "old", "--to", "new"],
+    );
+    let list_info = ListReposInfo {
+        private: true,
+        ..Default::default()
+    };
+    let repos = target.list_repos(list_info).await.unwrap();
+    let names: Vec<_> = repos.iter().map(|x| x.name.as_str()).collect();
+    assert_eq!(names, ["docs", "tool"]);
+    assert!(repos[0].private);
+}
+
+#[tokio::test]
+async fn sync_fork_without_server_support() {
+    let dir = temp_dir("sync");
+    let root = dir.join("server");
+    write_config(&dir, &[("local", &root)]);
+    let remote = remote(&root).await;
+    remote
+        .create_repo(RepoCreateInfo {
+            name: "tool".to_string(),
+            init: true,
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    remote
+        .create_fork(RepoForkOption {
+            owner: "alice".to_string(),
+            repo: "tool".to_string(),
+            organization: Some("team".to_string()),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    let push_commit = |owner: &str, message: &str| {
+        let clone = dir.join(format!("{owner}-clone"));
+        let _ = std::fs::remove_dir_all(&clone);
+        let url = root.join(format!("{owner}/tool.git"));
+        git(
+            &dir,
+            &[
+                "clone",
+                "--quiet",
+                &url.to_string_lossy(),
+                &clone.to_string_lossy(),
+            ],
+        );
+        git(
+            &clone,
+            &["commit", "--quiet", "--allow-empty", "-m", message],
+        );
+        git(&clone, &["push", "--quiet", "origin", "HEAD"]);
+    };
+
+    push_commit("alice", "Fix");
+    let output = gritty(&dir, &["repo", "sync", "team/tool", "local"]);
+    assert!(output.contains("team/tool: fast-forwarded"), "{output}");
+    let fork = remote.get_repo_info("team", "tool").await.unwrap();
+    assert_eq!(fork.last_commits[0].message, "Fix");
+
+    let output = gritty(&dir, &["repo", "sync", "team/tool", "local"]);
+    assert!(output.contains("already up to date"), "{output}");
+
+    push_commit("alice", "Upstream");
+    push_commit("team", "Fork");
+    let output = gritty(&dir, &["repo", "sync", "team/tool", "local"]);
+    assert!(output.contains("diverged"), "{output}");
+}
+
+#[tokio::test]
+async fn backup_mirrors_and_updates_repositories() {
+    let dir = temp_dir("backup");
+    let root = dir.join("server");
+    write_config(&dir, &[("local", &root)]);
+    let remote = remote(&root).await;
+    create(&remote, "tool", false).await;
+    create(&remote, "docs", true).await;
+    let clone = dir.join("tool");
+    remote
+        .clone_repo("alice", "tool", &clone.to_string_lossy(), false)
+        .await
+        .unwrap();
+    git(
+        &clone,
+        &["commit", "--quiet", "--allow-empty", "-m", "Start"],
+    );
+    git(
+        &clone,
+        &["push", "--quiet", "origin", "HEAD", "HEAD:feature"],
+    );
+
+    let output = gritty(&dir, &["backup", "local", "backups", "--jobs", "2"]);
+    assert!(output.contains("Cloned alice/tool"), "{output}");
+    assert!(output.contains("Cloned alice/docs"), "{output}");
+    let mirror = dir.join("backups/local/alice/tool.git");
+    assert!(mirror.join("HEAD").exists());
+    assert!(dir.join("backups/local/alice/docs.git/HEAD").exists());
+
+    git(
+        &clone,
+        &["commit", "--quiet", "--allow-empty", "-m", "Release"],
+    );
+    git(&clone, &["push", "--quiet", "origin", "HEAD", ":feature"]);
+    create(&remote, "broken", false).await;
+    std::fs::create_dir_all(dir.join("backups/local/alice/broken.git")).unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_gritty"))
+        .current_dir(&dir)
+        .args(["--config", "config.toml", "--color", "never"])
+        .args(["backup", "local", "backups"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Updated alice/tool"), "{stdout}");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Failed to back up alice/broken"),
+        "{stderr}"
+    );
+    assert!(
+        stderr.contains("Failed to back up 1 of 3 repositories"),
+        "{stderr}"
+    );
+
+    let refs = Command::new("git")
+        .args(["-C", &mirror.to_string_lossy(), "for-each-ref"])
+        .args(["--format=%(refname) %(subject)"])
+        .output()
+        .unwrap();
+    let refs = String::from_utf8_lossy(&refs.stdout);
+    assert!(refs.contains("Release"), "{refs}");
+    assert!(!refs.contains("refs/heads/feature"), "{refs}");
+
+    let manifest = std::fs::read_to_string(dir.join("backups/local/manifest.json")).unwrap();
+    let manifest: serde_json::Value = serde_json::from_str(&manifest).unwrap();
+    assert_eq!(manifest["remote"], "local");
+    let entries = manifest["repositories"].as_array().unwrap();
+    let status = |name: &str| {
+        let entry = entries
+            .iter()
+            .find(|x| x["repository"]["name"] == name)
+            .unwrap();
+        (entry["path"].clone(), entry["status"].clone())
+    };
+    assert_eq!(status("tool"), ("alice/tool.git".into(), "updated".into()));
+    assert_eq!(status("broken").1, "failed");
+    let docs: Repository = serde_json::from_value(
+        entries
+            .iter()
+            .find(|x| x["repository"]["name"] == "docs")
+            .unwrap()["repository"]
+            .clone(),
+    )
+    .unwrap();
+    assert!(docs.private);
+}
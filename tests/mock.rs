@@ -0,0 +1,77 @@
+//! Tests for [MockRemote], as used by library users through the `mock` feature.
+
+use gritty::{
+    error::ErrorKind,
+    remote::{
+        mock::{Call, Failure, Method, MockRemote},
+        CommitHistory, ListReposInfo, Remote, RepoCreateInfo, RepoForkOption, Repository,
+    },
+};
+
+#[tokio::test]
+async fn stores_repositories_forks_and_commits() {
+    let remote = MockRemote::default();
+    remote.insert(Repository {
+        name: "tool".to_string(),
+        owner: "upstream".to_string(),
+        ..Default::default()
+    });
+    let commit = remote.commit("upstream", "tool", "Add feature").unwrap();
+
+    let fork = remote
+        .create_fork(RepoForkOption {
+            owner: "upstream".to_string(),
+            repo: "tool".to_string(),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(fork.owner, "mock");
+    assert_eq!(fork.last_commits, [commit]);
+    assert_eq!(fork.clone_url, "https://mock.invalid/mock/tool.git");
+
+    let repos = remote
+        .list_repos(ListReposInfo {
+            forks: true,
+            commits: CommitHistory::Latest,
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(repos, [fork]);
+
+    remote.delete_repo("mock", "tool").await.unwrap();
+    assert_eq!(remote.parent("mock", "tool"), None);
+    let err = remote.delete_repo("mock", "tool").await.unwrap_err();
+    assert_eq!(err.kind, ErrorKind::NotFound);
+}
+
+#[tokio::test]
+async fn injected_failures() {
+    let remote = MockRemote::default();
+    assert!(remote.check_auth().await.unwrap());
+    remote.fail(Method::CheckAuth, Failure::Unauthorized);
+    assert!(!remote.check_auth().await.unwrap());
+
+    remote.fail(Method::CreateRepo, Failure::EmptyRepository);
+    let info = RepoCreateInfo {
+        name: "hello".to_string(),
+        ..Default::default()
+    };
+    let err = remote.create_repo(info.clone()).await.unwrap_err();
+    assert_eq!(err.status, Some(409));
+    assert!(remote.repo("mock", "hello").is_none());
+
+    remote.succeed(Method::CreateRepo);
+    remote.create_repo(info.clone()).await.unwrap();
+
+    assert_eq!(
+        remote.calls(),
+        [
+            Call::CheckAuth,
+            Call::CheckAuth,
+            Call::CreateRepo(info.clone()),
+            Call::CreateRepo(info),
+        ]
+    );
+}